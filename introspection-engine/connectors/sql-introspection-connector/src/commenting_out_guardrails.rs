@@ -94,7 +94,6 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
     for model in &mut datamodel.models {
         for field in &mut model.fields {
             if let FieldType::Unsupported(tpe) = &field.field_type {
-                field.is_commented_out = true;
                 unsupported_types.push(ModelAndFieldAndType {
                     model: model.name.clone(),
                     field: field.name.clone(),
@@ -104,28 +103,33 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         }
     }
 
-    // use unsupported types to drop @@id / @@unique /@@index
-    for mf in &unsupported_types {
-        let model = datamodel.find_model_mut(&mf.model).unwrap();
-        model.indices.retain(|i| !i.fields.contains(&mf.field));
-        if model.id_fields.contains(&mf.field) {
-            model.id_fields = vec![]
+    // models without uniques / ids, or with ids on unsupported types
+    for model in &mut datamodel.models {
+        let is_unsupported = |field_names: &Vec<String>| {
+            field_names
+                .iter()
+                .filter_map(|name| model.find_field(name))
+                .any(|f| f.field_type.is_unsupported())
         };
-    }
 
-    // models without uniques / ids
-    for model in &mut datamodel.models {
-        if model.id_fields.is_empty()
-            && !model
+        let id_is_unsupported =
+            model.fields.iter().any(|f| f.is_id && f.field_type.is_unsupported()) || is_unsupported(&model.id_fields);
+
+        let has_identifier = (!model.id_fields.is_empty() && !is_unsupported(&model.id_fields))
+            || model
                 .fields
                 .iter()
-                .any(|f| (f.is_id || f.is_unique) && !f.is_commented_out)
-            && !model.indices.iter().any(|i| i.is_unique())
-            && !models_with_one_to_one_relation.contains(&model.name)
-        {
-            model.is_commented_out = true;
+                .any(|f| (f.is_id || f.is_unique) && !f.is_commented_out && !f.field_type.is_unsupported())
+            || model
+                .indices
+                .iter()
+                .any(|i| i.is_unique() && !is_unsupported(&i.fields))
+            || models_with_one_to_one_relation.contains(&model.name);
+
+        if id_is_unsupported || !has_identifier {
+            model.is_ignored = true;
             model.documentation = Some(
-                "The underlying table does not contain a unique identifier and can therefore currently not be handled."
+                "The underlying table does not contain a unique identifier and can therefore currently not be handled by the Prisma Client."
                     .to_string(),
             );
             models_without_identifiers.push(Model {
@@ -134,12 +138,14 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         }
     }
 
-    // remove their backrelations
+    // ignore their backrelations
     for model_without_identifier in &models_without_identifiers {
         for model in &mut datamodel.models {
-            model
-                .fields
-                .retain(|f| !f.points_to_model(model_without_identifier.model.as_ref()));
+            for field in &mut model.fields {
+                if field.points_to_model(model_without_identifier.model.as_ref()) {
+                    field.is_ignored = true;
+                }
+            }
         }
     }
 
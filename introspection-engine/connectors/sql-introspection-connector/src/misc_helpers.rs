@@ -3,6 +3,8 @@ use datamodel::{
     Datamodel, DefaultNames, DefaultValue as DMLDef, Field, FieldArity, FieldType, IndexDefinition, Model,
     OnDeleteStrategy, RelationInfo, ScalarType, ValueGenerator as VG,
 };
+use once_cell::sync::Lazy;
+use regex::Regex;
 use sql_schema_describer::{
     Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType, SqlSchema, Table,
 };
@@ -110,6 +112,7 @@ pub fn calculate_many_to_many_field(foreign_key: &ForeignKey, relation_name: Str
         is_generated: false,
         is_updated_at: false,
         is_commented_out: false,
+        is_ignored: false,
     }
 }
 
@@ -130,10 +133,6 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
 pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> Field {
     debug!("Handling column {:?}", column);
     let field_type = calculate_scalar_field_type(&column);
-    let (is_commented_out, documentation) = match field_type {
-        FieldType::Unsupported(_) => (true, Some("This type is currently not supported.".to_string())),
-        _ => (false, None),
-    };
 
     let arity = match column.tpe.arity {
         _ if column.auto_increment && field_type == FieldType::Base(ScalarType::Int, None) => FieldArity::Required,
@@ -154,10 +153,11 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> Field {
         default_value,
         is_unique,
         is_id,
-        documentation,
+        documentation: None,
         is_generated: false,
         is_updated_at: false,
-        is_commented_out,
+        is_commented_out: false,
+        is_ignored: false,
     }
 }
 
@@ -200,6 +200,7 @@ pub(crate) fn calculate_relation_field(
         is_generated: false,
         is_updated_at: false,
         is_commented_out: false,
+        is_ignored: false,
     })
 }
 
@@ -259,6 +260,7 @@ pub(crate) fn calculate_backrelation_field(
                 is_generated: false,
                 is_updated_at: false,
                 is_commented_out: false,
+                is_ignored: false,
             })
         }
     }
@@ -271,12 +273,27 @@ pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldAri
         (_, ColumnTypeFamily::Int) if is_sequence(column, table) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (Some(SQLDef::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (Some(SQLDef::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
+        (Some(SQLDef::DBGENERATED(default)), ColumnTypeFamily::Int) if sequence_name(default).is_some() => {
+            Some(DMLDef::Expression(VG::new_sequence(sequence_name(default).unwrap())))
+        }
         (Some(SQLDef::DBGENERATED(_)), _) => Some(DMLDef::Expression(VG::new_dbgenerated())),
         (Some(SQLDef::VALUE(val)), _) => Some(DMLDef::Single(val.clone())),
         _ => None,
     }
 }
 
+/// Matches `nextval('seq'::regclass)` defaults, with optional quoting and schema qualification.
+static NEXTVAL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^nextval\('(?:"?[^'".]+"?\.)?"?([^'"]+)"?'::regclass\)$"#).unwrap());
+
+/// The name of the sequence a `nextval()` default draws from.
+fn sequence_name(default: &str) -> Option<&str> {
+    NEXTVAL_REGEX
+        .captures(default)
+        .and_then(|captures| captures.get(1))
+        .map(|name| name.as_str())
+}
+
 pub(crate) fn is_id(column: &Column, table: &Table) -> bool {
     table
         .primary_key
@@ -339,7 +356,16 @@ pub(crate) fn calculate_scalar_field_type(column: &Column) -> FieldType {
         ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
         ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
-        x => FieldType::Unsupported(x.to_string()),
+        _ => FieldType::Unsupported(native_type(column).to_owned()),
+    }
+}
+
+/// The native type of a column, as it is kept in `Unsupported("...")`. Postgres prefixes the
+/// element types of arrays with an underscore.
+fn native_type(column: &Column) -> &str {
+    match column.tpe.arity {
+        ColumnArity::List => column.tpe.full_data_type.trim_start_matches('_'),
+        _ => &column.tpe.full_data_type,
     }
 }
 
@@ -33,6 +33,8 @@ pub fn enrich(old_data_model: &Datamodel, introspection_result: &mut Introspecti
     // enum types on scalar fields              -> done         yes
     // enum values                              -> done         yes
     // enum values in defaults                  -> done         yes
+    // @@ignore on models                       -> done         yes
+    // @ignore on fields                        -> done         yes
 
     //todo introspection sometimes has to use @maps itself, which the user can then manually change
     // this has to be handled explicitly -.-also influences the naming in the warnings
@@ -327,6 +329,23 @@ pub fn enrich(old_data_model: &Datamodel, introspection_result: &mut Introspecti
         }
     }
 
+    // @@ignore on models and @ignore on fields
+    {
+        for model in &mut new_data_model.models {
+            if let Some(old_model) = old_data_model.find_model(&model.name) {
+                if old_model.is_ignored {
+                    model.is_ignored = true;
+                }
+
+                for field in &mut model.fields {
+                    if old_model.find_field(&field.name).map(|f| f.is_ignored).unwrap_or(false) {
+                        field.is_ignored = true;
+                    }
+                }
+            }
+        }
+    }
+
     //todo @defaults
     // potential error: what if there was a db default before and then it got removed, now re-introspection makes it virtual
     // you could not get rid of it
@@ -57,7 +57,7 @@ impl EnumAndValue {
 pub fn warning_models_without_identifier(affected: &Vec<Model>) -> Warning {
     Warning {
         code: 1,
-        message: "These models do not have a unique identifier or id and are therefore commented out.".into(),
+        message: "These models do not have a unique identifier or id and are therefore ignored.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
@@ -74,7 +74,7 @@ pub fn warning_fields_with_empty_names(affected: &Vec<ModelAndField>) -> Warning
 pub fn warning_unsupported_types(affected: &Vec<ModelAndFieldAndType>) -> Warning {
     Warning {
         code: 3,
-        message: "These fields were commented out because we currently do not support their types.".into(),
+        message: "These fields are not supported by the Prisma Client, because Prisma currently does not support their types.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
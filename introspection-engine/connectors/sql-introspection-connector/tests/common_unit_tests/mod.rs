@@ -30,31 +30,28 @@ fn a_data_model_can_be_generated_from_a_schema() {
             database_name: None,
             name: "Table1".to_string(),
             documentation: Some(
-                "The underlying table does not contain a unique identifier and can therefore currently not be handled."
+                "The underlying table does not contain a unique identifier and can therefore currently not be handled by the Prisma Client."
                     .to_string(),
             ),
             is_embedded: false,
             is_generated: false,
-            is_commented_out: true,
+            is_commented_out: false,
+            is_ignored: true,
             indices: vec![],
             id_fields: vec![],
             fields: col_types
                 .iter()
                 .map(|col_type| {
-                    let (field_type, is_commented_out, documentation) = match col_type {
-                        ColumnTypeFamily::Boolean => (FieldType::Base(ScalarType::Boolean, None), false, None),
-                        ColumnTypeFamily::DateTime => (FieldType::Base(ScalarType::DateTime, None), false, None),
-                        ColumnTypeFamily::Float => (FieldType::Base(ScalarType::Float, None), false, None),
-                        ColumnTypeFamily::Int => (FieldType::Base(ScalarType::Int, None), false, None),
-                        ColumnTypeFamily::String => (FieldType::Base(ScalarType::String, None), false, None),
-                        ColumnTypeFamily::Enum(name) => (FieldType::Enum(name.clone()), false, None),
-                        ColumnTypeFamily::Uuid => (FieldType::Base(ScalarType::String, None), false, None),
-                        ColumnTypeFamily::Json => (FieldType::Base(ScalarType::Json, None), false, None),
-                        x => (
-                            FieldType::Unsupported(x.to_string()),
-                            true,
-                            Some("This type is currently not supported.".to_string()),
-                        ),
+                    let field_type = match col_type {
+                        ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
+                        ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
+                        ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
+                        ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
+                        ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
+                        ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
+                        ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
+                        ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
+                        x => FieldType::Unsupported(x.to_string()),
                     };
                     Field {
                         name: col_type.to_string(),
@@ -64,15 +61,17 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         default_value: None,
                         is_unique: false,
                         is_id: false,
-                        documentation,
+                        documentation: None,
                         is_generated: false,
                         is_updated_at: false,
-                        is_commented_out,
+                        is_commented_out: false,
+                        is_ignored: false,
                     }
                 })
                 .collect(),
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -82,7 +81,10 @@ fn a_data_model_can_be_generated_from_a_schema() {
                 .iter()
                 .map(|family| Column {
                     name: family.to_string(),
-                    tpe: ColumnType::pure(family.to_owned(), ColumnArity::Nullable),
+                    tpe: ColumnType {
+                        full_data_type: family.to_string(),
+                        ..ColumnType::pure(family.to_owned(), ColumnArity::Nullable)
+                    },
                     default: None,
                     auto_increment: false,
                 })
@@ -108,6 +110,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             documentation: None,
             is_embedded: false,
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "optional".to_string(),
@@ -121,6 +124,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "required".to_string(),
@@ -134,6 +138,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "list".to_string(),
@@ -147,6 +152,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
@@ -154,6 +160,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -204,6 +211,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             documentation: None,
             is_embedded: false,
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "no_default".to_string(),
@@ -217,6 +225,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "int_default".to_string(),
@@ -230,6 +239,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "bool_default".to_string(),
@@ -243,6 +253,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "float_default".to_string(),
@@ -256,6 +267,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "string_default".to_string(),
@@ -269,6 +281,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
@@ -280,6 +293,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -344,6 +358,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 documentation: None,
                 is_embedded: false,
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -356,6 +371,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 }],
                 is_generated: false,
                 indices: vec![],
@@ -368,6 +384,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 documentation: None,
                 is_embedded: false,
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -380,6 +397,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 }],
                 is_generated: false,
                 indices: vec![],
@@ -392,6 +410,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 documentation: None,
                 is_embedded: false,
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -404,6 +423,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 }],
                 is_generated: false,
                 indices: vec![],
@@ -411,6 +431,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -504,6 +525,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             documentation: None,
             is_embedded: false,
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "non_unique".to_string(),
@@ -517,6 +539,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "unique".to_string(),
@@ -530,6 +553,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
@@ -537,6 +561,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -582,6 +607,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 documentation: None,
                 is_embedded: false,
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -595,6 +621,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "name".to_string(),
@@ -608,6 +635,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "User".to_string(),
@@ -627,6 +655,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
@@ -639,6 +668,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 documentation: None,
                 is_embedded: false,
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -652,6 +682,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "city_id".to_string(),
@@ -665,6 +696,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "city_name".to_string(),
@@ -678,6 +710,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "City".to_string(),
@@ -697,6 +730,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
@@ -705,6 +739,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -823,6 +858,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             documentation: None,
             is_embedded: false,
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "id".to_string(),
@@ -836,6 +872,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "name".to_string(),
@@ -849,6 +886,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "lastname".to_string(),
@@ -862,6 +900,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
@@ -873,6 +912,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -949,6 +989,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 documentation: None,
                 is_embedded: false,
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -962,6 +1003,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "name".to_string(),
@@ -975,6 +1017,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "User".to_string(),
@@ -994,6 +1037,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
@@ -1006,6 +1050,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 documentation: None,
                 is_embedded: false,
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -1019,6 +1064,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "city_id".to_string(),
@@ -1032,6 +1078,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "City".to_string(),
@@ -1051,6 +1098,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
@@ -1059,6 +1107,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -1177,6 +1226,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
                 },
             ],
         }],
+        composite_types: vec![],
     };
 
     let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -292,7 +292,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
 }
 
 #[test_each_connector(tags("mysql"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -308,10 +308,25 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestA
         })
         .await;
 
-    let dm = "// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n\n  // @@index([user_id], name: \"user_id\")\n// }\n\nmodel User {\n  id Int @default(autoincrement()) @id\n}\n";
+    let dm = r#"
+        /// The underlying table does not contain a unique identifier and can therefore currently not be handled by the Prisma Client.
+        model Post {
+            id      Int
+            user_id Int
+            User    User @relation(fields: [user_id], references: [id])
+
+            @@ignore
+            @@index([user_id], name: "user_id")
+        }
+
+        model User {
+            id      Int    @id @default(autoincrement())
+            Post    Post[] @ignore
+        }
+    "#;
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, dm);
+    custom_assert(&result, dm);
 }
 
 //todo maybe need to split due to
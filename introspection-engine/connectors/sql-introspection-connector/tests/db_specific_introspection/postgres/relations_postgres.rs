@@ -744,11 +744,23 @@ async fn introspecting_a_relation_based_on_an_unsupported_type_should_drop_it(ap
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":3,\"message\":\"These fields were commented out because we currently do not support their types.\",\"affected\":[{\"model\":\"Post\",\"field\":\"user_network_mac\",\"tpe\":\"macaddr\"},{\"model\":\"User\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":3,\"message\":\"These fields are not supported by the Prisma Client, because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Post\",\"field\":\"user_network_mac\",\"tpe\":\"macaddr\"},{\"model\":\"User\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
+    let dm = r#"
+            model Post {
+              id               Int                     @id @default(autoincrement())
+              user_network_mac Unsupported("macaddr")?
+            }
+
+            model User {
+              id          Int                    @id @default(autoincrement())
+              network_mac Unsupported("macaddr") @unique
+            }
+        "#;
+
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "model Post {\n  id                  Int      @default(autoincrement()) @id\n  // This type is currently not supported.\n  // user_network_mac macaddr?\n}\n\nmodel User {\n  id             Int     @default(autoincrement()) @id\n  // This type is currently not supported.\n  // network_mac macaddr @unique\n}\n");
+    custom_assert(&result, dm);
 }
 
 // #[test_each_connector(tags("postgres"))]
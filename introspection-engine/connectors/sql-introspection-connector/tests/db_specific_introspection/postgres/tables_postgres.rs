@@ -287,7 +287,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -300,10 +300,24 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestA
         })
         .await;
 
-    let dm = "// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n\nmodel User {\n  id Int @default(autoincrement()) @id\n}\n";
+    let dm = r#"
+        /// The underlying table does not contain a unique identifier and can therefore currently not be handled by the Prisma Client.
+        model Post {
+            id      Int
+            user_id Int
+            User    User @relation(fields: [user_id], references: [id])
+
+            @@ignore
+        }
+
+        model User {
+            id      Int    @id @default(autoincrement())
+            Post    Post[] @ignore
+        }
+    "#;
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, dm);
+    custom_assert(&result, dm);
 }
 
 #[test_each_connector(tags("postgres"))]
@@ -407,7 +421,7 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
                 t.inject_custom("int_static Integer DEFAULT 2");
                 t.inject_custom("int_serial Serial4");
                 t.inject_custom("int_function Integer DEFAULT EXTRACT(year from TIMESTAMP '2001-02-16 20:38:40')");
-                t.inject_custom("int_sequence Integer DEFAULT nextval('test_seq')"); // todo this is not recognized as autoincrement
+                t.inject_custom("int_sequence Integer DEFAULT nextval('test_seq')");
                 t.inject_custom("float_static Float DEFAULT 1.43");
                 t.inject_custom("boolean_static Boolean DEFAULT true");
                 t.inject_custom("datetime_now_current TIMESTAMP DEFAULT CURRENT_TIMESTAMP");
@@ -419,6 +433,11 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
         .await;
 
     let dm = r#"
+            datasource postgres {
+                provider = "postgres"
+                url = "postgresql://asdlj"
+            }
+
             model Test {
                 boolean_static          Boolean?    @default(true)
                 datetime_now            DateTime?   @default(now())
@@ -428,7 +447,7 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
                 float_static            Float?      @default(1.43)
                 id                      Int         @default(autoincrement()) @id
                 int_function            Int?        @default(dbgenerated())
-                int_sequence            Int?        @default(dbgenerated())
+                int_sequence            Int?        @default(sequence("test_seq"))
                 int_serial              Int        @default(autoincrement())
                 int_static              Int?        @default(2)
                 string_function         String?     @default(dbgenerated())
@@ -449,7 +468,47 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_an_unsupported_type_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_shared_named_sequence_should_work(api: &TestApi) {
+    let sequence = "CREATE SEQUENCE \"Numbers\" START 1";
+    api.database().execute_raw(sequence, &[]).await.unwrap();
+
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Invoice", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("number Integer NOT NULL DEFAULT nextval('\"Numbers\"')");
+            });
+            migration.create_table("CreditNote", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("number Integer NOT NULL DEFAULT nextval('\"Numbers\"')");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            datasource postgres {
+                provider = "postgres"
+                url = "postgresql://asdlj"
+            }
+
+            model CreditNote {
+                id      Int @id @default(autoincrement())
+                number  Int @default(sequence("Numbers"))
+            }
+
+            model Invoice {
+                id      Int @id @default(autoincrement())
+                number  Int @default(sequence("Numbers"))
+            }
+        "#;
+
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_an_unsupported_type_should_keep_its_native_type(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -464,11 +523,19 @@ async fn introspecting_an_unsupported_type_should_comment_it_out(api: &TestApi)
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":3,\"message\":\"These fields were commented out because we currently do not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":3,\"message\":\"These fields are not supported by the Prisma Client, because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
+    let dm = r#"
+            model Test {
+              id           Int                     @id @default(autoincrement())
+              network_inet String?
+              network_mac  Unsupported("macaddr")?
+            }
+        "#;
+
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "model Test {\n  id             Int      @default(autoincrement()) @id\n  network_inet   String?\n  // This type is currently not supported.\n  // network_mac macaddr?\n}\n");
+    custom_assert(&result, dm);
 }
 
 #[test_each_connector(tags("postgres"))]
@@ -541,7 +608,7 @@ async fn introspecting_default_values_on_lists_should_be_ignored(api: &TestApi)
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_an_unsupported_type_should_and_commenting_it_out_should_also_drop_its_usages(api: &TestApi) {
+async fn introspecting_an_unsupported_type_in_an_id_should_ignore_the_model_and_keep_its_usages(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -559,15 +626,29 @@ async fn introspecting_an_unsupported_type_should_and_commenting_it_out_should_a
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":3,\"message\":\"These fields were commented out because we currently do not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":1,\"message\":\"These models do not have a unique identifier or id and are therefore ignored.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields are not supported by the Prisma Client, because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
+    let dm = r#"
+            /// The underlying table does not contain a unique identifier and can therefore currently not be handled by the Prisma Client.
+            model Test {
+              dummy       Int
+              id          Int                    @unique
+              network_mac Unsupported("macaddr")
+
+              @@id([network_mac, dummy])
+              @@unique([network_mac, dummy], name: "unique")
+              @@index([network_mac, dummy], name: "non_unique")
+              @@ignore
+            }
+        "#;
+
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "model Test {\n  dummy          Int\n  id             Int     @unique\n  // This type is currently not supported.\n  // network_mac macaddr\n}\n");
+    custom_assert(&result, dm);
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_with_only_an_unsupported_id_type_should_ignore_it(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -581,9 +662,19 @@ async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_i
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":1,\"message\":\"These models do not have a unique identifier or id and are therefore commented out.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields were commented out because we currently do not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":1,\"message\":\"These models do not have a unique identifier or id and are therefore ignored.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields are not supported by the Prisma Client, because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
+    let dm = r#"
+            /// The underlying table does not contain a unique identifier and can therefore currently not be handled by the Prisma Client.
+            model Test {
+              dummy       Int
+              network_mac Unsupported("macaddr") @id
+
+              @@ignore
+            }
+        "#;
+
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Test {\n  // dummy       Int\n  // This type is currently not supported.\n  // network_mac macaddr @id\n// }\n");
+    custom_assert(&result, dm);
 }
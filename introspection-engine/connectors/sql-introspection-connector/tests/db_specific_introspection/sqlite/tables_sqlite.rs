@@ -258,7 +258,7 @@ async fn introspecting_a_table_with_optional_autoincrement_should_work(api: &Tes
 }
 
 #[test_each_connector(tags("sqlite"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -274,10 +274,24 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestA
         })
         .await;
 
-    let dm = "model User {\n  id Int @default(autoincrement()) @id\n}\n\n// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n";
+    let dm = r#"
+        model User {
+            id      Int    @id @default(autoincrement())
+            Post    Post[] @ignore
+        }
+
+        /// The underlying table does not contain a unique identifier and can therefore currently not be handled by the Prisma Client.
+        model Post {
+            id      Int
+            user_id Int
+            User    User @relation(fields: [user_id], references: [id])
+
+            @@ignore
+        }
+    "#;
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, dm);
+    custom_assert(&result, dm);
 }
 
 #[test_each_connector(tags("sqlite"))]
@@ -597,6 +597,51 @@ async fn re_introspecting_custom_virtual_relation_field_names(api: &TestApi) {
     custom_assert(&result, final_dm);
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn re_introspecting_ignored_models_and_fields(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("secret", types::text());
+            });
+
+            migration.create_table("Audit", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await;
+
+    let input_dm = r#"
+            model User {
+               id               Int         @id @default(autoincrement())
+               secret           String      @ignore
+            }
+
+            model Audit {
+               id               Int         @id @default(autoincrement())
+
+               @@ignore
+            }
+        "#;
+
+    let final_dm = r#"
+            model Audit {
+               id               Int         @id @default(autoincrement())
+
+               @@ignore
+            }
+
+            model User {
+               id               Int         @id @default(autoincrement())
+               secret           String      @ignore
+            }
+        "#;
+    let result = dbg!(api.re_introspect(input_dm).await);
+    custom_assert(&result, final_dm);
+}
+
 // #[test_each_connector(tags("postgres"))]
 // async fn re_introspecting_virtual_default(api: &TestApi) {
 //     let barrel = api.barrel();
@@ -115,6 +115,7 @@ impl BuiltinConnectors {
                 ConnectorCapability::ScalarLists,
                 ConnectorCapability::Enums,
                 ConnectorCapability::Json,
+                ConnectorCapability::Sequences,
             ],
         }
     }
@@ -33,6 +33,10 @@ pub trait Connector: Send + Sync {
     fn supports_json(&self) -> bool {
         self.has_capability(ConnectorCapability::Json)
     }
+
+    fn supports_sequences(&self) -> bool {
+        self.has_capability(ConnectorCapability::Sequences)
+    }
 }
 
 /// Not all Databases are created equal. Hence connectors for our datasources support different capabilities.
@@ -43,6 +47,7 @@ pub enum ConnectorCapability {
     RelationsOverNonUniqueCriteria,
     Enums,
     Json,
+    Sequences,
 }
 
 #[derive(Debug, Clone, PartialEq)]
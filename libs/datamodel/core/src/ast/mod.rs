@@ -1,5 +1,6 @@
 mod argument;
 mod comment;
+mod composite_type;
 mod directive;
 mod r#enum;
 mod expression;
@@ -18,6 +19,7 @@ pub mod renderer;
 
 pub use argument::*;
 pub use comment::*;
+pub use composite_type::*;
 pub use directive::*;
 pub use expression::*;
 pub use field::*;
@@ -43,7 +45,7 @@ pub use traits::*;
 /// Schema = Datamodel + Generators + Datasources
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaAst {
-    /// All models, enums, datasources, generators or type aliases
+    /// All models, enums, composite types, datasources, generators or type aliases
     pub tops: Vec<Top>,
 }
 
@@ -85,6 +87,17 @@ impl SchemaAst {
         })
     }
 
+    pub fn find_composite_type(&self, type_name: &str) -> Option<&CompositeType> {
+        self.composite_types().into_iter().find(|t| t.name.name == type_name)
+    }
+
+    pub fn find_composite_type_mut(&mut self, type_name: &str) -> Option<&mut CompositeType> {
+        self.tops.iter_mut().find_map(|top| match top {
+            Top::CompositeType(composite_type) if composite_type.name.name == type_name => Some(composite_type),
+            _ => None,
+        })
+    }
+
     pub fn find_enum(&self, enum_name: &str) -> Option<&Enum> {
         self.enums().into_iter().find(|e| e.name.name == enum_name)
     }
@@ -119,6 +132,16 @@ impl SchemaAst {
             .collect()
     }
 
+    pub fn composite_types(&self) -> Vec<&CompositeType> {
+        self.tops
+            .iter()
+            .filter_map(|top| match top {
+                Top::CompositeType(x) => Some(x),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn enums(&self) -> Vec<&Enum> {
         self.tops
             .iter()
@@ -190,6 +190,7 @@ fn parse_directive_arg(token: &pest::iterators::Pair<'_, Rule>) -> Argument {
 fn parse_base_type(token: &pest::iterators::Pair<'_, Rule>) -> String {
     match_first! { token, current,
         Rule::non_empty_identifier => current.as_str().to_string(),
+        Rule::unsupported_type => current.as_str().to_string(),
         _ => unreachable!("Encountered impossible type during parsing: {:?}", current.tokens())
     }
 }
@@ -271,19 +272,13 @@ fn parsing_catch_all(token: &pest::iterators::Pair<'_, Rule>) {
 }
 
 // Model parsing
-fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCollection> {
-    let mut errors = ErrorCollection::new();
+fn parse_model(token: &pest::iterators::Pair<'_, Rule>, errors: &mut ErrorCollection) -> Model {
     let mut name: Option<Identifier> = None;
     let mut directives: Vec<Directive> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comment: Option<Comment> = None;
 
     match_children! { token, current,
-        Rule::TYPE_KEYWORD => { errors.push(
-            DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()))
-        ) },
         Rule::non_empty_identifier => name = Some(current.to_id()),
         Rule::directive => directives.push(parse_directive(&current)),
         Rule::field_declaration => {
@@ -300,20 +295,19 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
                 "This line is not a valid field or directive definition.",
                 Span::from_pest(current.as_span()))
         ) },
+        Rule::MISSING_BLOCK_CLOSE => errors.push(new_missing_block_close_error(&name)),
         _ => parsing_catch_all(&current)
     }
 
-    errors.ok()?;
-
     match name {
-        Some(name) => Ok(Model {
+        Some(name) => Model {
             name,
             fields,
             directives,
             documentation: comment,
             span: Span::from_pest(token.as_span()),
             commented_out: false,
-        }),
+        },
         _ => panic!(
             "Encountered impossible model declaration during parsing: {:?}",
             token.as_str()
@@ -321,9 +315,66 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
     }
 }
 
+// Composite type parsing
+fn parse_composite_type(token: &pest::iterators::Pair<'_, Rule>, errors: &mut ErrorCollection) -> CompositeType {
+    let mut name: Option<Identifier> = None;
+    let mut type_keyword_span: Option<Span> = None;
+    let mut fields: Vec<Field> = vec![];
+    let mut comment: Option<Comment> = None;
+
+    match_children! { token, current,
+        Rule::TYPE_KEYWORD => type_keyword_span = Some(Span::from_pest(current.as_span())),
+        Rule::non_empty_identifier => name = Some(current.to_id()),
+        Rule::directive => { errors.push(
+            DatamodelError::new_validation_error(
+                "Composite types do not support block level attributes.",
+                Span::from_pest(current.as_span()))
+        ) },
+        Rule::field_declaration => {
+            match parse_field(&name.as_ref().unwrap().name, &current) {
+                Ok(field) => fields.push(field),
+                Err(err) => errors.push(err)
+            }
+        },
+        Rule::comment_block => {
+            comment = Some(parse_comment_block(&current))
+        },
+        Rule::BLOCK_LEVEL_CATCH_ALL => { errors.push(
+            DatamodelError::new_validation_error(
+                "This line is not a valid field definition.",
+                Span::from_pest(current.as_span()))
+        ) },
+        Rule::MISSING_BLOCK_CLOSE => errors.push(new_missing_block_close_error(&name)),
+        _ => parsing_catch_all(&current)
+    }
+
+    // A `type` block with an id is a model from the days when models were declared with `type`.
+    if fields
+        .iter()
+        .any(|field| field.directives.iter().any(|directive| directive.name.name == "id"))
+    {
+        errors.push(DatamodelError::new_legacy_parser_error(
+            "Model declarations have to be indicated with the `model` keyword.",
+            type_keyword_span.unwrap_or_else(Span::empty),
+        ));
+    }
+
+    match name {
+        Some(name) => CompositeType {
+            name,
+            fields,
+            documentation: comment,
+            span: Span::from_pest(token.as_span()),
+        },
+        _ => panic!(
+            "Encountered impossible composite type declaration during parsing: {:?}",
+            token.as_str()
+        ),
+    }
+}
+
 // Enum parsing
-fn parse_enum(token: &pest::iterators::Pair<'_, Rule>) -> Result<Enum, ErrorCollection> {
-    let mut errors = ErrorCollection::new();
+fn parse_enum(token: &pest::iterators::Pair<'_, Rule>, errors: &mut ErrorCollection) -> Enum {
     let mut name: Option<Identifier> = None;
     let mut directives: Vec<Directive> = vec![];
     let mut values: Vec<EnumValue> = vec![];
@@ -346,19 +397,18 @@ fn parse_enum(token: &pest::iterators::Pair<'_, Rule>) -> Result<Enum, ErrorColl
                 "This line is not a enum value definition.",
                 Span::from_pest(current.as_span()))
         ) },
+        Rule::MISSING_BLOCK_CLOSE => errors.push(new_missing_block_close_error(&name)),
         _ => parsing_catch_all(&current)
     }
 
-    errors.ok()?;
-
     match name {
-        Some(name) => Ok(Enum {
+        Some(name) => Enum {
             name,
             values,
             directives,
             documentation: comment,
             span: Span::from_pest(token.as_span()),
-        }),
+        },
         _ => panic!(
             "Encountered impossible enum declaration during parsing, name is missing: {:?}",
             token.as_str()
@@ -432,8 +482,7 @@ fn parse_key_value(token: &pest::iterators::Pair<'_, Rule>) -> Argument {
 }
 
 // Source parsing
-fn parse_source(token: &pest::iterators::Pair<'_, Rule>) -> Result<SourceConfig, ErrorCollection> {
-    let mut errors = ErrorCollection::new();
+fn parse_source(token: &pest::iterators::Pair<'_, Rule>, errors: &mut ErrorCollection) -> SourceConfig {
     let mut name: Option<Identifier> = None;
     let mut properties: Vec<Argument> = vec![];
     let mut comment: Option<Comment> = None;
@@ -449,18 +498,17 @@ fn parse_source(token: &pest::iterators::Pair<'_, Rule>) -> Result<SourceConfig,
                 "This line is not a valid definition within a datasource.",
                 Span::from_pest(current.as_span()))
         ) },
+        Rule::MISSING_BLOCK_CLOSE => errors.push(new_missing_block_close_error(&name)),
         _ => parsing_catch_all(&current)
     };
 
-    errors.ok()?;
-
     match name {
-        Some(name) => Ok(SourceConfig {
+        Some(name) => SourceConfig {
             name,
             properties,
             documentation: comment,
             span: Span::from_pest(token.as_span()),
-        }),
+        },
         _ => panic!(
             "Encountered impossible source declaration during parsing, name is missing: {:?}",
             token.as_str()
@@ -469,8 +517,7 @@ fn parse_source(token: &pest::iterators::Pair<'_, Rule>) -> Result<SourceConfig,
 }
 
 // Generator parsing
-fn parse_generator(token: &pest::iterators::Pair<'_, Rule>) -> Result<GeneratorConfig, ErrorCollection> {
-    let mut errors = ErrorCollection::new();
+fn parse_generator(token: &pest::iterators::Pair<'_, Rule>, errors: &mut ErrorCollection) -> GeneratorConfig {
     let mut name: Option<Identifier> = None;
     let mut properties: Vec<Argument> = vec![];
     let mut comments: Vec<String> = Vec::new();
@@ -485,18 +532,17 @@ fn parse_generator(token: &pest::iterators::Pair<'_, Rule>) -> Result<GeneratorC
                 "This line is not a valid definition within a generator.",
                 Span::from_pest(current.as_span()))
         ) },
+        Rule::MISSING_BLOCK_CLOSE => errors.push(new_missing_block_close_error(&name)),
         _ => parsing_catch_all(&current)
     };
 
-    errors.ok()?;
-
     match name {
-        Some(name) => Ok(GeneratorConfig {
+        Some(name) => GeneratorConfig {
             name,
             properties,
             documentation: doc_comments_to_string(&comments),
             span: Span::from_pest(token.as_span()),
-        }),
+        },
         _ => panic!(
             "Encountered impossible generator declaration during parsing, name is missing: {:?}",
             token.as_str()
@@ -504,6 +550,13 @@ fn parse_generator(token: &pest::iterators::Pair<'_, Rule>) -> Result<GeneratorC
     }
 }
 
+/// The error for a block that is not closed, on the name of the block.
+fn new_missing_block_close_error(name: &Option<Identifier>) -> DatamodelError {
+    let span = name.as_ref().map(|name| name.span).unwrap_or_else(Span::empty);
+
+    DatamodelError::new_validation_error("This block is not closed. It is missing a `}`.", span)
+}
+
 // Custom type parsing
 fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
     let mut name: Option<Identifier> = None;
@@ -563,6 +616,17 @@ fn parse_comment_block(token: &pest::iterators::Pair<'_, Rule>) -> Comment {
 
 /// Parses a Prisma V2 datamodel document into an internal AST representation.
 pub fn parse(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
+    let (ast, errors) = parse_with_recovery(datamodel_string);
+
+    errors.ok()?;
+
+    Ok(ast)
+}
+
+/// Parses a Prisma V2 datamodel document, recovering from syntax errors at block, field and enum
+/// value boundaries. Returns the AST of the well-formed parts of the document, and all the syntax
+/// errors.
+pub fn parse_with_recovery(datamodel_string: &str) -> (SchemaAst, ErrorCollection) {
     let mut errors = ErrorCollection::new();
     let datamodel_result = PrismaDatamodelParser::parse(Rule::schema, datamodel_string);
 
@@ -572,21 +636,14 @@ pub fn parse(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
             let mut top_level_definitions: Vec<Top> = vec![];
 
             match_children! { datamodel, current,
-                Rule::model_declaration => match parse_model(&current) {
-                    Ok(model) => top_level_definitions.push(Top::Model(model)),
-                    Err(mut err) => errors.append(&mut err)
-                },
-                Rule::enum_declaration => match parse_enum(&current){
-                    Ok(enm) => top_level_definitions.push(Top::Enum(enm)),
-                    Err(mut err) => errors.append(&mut err)
+                Rule::model_declaration => top_level_definitions.push(Top::Model(parse_model(&current, &mut errors))),
+                Rule::enum_declaration => top_level_definitions.push(Top::Enum(parse_enum(&current, &mut errors))),
+                Rule::composite_type_declaration => {
+                    top_level_definitions.push(Top::CompositeType(parse_composite_type(&current, &mut errors)))
                 },
-                Rule::source_block => match parse_source(&current) {
-                    Ok(source) => top_level_definitions.push(Top::Source(source)),
-                    Err(mut err) => errors.append(&mut err)
-                },
-                Rule::generator_block => match parse_generator(&current) {
-                    Ok(generator) => top_level_definitions.push(Top::Generator(generator)),
-                    Err(mut err) => errors.append(&mut err)
+                Rule::source_block => top_level_definitions.push(Top::Source(parse_source(&current, &mut errors))),
+                Rule::generator_block => {
+                    top_level_definitions.push(Top::Generator(parse_generator(&current, &mut errors)))
                 },
                 Rule::type_alias => top_level_definitions.push(Top::Type(parse_type(&current))),
                 Rule::comment_block => (),
@@ -606,11 +663,11 @@ pub fn parse(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
                 _ => panic!("Encountered impossible datamodel declaration during parsing: {:?}", current.tokens())
             }
 
-            errors.ok()?;
-
-            Ok(SchemaAst {
+            let ast = SchemaAst {
                 tops: top_level_definitions,
-            })
+            };
+
+            (ast, errors)
         }
         Err(err) => {
             dbg!(&err);
@@ -625,7 +682,7 @@ pub fn parse(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
             };
 
             errors.push(DatamodelError::new_parser_error(&expected, location));
-            Err(errors)
+            (SchemaAst { tops: Vec::new() }, errors)
         }
     }
 }
@@ -641,6 +698,7 @@ pub fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
 fn rule_to_string(rule: Rule) -> &'static str {
     match rule {
         Rule::model_declaration => "model declaration",
+        Rule::composite_type_declaration => "composite type declaration",
         Rule::enum_declaration => "enum declaration",
         Rule::source_block => "source definition",
         Rule::generator_block => "generator definition",
@@ -666,6 +724,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::optional_type => "optional type",
         Rule::base_type => "type",
         Rule::list_type => "list type",
+        Rule::unsupported_type => "unsupported type",
         Rule::field_type => "field type",
         Rule::field_declaration => "field declaration",
         Rule::type_alias => "type alias",
@@ -682,6 +741,8 @@ fn rule_to_string(rule: Rule) -> &'static str {
         // Those are helpers, so we get better error messages:
         Rule::BLOCK_OPEN => "Start of block (\"{\")",
         Rule::BLOCK_CLOSE => "End of block (\"}\")",
+        Rule::MISSING_BLOCK_CLOSE => "End of block (\"}\")",
+        Rule::BLOCK_START => "Start of a model, composite type, enum, datasource or generator",
         Rule::MODEL_KEYWORD => "\"model\" keyword",
         Rule::TYPE_KEYWORD => "\"type\" keyword",
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
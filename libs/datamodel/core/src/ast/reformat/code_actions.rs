@@ -0,0 +1,333 @@
+use super::edits::*;
+use super::missing_fields::find_all_missing_fields;
+use crate::ast::{self, renderer::*, Span};
+use crate::common::WritableString;
+use crate::error::DatamodelError;
+use crate::validator::directive::{
+    new_builtin_enum_directives, new_builtin_enum_value_directives, new_builtin_field_directives,
+    new_builtin_model_directives,
+};
+
+/// A fix for a problem of a schema, made of edits of the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeAction {
+    /// A description of the fix, to show to the user.
+    pub title: String,
+    /// The span of the problem the action fixes. For a validation error, it is the span of the error.
+    pub span: Span,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Finds the code actions for a schema:
+///
+/// - The back relation fields that are added to the datamodel during validation are added to the
+///   schema, as the formatter does.
+/// - The scalar fields in the `fields` argument of `@relation` that do not exist are added, with the
+///   type of the fields they reference.
+/// - A misspelled attribute is replaced with the known attribute with the closest name.
+/// - A model without unique criteria gets an `@id`.
+/// - The scalar fields of a one to one relation are made unique, as the relation can't have more
+///   than one record on each side.
+pub fn code_actions(schema: &str) -> Vec<CodeAction> {
+    let (ast, _) = ast::parser::parse_with_recovery(schema);
+    let mut actions = Vec::new();
+
+    if let Err(errors) = crate::parse_datamodel_and_ignore_datasource_urls(schema) {
+        for error in errors.to_iter() {
+            match error {
+                DatamodelError::DirectiveNotKnownError { directive_name, span } => {
+                    actions.extend(fix_directive_name(&ast, directive_name, *span))
+                }
+                DatamodelError::ModelWithoutUniqueCriteriaError { model_name, span, .. } => {
+                    actions.extend(add_id(schema, &ast, model_name, *span))
+                }
+                DatamodelError::UnknownRelationFieldsError { span, .. } => {
+                    actions.extend(add_relation_scalar_fields(&ast, *span))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    add_missing_back_relation_fields(schema, &ast, &mut actions);
+    make_one_to_one_relation_fields_unique(schema, &ast, &mut actions);
+
+    actions
+}
+
+fn fix_directive_name(schema_ast: &ast::SchemaAst, directive_name: &str, span: Span) -> Option<CodeAction> {
+    let is_at = |directives: &[ast::Directive]| directives.iter().any(|directive| directive.name.span == span);
+    let fields = schema_ast
+        .models()
+        .into_iter()
+        .flat_map(|model| model.fields.iter())
+        .chain(schema_ast.types());
+
+    let (known_names, prefix): (Vec<String>, &str) = if schema_ast.models().iter().any(|model| is_at(&model.directives))
+    {
+        (names(new_builtin_model_directives().directive_names()), "@@")
+    } else if schema_ast.enums().iter().any(|r#enum| is_at(&r#enum.directives)) {
+        (names(new_builtin_enum_directives().directive_names()), "@@")
+    } else if schema_ast
+        .enums()
+        .iter()
+        .flat_map(|r#enum| r#enum.values.iter())
+        .any(|value| is_at(&value.directives))
+    {
+        (names(new_builtin_enum_value_directives().directive_names()), "@")
+    } else if fields.into_iter().any(|field| is_at(&field.directives)) {
+        (names(new_builtin_field_directives().directive_names()), "@")
+    } else {
+        return None;
+    };
+
+    let (distance, closest_name) = known_names
+        .iter()
+        .map(|name| (edit_distance(directive_name, name), name))
+        .min()?;
+
+    if distance > 2 || distance >= directive_name.len() {
+        return None;
+    }
+
+    Some(CodeAction {
+        title: format!(
+            "Replace `{}{}` with `{}{}`.",
+            prefix, directive_name, prefix, closest_name
+        ),
+        span,
+        edits: vec![TextEdit::replace(span, closest_name.clone())],
+    })
+}
+
+fn names<'a>(names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    names.map(String::from).collect()
+}
+
+fn add_id(schema: &str, schema_ast: &ast::SchemaAst, model_name: &str, span: Span) -> Option<CodeAction> {
+    let model = schema_ast.find_model(model_name)?;
+
+    // A required field called `id` becomes the id. Otherwise, an id field is added.
+    let edit = match model.fields.iter().find(|field| field.name.name == "id") {
+        Some(field) if field.arity == ast::FieldArity::Required => add_field_attribute(field, "@id"),
+        Some(_) => return None,
+        None => {
+            let first_line_end = schema[model.name.span.end..].find('\n')? + model.name.span.end + 1;
+
+            TextEdit::insert(first_line_end, "  id Int @id @default(autoincrement())\n".to_owned())
+        }
+    };
+
+    Some(CodeAction {
+        title: format!("Add an id to the model `{}`.", model_name),
+        span,
+        edits: vec![edit],
+    })
+}
+
+fn add_relation_scalar_fields(schema_ast: &ast::SchemaAst, span: Span) -> Option<CodeAction> {
+    let model = schema_ast
+        .models()
+        .into_iter()
+        .find(|model| model.fields.iter().any(|field| field.span == span))?;
+    let field = model.fields.iter().find(|field| field.span == span)?;
+    let related_model = schema_ast.find_model(&field.field_type.name)?;
+    let relation = find_directive(&field.directives, "relation")?;
+    let fields = field_names(&find_argument(relation, "fields")?.value);
+    let references = field_names(&find_argument(relation, "references")?.value);
+    let arity = if field.arity == ast::FieldArity::Optional {
+        "?"
+    } else {
+        ""
+    };
+
+    let mut new_fields = String::new();
+
+    for (index, name) in fields.iter().enumerate() {
+        if model.fields.iter().any(|field| field.name.name == *name) {
+            continue;
+        }
+
+        let referenced_field = related_model
+            .fields
+            .iter()
+            .find(|field| Some(&field.name.name.as_str()) == references.get(index))?;
+
+        new_fields.push_str(&format!("  {} {}{}\n", name, referenced_field.field_type.name, arity));
+    }
+
+    if new_fields.is_empty() {
+        return None;
+    }
+
+    Some(CodeAction {
+        title: format!("Add the scalar fields of the relation field `{}`.", field.name.name),
+        span,
+        edits: vec![TextEdit::insert(field.span.end, new_fields)],
+    })
+}
+
+fn add_missing_back_relation_fields(schema: &str, schema_ast: &ast::SchemaAst, actions: &mut Vec<CodeAction>) {
+    let missing_fields = match find_all_missing_fields(schema) {
+        Ok(missing_fields) => missing_fields,
+        Err(_) => return,
+    };
+
+    for missing_field in missing_fields {
+        let model = match schema_ast.find_model(&missing_field.model) {
+            Some(model) => model,
+            None => continue,
+        };
+
+        actions.push(CodeAction {
+            title: format!(
+                "Add the back relation field `{}` to the model `{}`.",
+                missing_field.field.name.name, model.name.name
+            ),
+            span: model.name.span,
+            edits: vec![add_line_to_model(schema, model, &render_field(&missing_field.field))],
+        });
+    }
+}
+
+fn make_one_to_one_relation_fields_unique(schema: &str, schema_ast: &ast::SchemaAst, actions: &mut Vec<CodeAction>) {
+    for model in schema_ast.models() {
+        for field in model.fields.iter().filter(|field| field.arity != ast::FieldArity::List) {
+            let relation = match find_directive(&field.directives, "relation") {
+                Some(relation) => relation,
+                None => continue,
+            };
+
+            let fields = match find_argument(relation, "fields") {
+                Some(argument) => field_names(&argument.value),
+                None => continue,
+            };
+
+            if fields.is_empty() || !is_one_to_one(schema_ast, model, field) || is_unique(model, &fields) {
+                continue;
+            }
+
+            let edit = match (
+                fields.as_slice(),
+                model.fields.iter().find(|f| f.name.name == fields[0]),
+            ) {
+                ([_], Some(scalar_field)) => add_field_attribute(scalar_field, "@unique"),
+                ([_], None) => continue,
+                _ => add_line_to_model(schema, model, &format!("@@unique([{}])", fields.join(", "))),
+            };
+
+            actions.push(CodeAction {
+                title: format!(
+                    "Make the scalar fields of the one to one relation field `{}` unique.",
+                    field.name.name
+                ),
+                span: field.span,
+                edits: vec![edit],
+            });
+        }
+    }
+}
+
+/// Whether the relation field is the singular side of a one to one relation, i.e. the back relation
+/// field in the related model is singular as well.
+fn is_one_to_one(schema_ast: &ast::SchemaAst, model: &ast::Model, field: &ast::Field) -> bool {
+    let related_model = match schema_ast.find_model(&field.field_type.name) {
+        Some(related_model) => related_model,
+        None => return false,
+    };
+
+    let name = relation_name(field);
+
+    related_model
+        .fields
+        .iter()
+        .filter(|related_field| related_field.field_type.name == model.name.name)
+        .filter(|related_field| {
+            related_field.name.name != field.name.name || related_model.name.name != model.name.name
+        })
+        .filter(|related_field| relation_name(related_field) == name)
+        .any(|related_field| related_field.arity != ast::FieldArity::List)
+}
+
+fn relation_name(field: &ast::Field) -> Option<&str> {
+    let relation = find_directive(&field.directives, "relation")?;
+
+    relation
+        .arguments
+        .iter()
+        .find(|argument| argument.is_unnamed() || argument.name.name == "name")
+        .and_then(|argument| match &argument.value {
+            ast::Expression::StringValue(name, _) => Some(name.as_str()),
+            _ => None,
+        })
+}
+
+/// Whether the fields are the id or a unique criteria of the model.
+fn is_unique(model: &ast::Model, fields: &[&str]) -> bool {
+    let is_single_field_unique = |name: &str| {
+        model
+            .fields
+            .iter()
+            .find(|field| field.name.name == name)
+            .map(|field| {
+                find_directive(&field.directives, "id").is_some()
+                    || find_directive(&field.directives, "unique").is_some()
+            })
+            .unwrap_or(false)
+    };
+
+    if fields.len() == 1 && is_single_field_unique(fields[0]) {
+        return true;
+    }
+
+    model
+        .directives
+        .iter()
+        .filter(|directive| directive.name.name == "id" || directive.name.name == "unique")
+        .filter_map(|directive| {
+            // The fields are the default argument of `@@id` and `@@unique`.
+            directive
+                .arguments
+                .iter()
+                .find(|argument| argument.is_unnamed() || argument.name.name == "fields")
+        })
+        .any(|argument| {
+            let unique_fields = field_names(&argument.value);
+
+            unique_fields.len() == fields.len() && fields.iter().all(|field| unique_fields.contains(field))
+        })
+}
+
+fn render_field(field: &ast::Field) -> String {
+    let mut rendered = WritableString::new();
+
+    {
+        let mut renderer = Renderer::new(&mut rendered, 2);
+        let mut table = TableFormat::new();
+
+        Renderer::render_field(&mut table, field, false);
+        table.render(&mut renderer);
+    }
+
+    rendered.into().trim().to_owned()
+}
+
+/// The Levenshtein distance between two names.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + if a_char == *b_char { 0 } else { 1 };
+
+            row.push(substitution.min(previous_row[j + 1] + 1).min(row[j] + 1));
+        }
+
+        previous_row = row;
+    }
+
+    previous_row[b.len()]
+}
@@ -0,0 +1,114 @@
+//! Edits of the text of a schema, for the fixes of the problems found in it. The edits are made
+//! from the AST of the schema, so they keep the rest of the text as it was written.
+
+use crate::ast::{self, Span};
+
+/// A replacement of the text at a span of the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn insert(offset: usize, new_text: String) -> TextEdit {
+        TextEdit {
+            span: Span::new(offset, offset),
+            new_text,
+        }
+    }
+
+    pub fn replace(span: Span, new_text: String) -> TextEdit {
+        TextEdit { span, new_text }
+    }
+
+    pub fn delete(span: Span) -> TextEdit {
+        TextEdit {
+            span,
+            new_text: String::new(),
+        }
+    }
+}
+
+/// Applies the edits to the schema. The edits must not overlap.
+pub fn apply_edits(schema: &str, edits: &[TextEdit]) -> String {
+    let mut edits: Vec<&TextEdit> = edits.iter().collect();
+    let mut result = schema.to_owned();
+
+    // From the end of the schema, so the spans of the remaining edits stay valid.
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.span.start));
+
+    for edit in edits {
+        result.replace_range(edit.span.start..edit.span.end, &edit.new_text);
+    }
+
+    result
+}
+
+/// The edit adding a line, like a field or a block attribute, at the end of a model.
+pub fn add_line_to_model(schema: &str, model: &ast::Model, line: &str) -> TextEdit {
+    TextEdit::insert(start_of_closing_brace_line(schema, model), format!("  {}\n", line))
+}
+
+/// The edit adding an attribute, like `@unique`, after the type and the attributes of a field.
+pub fn add_field_attribute(field: &ast::Field, attribute: &str) -> TextEdit {
+    let end_of_field = field
+        .directives
+        .iter()
+        .map(|directive| directive.span.end)
+        .max()
+        .unwrap_or(field.field_type.span.end);
+
+    TextEdit::insert(end_of_field, format!(" {}", attribute))
+}
+
+/// The edit removing an attribute, with the whitespace before it. A block attribute on its own line
+/// is removed with the line.
+pub fn remove_attribute(schema: &str, directive: &ast::Directive) -> TextEdit {
+    let mut start = directive.span.start;
+    let mut end = directive.span.end;
+
+    while schema[..start].ends_with('@') {
+        start -= 1;
+    }
+
+    start = schema[..start].trim_end_matches(|c: char| c == ' ' || c == '\t').len();
+
+    if schema[..start].ends_with('\n') || start == 0 {
+        if schema[end..].starts_with("\r\n") {
+            end += 2;
+        } else if schema[end..].starts_with('\n') {
+            end += 1;
+        }
+    }
+
+    TextEdit::delete(Span::new(start, end))
+}
+
+pub fn find_directive<'a>(directives: &'a [ast::Directive], name: &str) -> Option<&'a ast::Directive> {
+    directives.iter().find(|directive| directive.name.name == name)
+}
+
+pub fn find_argument<'a>(directive: &'a ast::Directive, name: &str) -> Option<&'a ast::Argument> {
+    directive.arguments.iter().find(|argument| argument.name.name == name)
+}
+
+/// The names in a list of field names, like the `fields` argument of `@relation` or the unnamed
+/// argument of `@@index`.
+pub fn field_names(expression: &ast::Expression) -> Vec<&str> {
+    match expression {
+        ast::Expression::Array(values, _) => values.iter().flat_map(field_names).collect(),
+        ast::Expression::ConstantValue(name, _) => vec![name.as_str()],
+        _ => Vec::new(),
+    }
+}
+
+/// The offset of the start of the line with the closing brace of a model.
+fn start_of_closing_brace_line(schema: &str, model: &ast::Model) -> usize {
+    let closing_brace = model.span.end - 1;
+
+    schema[..closing_brace]
+        .rfind('\n')
+        .map(|newline| newline + 1)
+        .unwrap_or(0)
+}
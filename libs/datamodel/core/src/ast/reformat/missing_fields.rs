@@ -0,0 +1,34 @@
+use crate::error::ErrorCollection;
+
+/// A field that is added to the datamodel during validation, like a back relation field, but is
+/// missing from the schema.
+#[derive(Debug)]
+pub struct MissingField {
+    pub model: String,
+    pub field: crate::ast::Field,
+}
+
+// this finds all auto generated fields, that are added during auto generation AND are missing from the original input.
+pub fn find_all_missing_fields(schema_string: &str) -> Result<Vec<MissingField>, ErrorCollection> {
+    let schema_ast = crate::parse_schema_ast(&schema_string)?;
+    let datamodel = crate::parse_datamodel_and_ignore_datasource_urls(&schema_string)?;
+    let lowerer = crate::validator::LowerDmlToAst::new();
+    let mut result = Vec::new();
+
+    for model in datamodel.models() {
+        let ast_model = schema_ast.find_model(&model.name).unwrap();
+
+        for field in model.fields() {
+            if ast_model.fields.iter().find(|f| &f.name.name == &field.name).is_none() {
+                let ast_field = lowerer.lower_field(&field, &datamodel)?;
+
+                result.push(MissingField {
+                    model: model.name.clone(),
+                    field: ast_field,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
@@ -1,4 +1,10 @@
+mod code_actions;
 mod helpers;
+mod missing_fields;
 mod reformatter;
 
+pub mod edits;
+
+pub use code_actions::{code_actions, CodeAction};
+pub use edits::TextEdit;
 pub use reformatter::Reformatter;
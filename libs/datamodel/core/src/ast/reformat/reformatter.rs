@@ -4,7 +4,8 @@ use pest::Parser;
 // We have to use RefCell as rust cannot
 // do multiple mutable borrows inside a match statement.
 use super::helpers::*;
-use crate::common::WritableString;
+use super::missing_fields::{find_all_missing_fields, MissingField};
+use crate::common::{SchemaFiles, WritableString};
 
 pub struct Reformatter<'a> {
     input: &'a str,
@@ -13,33 +14,15 @@ pub struct Reformatter<'a> {
 
 impl<'a> Reformatter<'a> {
     pub fn new(input: &'a str) -> Self {
-        let missing_fields = Self::find_all_missing_fields(&input);
+        let missing_fields = find_all_missing_fields(&input);
         Reformatter { input, missing_fields }
     }
 
-    // this finds all auto generated fields, that are added during auto generation AND are missing from the original input.
-    fn find_all_missing_fields(schema_string: &str) -> Result<Vec<MissingField>, crate::error::ErrorCollection> {
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let datamodel = crate::parse_datamodel_and_ignore_datasource_urls(&schema_string)?;
-        let lowerer = crate::validator::LowerDmlToAst::new();
-        let mut result = Vec::new();
-
-        for model in datamodel.models() {
-            let ast_model = schema_ast.find_model(&model.name).unwrap();
-
-            for field in model.fields() {
-                if ast_model.fields.iter().find(|f| &f.name.name == &field.name).is_none() {
-                    let ast_field = lowerer.lower_field(&field, &datamodel)?;
-
-                    result.push(MissingField {
-                        model: model.name.clone(),
-                        field: ast_field,
-                    });
-                }
-            }
-        }
-
-        Ok(result)
+    /// Reformats one file of a schema split across multiple files. The back relation fields missing
+    /// from its models are found in the whole schema.
+    pub fn new_in_schema(input: &'a str, schema_files: &SchemaFiles) -> Self {
+        let missing_fields = find_all_missing_fields(schema_files.combined());
+        Reformatter { input, missing_fields }
     }
 
     pub fn reformat_to(&self, output: &mut dyn std::io::Write, ident_width: usize) {
@@ -111,6 +94,7 @@ impl<'a> Reformatter<'a> {
                     }
                 }
                 Rule::model_declaration => self.reformat_model(target, &current),
+                Rule::composite_type_declaration => self.reformat_composite_type(target, &current),
                 Rule::enum_declaration => self.reformat_enum(target, &current),
                 Rule::source_block => self.reformat_datasource(target, &current),
                 Rule::generator_block => self.reformat_generator(target, &current),
@@ -209,6 +193,23 @@ impl<'a> Reformatter<'a> {
         );
     }
 
+    fn reformat_composite_type(&self, target: &mut Renderer, token: &Token) {
+        self.reformat_block_element(
+            "type",
+            target,
+            token,
+            Box::new(|table, renderer, token| match token.as_rule() {
+                Rule::TYPE_KEYWORD => {}
+                Rule::directive => {
+                    table.render(renderer);
+                    Self::reformat_directive(renderer, &token, "@@");
+                }
+                Rule::field_declaration => Self::reformat_field(table, &token),
+                _ => Self::reformat_generic_token(table, &token),
+            }),
+        );
+    }
+
     fn reformat_block_element(
         &self,
         block_type: &'static str,
@@ -240,7 +241,8 @@ impl<'a> Reformatter<'a> {
                 Rule::BLOCK_OPEN => {
                     block_has_opened = true;
                 }
-                Rule::BLOCK_CLOSE => {}
+                // A block that is not closed gets its closing brace below.
+                Rule::BLOCK_CLOSE | Rule::MISSING_BLOCK_CLOSE => {}
 
                 Rule::non_empty_identifier | Rule::maybe_empty_identifier => {
                     // Begin.
@@ -390,7 +392,9 @@ impl<'a> Reformatter<'a> {
 
     fn get_identifier(token: &Token) -> String {
         for current in token.clone().into_inner() {
-            if let Rule::non_empty_identifier | Rule::maybe_empty_identifier = current.as_rule() {
+            if let Rule::non_empty_identifier | Rule::maybe_empty_identifier | Rule::unsupported_type =
+                current.as_rule()
+            {
                 return current.as_str().to_string();
             }
         }
@@ -585,9 +589,3 @@ impl<'a> Reformatter<'a> {
         }
     }
 }
-
-#[derive(Debug)]
-pub struct MissingField {
-    pub model: String,
-    pub field: crate::ast::Field,
-}
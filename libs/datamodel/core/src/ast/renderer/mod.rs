@@ -65,6 +65,7 @@ impl<'a> Renderer<'a> {
 
                     match other {
                         ast::Top::Model(model) => self.render_model(model),
+                        ast::Top::CompositeType(composite_type) => self.render_composite_type(composite_type),
                         ast::Top::Enum(enm) => self.render_enum(enm),
                         ast::Top::Source(source) => self.render_source_block(source),
                         ast::Top::Generator(generator) => self.render_generator_block(generator),
@@ -199,6 +200,28 @@ impl<'a> Renderer<'a> {
         self.end_line();
     }
 
+    fn render_composite_type(&mut self, composite_type: &ast::CompositeType) {
+        Self::render_documentation(self, composite_type);
+
+        self.write("type ");
+        self.write(&composite_type.name.name);
+        self.write(" {");
+        self.end_line();
+        self.indent_up();
+
+        let mut field_formatter = TableFormat::new();
+
+        for field in &composite_type.fields {
+            Self::render_field(&mut field_formatter, &field, false);
+        }
+
+        field_formatter.render(self);
+
+        self.indent_down();
+        self.write("}");
+        self.end_line();
+    }
+
     fn render_enum(&mut self, enm: &ast::Enum) {
         Self::render_documentation(self, enm);
 
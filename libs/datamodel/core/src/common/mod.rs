@@ -3,9 +3,11 @@ pub mod names;
 pub mod value_validator;
 
 mod fromstr;
+mod schema_files;
 mod string_helper;
 
 pub use datamodel_connector::scalars::ScalarType; // TODO: this reexport only eased refactoring. Consider removing it when we have found the right place for the referenced stuff.
 pub use fromstr::FromStrAndSpan;
 pub use names::DefaultNames;
+pub use schema_files::{FileSpan, SchemaFile, SchemaFiles};
 pub use string_helper::WritableString;
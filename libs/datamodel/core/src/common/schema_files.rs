@@ -0,0 +1,140 @@
+use crate::ast::Span;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The extension of schema files.
+const SCHEMA_FILE_EXTENSION: &str = "prisma";
+
+/// A schema split across multiple files.
+///
+/// The files are parsed and validated as one schema, the concatenation of their contents, so models
+/// and enums can be referenced from any file. The spans of the parsed schema and of the errors are
+/// offsets in the combined schema, and `locate` resolves them to a file and offsets in that file.
+#[derive(Debug, Clone)]
+pub struct SchemaFiles {
+    files: Vec<SchemaFile>,
+    /// The offset of each file in the combined schema.
+    offsets: Vec<usize>,
+    combined: String,
+}
+
+/// One file of a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaFile {
+    /// The name of the file, used in error messages.
+    pub name: String,
+    pub contents: String,
+}
+
+/// A span in one of the files of a schema.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FileSpan<'a> {
+    pub file: &'a SchemaFile,
+    /// The span in the file.
+    pub span: Span,
+}
+
+impl SchemaFiles {
+    pub fn new(files: Vec<SchemaFile>) -> SchemaFiles {
+        let mut offsets = Vec::with_capacity(files.len());
+        let mut combined = String::new();
+
+        for file in &files {
+            offsets.push(combined.len());
+            combined.push_str(&file.contents);
+            // Each file starts on a new line of the combined schema.
+            combined.push('\n');
+        }
+
+        SchemaFiles {
+            files,
+            offsets,
+            combined,
+        }
+    }
+
+    /// A schema in a single file.
+    pub fn single(name: &str, contents: &str) -> SchemaFiles {
+        SchemaFiles::new(vec![SchemaFile {
+            name: name.to_owned(),
+            contents: contents.to_owned(),
+        }])
+    }
+
+    /// Load the schema at the path: either a single file, or a directory, in which case all the
+    /// `.prisma` files in it and its subdirectories make up the schema, in the order of their paths.
+    pub fn load(path: &Path) -> io::Result<SchemaFiles> {
+        if !path.is_dir() {
+            return Ok(SchemaFiles::single(
+                &path.display().to_string(),
+                &fs::read_to_string(path)?,
+            ));
+        }
+
+        let mut paths = Vec::new();
+        collect_schema_file_paths(path, &mut paths)?;
+        paths.sort();
+
+        let files = paths
+            .into_iter()
+            .map(|file_path| {
+                let name = file_path.strip_prefix(path).unwrap_or(&file_path).display().to_string();
+
+                Ok(SchemaFile {
+                    name,
+                    contents: fs::read_to_string(&file_path)?,
+                })
+            })
+            .collect::<io::Result<Vec<SchemaFile>>>()?;
+
+        Ok(SchemaFiles::new(files))
+    }
+
+    /// The schema made of all the files, to pass to the parsing functions.
+    pub fn combined(&self) -> &str {
+        &self.combined
+    }
+
+    pub fn files(&self) -> &[SchemaFile] {
+        &self.files
+    }
+
+    pub fn find_file(&self, name: &str) -> Option<&SchemaFile> {
+        self.files.iter().find(|file| file.name == name)
+    }
+
+    /// The file a span of the combined schema is in, and the span in that file.
+    pub fn locate(&self, span: Span) -> Option<FileSpan<'_>> {
+        let index = self.offsets.iter().rposition(|offset| *offset <= span.start)?;
+        let file = &self.files[index];
+        let offset = self.offsets[index];
+
+        Some(FileSpan {
+            file,
+            span: Span::new(
+                span.start - offset,
+                std::cmp::min(span.end - offset, file.contents.len()),
+            ),
+        })
+    }
+}
+
+fn collect_schema_file_paths(directory: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_schema_file_paths(&path, paths)?;
+        } else if path
+            .extension()
+            .map(|ext| ext == SCHEMA_FILE_EXTENSION)
+            .unwrap_or(false)
+        {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
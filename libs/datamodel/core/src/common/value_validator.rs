@@ -199,8 +199,8 @@ impl ValueValidator {
 
     pub fn as_default_value_for_scalar_type(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                let generator = self.get_value_generator(&name)?;
+            ast::Expression::Function(name, args, _) => {
+                let generator = self.get_value_generator(&name, &args)?;
                 generator
                     .check_compatibility_with_scalar_type(scalar_type)
                     .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))?;
@@ -215,13 +215,25 @@ impl ValueValidator {
 
     pub fn as_value_generator(&self) -> Result<ValueGenerator, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => self.get_value_generator(&name),
+            ast::Expression::Function(name, args, _) => self.get_value_generator(&name, &args),
             _ => Err(self.construct_type_mismatch_error("function")),
         }
     }
 
-    fn get_value_generator(&self, name: &str) -> Result<ValueGenerator, DatamodelError> {
-        ValueGenerator::new(name.to_string(), vec![])
+    fn get_value_generator(&self, name: &str, args: &[ast::Expression]) -> Result<ValueGenerator, DatamodelError> {
+        let args = args
+            .iter()
+            .map(|arg| match arg {
+                ast::Expression::NumericValue(_, _) => ValueValidator::new(arg).as_int().map(PrismaValue::Int),
+                ast::Expression::StringValue(value, _) => Ok(PrismaValue::String(value.clone())),
+                _ => Err(DatamodelError::new_functional_evaluation_error(
+                    "Arguments of functions must be numbers or strings.",
+                    arg.span(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ValueGenerator::new(name.to_string(), args)
             .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))
     }
 }
@@ -9,6 +9,8 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// urls of read replicas of the database behind `url`
+    pub replicas: Vec<StringFromEnvVar>,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
@@ -28,3 +30,18 @@ impl Datasource {
         };
     }
 }
+
+/// The value of the query parameter `name` in the connection string `url`, like
+/// `10` for `connection_limit` in `postgresql://localhost/db?connection_limit=10`.
+pub fn url_parameter<'a>(url: &'a str, name: &str) -> Option<&'a str> {
+    let params = url.splitn(2, '?').nth(1)?;
+
+    params.split('&').find_map(|pair| {
+        let mut splitted = pair.splitn(2, '=');
+
+        match (splitted.next(), splitted.next()) {
+            (Some(key), Some(value)) if key == name => Some(value),
+            _ => None,
+        }
+    })
+}
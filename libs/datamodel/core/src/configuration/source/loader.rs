@@ -1,5 +1,7 @@
 use super::{
-    builtin_datasource_providers::{MySqlDatasourceProvider, PostgresDatasourceProvider, SqliteDatasourceProvider},
+    builtin_datasource_providers::{
+        MySqlDatasourceProvider, PostgresDatasourceProvider, SqliteDatasourceProvider, SQLITE_SOURCE_NAME,
+    },
     datasource_provider::DatasourceProvider,
 };
 use crate::common::arguments::Arguments;
@@ -124,6 +126,28 @@ impl SourceLoader {
             ));
         }
 
+        let replicas_arg = args.optional_arg("replicas");
+        let replicas_span = replicas_arg.as_ref().map(|arg| arg.span()).unwrap_or(ast_source.span);
+
+        let replicas = match replicas_arg {
+            Some(_) if ignore_datasource_urls => Vec::new(),
+            Some(replicas_arg) => {
+                let mut replicas = Vec::new();
+
+                for replica_arg in replicas_arg.as_array() {
+                    let (from_env_var, value) = replica_arg.as_str_from_env()?;
+
+                    replicas.push(StringFromEnvVar {
+                        from_env_var,
+                        value: value.trim().to_owned(),
+                    });
+                }
+
+                replicas
+            }
+            None => Vec::new(),
+        };
+
         let documentation = ast_source.documentation.clone().map(|comment| comment.text);
         let url = StringFromEnvVar {
             from_env_var: env_var_for_url,
@@ -148,7 +172,14 @@ impl SourceLoader {
                 let url_check_result = provider.can_handle_url(source_name, &url).map_err(|err_msg| {
                     DatamodelError::new_source_validation_error(&err_msg, source_name, url_args.span())
                 });
-                url_check_result.map(|_| provider)
+
+                let replica_check_result = replicas.iter().try_for_each(|replica| {
+                    provider.can_handle_url(source_name, replica).map_err(|err_msg| {
+                        DatamodelError::new_source_validation_error(&err_msg, source_name, replicas_span)
+                    })
+                });
+
+                url_check_result.and(replica_check_result).map(|_| provider)
             })
             .collect();
 
@@ -162,11 +193,21 @@ impl SourceLoader {
         let (successes, errors): (Vec<_>, Vec<_>) = validated_providers.into_iter().partition(|result| result.is_ok());
         if !successes.is_empty() {
             let first_successful_provider = successes.into_iter().next().unwrap()?;
+
+            if !replicas.is_empty() && first_successful_provider.is_provider(SQLITE_SOURCE_NAME) {
+                return Err(DatamodelError::new_source_validation_error(
+                    "Read replicas are not supported for SQLite datasources.",
+                    source_name,
+                    replicas_span,
+                ));
+            }
+
             Ok(Datasource {
                 name: source_name.to_string(),
                 provider: providers,
                 active_provider: first_successful_provider.canonical_name().to_string(),
                 url,
+                replicas,
                 documentation: documentation.clone(),
                 combined_connector,
                 active_connector: first_successful_provider.connector(),
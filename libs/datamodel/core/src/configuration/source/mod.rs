@@ -8,7 +8,7 @@ pub use builtin_datasource_providers::{
     MsSqlDatasourceProvider, MySqlDatasourceProvider, PostgresDatasourceProvider, SqliteDatasourceProvider,
     MSSQL_SOURCE_NAME, MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME,
 };
-pub use datasource::Datasource;
+pub use datasource::{url_parameter, Datasource};
 pub use datasource_provider::DatasourceProvider;
 pub use loader::SourceLoader;
 pub use serializer::SourceSerializer;
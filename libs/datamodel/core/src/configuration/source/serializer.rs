@@ -31,6 +31,23 @@ impl SourceSerializer {
             }
         }
 
+        if !source.replicas.is_empty() {
+            let replicas = source
+                .replicas
+                .iter()
+                .map(|replica| match replica.from_env_var {
+                    Some(ref env_var) => ast::Expression::Function(
+                        "env".to_owned(),
+                        vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())],
+                        ast::Span::empty(),
+                    ),
+                    None => ast::Expression::StringValue(replica.value.clone(), ast::Span::empty()),
+                })
+                .collect();
+
+            arguments.push(ast::Argument::new_array("replicas", replicas));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(&source.name),
             properties: arguments,
@@ -0,0 +1,39 @@
+use super::*;
+
+/// Represents a composite type in the datamodel: a named group of fields that is stored inside a
+/// field of a model, like an address.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompositeType {
+    /// Name of the composite type.
+    pub name: String,
+    /// Fields of the composite type.
+    pub fields: Vec<Field>,
+    /// Comments for this composite type.
+    pub documentation: Option<String>,
+}
+
+impl CompositeType {
+    /// Creates a new composite type with the given name and no fields.
+    pub fn new(name: &str) -> CompositeType {
+        CompositeType {
+            name: String::from(name),
+            fields: Vec::new(),
+            documentation: None,
+        }
+    }
+
+    /// Adds a field to this composite type.
+    pub fn add_field(&mut self, field: Field) {
+        self.fields.push(field)
+    }
+
+    /// Gets an iterator over all fields.
+    pub fn fields(&self) -> std::slice::Iter<Field> {
+        self.fields.iter()
+    }
+
+    /// Finds a field by name.
+    pub fn find_field(&self, name: &str) -> Option<&Field> {
+        self.fields().find(|field| field.name == name)
+    }
+}
@@ -1,6 +1,7 @@
 use super::ScalarType;
 use chrono::Utc;
 use prisma_value::PrismaValue;
+use rand::Rng;
 use std::fmt;
 use uuid::Uuid;
 
@@ -31,7 +32,7 @@ pub struct ValueGenerator {
 
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
-        let generator = ValueGeneratorFn::new(name.as_ref())?;
+        let generator = ValueGeneratorFn::new(name.as_ref(), &args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -56,12 +57,28 @@ impl ValueGenerator {
         ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_ulid() -> Self {
+        ValueGenerator::new("ulid".to_owned(), vec![]).unwrap()
+    }
+
+    pub fn new_nanoid() -> Self {
+        ValueGenerator::new("nanoid".to_owned(), vec![]).unwrap()
+    }
+
+    pub fn new_sequence(name: &str) -> Self {
+        ValueGenerator::new("sequence".to_owned(), vec![PrismaValue::String(name.to_owned())]).unwrap()
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
 
-    fn args(&self) -> &[PrismaValue] {
-        &self.args
+    /// The name of the database sequence, if this is a `sequence("name")` generator.
+    pub fn sequence_name(&self) -> Option<&str> {
+        match (self.generator, self.args.first()) {
+            (ValueGeneratorFn::Sequence, Some(PrismaValue::String(name))) => Some(name.as_str()),
+            _ => None,
+        }
     }
 
     pub fn generate(&self) -> Option<PrismaValue> {
@@ -84,20 +101,42 @@ impl ValueGenerator {
 #[derive(Clone, Copy, PartialEq)]
 pub enum ValueGeneratorFn {
     UUID,
+    UUIDv7,
     CUID,
+    ULID,
+    Nanoid(u8),
     Now,
     Autoincrement,
     DbGenerated,
+    Sequence,
 }
 
 impl ValueGeneratorFn {
-    fn new(name: &str) -> std::result::Result<Self, String> {
-        match name {
-            "cuid" => Ok(Self::CUID),
-            "uuid" => Ok(Self::UUID),
-            "now" => Ok(Self::Now),
-            "autoincrement" => Ok(Self::Autoincrement),
-            "dbgenerated" => Ok(Self::DbGenerated),
+    fn new(name: &str, args: &[PrismaValue]) -> std::result::Result<Self, String> {
+        match (name, args) {
+            ("uuid", []) | ("uuid", [PrismaValue::Int(4)]) => Ok(Self::UUID),
+            ("uuid", [PrismaValue::Int(7)]) => Ok(Self::UUIDv7),
+            ("uuid", _) => Err("The function `uuid()` only accepts the versions 4 and 7 as argument.".to_owned()),
+            ("nanoid", []) => Ok(Self::Nanoid(NANOID_DEFAULT_LENGTH)),
+            ("nanoid", [PrismaValue::Int(length)]) if *length >= 2 && *length <= 255 => Ok(Self::Nanoid(*length as u8)),
+            ("nanoid", _) => {
+                Err("The function `nanoid()` only accepts a length between 2 and 255 as argument.".to_owned())
+            }
+            ("sequence", [PrismaValue::String(sequence)]) if sequence.contains('"') => {
+                Err("The name of the sequence in `sequence()` can't contain double quotes.".to_owned())
+            }
+            ("sequence", [PrismaValue::String(sequence)]) if !sequence.is_empty() => Ok(Self::Sequence),
+            ("sequence", _) => {
+                Err("The function `sequence()` expects the name of the sequence as its only argument.".to_owned())
+            }
+            ("cuid", _) | ("ulid", _) | ("now", _) | ("autoincrement", _) | ("dbgenerated", _) if !args.is_empty() => {
+                Err(format!("The function `{}()` does not take any arguments.", name))
+            }
+            ("cuid", _) => Ok(Self::CUID),
+            ("ulid", _) => Ok(Self::ULID),
+            ("now", _) => Ok(Self::Now),
+            ("autoincrement", _) => Ok(Self::Autoincrement),
+            ("dbgenerated", _) => Ok(Self::DbGenerated),
             _ => Err(format!("The function {} is not a known function.", name)),
         }
     }
@@ -105,19 +144,27 @@ impl ValueGeneratorFn {
     fn invoke(&self) -> Option<PrismaValue> {
         match self {
             Self::UUID => Self::generate_uuid(),
+            Self::UUIDv7 => Self::generate_uuid_v7(),
             Self::CUID => Self::generate_cuid(),
+            Self::ULID => Self::generate_ulid(),
+            Self::Nanoid(length) => Self::generate_nanoid(*length),
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
+            Self::Sequence => None,
         }
     }
 
     fn can_handle(&self, scalar_type: ScalarType) -> bool {
         match (self, scalar_type) {
             (Self::UUID, ScalarType::String) => true,
+            (Self::UUIDv7, ScalarType::String) => true,
             (Self::CUID, ScalarType::String) => true,
+            (Self::ULID, ScalarType::String) => true,
+            (Self::Nanoid(_), ScalarType::String) => true,
             (Self::Now, ScalarType::DateTime) => true,
             (Self::Autoincrement, ScalarType::Int) => true,
+            (Self::Sequence, ScalarType::Int) => true,
             (Self::DbGenerated, _) => true,
             _ => false,
         }
@@ -131,14 +178,60 @@ impl ValueGeneratorFn {
         Some(PrismaValue::Uuid(Uuid::new_v4()))
     }
 
+    /// A version 7 UUID: a 48 bit unix timestamp in milliseconds followed by random bits.
+    fn generate_uuid_v7() -> Option<PrismaValue> {
+        let mut bytes: [u8; 16] = rand::thread_rng().gen();
+
+        bytes[..6].copy_from_slice(&unix_millis().to_be_bytes()[2..]);
+        bytes[6] = (bytes[6] & 0x0f) | 0x70; // version 7
+        bytes[8] = (bytes[8] & 0x3f) | 0x80; // RFC 4122 variant
+
+        Some(PrismaValue::Uuid(Uuid::from_bytes(bytes)))
+    }
+
+    /// A ULID: a 48 bit unix timestamp in milliseconds followed by 80 random bits,
+    /// encoded as 26 characters of Crockford's base32.
+    fn generate_ulid() -> Option<PrismaValue> {
+        const ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+        let randomness: u128 = rand::thread_rng().gen::<u128>() & ((1 << 80) - 1);
+        let value = (u128::from(unix_millis()) << 80) | randomness;
+
+        let ulid = (0..26)
+            .map(|i| ALPHABET[((value >> (125 - 5 * i)) & 0x1f) as usize] as char)
+            .collect();
+
+        Some(PrismaValue::String(ulid))
+    }
+
+    fn generate_nanoid(length: u8) -> Option<PrismaValue> {
+        const ALPHABET: &[u8] = b"_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        let mut rng = rand::thread_rng();
+        let nanoid = (0..length)
+            .map(|_| ALPHABET[(rng.gen::<u8>() & 0x3f) as usize] as char)
+            .collect();
+
+        Some(PrismaValue::String(nanoid))
+    }
+
     fn generate_now() -> Option<PrismaValue> {
         Some(PrismaValue::DateTime(Utc::now()))
     }
 }
 
+const NANOID_DEFAULT_LENGTH: u8 = 21;
+
+/// The current unix timestamp in milliseconds, truncated to 48 bits.
+fn unix_millis() -> u64 {
+    (Utc::now().timestamp_millis() as u64) & 0xffff_ffff_ffff
+}
+
 impl PartialEq for ValueGenerator {
     fn eq(&self, other: &Self) -> bool {
-        self.name() == other.name() && self.args() == other.args()
+        // The arguments are normalised by the generator, like `uuid()` and `uuid(4)`, except for
+        // the name of the sequence.
+        self.generator == other.generator && self.sequence_name() == other.sequence_name()
     }
 }
 
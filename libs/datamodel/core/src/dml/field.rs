@@ -43,6 +43,8 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a field holding a value of the composite type with the given name.
+    CompositeType(String),
     /// Connector specific field type.
     ConnectorSpecific(ScalarFieldType),
     /// This is a field with an unsupported datatype.
@@ -59,6 +61,20 @@ impl FieldType {
         }
     }
 
+    pub fn is_composite_type(&self) -> bool {
+        match self {
+            Self::CompositeType(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_unsupported(&self) -> bool {
+        match self {
+            Self::Unsupported(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_compatible_with(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Base(a, _), Self::Base(b, _)) => a == b, // the name of the type alias is not important for the comparison
@@ -111,6 +127,9 @@ pub struct Field {
 
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
+
+    /// Indicates if this field is marked with @ignore, and left out of the client.
+    pub is_ignored: bool,
 }
 
 impl Field {
@@ -167,6 +186,7 @@ impl Field {
             is_generated: false,
             is_updated_at: false,
             is_commented_out: false,
+            is_ignored: false,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -21,6 +21,8 @@ pub struct Model {
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
+    /// Indicates if this model is marked with @@ignore, and left out of the client.
+    pub is_ignored: bool,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -69,6 +71,7 @@ impl Model {
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
+            is_ignored: false,
         }
     }
 
@@ -171,11 +174,13 @@ impl Model {
         }
 
         // third candidate: a required scalar field with a unique index.
+        // Fields of unsupported types can't be read by the client, so they can't identify records.
         {
             let mut unique_required_fields: Vec<_> = self
                 .fields
                 .iter()
-                .filter(|field| field.is_unique && (field.arity.is_required() || allow_optional))
+                .filter(|field| field.is_unique && !field.field_type.is_unsupported())
+                .filter(|field| field.arity.is_required() || allow_optional)
                 .map(|f| UniqueCriteria::new(vec![f]))
                 .collect();
 
@@ -191,7 +196,11 @@ impl Model {
                 .filter_map(|id| {
                     let fields: Vec<_> = id.fields.iter().map(|f| self.find_field(&f).unwrap()).collect();
                     let all_fields_are_required = fields.iter().all(|f| f.arity.is_required());
-                    if all_fields_are_required || allow_optional {
+                    let has_unsupported_fields = fields.iter().any(|f| f.field_type.is_unsupported());
+
+                    if has_unsupported_fields {
+                        None
+                    } else if all_fields_are_required || allow_optional {
                         Some(UniqueCriteria::new(fields))
                     } else {
                         None
@@ -2,6 +2,7 @@ mod collection;
 pub use collection::*;
 
 use crate::ast::Span;
+use crate::common::{FileSpan, SchemaFiles};
 use colored::Colorize;
 
 // No format for this file, on purpose.
@@ -101,6 +102,9 @@ pub enum DatamodelError {
     #[fail(display = "Error validating model \"{}\": {}", model_name, message)]
     ModelValidationError { message: String, model_name: String, span: Span  },
 
+    #[fail(display = "Error validating model \"{}\": Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model.{}", model_name, loose_criterias_str)]
+    ModelWithoutUniqueCriteriaError { model_name: String, loose_criterias: Vec<Vec<String>>, loose_criterias_str: String, span: Span },
+
     #[fail(display = "Error validating field `{}` in model `{}`: {}", field, model, message)]
     FieldValidationError { message: String, model: String, field: String, span: Span },
 
@@ -110,8 +114,14 @@ pub enum DatamodelError {
     #[fail(display = "Error validating enum `{}`: {}", enum_name, message)]
     EnumValidationError { message: String, enum_name: String, span: Span },
 
+    #[fail(display = "Error validating composite type `{}`: {}", composite_type_name, message)]
+    CompositeTypeValidationError { message: String, composite_type_name: String, span: Span },
+
     #[fail(display = "Error validating: {}", message)]
     ValidationError { message: String, span: Span  },
+
+    #[fail(display = "Error validating: The argument fields must refer only to existing fields. The following fields do not exist in this model: {}", unknown_fields_str)]
+    UnknownRelationFieldsError { unknown_fields: Vec<String>, unknown_fields_str: String, span: Span },
 }
 
 #[rustfmt::skip]
@@ -254,6 +264,28 @@ impl DatamodelError {
         }
     }
 
+    /// The error for a model without unique criteria. The loose criterias are the unique criterias
+    /// with optional fields, which are mentioned in the message.
+    pub fn new_model_without_unique_criteria_error(model_name: &str, loose_criterias: Vec<Vec<String>>, span: Span) -> DatamodelError {
+        let loose_criterias_str = if loose_criterias.is_empty() {
+            String::new()
+        } else {
+            let descriptions: Vec<String> = loose_criterias.iter().map(|fields| format!("- {}", fields.join(", "))).collect();
+
+            format!(
+                " The following unique criterias were not considered as they contain fields that are not required:\n{}",
+                descriptions.join("\n")
+            )
+        };
+
+        DatamodelError::ModelWithoutUniqueCriteriaError {
+            model_name: String::from(model_name),
+            loose_criterias,
+            loose_criterias_str,
+            span,
+        }
+    }
+
     pub fn new_enum_validation_error(message: &str, enum_name: &str, span: Span) -> DatamodelError {
         DatamodelError::EnumValidationError {
             message: String::from(message),
@@ -262,6 +294,14 @@ impl DatamodelError {
         }
     }
 
+    pub fn new_composite_type_validation_error(message: &str, composite_type_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::CompositeTypeValidationError {
+            message: String::from(message),
+            composite_type_name: String::from(composite_type_name),
+            span,
+        }
+    }
+
     pub fn new_field_validation_error(message: &str, model: &str, field: &str, span: Span) -> DatamodelError {
         DatamodelError::FieldValidationError {
             message: message.to_owned(),
@@ -286,6 +326,10 @@ impl DatamodelError {
         }
     }
 
+    pub fn new_unknown_relation_fields_error(unknown_fields: Vec<String>, span: Span) -> DatamodelError {
+        DatamodelError::UnknownRelationFieldsError { unknown_fields_str: unknown_fields.join(", "), unknown_fields, span }
+    }
+
     pub fn new_legacy_parser_error(message: &str, span: Span) -> DatamodelError {
         DatamodelError::LegacyParserError {
             message: String::from(message),
@@ -360,6 +404,8 @@ impl DatamodelError {
             DatamodelError::ValidationError { span, .. } => *span,
             DatamodelError::LegacyParserError { span, .. } => *span,
             DatamodelError::ModelValidationError { span, .. } => *span,
+            DatamodelError::ModelWithoutUniqueCriteriaError { span, .. } => *span,
+            DatamodelError::UnknownRelationFieldsError { span, .. } => *span,
             DatamodelError::DuplicateDirectiveError { span, .. } => *span,
             DatamodelError::DuplicateConfigKeyError { span, .. } => *span,
             DatamodelError::DuplicateTopError { span, .. } => *span,
@@ -372,6 +418,7 @@ impl DatamodelError {
             DatamodelError::FieldValidationError {span , ..} => *span,
             DatamodelError::SourceValidationError {span, ..} => *span,
             DatamodelError::EnumValidationError {span, ..} => *span,
+            DatamodelError::CompositeTypeValidationError {span, ..} => *span,
         }
     }
     pub fn description(&self) -> String {
@@ -379,15 +426,28 @@ impl DatamodelError {
     }
 
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
-        pretty_print_error(f, file_name, text, self)
+        pretty_print_error(f, file_name, text, self.span(), self)
+    }
+
+    /// The file of a multi-file schema the error is in, and its span in that file.
+    pub fn file_span<'a>(&self, files: &'a SchemaFiles) -> Option<FileSpan<'a>> {
+        files.locate(self.span())
+    }
+
+    /// Pretty prints the error in a schema split across multiple files, with the name of the file
+    /// it is in.
+    pub fn pretty_print_in_files(&self, f: &mut dyn std::io::Write, files: &SchemaFiles) -> std::io::Result<()> {
+        match self.file_span(files) {
+            Some(file_span) => pretty_print_error(f, &file_span.file.name, &file_span.file.contents, file_span.span, self),
+            None => self.pretty_print(f, "schema.prisma", files.combined()),
+        }
     }
 }
 
 /// Given the datamodel text representation, pretty prints an error, including
 /// the offending portion of the source code, for human-friendly reading.
 #[rustfmt::skip]
-fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, error_obj: &DatamodelError) -> std::io::Result<()> {
-    let span = error_obj.span();
+fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, span: Span, error_obj: &DatamodelError) -> std::io::Result<()> {
     let error = error_obj.description();
 
     let start_line_number = text[..span.start].matches("\n").count();
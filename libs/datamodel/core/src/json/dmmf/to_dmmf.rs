@@ -19,14 +19,20 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
         datamodel.enums.push(enum_to_dmmf(&enum_model));
     }
 
-    for model in schema.models() {
-        datamodel.models.push(model_to_dmmf(&model));
+    // Ignored models and fields, and fields of unsupported types, are left out of the client.
+    for model in schema.models().filter(|model| !model.is_ignored) {
+        datamodel.models.push(model_to_dmmf(schema, &model));
+    }
+
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
     }
 
     datamodel
@@ -54,12 +60,27 @@ fn enum_value_to_dmmf(en: &dml::EnumValue) -> EnumValue {
     }
 }
 
-fn model_to_dmmf(model: &dml::Model) -> Model {
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type
+            .fields()
+            .map(|f| field_to_dmmf(&composite_type.fields, f))
+            .collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
+fn model_to_dmmf(schema: &dml::Datamodel, model: &dml::Model) -> Model {
     Model {
         name: model.name.clone(),
         db_name: model.database_name.clone(),
         is_embedded: model.is_embedded,
-        fields: model.fields().map(|f| field_to_dmmf(model, f)).collect(),
+        fields: model
+            .fields()
+            .filter(|f| !is_ignored(schema, f) && !f.field_type.is_unsupported())
+            .map(|f| field_to_dmmf(&model.fields, f))
+            .collect(),
         is_generated: Some(model.is_generated),
         documentation: model.documentation.clone(),
         id_fields: model.id_fields.clone(),
@@ -91,8 +112,26 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     }
 }
 
-fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
-    let a_relation_field_is_based_on_this_field: bool = model.fields.iter().any(|f| match &f.field_type {
+/// Whether the field is left out of the client. Like in the query schema, relation fields are left out when any
+/// side of their relation is ignored.
+fn is_ignored(schema: &dml::Datamodel, field: &dml::Field) -> bool {
+    if field.is_ignored {
+        return true;
+    }
+
+    match &field.field_type {
+        dml::FieldType::Relation(rel_info) => {
+            let related_model = schema.find_model(&rel_info.to);
+
+            related_model.map(|model| model.is_ignored).unwrap_or(false)
+                || schema.find_related_field_for_info(rel_info).is_ignored
+        }
+        _ => false,
+    }
+}
+
+fn field_to_dmmf(sibling_fields: &[dml::Field], field: &dml::Field) -> Field {
+    let a_relation_field_is_based_on_this_field: bool = sibling_fields.iter().any(|f| match &f.field_type {
         dml::FieldType::Relation(rel_info) => rel_info.fields.contains(&field.name),
         _ => false,
     });
@@ -120,6 +159,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
 fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type {
         dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::CompositeType(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
         _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
@@ -165,6 +205,7 @@ fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
         dml::FieldType::ConnectorSpecific(sft) => type_to_string(&sft.prisma_type()),
@@ -8,6 +8,8 @@ pub struct SourceConfig {
     pub provider: Vec<String>,
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub replicas: Vec<StringFromEnvVar>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
@@ -38,6 +40,7 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         provider: source.provider.clone(),
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
+        replicas: source.replicas.clone(),
         documentation: source.documentation.clone(),
     }
 }
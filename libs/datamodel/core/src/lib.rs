@@ -59,7 +59,7 @@ pub mod error;
 pub mod json;
 pub mod validator;
 
-pub use common::DefaultNames;
+pub use common::{DefaultNames, FileSpan, SchemaFile, SchemaFiles};
 pub use configuration::*;
 pub use dml::*;
 
@@ -100,11 +100,52 @@ fn parse_datamodel_internal(
     datamodel_string: &str,
     ignore_datasource_urls: bool,
 ) -> Result<Datamodel, error::ErrorCollection> {
-    let ast = ast::parser::parse(datamodel_string)?;
-    let sources = load_sources(&ast, ignore_datasource_urls, vec![])?;
-    let validator = ValidationPipeline::with_sources(&sources);
+    // The well-formed parts of the schema are validated even if there are syntax errors, to report all
+    // the errors at once.
+    let (ast, mut errors) = ast::parser::parse_with_recovery(datamodel_string);
+
+    let validation_result = load_sources(&ast, ignore_datasource_urls, vec![]).and_then(|sources| {
+        let validator = ValidationPipeline::with_sources(&sources);
+        validator.validate(&ast)
+    });
+
+    match validation_result {
+        Ok(datamodel) if !errors.has_errors() => Ok(datamodel),
+        Ok(_) => Err(errors),
+        Err(validation_errors) => {
+            errors.append_vec(errors_outside_of_broken_blocks(&ast, &errors, validation_errors));
+            Err(errors)
+        }
+    }
+}
 
-    validator.validate(&ast)
+/// The validation errors that are not in a block with syntax errors. Those are most likely caused by
+/// the parts of the block that could not be parsed.
+fn errors_outside_of_broken_blocks(
+    ast: &SchemaAst,
+    syntax_errors: &error::ErrorCollection,
+    validation_errors: error::ErrorCollection,
+) -> Vec<error::DatamodelError> {
+    use ast::WithSpan;
+
+    let contains = |span: &ast::Span, offset: usize| span.start <= offset && offset < span.end;
+
+    let broken_blocks: Vec<&ast::Span> = ast
+        .tops
+        .iter()
+        .map(|top| top.span())
+        .filter(|block| {
+            syntax_errors
+                .to_iter()
+                .any(|error| contains(*block, error.span().start))
+        })
+        .collect();
+
+    validation_errors
+        .errors
+        .into_iter()
+        .filter(|error| !broken_blocks.iter().any(|block| contains(*block, error.span().start)))
+        .collect()
 }
 
 /// Validates a [Schema AST](/ast/struct.SchemaAst.html) and returns its
@@ -128,6 +169,18 @@ pub fn parse_schema_ast(datamodel_string: &str) -> Result<SchemaAst, error::Erro
     ast::parser::parse(datamodel_string)
 }
 
+/// Parses a datamodel string, recovering from syntax errors. Returns the AST of the well-formed parts
+/// of the schema, and all the syntax errors.
+pub fn parse_schema_ast_with_recovery(datamodel_string: &str) -> (SchemaAst, error::ErrorCollection) {
+    ast::parser::parse_with_recovery(datamodel_string)
+}
+
+/// Finds the code actions for a datamodel string: the fixes of the known validation errors, to offer
+/// as quick fixes in editors.
+pub fn code_actions(datamodel_string: &str) -> Vec<ast::reformat::CodeAction> {
+    ast::reformat::code_actions(datamodel_string)
+}
+
 /// Loads all configuration blocks from a datamodel using the built-in source definitions.
 pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, error::ErrorCollection> {
     let ast = ast::parser::parse(datamodel_string)?;
@@ -40,6 +40,22 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
                     }
                 }
             }
+        } else if let dml::FieldType::Unsupported(_) = &field.field_type {
+            let generator = args.default_arg("value")?.as_value_generator()?;
+
+            if generator == ValueGenerator::new_dbgenerated() {
+                field.default_value = Some(dml::DefaultValue::Expression(generator));
+            } else {
+                return self.new_directive_validation_error(
+                    "Only @default(dbgenerated()) can be used for Unsupported types.",
+                    args.span(),
+                );
+            }
+        } else if let dml::FieldType::CompositeType(_) = &field.field_type {
+            return self.new_directive_validation_error(
+                "Cannot set a default value on a field of a composite type.",
+                args.span(),
+            );
         } else {
             return self.new_directive_validation_error("Cannot set a default value on a relation field.", args.span());
         }
@@ -27,6 +27,18 @@ impl DirectiveValidator<dml::Field> for IdDirectiveValidator {
             );
         }
 
+        if let dml::FieldType::CompositeType(composite_type) = &obj.field_type {
+            return self.new_directive_validation_error(
+                &format!(
+                    "The field `{}` is of the composite type `{}` and cannot be marked with `@{}`. Only scalar fields can be declared as id.",
+                    &obj.name,
+                    composite_type,
+                    self.directive_name()
+                ),
+                args.span(),
+            );
+        }
+
         obj.is_id = true;
 
         Ok(())
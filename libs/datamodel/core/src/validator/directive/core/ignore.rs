@@ -0,0 +1,57 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@ignore` directive.
+pub struct IgnoreDirectiveValidator {}
+
+const DIRECTIVE_NAME: &'static str = "ignore";
+
+impl DirectiveValidator<dml::Model> for IgnoreDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        DIRECTIVE_NAME
+    }
+
+    fn validate_and_apply(&self, _args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_ignored = true;
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if model.is_ignored {
+            return Ok(vec![ast::Directive::new(self.directive_name(), vec![])]);
+        }
+
+        Ok(vec![])
+    }
+}
+
+/// Prismas builtin `@ignore` directive.
+pub struct IgnoreDirectiveValidatorForField {}
+
+impl DirectiveValidator<dml::Field> for IgnoreDirectiveValidatorForField {
+    fn directive_name(&self) -> &'static str {
+        DIRECTIVE_NAME
+    }
+
+    fn validate_and_apply(&self, _args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        obj.is_ignored = true;
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if field.is_ignored {
+            return Ok(vec![ast::Directive::new(self.directive_name(), vec![])]);
+        }
+
+        Ok(vec![])
+    }
+}
@@ -4,6 +4,7 @@ use crate::validator::directive::DirectiveListValidator;
 mod default;
 mod embedded;
 mod id;
+mod ignore;
 mod map;
 mod relation;
 mod unique_and_index;
@@ -20,6 +21,7 @@ pub fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
     validator.add(Box::new(default::DefaultDirectiveValidator {}));
     validator.add(Box::new(relation::RelationDirectiveValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtDirectiveValidator {}));
+    validator.add(Box::new(ignore::IgnoreDirectiveValidatorForField {}));
 
     validator
 }
@@ -33,6 +35,7 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
+    validator.add(Box::new(ignore::IgnoreDirectiveValidator {}));
 
     validator
 }
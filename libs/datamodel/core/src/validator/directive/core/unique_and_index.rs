@@ -19,6 +19,18 @@ impl DirectiveValidator<dml::Field> for FieldLevelUniqueDirectiveValidator {
             );
         }
 
+        if let dml::FieldType::CompositeType(composite_type) = &obj.field_type {
+            return self.new_directive_validation_error(
+                &format!(
+                    "The field `{}` is of the composite type `{}` and cannot be marked with `@{}`. Only scalar fields can be made unique.",
+                    &obj.name,
+                    composite_type,
+                    self.directive_name()
+                ),
+                args.span(),
+            );
+        }
+
         if let dml::FieldType::Relation(rel_info) = &obj.field_type {
             let suggestion = if rel_info.fields.len() == 1 {
                 format!(" Did you mean to put it on `{}`?", rel_info.fields.first().unwrap())
@@ -55,6 +55,11 @@ impl<T: 'static> DirectiveListValidator<T> {
         }
     }
 
+    /// The names of the known directives.
+    pub fn directive_names(&self) -> impl Iterator<Item = &str> {
+        self.known_directives.keys().map(|name| name.as_str())
+    }
+
     /// For each directive in the given object, picks the correct
     /// directive definition and uses it to validate and apply the directive.
     pub fn validate_and_apply(&self, ast: &dyn ast::WithDirectives, t: &mut T) -> Result<(), ErrorCollection> {
@@ -45,6 +45,12 @@ impl<'a> LiftAstToDml<'a> {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::CompositeType(composite_type) => {
+                    match self.lift_composite_type(&composite_type, ast_schema) {
+                        Ok(composite_type) => schema.add_composite_type(composite_type),
+                        Err(mut err) => errors.append(&mut err),
+                    }
+                }
                 ast::Top::Source(_) => { /* Source blocks are explicitly ignored by the validator */ }
                 ast::Top::Generator(_) => { /* Generator blocks are explicitly ignored by the validator */ }
                 // TODO: For now, type blocks are never checked on their own.
@@ -84,6 +90,64 @@ impl<'a> LiftAstToDml<'a> {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    fn lift_composite_type(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, ErrorCollection> {
+        let mut composite_type = dml::CompositeType::new(&ast_composite_type.name.name);
+        composite_type.documentation = ast_composite_type.documentation.clone().map(|comment| comment.text);
+
+        let mut errors = ErrorCollection::new();
+
+        for ast_field in &ast_composite_type.fields {
+            // The fields of composite types are stored as part of a document, so only default values
+            // apply to them.
+            let unsupported_directives: Vec<&ast::Directive> = ast_field
+                .directives
+                .iter()
+                .filter(|directive| directive.name.name != "default")
+                .collect();
+
+            for directive in &unsupported_directives {
+                errors.push(DatamodelError::new_composite_type_validation_error(
+                    &format!(
+                        "The attribute `@{}` is not supported on fields of composite types.",
+                        directive.name.name
+                    ),
+                    &ast_composite_type.name.name,
+                    directive.span,
+                ));
+            }
+
+            if !unsupported_directives.is_empty() {
+                continue;
+            }
+
+            match self.lift_field(ast_field, ast_schema) {
+                Ok(field) if field.field_type.is_relation() => {
+                    errors.push(DatamodelError::new_composite_type_validation_error(
+                        &format!(
+                            "The field `{}` refers to the model `{}`. Composite types can not have relation fields.",
+                            field.name, ast_field.field_type.name
+                        ),
+                        &ast_composite_type.name.name,
+                        ast_field.field_type.span,
+                    ));
+                }
+                Ok(field) => composite_type.add_field(field),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(composite_type)
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, ErrorCollection> {
         let mut errors = ErrorCollection::new();
@@ -181,6 +245,20 @@ impl<'a> LiftAstToDml<'a> {
     ) -> Result<(dml::FieldType, Vec<ast::Directive>), DatamodelError> {
         let type_name = &ast_field.field_type.name;
 
+        if let Some(native_type) = unsupported_native_type(type_name) {
+            if !is_valid_native_type(native_type) {
+                return Err(DatamodelError::new_validation_error(
+                    &format!(
+                        "\"{}\" is not a valid database type. Unsupported types can only contain names, numbers, spaces and arguments in parentheses, separated by commas.",
+                        native_type
+                    ),
+                    ast_field.field_type.span,
+                ));
+            }
+
+            return Ok((dml::FieldType::Unsupported(native_type.to_owned()), vec![]));
+        }
+
         if let Ok(scalar_type) = ScalarType::from_str(type_name) {
             if USE_CONNECTORS_FOR_CUSTOM_TYPES {
                 let pg_connector = BuiltinConnectors::postgres();
@@ -204,6 +282,8 @@ impl<'a> LiftAstToDml<'a> {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
+        } else if ast_schema.find_composite_type(type_name).is_some() {
+            Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
         } else {
             self.resolve_custom_type(ast_field, ast_schema, checked_types)
         }
@@ -234,7 +314,7 @@ impl<'a> LiftAstToDml<'a> {
             let (field_type, mut attrs) =
                 self.lift_field_type(custom_type, Some(type_name.to_owned()), ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if let dml::FieldType::Relation(_) | dml::FieldType::CompositeType(_) = field_type {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
@@ -264,3 +344,38 @@ impl<'a> LiftAstToDml<'a> {
         }
     }
 }
+
+/// Internal: The native type of an `Unsupported("...")` field type.
+fn unsupported_native_type(type_name: &str) -> Option<&str> {
+    const PREFIX: &str = "Unsupported(\"";
+    const SUFFIX: &str = "\")";
+
+    if type_name.len() >= PREFIX.len() + SUFFIX.len() && type_name.starts_with(PREFIX) && type_name.ends_with(SUFFIX) {
+        Some(&type_name[PREFIX.len()..type_name.len() - SUFFIX.len()])
+    } else {
+        None
+    }
+}
+
+/// Internal: Whether the native type of an `Unsupported("...")` field type is a type name, optionally followed by
+/// arguments in parentheses, like `geometry(Point, 4326)`. The type is rendered into migrations as is, so anything
+/// else, like quotes or semicolons, is rejected.
+fn is_valid_native_type(native_type: &str) -> bool {
+    if !native_type.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        return false;
+    }
+
+    let mut depth = 0usize;
+
+    for c in native_type.chars() {
+        match c {
+            c if c.is_ascii_alphanumeric() || c == '_' || c == ' ' => (),
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            ',' if depth > 0 => (),
+            _ => return false,
+        }
+    }
+
+    depth == 0
+}
@@ -28,6 +28,13 @@ impl LowerDmlToAst {
             }
         }
 
+        for composite_type in datamodel.composite_types() {
+            match self.lower_composite_type(composite_type, datamodel) {
+                Ok(res) => tops.push(ast::Top::CompositeType(res)),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
         for enm in datamodel.enums() {
             match self.lower_enum(enm, datamodel) {
                 Ok(res) => tops.push(ast::Top::Enum(res)),
@@ -63,6 +70,33 @@ impl LowerDmlToAst {
         })
     }
 
+    fn lower_composite_type(
+        &self,
+        composite_type: &dml::CompositeType,
+        datamodel: &dml::Datamodel,
+    ) -> Result<ast::CompositeType, ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+        let mut fields: Vec<ast::Field> = Vec::new();
+
+        for field in composite_type.fields() {
+            match self.lower_field(field, datamodel) {
+                Ok(ast_field) => fields.push(ast_field),
+                Err(mut err) => errors.append(&mut err),
+            };
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(ast::CompositeType {
+            name: ast::Identifier::new(&composite_type.name),
+            fields,
+            documentation: composite_type.documentation.clone().map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
+        })
+    }
+
     fn lower_enum(&self, enm: &dml::Enum, datamodel: &dml::Datamodel) -> Result<ast::Enum, ErrorCollection> {
         Ok(ast::Enum {
             name: ast::Identifier::new(&enm.name),
@@ -140,8 +174,9 @@ impl LowerDmlToAst {
                 ast::Identifier::new(&custom_type_name.as_ref().unwrap_or(&tpe.to_string()))
             }
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
-            dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&format!("Unsupported(\"{}\")", tpe)),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
+            dml::FieldType::CompositeType(tpe) => ast::Identifier::new(tpe),
             _ => unimplemented!("Connector specific types are not supported atm."),
         }
     }
@@ -34,6 +34,11 @@ impl Precheck {
                     top_level_types_checker.check_if_duplicate_exists(top, error_fn);
                     Self::precheck_model(&model, &mut errors);
                 }
+                ast::Top::CompositeType(composite_type) => {
+                    Self::assert_is_not_a_reserved_scalar_type(&composite_type.name, &mut errors);
+                    top_level_types_checker.check_if_duplicate_exists(top, error_fn);
+                    Self::precheck_composite_type(&composite_type, &mut errors);
+                }
                 ast::Top::Type(custom_type) => {
                     Self::assert_is_not_a_reserved_scalar_type(&custom_type.name, &mut errors);
                     top_level_types_checker.check_if_duplicate_exists(top, error_fn);
@@ -87,6 +92,20 @@ impl Precheck {
         errors.append(&mut checker.errors());
     }
 
+    fn precheck_composite_type(composite_type: &ast::CompositeType, errors: &mut ErrorCollection) {
+        let mut checker = DuplicateChecker::new();
+        for field in &composite_type.fields {
+            checker.check_if_duplicate_exists(field, |_| {
+                DatamodelError::new_duplicate_field_error(
+                    &composite_type.name.name,
+                    &field.name.name,
+                    field.identifier().span,
+                )
+            });
+        }
+        errors.append(&mut checker.errors());
+    }
+
     fn precheck_generator_config(config: &ast::GeneratorConfig, errors: &mut ErrorCollection) {
         let mut checker = DuplicateChecker::new();
         for arg in &config.properties {
@@ -189,6 +189,7 @@ impl Standardiser {
                         let mut back_relation_field =
                             dml::Field::new_generated(&model.name, dml::FieldType::Relation(relation_info));
                         back_relation_field.arity = dml::FieldArity::List;
+                        back_relation_field.is_ignored = model.is_ignored;
 
                         result.push(AddMissingBackRelationField {
                             model: rel.to.clone(),
@@ -262,6 +263,7 @@ impl Standardiser {
                         let mut back_relation_field =
                             dml::Field::new_generated(&model.name, dml::FieldType::Relation(relation_info));
                         back_relation_field.arity = dml::FieldArity::Optional;
+                        back_relation_field.is_ignored = model.is_ignored;
 
                         result.push(AddMissingBackRelationField {
                             model: rel.to.clone(),
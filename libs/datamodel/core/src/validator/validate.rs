@@ -65,6 +65,12 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_ignored_fields(schema, ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -93,6 +99,14 @@ impl<'a> Validator<'a> {
             all_errors.append(&mut errors_for_model);
         }
 
+        for composite_type in schema.composite_types() {
+            let ast_composite_type = ast_schema.find_composite_type(&composite_type.name).expect(STATE_ERROR);
+
+            if let Err(err) = self.validate_composite_type_is_not_recursive(ast_composite_type, schema) {
+                all_errors.push(err);
+            }
+        }
+
         if all_errors.has_errors() {
             Err(all_errors)
         } else {
@@ -144,6 +158,15 @@ impl<'a> Validator<'a> {
             }
         }
 
+        for composite_type in ast_schema.composite_types() {
+            errors.push_opt(composite_type.name.validate("Composite Type").err());
+
+            for field in composite_type.fields.iter() {
+                errors.push_opt(field.name.validate("Field").err());
+                errors.append(&mut field.validate_directives());
+            }
+        }
+
         for enum_decl in ast_schema.enums() {
             errors.push_opt(enum_decl.name.validate("Enum").err());
             errors.append(&mut enum_decl.validate_directives());
@@ -167,7 +190,12 @@ impl<'a> Validator<'a> {
         };
 
         for field in model.fields() {
-            if field.arity == FieldArity::List && !scalar_lists_are_supported && !field.field_type.is_relation() {
+            // Lists of composite types are stored in a single Json value.
+            if field.arity == FieldArity::List
+                && !scalar_lists_are_supported
+                && !field.field_type.is_relation()
+                && !field.field_type.is_composite_type()
+            {
                 let ast_field = ast_model
                     .fields
                     .iter()
@@ -199,12 +227,13 @@ impl<'a> Validator<'a> {
                 .find(|ast_field| ast_field.name.name == field.name)
                 .unwrap();
 
+            // TODO: this is really ugly
+            let supports_json_type = match self.source {
+                Some(source) => source.combined_connector.supports_json(),
+                None => false,
+            };
+
             if let Some(dml::ScalarType::Json) = field.field_type.scalar_type() {
-                // TODO: this is really ugly
-                let supports_json_type = match self.source {
-                    Some(source) => source.combined_connector.supports_json(),
-                    None => false,
-                };
                 if !supports_json_type {
                     errors.push(DatamodelError::new_field_validation_error(
                         &format!("Field `{}` in model `{}` can't be of type Json. The current connector does not support the Json type.", &field.name, &model.name),
@@ -214,6 +243,101 @@ impl<'a> Validator<'a> {
                     ));
                 }
             }
+
+            if let dml::FieldType::CompositeType(composite_type) = &field.field_type {
+                if !supports_json_type {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be of the composite type `{}`. Composite types are stored as Json, and the current connector does not support the Json type.", &field.name, &model.name, composite_type),
+                        &model.name,
+                        &field.name,
+                        ast_field.span.clone(),
+                    ));
+                }
+            }
+
+            let supports_sequences = match self.source {
+                Some(source) => source.combined_connector.supports_sequences(),
+                None => false,
+            };
+
+            let uses_sequence = match &field.default_value {
+                Some(dml::DefaultValue::Expression(generator)) => generator.sequence_name().is_some(),
+                _ => false,
+            };
+
+            if uses_sequence && !supports_sequences {
+                errors.push(DatamodelError::new_field_validation_error(
+                    &format!("Field `{}` in model `{}` can't use `sequence()` as default. The current connector does not support sequences.", &field.name, &model.name),
+                    &model.name,
+                    &field.name,
+                    ast_field.span.clone(),
+                ));
+            }
+
+            // The client can't read unsupported values, so it can't identify records by them.
+            let is_id = field.is_id || model.id_fields.contains(&field.name);
+
+            if field.field_type.is_unsupported() && is_id && !model.is_ignored {
+                errors.push(DatamodelError::new_field_validation_error(
+                    "Fields of unsupported types can not be used as id. Mark the model with `@@ignore` to leave it out of the client.",
+                    &model.name,
+                    &field.name,
+                    ast_field.span.clone(),
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Ignored fields are left out of the client, so they can't identify records. Relation fields
+    /// pointing to ignored models have to be ignored as well.
+    fn validate_ignored_fields(
+        &self,
+        datamodel: &dml::Datamodel,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for field in model.fields() {
+            let ast_field = match ast_model
+                .fields
+                .iter()
+                .find(|ast_field| ast_field.name.name == field.name)
+            {
+                Some(ast_field) => ast_field,
+                None => continue,
+            };
+
+            if field.is_ignored && field.is_id {
+                errors.push(DatamodelError::new_field_validation_error(
+                    "Fields that are marked with @id can not be ignored.",
+                    &model.name,
+                    &field.name,
+                    ast_field.span.clone(),
+                ));
+            }
+
+            if let dml::FieldType::Relation(rel) = &field.field_type {
+                let related_model_is_ignored = datamodel.find_model(&rel.to).map(|m| m.is_ignored).unwrap_or(false);
+
+                if related_model_is_ignored && !field.is_ignored && !model.is_ignored {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!(
+                            "The relation field `{}` on model `{}` must specify the `@ignore` attribute, because the model `{}` it is pointing to is marked ignored.",
+                            &field.name, &model.name, &rel.to
+                        ),
+                        &model.name,
+                        &field.name,
+                        ast_field.span.clone(),
+                    ));
+                }
+            }
         }
 
         if errors.has_errors() {
@@ -223,6 +347,62 @@ impl<'a> Validator<'a> {
         }
     }
 
+    /// A composite type must not contain itself through required fields, as its values would be
+    /// infinitely large.
+    fn validate_composite_type_is_not_recursive(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        schema: &dml::Datamodel,
+    ) -> Result<(), DatamodelError> {
+        fn find_cycle(schema: &dml::Datamodel, path: &mut Vec<String>) -> bool {
+            let composite_type = match path.last().and_then(|name| schema.find_composite_type(name)) {
+                Some(composite_type) => composite_type,
+                None => return false,
+            };
+
+            for field in composite_type.fields() {
+                let next = match &field.field_type {
+                    dml::FieldType::CompositeType(next) if field.arity.is_required() => next,
+                    _ => continue,
+                };
+
+                if next == &path[0] {
+                    path.push(next.clone());
+                    return true;
+                }
+
+                if path.contains(next) {
+                    continue;
+                }
+
+                path.push(next.clone());
+
+                if find_cycle(schema, path) {
+                    return true;
+                }
+
+                path.pop();
+            }
+
+            false
+        }
+
+        let mut path = vec![ast_composite_type.name.name.clone()];
+
+        if find_cycle(schema, &mut path) {
+            return Err(DatamodelError::new_composite_type_validation_error(
+                &format!(
+                    "The composite type is recursive through required fields: {}. Make one of the fields optional or a list.",
+                    path.join(" -> ")
+                ),
+                &ast_composite_type.name.name,
+                ast_composite_type.name.span,
+            ));
+        }
+
+        Ok(())
+    }
+
     fn validate_model_has_strict_unique_criteria(
         &self,
         ast_model: &ast::Model,
@@ -251,32 +431,19 @@ impl<'a> Validator<'a> {
             return multiple_id_criteria_error;
         }
 
-        let loose_criterias = model.loose_unique_criterias();
-        let suffix = if loose_criterias.is_empty() {
-            "".to_string()
-        } else {
-            let criteria_descriptions: Vec<_> = loose_criterias
-                .iter()
-                .map(|criteria| {
-                    let field_names: Vec<_> = criteria.fields.iter().map(|f| f.name.clone()).collect();
-                    format!("- {}", field_names.join(", "))
-                })
-                .collect();
-            format!(
-                " The following unique criterias were not considered as they contain fields that are not required:\n{}",
-                criteria_descriptions.join("\n")
-            )
-        };
-        let missing_id_criteria_error = Err(DatamodelError::new_model_validation_error(
-            &format!(
-                "Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model.{suffix}",
-                suffix = suffix
-            ),
+        let loose_criterias = model
+            .loose_unique_criterias()
+            .iter()
+            .map(|criteria| criteria.fields.iter().map(|f| f.name.clone()).collect())
+            .collect();
+        let missing_id_criteria_error = Err(DatamodelError::new_model_without_unique_criteria_error(
             &model.name,
+            loose_criterias,
             ast_model.span,
         ));
 
-        if model.strict_unique_criterias().is_empty() {
+        // Ignored models are left out of the client, so they don't need to identify records.
+        if model.strict_unique_criterias().is_empty() && !model.is_ignored {
             return missing_id_criteria_error;
         }
 
@@ -380,10 +547,10 @@ impl<'a> Validator<'a> {
                     && !rel_info.fields.is_empty(); // TODO: hack to maintain backwards compatibility for test schemas that don't specify fields yet
 
                 if !unknown_fields.is_empty() {
-                    errors.push(DatamodelError::new_validation_error(
-                        &format!("The argument fields must refer only to existing fields. The following fields do not exist in this model: {}", unknown_fields.join(", ")),
-                        ast_field.span.clone())
-                    );
+                    errors.push(DatamodelError::new_unknown_relation_fields_error(
+                        unknown_fields,
+                        ast_field.span.clone(),
+                    ));
                 }
 
                 if !referenced_relation_fields.is_empty() {
@@ -3,3 +3,4 @@ pub mod base_types;
 pub mod basic;
 pub mod comments;
 pub mod duplicates;
+pub mod multiple_files;
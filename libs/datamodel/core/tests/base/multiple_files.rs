@@ -0,0 +1,103 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError, SchemaFile, SchemaFiles};
+use pretty_assertions::assert_eq;
+
+fn schema_files(files: &[(&str, &str)]) -> SchemaFiles {
+    SchemaFiles::new(
+        files
+            .iter()
+            .map(|(name, contents)| SchemaFile {
+                name: (*name).to_owned(),
+                contents: (*contents).to_owned(),
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn models_can_reference_models_and_enums_in_other_files() {
+    let files = schema_files(&[
+        (
+            "blog.prisma",
+            r#"
+            model Blog {
+                id    Int    @id
+                posts Post[]
+            }
+            "#,
+        ),
+        (
+            "post.prisma",
+            r#"
+            model Post {
+                id     Int        @id
+                blogId Int
+                blog   Blog       @relation(fields: [blogId], references: [id])
+                status PostStatus
+            }
+
+            enum PostStatus {
+                DRAFT
+                PUBLISHED
+            }
+            "#,
+        ),
+    ]);
+
+    let schema = parse(files.combined());
+
+    schema
+        .assert_has_model("Blog")
+        .assert_has_field("posts")
+        .assert_relation_to("Post");
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("blog")
+        .assert_relation_to("Blog")
+        .assert_relation_base_fields(&["blogId"]);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("status")
+        .assert_enum_type("PostStatus");
+}
+
+#[test]
+fn errors_are_located_in_their_file() {
+    let post = "model Post {\n  id     Int @id\n  author Usr\n}";
+    let files = schema_files(&[("user.prisma", "model User {\n  id Int @id\n}"), ("post.prisma", post)]);
+
+    let errors = parse_error(files.combined());
+    let error = errors.to_iter().next().unwrap();
+    let file_span = error.file_span(&files).unwrap();
+    let type_start = post.find("Usr").unwrap();
+
+    assert_eq!(error, &DatamodelError::new_type_not_found_error("Usr", error.span()));
+    assert_eq!(file_span.file.name, "post.prisma");
+    assert_eq!(file_span.span, Span::new(type_start, type_start + 3));
+    assert!(errors.to_pretty_string_in_files(&files).contains("post.prisma:3"));
+}
+
+#[test]
+fn loading_a_directory_reads_all_schema_files_in_path_order() {
+    let directory = std::env::temp_dir().join(format!("datamodel-multiple-files-{}", std::process::id()));
+    let nested = directory.join("models");
+
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(directory.join("schema.prisma"), "model Blog {\n  id Int @id\n}").unwrap();
+    std::fs::write(nested.join("post.prisma"), "model Post {\n  id Int @id\n}").unwrap();
+    std::fs::write(directory.join("README.md"), "Not a schema file.").unwrap();
+
+    let files = SchemaFiles::load(&directory).unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    let names: Vec<&str> = files.files().iter().map(|file| file.name.as_str()).collect();
+    let expected_post_file_name = std::path::Path::new("models").join("post.prisma");
+
+    assert_eq!(names, &[expected_post_file_name.to_str().unwrap(), "schema.prisma"]);
+
+    let schema = parse(files.combined());
+    schema.assert_has_model("Blog");
+    schema.assert_has_model("Post");
+}
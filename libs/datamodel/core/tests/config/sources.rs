@@ -316,6 +316,95 @@ fn fail_to_load_sources_for_invalid_source() {
     }
 }
 
+#[test]
+#[serial]
+fn must_load_read_replicas() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://primary"
+          replicas = ["postgresql://replica1", env("REPLICA_URL")]
+        }
+    "#;
+    std::env::set_var("REPLICA_URL", "postgresql://replica2");
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://primary"
+          },
+          "replicas": [
+            {
+              "fromEnvVar": null,
+              "value": "postgresql://replica1"
+            },
+            {
+              "fromEnvVar": "REPLICA_URL",
+              "value": "postgresql://replica2"
+            }
+          ]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn must_error_if_wrong_protocol_is_used_for_a_replica() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://primary"
+            replicas = ["mysql://replica"]
+        }
+    "#;
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+    let errors = config.err().expect("This must error");
+    errors.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `myds` must start with the protocol `postgresql://`.",
+        "myds",
+        Span::new(127, 146),
+    ));
+}
+
+#[test]
+fn must_error_for_replicas_on_sqlite() {
+    let schema = r#"
+        datasource myds {
+            provider = "sqlite"
+            url = "file:dev.db"
+            replicas = ["file:replica.db"]
+        }
+    "#;
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+    let errors = config.err().expect("This must error");
+    errors.assert_is(DatamodelError::new_source_validation_error(
+        "Read replicas are not supported for SQLite datasources.",
+        "myds",
+        Span::new(114, 133),
+    ));
+}
+
+#[test]
+fn url_parameters_are_read_from_the_connection_string() {
+    let url = "postgresql://localhost/db?schema=app&connection_limit=10&flag";
+
+    assert_eq!(datamodel::url_parameter(url, "connection_limit"), Some("10"));
+    assert_eq!(datamodel::url_parameter(url, "schema"), Some("app"));
+    assert_eq!(datamodel::url_parameter(url, "flag"), None);
+    assert_eq!(datamodel::url_parameter(url, "socket_timeout"), None);
+    assert_eq!(datamodel::url_parameter("file:dev.db", "schema"), None);
+}
+
 fn assert_eq_json(a: &str, b: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
@@ -130,3 +130,107 @@ fn must_error_if_autoincrement_function_is_used_for_fields_that_are_not_int() {
         Span::new(70, 85),
     ));
 }
+
+#[test]
+fn must_error_if_functions_without_arguments_are_given_arguments() {
+    let dml = r#"
+    model Model {
+        id String @id @default(cuid(2))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors
+        .assert_is_message("Error parsing attribute \"@default\": The function `cuid()` does not take any arguments.");
+}
+
+#[test]
+fn must_error_if_uuid_function_is_given_an_unknown_version() {
+    let dml = r#"
+    model Model {
+        id String @id @default(uuid(5))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@default\": The function `uuid()` only accepts the versions 4 and 7 as argument.",
+    );
+}
+
+#[test]
+fn must_error_if_nanoid_function_is_given_an_invalid_length() {
+    let dml = r#"
+    model Model {
+        id String @id @default(nanoid(1))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@default\": The function `nanoid()` only accepts a length between 2 and 255 as argument.",
+    );
+}
+
+#[test]
+fn must_error_if_sequence_function_is_not_given_a_name() {
+    let dml = r#"
+    model Model {
+        id Int @id @default(sequence())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error parsing attribute \"@default\": The function `sequence()` expects the name of the sequence as its only argument.");
+}
+
+#[test]
+fn must_error_if_the_sequence_name_contains_double_quotes() {
+    let dml = r#"
+    model Model {
+        id Int @id @default(sequence("user\"ids"))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@default\": The name of the sequence in `sequence()` can't contain double quotes.",
+    );
+}
+
+#[test]
+fn must_error_if_sequence_function_is_used_for_fields_that_are_not_int() {
+    let dml = r#"
+    model Model {
+        id String @id @default(sequence("ids"))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@default\": The function `sequence()` can not be used on fields of type `String`.",
+    );
+}
+
+#[test]
+fn must_error_if_sequence_function_is_used_on_connectors_without_sequences() {
+    let dml = format!(
+        r#"{}
+
+    model Model {{
+        id Int @id @default(sequence("ids"))
+    }}
+    "#,
+        SQLITE_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message("Error validating field `id` in model `Model`: Field `id` in model `Model` can't use `sequence()` as default. The current connector does not support sequences.");
+}
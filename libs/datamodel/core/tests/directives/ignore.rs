@@ -0,0 +1,169 @@
+use crate::common::*;
+use datamodel::render_datamodel_to_string;
+
+#[test]
+fn ignore_must_work_on_models_and_fields() {
+    let dml = r#"
+    model User {
+        id     Int    @id
+        secret String @ignore
+        posts  Post[] @ignore
+    }
+
+    model Post {
+        id     Int
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let user = datamodel.assert_has_model("User");
+    assert!(!user.is_ignored);
+    assert!(!user.assert_has_field("id").is_ignored);
+    assert!(user.assert_has_field("secret").is_ignored);
+    assert!(user.assert_has_field("posts").is_ignored);
+
+    assert!(datamodel.assert_has_model("Post").is_ignored);
+}
+
+#[test]
+fn ignore_must_be_rendered() {
+    let dml = r#"
+    model User {
+        id     Int    @id
+        secret String @ignore
+    }
+
+    model Audit {
+        id Int
+
+        @@ignore
+    }
+    "#;
+
+    let rendered = render_datamodel_to_string(&parse(dml)).unwrap();
+    let datamodel = parse(&rendered);
+
+    assert!(datamodel.assert_has_model("User").assert_has_field("secret").is_ignored);
+    assert!(datamodel.assert_has_model("Audit").is_ignored);
+}
+
+#[test]
+fn generated_back_relation_fields_to_ignored_models_must_be_ignored() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model Post {
+        id     Int
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let back_relation_field = datamodel.assert_has_model("User").assert_has_field("Post");
+    assert!(back_relation_field.is_generated);
+    assert!(back_relation_field.is_ignored);
+}
+
+#[test]
+fn relation_fields_to_ignored_models_must_be_ignored() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error validating field `posts` in model `User`: The relation field `posts` on model `User` must specify the `@ignore` attribute, because the model `Post` it is pointing to is marked ignored.");
+}
+
+#[test]
+fn id_fields_must_not_be_ignored() {
+    let dml = r#"
+    model User {
+        id Int @id @ignore
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `id` in model `User`: Fields that are marked with @id can not be ignored.",
+    );
+}
+
+#[test]
+fn ignored_models_and_fields_must_not_be_part_of_the_dmmf() {
+    let dml = r#"
+    model User {
+        id     Int    @id
+        secret String @ignore
+    }
+
+    model Audit {
+        id Int
+
+        @@ignore
+    }
+    "#;
+
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&parse(dml));
+    let models = dmmf["models"].as_array().unwrap();
+
+    assert_eq!(models.len(), 1);
+    assert_eq!(models[0]["name"], "User");
+
+    let fields = models[0]["fields"].as_array().unwrap();
+
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0]["name"], "id");
+}
+
+#[test]
+fn relation_fields_whose_opposite_field_is_ignored_must_not_be_part_of_the_dmmf() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @ignore
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&parse(dml));
+    let models = dmmf["models"].as_array().unwrap();
+
+    let post = models.iter().find(|model| model["name"] == "Post").unwrap();
+    let field_names: Vec<_> = post["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| field["name"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(field_names, vec!["id", "userId"]);
+}
@@ -3,6 +3,7 @@ pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
 pub mod id_positive;
+pub mod ignore;
 pub mod index;
 pub mod map;
 pub mod relations_basic;
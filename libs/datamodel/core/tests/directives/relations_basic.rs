@@ -115,12 +115,10 @@ fn must_error_when_non_existing_fields_are_used() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(
-        DatamodelError::new_validation_error(
-            "The argument fields must refer only to existing fields. The following fields do not exist in this model: authorFirstName, authorLastName", 
-                Span::new(232, 332)
-        )
-    );
+    errors.assert_is(DatamodelError::new_unknown_relation_fields_error(
+        vec!["authorFirstName".to_owned(), "authorLastName".to_owned()],
+        Span::new(232, 332),
+    ));
 }
 
 #[test]
@@ -55,7 +55,10 @@ fn relation_must_error_when_base_field_does_not_exist() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument fields must refer only to existing fields. The following fields do not exist in this model: userId", Span::new(162, 218)));
+    errors.assert_is(DatamodelError::new_unknown_relation_fields_error(
+        vec!["userId".to_owned()],
+        Span::new(162, 218),
+    ));
 }
 
 #[test]
@@ -11,9 +11,9 @@ fn must_error_on_model_without_unique_criteria() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is(DatamodelError::new_model_validation_error(
-        "Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model.",
+    errors.assert_is(DatamodelError::new_model_without_unique_criteria_error(
         "Model",
+        Vec::new(),
         Span::new(5, 42),
     ));
 }
@@ -32,11 +32,15 @@ fn must_error_if_only_loose_unique_criterias_are_present() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is(DatamodelError::new_model_validation_error(
-        "Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model. The following unique criterias were not considered as they contain fields that are not required:\n- name\n- a, b",
+    errors.assert_is(DatamodelError::new_model_without_unique_criteria_error(
         "Model",
+        vec![vec!["name".to_owned()], vec!["a".to_owned(), "b".to_owned()]],
         Span::new(5, 139),
     ));
+    assert_eq!(
+        errors.errors[0].description(),
+        "Error validating model \"Model\": Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model. The following unique criterias were not considered as they contain fields that are not required:\n- name\n- a, b"
+    );
 }
 
 #[test]
@@ -1,5 +1,6 @@
 use crate::common::*;
 use datamodel::{common::ScalarType, DefaultValue, ValueGenerator};
+use prisma_value::PrismaValue;
 
 #[test]
 fn correctly_handle_server_side_now_function() {
@@ -60,3 +61,113 @@ fn correctly_handle_server_side_uuid_function() {
         .assert_base_type(&ScalarType::String)
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_uuid()));
 }
+
+#[test]
+fn correctly_handle_server_side_uuid_function_with_version() {
+    let dml = r#"
+    model User {
+        id Int @id
+        v4 String @default(uuid(4))
+        v7 String @default(uuid(7))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    user_model
+        .assert_has_field("v4")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(4)]).unwrap(),
+        ));
+    user_model
+        .assert_has_field("v4")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_uuid()));
+    user_model
+        .assert_has_field("v7")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)]).unwrap(),
+        ));
+}
+
+#[test]
+fn correctly_handle_server_side_ulid_and_nanoid_functions() {
+    let dml = r#"
+    model User {
+        id       String @id @default(ulid())
+        slug     String @default(nanoid())
+        shortId  String @default(nanoid(10))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    user_model
+        .assert_has_field("id")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_ulid()));
+    user_model
+        .assert_has_field("slug")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid()));
+    user_model
+        .assert_has_field("shortId")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("nanoid".to_owned(), vec![PrismaValue::Int(10)]).unwrap(),
+        ));
+}
+
+#[test]
+fn correctly_handle_server_side_sequence_function() {
+    let dml = format!(
+        r#"{}
+
+    model User {{
+        id     Int @id @default(autoincrement())
+        number Int @default(sequence("user_numbers"))
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let schema = parse(&dml);
+    let number = schema.assert_has_model("User").assert_has_field("number").clone();
+
+    assert_eq!(
+        number.default_value,
+        Some(DefaultValue::Expression(ValueGenerator::new_sequence("user_numbers")))
+    );
+
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+    assert!(rendered.contains(r#"@default(sequence("user_numbers"))"#));
+}
+
+#[test]
+fn server_side_functions_must_generate_well_formed_values() {
+    let ulid = ValueGenerator::new_ulid().generate().unwrap().into_string().unwrap();
+    assert_eq!(ulid.len(), 26);
+    assert!(ulid.chars().all(|c| "0123456789ABCDEFGHJKMNPQRSTVWXYZ".contains(c)));
+
+    let nanoid = ValueGenerator::new_nanoid().generate().unwrap().into_string().unwrap();
+    assert_eq!(nanoid.len(), 21);
+    assert!(nanoid
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'));
+
+    let short_nanoid = ValueGenerator::new("nanoid".to_owned(), vec![PrismaValue::Int(10)])
+        .unwrap()
+        .generate()
+        .unwrap()
+        .into_string()
+        .unwrap();
+    assert_eq!(short_nanoid.len(), 10);
+
+    match ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)])
+        .unwrap()
+        .generate()
+    {
+        Some(PrismaValue::Uuid(uuid)) => assert_eq!(uuid.get_version_num(), 7),
+        other => panic!("Expected a UUID, got {:?}", other),
+    }
+
+    assert_eq!(ValueGenerator::new_sequence("user_numbers").generate(), None);
+}
@@ -0,0 +1,124 @@
+use crate::common::*;
+use datamodel::ast::Span;
+use datamodel::error::DatamodelError;
+
+fn span_of(dml: &str, text: &str) -> Span {
+    let start = dml.find(text).unwrap();
+
+    Span::new(start, start + text.len())
+}
+
+#[test]
+fn syntax_errors_in_multiple_blocks_are_all_reported_with_the_validation_errors() {
+    let dml = r#"model User {
+  id   Int    @id
+  name String @default(
+}
+
+model Post {
+  id     Int @id
+  title: String
+}
+
+model Comment {
+  id     Int @id
+  author Usr
+}
+"#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(3);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_validation_error(
+            "This line is not a valid field or directive definition.",
+            span_of(dml, "name String @default(\n"),
+        ),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_legacy_parser_error("Field declarations don't require a `:`.", span_of(dml, ":")),
+    );
+    errors.assert_is_at(2, DatamodelError::new_type_not_found_error("Usr", span_of(dml, "Usr")));
+}
+
+#[test]
+fn validation_errors_in_blocks_with_syntax_errors_are_not_reported() {
+    // The model has no id once the broken field is left out, which is not worth reporting.
+    let dml = r#"model User {
+  id: Int @id
+}
+"#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_legacy_parser_error(
+        "Field declarations don't require a `:`.",
+        span_of(dml, ":"),
+    ));
+}
+
+#[test]
+fn a_block_that_is_not_closed_ends_at_the_next_block() {
+    let dml = r#"model User {
+  id Int @id
+
+model Post {
+  id     Int @id
+  author Usr
+}
+"#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(2);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_validation_error("This block is not closed. It is missing a `}`.", span_of(dml, "User")),
+    );
+    errors.assert_is_at(1, DatamodelError::new_type_not_found_error("Usr", span_of(dml, "Usr")));
+}
+
+#[test]
+fn the_well_formed_parts_of_a_schema_with_syntax_errors_are_parsed() {
+    let dml = r#"model User {
+  id   Int    @id
+  name String @default(
+  email String
+}
+
+enum Role {
+  USER
+  ADMIN
+
+model Post {
+  id Int @id
+}
+"#;
+
+    let (ast, errors) = datamodel::parse_schema_ast_with_recovery(dml);
+
+    assert_eq!(errors.errors.len(), 2);
+
+    let user_fields: Vec<&str> = ast
+        .find_model("User")
+        .unwrap()
+        .fields
+        .iter()
+        .map(|field| field.name.name.as_str())
+        .collect();
+
+    assert_eq!(user_fields, &["id", "email"]);
+
+    let role_values: Vec<&str> = ast
+        .find_enum("Role")
+        .unwrap()
+        .values
+        .iter()
+        .map(|value| value.name.name.as_str())
+        .collect();
+
+    assert_eq!(role_values, &["USER", "ADMIN"]);
+    assert!(ast.find_model("Post").is_some());
+}
@@ -1,2 +1,3 @@
+pub mod error_recovery;
 pub mod literals;
 pub mod nice_errors;
@@ -0,0 +1,165 @@
+use datamodel::ast::reformat::{edits::apply_edits, CodeAction};
+use pretty_assertions::assert_eq;
+
+fn apply(schema: &str, action: &CodeAction) -> String {
+    apply_edits(schema, &action.edits)
+}
+
+fn assert_single_action(input: &str, expected: &str) {
+    let actions = datamodel::code_actions(input);
+
+    assert_eq!(actions.len(), 1, "{:?}", actions);
+    assert_eq!(apply(input, &actions[0]), expected);
+}
+
+#[test]
+fn missing_relation_scalar_fields_must_be_added() {
+    let input = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id     Int   @id
+  author User? @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    let expected = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id     Int   @id
+  author User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+"#;
+
+    assert_single_action(input, expected);
+}
+
+#[test]
+fn one_to_one_relation_scalar_fields_must_be_made_unique() {
+    let input = r#"model User {
+  id      Int      @id
+  profile Profile?
+}
+
+model Profile {
+  id     Int  @id
+  userId Int
+  user   User @relation(fields: [userId], references: [id])
+}
+"#;
+
+    let expected = r#"model User {
+  id      Int      @id
+  profile Profile?
+}
+
+model Profile {
+  id     Int  @id
+  userId Int @unique
+  user   User @relation(fields: [userId], references: [id])
+}
+"#;
+
+    assert_single_action(input, expected);
+}
+
+#[test]
+fn misspelled_attributes_must_be_replaced() {
+    let input = r#"model User {
+  id    Int    @id
+  email String @uniqe
+}
+"#;
+
+    let expected = r#"model User {
+  id    Int    @id
+  email String @unique
+}
+"#;
+
+    assert_single_action(input, expected);
+}
+
+#[test]
+fn a_missing_id_must_be_added() {
+    let input = r#"model User {
+  email String
+}
+"#;
+
+    let expected = r#"model User {
+  id Int @id @default(autoincrement())
+  email String
+}
+"#;
+
+    assert_single_action(input, expected);
+}
+
+#[test]
+fn a_field_called_id_must_become_the_id() {
+    let input = r#"model User {
+  id    String @default(cuid())
+  email String
+}
+"#;
+
+    let expected = r#"model User {
+  id    String @default(cuid()) @id
+  email String
+}
+"#;
+
+    assert_single_action(input, expected);
+}
+
+#[test]
+fn missing_back_relation_fields_must_be_added() {
+    let input = r#"model User {
+  id Int @id
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    let expected = r#"model User {
+  id Int @id
+  Post Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    assert_single_action(input, expected);
+}
+
+#[test]
+fn valid_schemas_have_no_code_actions() {
+    let input = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    assert!(datamodel::code_actions(input).is_empty());
+}
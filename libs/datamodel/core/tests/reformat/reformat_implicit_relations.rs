@@ -111,6 +111,44 @@ model Post {
     assert_reformat(input, expected);
 }
 
+#[test]
+fn back_relation_fields_must_be_added_to_the_file_of_their_model() {
+    let blog = r#"model Blog {
+  id    Int     @id
+}
+"#;
+
+    let post = r#"model Post {
+  id     Int  @id
+  blogId Int
+  Blog   Blog @relation(fields: [blogId], references: [id])
+}
+"#;
+
+    let expected_blog = r#"model Blog {
+  id   Int    @id
+  Post Post[]
+}
+"#;
+
+    let files = datamodel::SchemaFiles::new(vec![
+        datamodel::SchemaFile {
+            name: "blog.prisma".to_owned(),
+            contents: blog.to_owned(),
+        },
+        datamodel::SchemaFile {
+            name: "post.prisma".to_owned(),
+            contents: post.to_owned(),
+        },
+    ]);
+
+    let reformat =
+        |input: &str| datamodel::ast::reformat::Reformatter::new_in_schema(input, &files).reformat_to_string();
+
+    assert_eq!(reformat(blog), expected_blog);
+    assert_eq!(reformat(post), post);
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     println!("schema: {:?}", schema);
     let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
@@ -0,0 +1,184 @@
+use crate::common::*;
+use datamodel::{common::ScalarType, dml};
+use prisma_value::PrismaValue;
+
+#[test]
+fn composite_types_can_be_used_as_field_types() {
+    let dml = format!(
+        r#"{}
+
+    model User {{
+        id        Int        @id
+        address   Address
+        billing   Address?
+        lineItems LineItem[]
+    }}
+
+    type Address {{
+        street String
+        zip    String?
+        geo    Geo?
+    }}
+
+    type Geo {{
+        lat Float
+        lng Float
+    }}
+
+    type LineItem {{
+        sku      String
+        quantity Int    @default(1)
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let user = datamodel.assert_has_model("User");
+
+    let address = user.assert_has_field("address");
+    assert_eq!(address.field_type, dml::FieldType::CompositeType("Address".to_owned()));
+    address.assert_arity(&dml::FieldArity::Required);
+
+    user.assert_has_field("billing")
+        .assert_arity(&dml::FieldArity::Optional);
+
+    let line_items = user.assert_has_field("lineItems");
+    assert_eq!(
+        line_items.field_type,
+        dml::FieldType::CompositeType("LineItem".to_owned())
+    );
+    line_items.assert_arity(&dml::FieldArity::List);
+
+    let address_type = datamodel.find_composite_type("Address").unwrap();
+    address_type
+        .find_field("street")
+        .unwrap()
+        .assert_base_type(&ScalarType::String);
+    address_type
+        .find_field("geo")
+        .unwrap()
+        .assert_arity(&dml::FieldArity::Optional);
+
+    datamodel
+        .find_composite_type("LineItem")
+        .unwrap()
+        .find_field("quantity")
+        .unwrap()
+        .assert_default_value(dml::DefaultValue::Single(PrismaValue::Int(1)));
+}
+
+#[test]
+fn composite_types_must_not_be_recursive_through_required_fields() {
+    let dml = format!(
+        r#"{}
+
+    model User {{
+        id      Int  @id
+        address Address
+    }}
+
+    type Address {{
+        street String
+        parent Address
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message("Error validating composite type `Address`: The composite type is recursive through required fields: Address -> Address. Make one of the fields optional or a list.");
+}
+
+#[test]
+fn composite_types_can_be_recursive_through_optional_fields_and_lists() {
+    let dml = format!(
+        r#"{}
+
+    model Category {{
+        id   Int  @id
+        tree Node
+    }}
+
+    type Node {{
+        name     String
+        parent   Node?
+        children Node[]
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    parse(&dml);
+}
+
+#[test]
+fn composite_fields_cannot_be_ids() {
+    let dml = format!(
+        r#"{}
+
+    model User {{
+        address Address @id
+    }}
+
+    type Address {{
+        street String
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message("Error parsing attribute \"@id\": The field `address` is of the composite type `Address` and cannot be marked with `@id`. Only scalar fields can be declared as id.");
+}
+
+#[test]
+fn composite_types_cannot_have_relation_fields() {
+    let dml = format!(
+        r#"{}
+
+    model User {{
+        id      Int     @id
+        address Address
+    }}
+
+    model Country {{
+        id Int @id
+    }}
+
+    type Address {{
+        street  String
+        country Country
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message("Error validating composite type `Address`: The field `country` refers to the model `Country`. Composite types can not have relation fields.");
+}
+
+#[test]
+fn composite_fields_require_json_support() {
+    let dml = format!(
+        r#"{}
+
+    model User {{
+        id      Int     @id
+        address Address
+    }}
+
+    type Address {{
+        street String
+    }}
+    "#,
+        SQLITE_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message("Error validating field `address` in model `User`: Field `address` in model `User` can't be of the composite type `Address`. Composite types are stored as Json, and the current connector does not support the Json type.");
+}
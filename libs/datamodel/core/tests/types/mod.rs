@@ -1,2 +1,4 @@
+pub mod composite_types;
 pub mod negative;
 pub mod positive;
+pub mod unsupported;
@@ -0,0 +1,192 @@
+use crate::common::*;
+use datamodel::{dml, render_datamodel_to_string, ValueGenerator};
+
+#[test]
+fn unsupported_types_must_keep_their_native_type() {
+    let dml = format!(
+        r#"{}
+
+    model Post {{
+        id       Int                       @id
+        search   Unsupported("tsvector")
+        location Unsupported("geometry(Point,4326)")?
+        ranges   Unsupported("int4range")[]
+        vector   Unsupported("tsvector")    @default(dbgenerated())
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let post = datamodel.assert_has_model("Post");
+
+    let search = post.assert_has_field("search");
+    assert_eq!(search.field_type, dml::FieldType::Unsupported("tsvector".to_owned()));
+    search.assert_arity(&dml::FieldArity::Required);
+
+    let location = post.assert_has_field("location");
+    assert_eq!(
+        location.field_type,
+        dml::FieldType::Unsupported("geometry(Point,4326)".to_owned())
+    );
+    location.assert_arity(&dml::FieldArity::Optional);
+
+    post.assert_has_field("ranges").assert_arity(&dml::FieldArity::List);
+
+    assert_eq!(
+        post.assert_has_field("vector").default_value,
+        Some(dml::DefaultValue::Expression(ValueGenerator::new_dbgenerated()))
+    );
+}
+
+#[test]
+fn unsupported_types_must_be_rendered() {
+    let dml = r#"
+    model Post {
+        id     Int                     @id
+        search Unsupported("tsvector")?
+    }
+    "#;
+
+    let rendered = render_datamodel_to_string(&parse(dml)).unwrap();
+    assert!(rendered.contains(r#"Unsupported("tsvector")?"#));
+
+    let search = parse(&rendered)
+        .assert_has_model("Post")
+        .assert_has_field("search")
+        .clone();
+    assert_eq!(search.field_type, dml::FieldType::Unsupported("tsvector".to_owned()));
+}
+
+#[test]
+fn unsupported_types_must_be_kept_by_the_reformatter() {
+    let input = r#"
+    model Post {
+        id Int @id
+        search   Unsupported("tsvector")?
+    }
+    "#;
+
+    let expected = r#"model Post {
+  id     Int                      @id
+  search Unsupported("tsvector")?
+}
+"#;
+
+    let result = datamodel::ast::reformat::Reformatter::new(&input).reformat_to_string();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn unsupported_types_must_only_allow_dbgenerated_defaults() {
+    let dml = r#"
+    model Post {
+        id     Int                     @id
+        search Unsupported("tsvector") @default("")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@default\": Only @default(dbgenerated()) can be used for Unsupported types.",
+    );
+}
+
+#[test]
+fn unsupported_types_must_not_be_used_as_id() {
+    let dml = r#"
+    model Post {
+        mac Unsupported("macaddr") @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error validating field `mac` in model `Post`: Fields of unsupported types can not be used as id. Mark the model with `@@ignore` to leave it out of the client.");
+}
+
+#[test]
+fn unsupported_types_may_be_used_as_id_of_ignored_models() {
+    let dml = r#"
+    model Post {
+        mac Unsupported("macaddr") @id
+
+        @@ignore
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    assert!(datamodel.assert_has_model("Post").is_ignored);
+}
+
+#[test]
+fn unsupported_types_must_not_be_part_of_the_dmmf() {
+    let dml = r#"
+    model Post {
+        id     Int                     @id
+        search Unsupported("tsvector")
+    }
+    "#;
+
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&parse(dml));
+    let fields = dmmf["models"][0]["fields"].as_array().unwrap();
+
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0]["name"], "id");
+}
+
+#[test]
+fn unsupported_types_must_be_valid_database_types() {
+    for native_type in &[
+        "text; DROP TABLE \\\"Post\\\"",
+        "text'",
+        "text -- comment",
+        "int, evil text",
+        "geometry(Point",
+        "geometry)Point(",
+        "",
+    ] {
+        let dml = format!(
+            r#"
+    model Post {{
+        id     Int                     @id
+        search Unsupported("{}")
+    }}
+    "#,
+            native_type
+        );
+
+        let errors = parse_error(&dml);
+
+        assert!(
+            errors.errors[0].to_string().contains("is not a valid database type"),
+            "{:?}",
+            errors
+        );
+    }
+}
+
+#[test]
+fn unsupported_types_may_have_arguments() {
+    let dml = r#"
+    model Post {
+        id       Int                                    @id
+        location Unsupported("geometry(Point, 4326)")?
+        time     Unsupported("timestamp(3) with time zone")?
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let post = datamodel.assert_has_model("Post");
+
+    assert_eq!(
+        post.assert_has_field("location").field_type,
+        dml::FieldType::Unsupported("geometry(Point, 4326)".to_owned())
+    );
+    assert_eq!(
+        post.assert_has_field("time").field_type,
+        dml::FieldType::Unsupported("timestamp(3) with time zone".to_owned())
+    );
+}
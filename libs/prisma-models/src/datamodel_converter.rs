@@ -29,6 +29,7 @@ impl<'a> DatamodelConverter<'a> {
             models: self.convert_models(),
             relations: self.convert_relations(),
             enums: self.convert_enums(),
+            composite_types: self.convert_composite_types(),
             version: Some("v2".to_string()),
         }
     }
@@ -52,9 +53,29 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    fn convert_composite_types(&self) -> Vec<InternalCompositeType> {
+        self.datamodel
+            .composite_types()
+            .map(|composite_type| InternalCompositeType {
+                name: composite_type.name.clone(),
+                fields: composite_type
+                    .fields()
+                    .map(|field| InternalCompositeTypeField {
+                        name: field.name.clone(),
+                        type_identifier: field.type_identifier(),
+                        arity: field.arity,
+                        internal_enum: field.internal_enum(self.datamodel),
+                        composite_type: field.composite_type_name(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
+            .filter(|model| !model.is_ignored)
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
@@ -70,7 +91,8 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_fields(&self, model: &dml::Model) -> Vec<FieldTemplate> {
         model
             .fields()
-            .map(|field| match field.field_type {
+            .filter(|field| !field.is_ignored && !field.field_type.is_unsupported())
+            .filter_map(|field| match field.field_type {
                 dml::FieldType::Relation(ref ri) => {
                     let relation = self
                         .relations
@@ -83,7 +105,11 @@ impl<'a> DatamodelConverter<'a> {
                             )
                         });
 
-                    FieldTemplate::Relation(RelationFieldTemplate {
+                    if relation.is_ignored() {
+                        return None;
+                    }
+
+                    Some(FieldTemplate::Relation(RelationFieldTemplate {
                         name: field.name.clone(),
                         is_id: field.is_id,
                         is_required: field.is_required(),
@@ -93,9 +119,9 @@ impl<'a> DatamodelConverter<'a> {
                         relation_name: relation.name(),
                         relation_side: relation.relation_side(field),
                         relation_info: ri.clone(),
-                    })
+                    }))
                 }
-                _ => FieldTemplate::Scalar(ScalarFieldTemplate {
+                _ => Some(FieldTemplate::Scalar(ScalarFieldTemplate {
                     name: field.name.clone(),
                     type_identifier: field.type_identifier(),
                     is_required: field.is_required(),
@@ -105,10 +131,11 @@ impl<'a> DatamodelConverter<'a> {
                     is_auto_generated_int_id: field.is_auto_generated_int_id(),
                     behaviour: field.behaviour(),
                     internal_enum: field.internal_enum(self.datamodel),
+                    composite_type: field.composite_type_name(),
                     db_name: field.database_name.clone(),
                     arity: field.arity,
                     default_value: field.default_value.clone(),
-                }),
+                })),
             })
             .collect()
     }
@@ -116,6 +143,7 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_relations(&self) -> Vec<RelationTemplate> {
         self.relations
             .iter()
+            .filter(|r| !r.is_ignored())
             .map(|r| RelationTemplate {
                 name: r.name(),
                 model_a_on_delete: OnDelete::SetNull,
@@ -319,6 +347,11 @@ impl TempRelationHolder {
         "B".to_string()
     }
 
+    /// Relations are left out of the client if one of their sides is ignored.
+    pub fn is_ignored(&self) -> bool {
+        self.model_a.is_ignored || self.model_b.is_ignored || self.field_a.is_ignored || self.field_b.is_ignored
+    }
+
     pub fn is_one_to_one(&self) -> bool {
         !self.field_a.is_list() && !self.field_b.is_list()
     }
@@ -369,6 +402,7 @@ trait DatamodelFieldExtensions {
     fn final_db_name(&self) -> String;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
+    fn composite_type_name(&self) -> Option<String>;
     // fn default_value(&self) -> Option<dml::DefaultValue>; todo this is not applicable anymore
 }
 
@@ -377,6 +411,7 @@ impl DatamodelFieldExtensions for dml::Field {
         match &self.field_type {
             dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
             dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
             dml::FieldType::Base(scalar, _) => match scalar {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
@@ -385,7 +420,7 @@ impl DatamodelFieldExtensions for dml::Field {
                 dml::ScalarType::String => TypeIdentifier::String,
                 dml::ScalarType::Json => TypeIdentifier::Json,
             },
-            dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
+            dml::FieldType::Unsupported(_) => panic!("Fields of unsupported types are not part of the client"),
             dml::FieldType::ConnectorSpecific { .. } => {
                 unimplemented!("Connector Specific types are not supported here yet")
             }
@@ -396,8 +431,9 @@ impl DatamodelFieldExtensions for dml::Field {
         self.arity == dml::FieldArity::Required
     }
 
+    /// Lists of composite types are stored as a single JSON array, not as a scalar list.
     fn is_list(&self) -> bool {
-        self.arity == dml::FieldArity::List
+        self.arity == dml::FieldArity::List && !self.field_type.is_composite_type()
     }
 
     fn is_unique(&self, model: &dml::Model) -> bool {
@@ -461,6 +497,13 @@ impl DatamodelFieldExtensions for dml::Field {
         }
     }
 
+    fn composite_type_name(&self) -> Option<String> {
+        match self.field_type {
+            dml::FieldType::CompositeType(ref name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
     // fn default_value(&self) -> Option<dml::DefaultValue> {
     //     self.default_value.clone()
     // }
@@ -25,6 +25,7 @@ pub struct ScalarFieldTemplate {
     pub is_auto_generated_int_id: bool,
     pub behaviour: Option<FieldBehaviour>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<String>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
@@ -39,6 +40,9 @@ pub struct ScalarField {
     pub is_id: bool,
     pub is_auto_generated_int_id: bool,
     pub internal_enum: Option<InternalEnum>,
+    /// The name of the composite type of the field. Composite fields are stored as a JSON document,
+    /// lists of composites as a JSON array.
+    pub composite_type: Option<String>,
     pub behaviour: Option<FieldBehaviour>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
@@ -61,6 +65,7 @@ impl Hash for ScalarField {
         self.is_id.hash(state);
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
+        self.composite_type.hash(state);
         self.behaviour.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
@@ -78,6 +83,7 @@ impl PartialEq for ScalarField {
             && self.is_id == other.is_id
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
+            && self.composite_type == other.composite_type
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
@@ -112,6 +118,7 @@ impl ScalarFieldTemplate {
             read_only: OnceCell::new(),
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
+            composite_type: self.composite_type,
             behaviour: self.behaviour,
             arity: self.arity,
             db_name: self.db_name,
@@ -175,7 +182,25 @@ impl ScalarField {
     }
 
     pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
-        (self.type_identifier.clone(), self.arity)
+        if self.is_composite_list() {
+            (self.type_identifier.clone(), FieldArity::Optional)
+        } else {
+            (self.type_identifier.clone(), self.arity)
+        }
+    }
+
+    pub fn is_composite(&self) -> bool {
+        self.composite_type.is_some()
+    }
+
+    /// Lists of composite types are a single JSON array in the database, so `is_list` is not set on them.
+    pub fn is_composite_list(&self) -> bool {
+        self.is_composite() && self.arity == FieldArity::List
+    }
+
+    pub fn internal_composite_type(&self) -> Option<InternalCompositeType> {
+        let name = self.composite_type.as_ref()?;
+        self.internal_data_model().find_composite_type(name).cloned()
     }
 
     pub fn is_read_only(&self) -> bool {
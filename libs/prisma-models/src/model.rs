@@ -143,6 +143,14 @@ impl Model {
             .expect("InternalDataModel does not exist anymore. Parent internal_data_model is deleted without deleting the child internal_data_model.")
     }
 
+    /// Fields of unsupported types are not part of the client, so records can only be created
+    /// if the database provides defaults for the required ones.
+    pub fn supports_create_operation(&self) -> bool {
+        self.dml_model.fields().all(|field| {
+            !field.field_type.is_unsupported() || !field.arity.is_required() || field.default_value.is_some()
+        })
+    }
+
     pub fn map_scalar_db_field_name(&self, name: &str) -> Option<ScalarFieldRef> {
         self.fields()
             .scalar()
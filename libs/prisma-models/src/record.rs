@@ -1,5 +1,5 @@
 use crate::{DomainError, ModelProjection, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub struct SingleRecord {
@@ -62,6 +62,29 @@ impl ManyRecords {
         })
     }
 
+    /// Removes every record that has the same values for the given fields as a record before it.
+    /// Records of different parents are never considered equal.
+    pub fn distinct(&mut self, projection: &ModelProjection) {
+        let field_indices: Vec<usize> = projection
+            .db_names()
+            .map(|name| {
+                self.field_names
+                    .iter()
+                    .position(|field_name| field_name == &name)
+                    .expect("Distinct fields must be part of the selected fields.")
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+
+        self.records.retain(|record| {
+            let parent: Option<Vec<PrismaValue>> = record.parent_id.as_ref().map(|id| id.values().collect());
+            let values: Vec<PrismaValue> = field_indices.iter().map(|i| record.values[*i].clone()).collect();
+
+            seen.insert((parent, values))
+        });
+    }
+
     pub fn push(&mut self, record: Record) {
         self.records.push(record);
     }
@@ -83,16 +83,12 @@ impl TryFrom<serde_json::Value> for PrismaValue {
                 }
             }
             serde_json::Value::Object(obj) => match obj.get("prisma__type").as_ref().and_then(|s| s.as_str()) {
-                Some("date") => {
+                Some(type_name) if TYPED_VALUE_TAGS.contains(&type_name) => {
                     let value = obj
                         .get("prisma__value")
-                        .and_then(|v| v.as_str())
-                        .ok_or_else(|| ConversionFailure::new("JSON date object", "PrismaValue"))?;
+                        .ok_or_else(|| ConversionFailure::new("typed JSON object", "PrismaValue"))?;
 
-                    let date = DateTime::parse_from_rfc3339(value)
-                        .map_err(|_| ConversionFailure::new("JSON date object", "PrismaValue"))?;
-
-                    Ok(PrismaValue::DateTime(date.into()))
+                    parse_typed_value(type_name, value)
                 }
                 _ => Ok(PrismaValue::Json(serde_json::to_string(&obj).unwrap())),
             },
@@ -100,6 +96,96 @@ impl TryFrom<serde_json::Value> for PrismaValue {
     }
 }
 
+/// Values of `{ "prisma__type": <tag>, "prisma__value": <value> }` objects are
+/// converted to the `PrismaValue` the tag names instead of being guessed from
+/// the JSON type.
+const TYPED_VALUE_TAGS: &[&str] = &[
+    "date", "datetime", "decimal", "float", "bigint", "int", "uuid", "enum", "string", "json", "bool", "list", "null",
+];
+
+fn parse_typed_value(type_name: &str, value: &serde_json::Value) -> PrismaValueResult<PrismaValue> {
+    let as_string = || match value {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    };
+
+    match type_name {
+        "date" | "datetime" => {
+            let date = value
+                .as_str()
+                .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+                .ok_or_else(|| ConversionFailure::new("JSON date object", "PrismaValue"))?;
+
+            Ok(PrismaValue::DateTime(date.into()))
+        }
+        "decimal" | "float" => as_string()
+            .and_then(|s| Decimal::from_str(&s).ok())
+            .map(PrismaValue::Float)
+            .ok_or_else(|| ConversionFailure::new("JSON decimal object", "PrismaValue")),
+        "bigint" | "int" => as_string()
+            .and_then(|s| s.parse::<i64>().ok())
+            .map(PrismaValue::Int)
+            .ok_or_else(|| ConversionFailure::new("JSON bigint object", "PrismaValue")),
+        "uuid" => value
+            .as_str()
+            .and_then(|s| Uuid::parse_str(s).ok())
+            .map(PrismaValue::Uuid)
+            .ok_or_else(|| ConversionFailure::new("JSON uuid object", "PrismaValue")),
+        "enum" => value
+            .as_str()
+            .map(|s| PrismaValue::Enum(s.to_owned()))
+            .ok_or_else(|| ConversionFailure::new("JSON enum object", "PrismaValue")),
+        "string" => value
+            .as_str()
+            .map(|s| PrismaValue::String(s.to_owned()))
+            .ok_or_else(|| ConversionFailure::new("JSON string object", "PrismaValue")),
+        "json" => match value {
+            serde_json::Value::String(s) => Ok(PrismaValue::Json(s.clone())),
+            value => Ok(PrismaValue::Json(value.to_string())),
+        },
+        "bool" => value
+            .as_bool()
+            .map(PrismaValue::Boolean)
+            .ok_or_else(|| ConversionFailure::new("JSON bool object", "PrismaValue")),
+        "list" => match value {
+            serde_json::Value::Array(values) => values
+                .iter()
+                .cloned()
+                .map(PrismaValue::try_from)
+                .collect::<PrismaValueResult<Vec<_>>>()
+                .map(PrismaValue::List),
+            _ => Err(ConversionFailure::new("JSON list object", "PrismaValue")),
+        },
+        // The value of a typed null is the tag of the type it stands in for, or null if unknown.
+        "null" => match value {
+            serde_json::Value::Null => Ok(PrismaValue::Null(TypeHint::Unknown)),
+            serde_json::Value::String(tag) => null_type_hint(tag)
+                .map(PrismaValue::Null)
+                .ok_or_else(|| ConversionFailure::new("JSON null object", "PrismaValue")),
+            _ => Err(ConversionFailure::new("JSON null object", "PrismaValue")),
+        },
+        _ => Err(ConversionFailure::new("typed JSON object", "PrismaValue")),
+    }
+}
+
+fn null_type_hint(tag: &str) -> Option<TypeHint> {
+    let hint = match tag {
+        "date" | "datetime" => TypeHint::DateTime,
+        "decimal" | "float" => TypeHint::Float,
+        "bigint" | "int" => TypeHint::Int,
+        "uuid" => TypeHint::UUID,
+        "enum" => TypeHint::Enum,
+        "string" => TypeHint::String,
+        "json" => TypeHint::Json,
+        "bool" => TypeHint::Boolean,
+        "list" => TypeHint::Array,
+        _ => return None,
+    };
+
+    Some(hint)
+}
+
 fn serialize_date<S>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -249,3 +335,72 @@ impl TryFrom<PrismaValue> for i64 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn typed(tag: &str, value: serde_json::Value) -> PrismaValueResult<PrismaValue> {
+        PrismaValue::try_from(json!({ "prisma__type": tag, "prisma__value": value }))
+    }
+
+    #[test]
+    fn typed_floats_are_converted_to_floats() {
+        let expected = PrismaValue::Float(Decimal::from_str("1.5").unwrap());
+
+        assert_eq!(typed("float", json!(1.5)).unwrap(), expected);
+        assert_eq!(typed("float", json!("1.5")).unwrap(), expected);
+        assert_eq!(typed("float", json!(3)).unwrap(), PrismaValue::Float(Decimal::from(3)));
+        assert!(typed("float", json!("one and a half")).is_err());
+    }
+
+    #[test]
+    fn typed_lists_convert_their_elements() {
+        let list = typed(
+            "list",
+            json!([1, { "prisma__type": "bigint", "prisma__value": "9007199254740993" }, null]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            list,
+            PrismaValue::List(vec![
+                PrismaValue::Int(1),
+                PrismaValue::Int(9_007_199_254_740_993),
+                PrismaValue::Null(TypeHint::Unknown),
+            ])
+        );
+
+        assert!(typed("list", json!("[1, 2]")).is_err());
+    }
+
+    #[test]
+    fn typed_nulls_carry_their_type_hint() {
+        assert_eq!(
+            typed("null", json!(null)).unwrap(),
+            PrismaValue::Null(TypeHint::Unknown)
+        );
+        assert_eq!(typed("null", json!("int")).unwrap(), PrismaValue::Null(TypeHint::Int));
+        assert_eq!(
+            typed("null", json!("datetime")).unwrap(),
+            PrismaValue::Null(TypeHint::DateTime)
+        );
+        assert_eq!(
+            typed("null", json!("list")).unwrap(),
+            PrismaValue::Null(TypeHint::Array)
+        );
+        assert!(typed("null", json!("unicorn")).is_err());
+        assert!(typed("null", json!(0)).is_err());
+    }
+
+    #[test]
+    fn objects_with_unknown_tags_are_json() {
+        let value = PrismaValue::try_from(json!({ "prisma__type": "unicorn", "prisma__value": 1 })).unwrap();
+
+        assert_eq!(
+            value,
+            PrismaValue::Json(r#"{"prisma__type":"unicorn","prisma__value":1}"#.to_owned())
+        );
+    }
+}
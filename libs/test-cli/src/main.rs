@@ -95,6 +95,8 @@ async fn main() -> anyhow::Result<()> {
                 assume_to_be_applied: Some(Vec::new()),
                 datamodel: datamodel_string.clone(),
                 migration_id: migration_id.clone(),
+                renames: None,
+                render_script: None,
             };
 
             api.reset(&serde_json::Value::Null).await?;
@@ -105,6 +107,8 @@ async fn main() -> anyhow::Result<()> {
                 force,
                 migration_id,
                 steps: result.datamodel_steps,
+                renames: None,
+                data_migrations: None,
             };
 
             let result = api.apply_migration(&apply_input).await?;
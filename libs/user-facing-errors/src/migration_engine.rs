@@ -44,6 +44,16 @@ pub struct MigrateSystemDatabase {
     pub database_name: String,
 }
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3005",
+    message = "Timed out after ${timeout} waiting for the migration lock on the `${database_name}` database. Another migration is being applied to it, try again once it has finished."
+)]
+pub struct MigrationLockTimeout {
+    pub database_name: String,
+    pub timeout: String,
+}
+
 // Tests
 
 #[cfg(test)]
@@ -17,7 +17,8 @@ struct MigrationEngineCli {
     /// Run only a single command, then exit
     #[structopt(short = "s", long)]
     single_cmd: bool,
-    /// Path to the datamodel
+    /// Path to the datamodel, or to a directory with the `.prisma` files of a datamodel split across
+    /// multiple files
     #[structopt(short = "d", long, name = "FILE")]
     datamodel: Option<String>,
     #[structopt(subcommand)]
@@ -63,28 +64,27 @@ async fn main() {
 }
 
 async fn start_engine(datamodel_location: &str, single_cmd: bool) -> ! {
-    use std::io::Read as _;
-
     tracing::info!(git_hash = env!("GIT_HASH"), "Starting migration engine RPC server",);
-    let mut file = std::fs::File::open(datamodel_location).expect("error opening datamodel file");
+    let schema_files =
+        datamodel::SchemaFiles::load(std::path::Path::new(datamodel_location)).expect("error reading datamodel files");
 
-    let mut datamodel = String::new();
-    file.read_to_string(&mut datamodel).unwrap();
+    // The files of a split datamodel are stored as one combined datamodel.
+    let datamodel = schema_files.combined();
 
     if single_cmd {
-        let api = RpcApi::new(&datamodel).await.unwrap();
+        let api = RpcApi::new(datamodel).await.unwrap();
         let response = api.handle().unwrap();
 
         println!("{}", response);
     } else {
-        match RpcApi::new(&datamodel).await {
+        match RpcApi::new(datamodel).await {
             // Block the thread and handle IO in async until EOF.
             Ok(api) => json_rpc_stdio::run(api.io_handler()).await.unwrap(),
             Err(err) => {
                 let (error, exit_code) = match &err {
                     CoreError::DatamodelError(errors) => {
                         let error = user_facing_errors::UnknownError {
-                            message: migration_core::api::pretty_print_datamodel_errors(errors, &datamodel)
+                            message: migration_core::api::pretty_print_datamodel_errors(errors, &schema_files)
                                 .expect("rendering error"),
                             backtrace: Some(format!("{:?}", user_facing_errors::new_backtrace())),
                         };
@@ -0,0 +1,76 @@
+use crate::{ConnectorError, ConnectorResult, ErrorKind};
+use datamodel::Datamodel;
+use serde::{Deserialize, Serialize};
+
+/// A user-supplied script migrating data between the datamodel steps of a migration, for example to
+/// fill new columns from a column that a later step drops.
+///
+/// Data migrations are stored with the migration, but they are not reverted when the migration is
+/// unapplied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DataMigration {
+    /// The script to run, in the query language of the database.
+    pub script: String,
+    /// Where the script runs among the datamodel steps of the migration.
+    pub position: DataMigrationPosition,
+}
+
+/// The position of a data migration, relative to the index of a datamodel step of the migration.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum DataMigrationPosition {
+    BeforeStep(usize),
+    AfterStep(usize),
+}
+
+impl DataMigration {
+    /// The number of datamodel steps migrated before the script runs, or `None` if it doesn't fit
+    /// in a `usize`.
+    pub fn preceding_steps_count(&self) -> Option<usize> {
+        match self.position {
+            DataMigrationPosition::BeforeStep(index) => Some(index),
+            DataMigrationPosition::AfterStep(index) => index.checked_add(1),
+        }
+    }
+}
+
+/// The datamodel a group of data migrations runs at, that is the datamodel of the migration after
+/// the steps preceding them.
+#[derive(Debug, Clone)]
+pub struct DataMigrationCheckpoint {
+    pub datamodel: Datamodel,
+    /// The scripts to run, in the order they were supplied in.
+    pub scripts: Vec<String>,
+}
+
+/// Groups the data migrations by the number of datamodel steps preceding them, in the order of the
+/// steps. Data migrations at the same position keep the order they were supplied in.
+pub fn group_data_migrations(
+    data_migrations: &[DataMigration],
+    steps_count: usize,
+) -> ConnectorResult<Vec<(usize, Vec<String>)>> {
+    let mut groups: Vec<(usize, Vec<String>)> = Vec::new();
+
+    for data_migration in data_migrations {
+        let preceding_steps_count = match data_migration.preceding_steps_count() {
+            Some(count) if count <= steps_count => count,
+            _ => {
+                return Err(ConnectorError::from_kind(ErrorKind::Generic(anyhow::anyhow!(
+                    "The data migration position {:?} is out of bounds, the migration has {} steps.",
+                    data_migration.position,
+                    steps_count
+                ))))
+            }
+        };
+
+        match groups.iter_mut().find(|(count, _)| *count == preceding_steps_count) {
+            Some((_, scripts)) => scripts.push(data_migration.script.clone()),
+            None => groups.push((preceding_steps_count, vec![data_migration.script.clone()])),
+        }
+    }
+
+    groups.sort_by_key(|(count, _)| *count);
+
+    Ok(groups)
+}
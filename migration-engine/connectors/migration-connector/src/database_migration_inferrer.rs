@@ -1,4 +1,4 @@
-use crate::{ConnectorResult, MigrationStep};
+use crate::{ConnectorResult, DataMigrationCheckpoint, MigrationStep, Rename};
 use datamodel::Datamodel;
 
 /// The component responsible for generating a [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
@@ -7,7 +7,27 @@ use datamodel::Datamodel;
 #[async_trait::async_trait]
 pub trait DatabaseMigrationInferrer<T>: Send + Sync {
     /// Infer the database migration steps. The previous datamodel is provided, but the implementor can ignore it.
-    async fn infer(&self, previous: &Datamodel, next: &Datamodel, steps: &[MigrationStep]) -> ConnectorResult<T>;
+    /// The `renames` are migrated as renames instead of dropping and recreating what was renamed, where the
+    /// database supports it.
+    async fn infer(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[MigrationStep],
+        renames: &[Rename],
+    ) -> ConnectorResult<T>;
+
+    /// Infer the database migration steps like `infer`, with the scripts of the data migrations running after the
+    /// database has been migrated to the datamodel of their checkpoint. The checkpoints are in the order of the
+    /// datamodel steps.
+    async fn infer_with_data_migrations(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[MigrationStep],
+        renames: &[Rename],
+        checkpoints: &[DataMigrationCheckpoint],
+    ) -> ConnectorResult<T>;
 
     /// Infer a database migration based on the previous and next datamodels. The method signature is identical to `infer`,
     /// but it is expected that this method is implemented based on the provided previous datamodel, and does not rely
@@ -17,5 +37,15 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
         previous: &Datamodel,
         next: &Datamodel,
         steps: &[MigrationStep],
+        renames: &[Rename],
     ) -> ConnectorResult<T>;
+
+    /// Infer the database migration reverting a migration returned by `infer`. It migrates the database from the
+    /// state the migration leads to back to the state the migration was inferred from, and is stored with the
+    /// migration so it can be reviewed and run when the migration is unapplied.
+    fn infer_down(&self, database_migration: &T) -> ConnectorResult<T>;
+
+    /// Check that two database migrations lead to the same database schema, for example to verify
+    /// that squashed migrations have the same end state as the original ones.
+    fn migrate_to_the_same_schema(&self, database_migration: &T, other: &T) -> bool;
 }
@@ -15,6 +15,10 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
 
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
+
+    /// Render the script running the exact statements `apply_step` runs for the whole migration, in
+    /// order, so it can be reviewed and run by hand. Rendering the script has no side effects.
+    fn render_script(&self, database_migration: &T) -> ConnectorResult<String>;
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
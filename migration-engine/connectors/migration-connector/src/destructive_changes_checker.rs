@@ -24,6 +24,7 @@ pub struct DestructiveChangeDiagnostics {
     pub errors: Vec<MigrationError>,
     pub warnings: Vec<MigrationWarning>,
     pub unexecutable_migrations: Vec<UnexecutableMigration>,
+    pub irreversible_steps: Vec<IrreversibleStep>,
 }
 
 impl DestructiveChangeDiagnostics {
@@ -32,6 +33,7 @@ impl DestructiveChangeDiagnostics {
             errors: Vec::new(),
             warnings: Vec::new(),
             unexecutable_migrations: Vec::new(),
+            irreversible_steps: Vec::new(),
         }
     }
 
@@ -75,6 +77,13 @@ pub struct UnexecutableMigration {
     pub description: String,
 }
 
+/// A step of a migration that the down migration can't revert completely, e.g. because it drops data. Emitted by
+/// the [DestructiveChangesChecker](trait.DestructiveChangesChecker.html) when the migration is created.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct IrreversibleStep {
+    pub description: String,
+}
+
 /// An implementor of [DestructiveChangesChecker](trait.DestructiveChangesChecker.html) that performs no check.
 pub struct EmptyDestructiveChangesChecker<T> {
     database_migration: PhantomData<T>,
@@ -3,21 +3,27 @@
 
 //! This crate defines the API exposed by the connectors to the migration engine core. The entry point for this API is the [MigrationConnector](trait.MigrationConnector.html) trait.
 
+mod data_migrations;
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_changes_checker;
 mod error;
 mod migration_applier;
 mod migration_persistence;
+mod renames;
+mod schema_drift;
 
 pub mod steps;
 
+pub use data_migrations::*;
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_changes_checker::*;
 pub use error::*;
 pub use migration_applier::*;
 pub use migration_persistence::*;
+pub use renames::*;
+pub use schema_drift::*;
 pub use steps::MigrationStep;
 
 use std::fmt::Debug;
@@ -45,6 +51,14 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Introspect the database into a datamodel, to baseline databases that were not created with
+    /// migrations.
+    async fn introspect(&self) -> ConnectorResult<datamodel::Datamodel>;
+
+    /// Compare the live database with the database schema of the datamodel, to detect changes
+    /// made to the database outside of migrations.
+    async fn check_drift(&self, datamodel: &datamodel::Datamodel) -> ConnectorResult<SchemaDrift>;
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
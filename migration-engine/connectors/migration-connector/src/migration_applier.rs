@@ -1,6 +1,7 @@
 use crate::*;
 
-/// Apply and unapply migrations on the connector's database.
+/// Apply and unapply migrations on the connector's database. Callers hold the migration lock (see
+/// `MigrationPersistence::lock`) from reading the migration state the migration was inferred from on.
 #[async_trait::async_trait]
 pub trait MigrationApplier<T>
 where
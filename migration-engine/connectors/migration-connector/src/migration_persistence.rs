@@ -1,4 +1,4 @@
-use crate::{error::ConnectorError, steps::*, ConnectorResult};
+use crate::{error::ConnectorError, steps::*, ConnectorResult, DataMigration};
 use chrono::{DateTime, Utc};
 use datamodel::{ast::SchemaAst, error::ErrorCollection, Datamodel};
 use serde::Serialize;
@@ -12,6 +12,17 @@ pub trait MigrationPersistence: Send + Sync {
     /// Drop all persisted state.
     async fn reset(&self) -> Result<(), ConnectorError>;
 
+    /// Take the migration lock on the database, waiting for other migration runs holding it to
+    /// release it. The default impl does nothing, for connectors without concurrent clients.
+    async fn lock(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    /// Release the migration lock taken with `lock`.
+    async fn unlock(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
     async fn last_non_watch_applied_migration(&self) -> Result<Option<Migration>, ConnectorError> {
         let migration =
             self.load_all().await?.into_iter().rev().find(|migration| {
@@ -47,6 +58,11 @@ pub trait MigrationPersistence: Send + Sync {
     /// Write the migration to the Migration table.
     async fn create(&self, migration: Migration) -> Result<Migration, ConnectorError>;
 
+    /// Atomically replace a contiguous range of migrations with the migration they were squashed
+    /// into. The squashed migration takes the revision of the first migration of the range, so it
+    /// keeps its place in the history.
+    async fn squash(&self, squashed_migrations: &[Migration], migration: Migration) -> ConnectorResult<Migration>;
+
     /// Used by the MigrationApplier to write the progress of a [Migration](struct.Migration.html)
     /// into the database.
     async fn update(&self, params: &MigrationUpdateParams) -> Result<(), ConnectorError>;
@@ -79,6 +95,11 @@ pub struct Migration {
     /// The schema migration steps to apply to get to the target Prisma schema.
     pub datamodel_steps: Vec<MigrationStep>,
     pub database_migration: serde_json::Value,
+    /// The database migration reverting `database_migration`. Migrations persisted before down migrations were
+    /// stored don't have one.
+    pub down_database_migration: Option<serde_json::Value>,
+    /// The data migrations running between the datamodel steps.
+    pub data_migrations: Vec<DataMigration>,
     pub errors: Vec<String>,
     pub started_at: DateTime<Utc>,
     pub finished_at: Option<DateTime<Utc>>,
@@ -113,6 +134,8 @@ pub struct NewMigration {
     pub datamodel_string: String,
     pub datamodel_steps: Vec<MigrationStep>,
     pub database_migration: serde_json::Value,
+    pub down_database_migration: serde_json::Value,
+    pub data_migrations: Vec<DataMigration>,
 }
 
 impl Migration {
@@ -122,6 +145,8 @@ impl Migration {
             datamodel_string,
             datamodel_steps,
             database_migration,
+            down_database_migration,
+            data_migrations,
         } = params;
 
         Migration {
@@ -133,6 +158,8 @@ impl Migration {
             applied: 0,
             rolled_back: 0,
             database_migration,
+            down_database_migration: Some(down_database_migration),
+            data_migrations,
             errors: Vec::new(),
             started_at: Self::timestamp_without_nanos(),
             finished_at: None,
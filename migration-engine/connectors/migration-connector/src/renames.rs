@@ -0,0 +1,201 @@
+use crate::steps::{MigrationStep, UpdateField, UpdateModel};
+use datamodel::{Datamodel, Field, FieldType, WithDatabaseName};
+use serde::{Deserialize, Serialize};
+
+/// A model or field that is kept under a new name or database name. Connectors migrate renames by
+/// renaming the underlying table or column instead of dropping and recreating it, so the data is
+/// kept.
+///
+/// Renames are detected from the datamodel steps of a migration, but users can confirm or override
+/// them through the RPC API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "tpe", rename_all = "camelCase", deny_unknown_fields)]
+pub enum Rename {
+    #[serde(rename_all = "camelCase")]
+    Model { previous_name: String, next_name: String },
+
+    /// A field rename. `model` is the name of the model in the next datamodel.
+    #[serde(rename_all = "camelCase")]
+    Field {
+        model: String,
+        previous_name: String,
+        next_name: String,
+    },
+}
+
+/// Detects the models and fields that are renamed by a migration. These are:
+///
+/// - Models and fields renamed by an `UpdateModel` or `UpdateField` step.
+/// - Models and fields that keep their name, but get a new database name through `@@map` or `@map`.
+pub fn detect_renames(previous: &Datamodel, next: &Datamodel, steps: &[MigrationStep]) -> Vec<Rename> {
+    let mut renames: Vec<Rename> = Vec::new();
+
+    for step in steps {
+        match step {
+            MigrationStep::UpdateModel(UpdateModel {
+                model,
+                new_name: Some(new_name),
+            }) => renames.push(Rename::Model {
+                previous_name: model.clone(),
+                next_name: new_name.clone(),
+            }),
+            MigrationStep::UpdateField(UpdateField {
+                model,
+                field,
+                new_name: Some(new_name),
+                ..
+            }) => {
+                // The step refers to the model by its previous name if the model is renamed later on.
+                let model = if next.has_model(model) {
+                    model.clone()
+                } else {
+                    next_model_name(model, &renames).to_owned()
+                };
+
+                renames.push(Rename::Field {
+                    model,
+                    previous_name: field.clone(),
+                    next_name: new_name.clone(),
+                })
+            }
+            _ => (),
+        }
+    }
+
+    let mut mapped = Vec::new();
+
+    for next_model in next.models() {
+        let previous_model = match previous.find_model(previous_model_name(&next_model.name, &renames)) {
+            Some(model) => model,
+            None => continue,
+        };
+
+        if previous_model.name == next_model.name
+            && previous_model.final_database_name() != next_model.final_database_name()
+        {
+            mapped.push(Rename::Model {
+                previous_name: previous_model.name.clone(),
+                next_name: next_model.name.clone(),
+            });
+        }
+
+        for next_field in next_model.fields().filter(|field| has_column(field)) {
+            let is_renamed = renames.iter().any(|rename| match rename {
+                Rename::Field { model, next_name, .. } => model == &next_model.name && next_name == &next_field.name,
+                Rename::Model { .. } => false,
+            });
+
+            let previous_field = match previous_model.find_field(&next_field.name) {
+                Some(field) if !is_renamed && has_column(field) => field,
+                _ => continue,
+            };
+
+            if previous_field.final_database_name() != next_field.final_database_name() {
+                mapped.push(Rename::Field {
+                    model: next_model.name.clone(),
+                    previous_name: previous_field.name.clone(),
+                    next_name: next_field.name.clone(),
+                });
+            }
+        }
+    }
+
+    renames.extend(mapped);
+
+    renames
+}
+
+/// The renames migrating back from the next to the previous datamodel.
+pub fn invert_renames(renames: &[Rename]) -> Vec<Rename> {
+    renames
+        .iter()
+        .map(|rename| match rename {
+            Rename::Model {
+                previous_name,
+                next_name,
+            } => Rename::Model {
+                previous_name: next_name.clone(),
+                next_name: previous_name.clone(),
+            },
+            Rename::Field {
+                model,
+                previous_name,
+                next_name,
+            } => Rename::Field {
+                model: previous_model_name(model, renames).to_owned(),
+                previous_name: next_name.clone(),
+                next_name: previous_name.clone(),
+            },
+        })
+        .collect()
+}
+
+/// Checks that every renamed model and field exists under its previous name in the previous
+/// datamodel, and under its next name in the next datamodel.
+pub fn validate_renames(renames: &[Rename], previous: &Datamodel, next: &Datamodel) -> Result<(), String> {
+    for rename in renames {
+        let is_valid = match rename {
+            Rename::Model {
+                previous_name,
+                next_name,
+            } => previous.has_model(previous_name) && next.has_model(next_name),
+            Rename::Field {
+                model,
+                previous_name,
+                next_name,
+            } => {
+                let previous_field = previous
+                    .find_model(previous_model_name(model, renames))
+                    .and_then(|model| model.find_field(previous_name));
+                let next_field = next.find_model(model).and_then(|model| model.find_field(next_name));
+
+                previous_field.filter(|field| has_column(field)).is_some()
+                    && next_field.filter(|field| has_column(field)).is_some()
+            }
+        };
+
+        if !is_valid {
+            return Err(format!(
+                "The rename {:?} does not match the previous and next datamodels.",
+                rename
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The name the given model of the next datamodel has in the previous datamodel.
+pub fn previous_model_name<'a>(next_name: &'a str, renames: &'a [Rename]) -> &'a str {
+    renames
+        .iter()
+        .find_map(|rename| match rename {
+            Rename::Model {
+                previous_name,
+                next_name: name,
+            } if name == next_name => Some(previous_name.as_str()),
+            _ => None,
+        })
+        .unwrap_or(next_name)
+}
+
+fn next_model_name<'a>(previous_name: &'a str, renames: &'a [Rename]) -> &'a str {
+    renames
+        .iter()
+        .find_map(|rename| match rename {
+            Rename::Model {
+                previous_name: name,
+                next_name,
+            } if name == previous_name => Some(next_name.as_str()),
+            _ => None,
+        })
+        .unwrap_or(previous_name)
+}
+
+/// Scalar and enum fields are stored in a column of their own.
+fn has_column(field: &Field) -> bool {
+    match field.field_type {
+        FieldType::Base(..) | FieldType::Enum(_) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+/// The differences between the database schema the applied migrations lead to and the schema of
+/// the live database, for example after the database was changed by hand.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDrift {
+    /// The connector-specific steps that would migrate the live database back to the expected
+    /// schema.
+    pub steps: Vec<serde_json::Value>,
+    /// A readable description of the differences.
+    pub summary: Vec<String>,
+}
+
+impl SchemaDrift {
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
@@ -21,6 +21,8 @@ pub enum MigrationStep {
     CreateEnum(CreateEnum),
     UpdateEnum(UpdateEnum),
     DeleteEnum(DeleteEnum),
+    CreateCompositeType(CreateCompositeType),
+    DeleteCompositeType(DeleteCompositeType),
     CreateTypeAlias(CreateTypeAlias),
     UpdateTypeAlias(UpdateTypeAlias),
     DeleteTypeAlias(DeleteTypeAlias),
@@ -312,6 +314,20 @@ impl MigrationExpression {
     }
 }
 
+/// Creates an empty composite type. The fields of composite types are created, updated and deleted
+/// with the field steps, with the name of the composite type as the model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateCompositeType {
+    pub composite_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DeleteCompositeType {
+    pub composite_type: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreateTypeAlias {
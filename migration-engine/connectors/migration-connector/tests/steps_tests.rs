@@ -124,6 +124,24 @@ fn DeleteField_must_work() {
     assert_symmetric_serde(json, expected_struct);
 }
 
+#[test]
+fn CreateCompositeType_must_work() {
+    let json = r#"{"tag":"CreateCompositeType","compositeType":"Address"}"#;
+    let expected_struct = MigrationStep::CreateCompositeType(CreateCompositeType {
+        composite_type: "Address".to_string(),
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn DeleteCompositeType_must_work() {
+    let json = r#"{"tag":"DeleteCompositeType","compositeType":"Address"}"#;
+    let expected_struct = MigrationStep::DeleteCompositeType(DeleteCompositeType {
+        composite_type: "Address".to_string(),
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
 #[test]
 fn CreateEnum_must_work() {
     let json = r#"
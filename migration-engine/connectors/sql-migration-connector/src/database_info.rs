@@ -1,4 +1,4 @@
-use super::SqlResult;
+use super::{online_migrations::OnlineMigrations, SqlResult};
 use datamodel::Datamodel;
 use migration_connector::MigrationError;
 use quaint::{
@@ -10,15 +10,21 @@ use quaint::{
 pub struct DatabaseInfo {
     connection_info: ConnectionInfo,
     database_version: Option<String>,
+    online_migrations: OnlineMigrations,
 }
 
 impl DatabaseInfo {
-    pub(crate) async fn new(connection: &Quaint, connection_info: ConnectionInfo) -> SqlResult<Self> {
+    pub(crate) async fn new(
+        connection: &Quaint,
+        connection_info: ConnectionInfo,
+        online_migrations: OnlineMigrations,
+    ) -> SqlResult<Self> {
         let database_version = get_database_version(connection, &connection_info).await?;
 
         Ok(DatabaseInfo {
             connection_info,
             database_version,
+            online_migrations,
         })
     }
 
@@ -31,6 +37,17 @@ impl DatabaseInfo {
                 .unwrap_or(false)
     }
 
+    /// `ALTER TABLE ... RENAME COLUMN` is only available from MySQL 8 on.
+    pub(crate) fn is_mysql_8(&self) -> bool {
+        self.connection_info.sql_family() == SqlFamily::Mysql
+            && !self.is_mariadb()
+            && self
+                .database_version
+                .as_ref()
+                .map(|version| version.starts_with("8."))
+                .unwrap_or(false)
+    }
+
     pub(crate) fn is_mariadb(&self) -> bool {
         self.connection_info.sql_family() == SqlFamily::Mysql
             && self
@@ -40,6 +57,10 @@ impl DatabaseInfo {
                 .unwrap_or(false)
     }
 
+    pub(crate) fn online_migrations(&self) -> &OnlineMigrations {
+        &self.online_migrations
+    }
+
     pub(crate) fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
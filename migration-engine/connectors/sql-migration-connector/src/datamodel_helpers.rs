@@ -127,6 +127,8 @@ impl<'a> FieldRef<'a> {
                 r#enum: self.datamodel.find_enum(name).unwrap(),
             }),
             FieldType::Base(scalar_type, _) => TypeRef::Base(*scalar_type),
+            FieldType::CompositeType(_) => TypeRef::CompositeType,
+            FieldType::Unsupported(native_type) => TypeRef::Unsupported(native_type),
             _ => TypeRef::Other,
         }
     }
@@ -172,6 +174,10 @@ impl<'a> FieldRef<'a> {
 pub(super) enum TypeRef<'a> {
     Enum(EnumRef<'a>),
     Base(ScalarType),
+    /// Composite types are stored as a JSON document in a single column.
+    CompositeType,
+    /// A native database type Prisma can't represent, kept as is in the database.
+    Unsupported(&'a str),
     Other,
 }
 
@@ -1,9 +1,13 @@
 use migration_connector::{ConnectorError, ErrorKind};
 use quaint::error::{Error as QuaintError, ErrorKind as QuaintKind};
+use std::time::Duration;
 use thiserror::Error;
 use tracing_error::SpanTrace;
 use user_facing_errors::{
-    migration_engine::MigrateSystemDatabase, quaint::render_quaint_error, query_engine::DatabaseConstraint, KnownError,
+    migration_engine::{MigrateSystemDatabase, MigrationLockTimeout as MigrationLockTimeoutError},
+    quaint::render_quaint_error,
+    query_engine::DatabaseConstraint,
+    KnownError,
 };
 
 pub type SqlResult<T> = Result<T, SqlError>;
@@ -213,3 +217,28 @@ impl From<SystemDatabase> for ConnectorError {
         }
     }
 }
+
+#[derive(Debug, Error)]
+#[error(
+    "Timed out after {}ms waiting for the migration lock on the `{database_name}` database.",
+    .timeout.as_millis()
+)]
+pub(crate) struct MigrationLockTimeout {
+    pub(crate) database_name: String,
+    pub(crate) timeout: Duration,
+}
+
+impl From<MigrationLockTimeout> for ConnectorError {
+    fn from(err: MigrationLockTimeout) -> ConnectorError {
+        let user_facing = MigrationLockTimeoutError {
+            database_name: err.database_name.clone(),
+            timeout: format!("{}ms", err.timeout.as_millis()),
+        };
+
+        ConnectorError {
+            user_facing_error: Some(KnownError::new(user_facing).unwrap()),
+            kind: ErrorKind::Generic(err.into()),
+            context: SpanTrace::capture(),
+        }
+    }
+}
@@ -3,13 +3,17 @@
 //! detail of the SQL connector.
 
 use crate::{
-    catch, connect, database_info::DatabaseInfo, sql_destructive_changes_checker::DestructiveChangeCheckerFlavour,
+    catch, connect,
+    database_info::DatabaseInfo,
+    migration_lock::{self, LockFile},
+    sql_destructive_changes_checker::DestructiveChangeCheckerFlavour,
     CheckDatabaseInfoResult, SqlError, SqlResult, SystemDatabase,
 };
 use futures::future::TryFutureExt;
 use migration_connector::{ConnectorError, ConnectorResult};
 use once_cell::sync::Lazy;
 use quaint::{
+    ast::Value,
     connector::{ConnectionInfo, MysqlUrl, PostgresUrl, Queryable},
     single::Quaint,
 };
@@ -19,7 +23,7 @@ use std::{
     collections::HashMap,
     fs,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 use url::Url;
 
@@ -29,6 +33,7 @@ pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn
         ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour(url.clone())),
         ConnectionInfo::Sqlite { file_path, .. } => Box::new(SqliteFlavour {
             file_path: file_path.clone(),
+            lock_file: Mutex::new(None),
         }),
         ConnectionInfo::Mssql(_) => todo!("Greetings from Redmond!"),
     }
@@ -53,6 +58,13 @@ pub(crate) trait SqlFlavour: DestructiveChangeCheckerFlavour {
 
     /// Create the database schema.
     async fn initialize(&self, conn: &dyn Queryable, database_info: &DatabaseInfo) -> SqlResult<()>;
+
+    /// Try to take the migration lock on the schema, without waiting. Returns whether the lock was
+    /// taken. It is held until `release_migration_lock` is called.
+    async fn try_acquire_migration_lock(&self, conn: &dyn Queryable, schema_name: &str) -> SqlResult<bool>;
+
+    /// Release the migration lock taken with `try_acquire_migration_lock`.
+    async fn release_migration_lock(&self, conn: &dyn Queryable, schema_name: &str) -> SqlResult<()>;
 }
 
 pub(crate) struct MysqlFlavour(MysqlUrl);
@@ -112,10 +124,34 @@ impl SqlFlavour for MysqlFlavour {
 
         Ok(())
     }
+
+    async fn try_acquire_migration_lock(&self, conn: &dyn Queryable, schema_name: &str) -> SqlResult<bool> {
+        // Named locks are held by the session, so they are released if the engine dies.
+        let lock_name = migration_lock::named_lock_name(schema_name);
+        let result_set = conn
+            .query_raw("SELECT GET_LOCK(?, 0) AS locked", &[Value::from(lock_name.as_str())])
+            .await?;
+
+        Ok(result_set
+            .first()
+            .and_then(|row| row.get("locked").and_then(|locked| locked.as_i64()))
+            == Some(1))
+    }
+
+    async fn release_migration_lock(&self, conn: &dyn Queryable, schema_name: &str) -> SqlResult<()> {
+        let lock_name = migration_lock::named_lock_name(schema_name);
+
+        conn.query_raw("SELECT RELEASE_LOCK(?)", &[Value::from(lock_name.as_str())])
+            .await?;
+
+        Ok(())
+    }
 }
 
 pub(crate) struct SqliteFlavour {
     file_path: String,
+    /// The migration lock, while this connector holds it.
+    lock_file: Mutex<Option<LockFile>>,
 }
 
 #[async_trait::async_trait]
@@ -162,6 +198,41 @@ impl SqlFlavour for SqliteFlavour {
 
         Ok(())
     }
+
+    async fn try_acquire_migration_lock(&self, _conn: &dyn Queryable, _schema_name: &str) -> SqlResult<bool> {
+        // SQLite has no session-level locks, the lock is a file next to the database file. It is
+        // removed when the connector is dropped, and reclaimed if the engine dies while holding it.
+        let mut lock_file = self.lock_file.lock().unwrap();
+
+        if lock_file.is_some() {
+            return Ok(false);
+        }
+
+        match LockFile::try_acquire(self.migration_lock_file_path()) {
+            Ok(acquired) => {
+                *lock_file = acquired;
+                Ok(lock_file.is_some())
+            }
+            Err(err) => Err(SqlError::Generic(anyhow::anyhow!(
+                "Could not create the migration lock file at `{}`: {}",
+                self.migration_lock_file_path().display(),
+                err
+            ))),
+        }
+    }
+
+    async fn release_migration_lock(&self, _conn: &dyn Queryable, _schema_name: &str) -> SqlResult<()> {
+        self.lock_file.lock().unwrap().take();
+
+        Ok(())
+    }
+}
+
+impl SqliteFlavour {
+    /// The migration lock file, see `LockFile`.
+    fn migration_lock_file_path(&self) -> PathBuf {
+        PathBuf::from(format!("{}.migration_lock", self.file_path))
+    }
 }
 
 pub(crate) struct PostgresFlavour(PostgresUrl);
@@ -200,6 +271,31 @@ impl SqlFlavour for PostgresFlavour {
 
         Ok(())
     }
+
+    async fn try_acquire_migration_lock(&self, conn: &dyn Queryable, schema_name: &str) -> SqlResult<bool> {
+        // Session-level advisory locks are released if the engine dies.
+        let result_set = conn
+            .query_raw(
+                "SELECT pg_try_advisory_lock($1) AS locked",
+                &[Value::from(migration_lock::advisory_lock_key(schema_name))],
+            )
+            .await?;
+
+        Ok(result_set
+            .first()
+            .and_then(|row| row.get("locked").and_then(|locked| locked.as_bool()))
+            .unwrap_or(false))
+    }
+
+    async fn release_migration_lock(&self, conn: &dyn Queryable, schema_name: &str) -> SqlResult<()> {
+        conn.query_raw(
+            "SELECT pg_advisory_unlock($1)",
+            &[Value::from(migration_lock::advisory_lock_key(schema_name))],
+        )
+        .await?;
+
+        Ok(())
+    }
 }
 
 /// Try to connect as an admin to a postgres database. We try to pick a default database from which
@@ -6,6 +6,9 @@ mod database_info;
 mod datamodel_helpers;
 mod error;
 mod flavour;
+mod migration_lock;
+mod online_migrations;
+mod schema_drift;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
@@ -24,6 +27,7 @@ use component::Component;
 use database_info::DatabaseInfo;
 use flavour::SqlFlavour;
 use migration_connector::*;
+use online_migrations::OnlineMigrations;
 use quaint::{
     error::ErrorKind,
     prelude::{ConnectionInfo, Queryable, SqlFamily},
@@ -43,6 +47,8 @@ pub struct SqlMigrationConnector {
     pub database: Arc<dyn Queryable + Send + Sync + 'static>,
     pub database_info: DatabaseInfo,
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+    /// How long to wait for other migration runs to release the migration lock.
+    migration_lock_timeout: Duration,
 }
 
 impl SqlMigrationConnector {
@@ -55,6 +61,7 @@ impl SqlMigrationConnector {
             flavour,
             database_info,
             database: Arc::new(connection),
+            migration_lock_timeout: migration_lock::lock_timeout_from_url(database_str),
         })
     }
 
@@ -143,6 +150,24 @@ impl MigrationConnector for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn introspect(&self) -> ConnectorResult<datamodel::Datamodel> {
+        use sql_introspection_connector::calculate_datamodel::calculate_datamodel;
+
+        let sql_schema = catch(self.database_info.connection_info(), self.describe_schema()).await?;
+        let introspection_result = calculate_datamodel(&sql_schema, &self.database_info.sql_family())
+            .map_err(|err| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err.into())))?;
+
+        Ok(introspection_result.datamodel)
+    }
+
+    async fn check_drift(&self, datamodel: &datamodel::Datamodel) -> ConnectorResult<SchemaDrift> {
+        catch(
+            self.database_info.connection_info(),
+            schema_drift::check_drift(self, datamodel),
+        )
+        .await
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(&self, datamodel: &datamodel::dml::Datamodel) -> Vec<MigrationError> {
@@ -208,9 +233,22 @@ async fn connect(database_str: &str) -> ConnectorResult<(Quaint, DatabaseInfo)>
             SqlError::from(ErrorKind::ConnectTimeout("Tokio timer".into())).into_connector_error(&connection_info)
         })??;
 
-    let database_info = DatabaseInfo::new(&connection, connection.connection_info().clone())
+    let online_migrations = OnlineMigrations::from_url(database_str, connection_info.sql_family());
+    let database_info = DatabaseInfo::new(&connection, connection.connection_info().clone(), online_migrations)
         .await
         .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
+    // The connection is only used for migrating, the timeouts can be set for the whole session.
+    for statement in database_info
+        .online_migrations()
+        .session_statements(database_info.sql_family(), database_info.is_mariadb())
+    {
+        connection
+            .raw_cmd(&statement)
+            .await
+            .map_err(SqlError::from)
+            .map_err(|err| err.into_connector_error(&connection_info))?;
+    }
+
     Ok((connection, database_info))
 }
@@ -0,0 +1,211 @@
+//! The migration lock keeps concurrent migration runs (for example from several deployments starting at the same
+//! time) from applying migrations to the same database schema at once.
+
+use chrono::{DateTime, Utc};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// Connection string parameter holding how long to wait for the migration lock, in milliseconds.
+const LOCK_TIMEOUT_PARAM: &str = "migration_lock_timeout";
+
+/// How long to wait for the migration lock, unless the connection string sets it.
+const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long to wait between attempts at taking the migration lock.
+pub(crate) const LOCK_POLLING_INTERVAL: Duration = Duration::from_millis(250);
+
+/// SQLite lock files older than this are left behind by a migration run that died, even if its process id was
+/// reused by another process since.
+const STALE_LOCK_FILE_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Reads the migration lock timeout from the `migration_lock_timeout` connection string parameter.
+pub(crate) fn lock_timeout_from_url(url: &str) -> Duration {
+    datamodel::url_parameter(url, LOCK_TIMEOUT_PARAM)
+        .and_then(|millis| millis.parse().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_LOCK_TIMEOUT)
+}
+
+/// The migration lock on a SQLite database: a file next to the database file, holding the id of the process that
+/// took the lock and when. The file is removed when the guard is dropped, and a file left behind by a process that
+/// died is reclaimed by the next migration run.
+#[derive(Debug)]
+pub(crate) struct LockFile {
+    path: PathBuf,
+}
+
+impl LockFile {
+    /// Try to take the lock, without waiting. Returns `None` if another migration run holds it.
+    pub(crate) fn try_acquire(path: PathBuf) -> io::Result<Option<LockFile>> {
+        if let Some(lock_file) = Self::try_create(&path)? {
+            return Ok(Some(lock_file));
+        }
+
+        if !Self::is_stale(&path) {
+            return Ok(None);
+        }
+
+        tracing::warn!("Reclaiming the stale migration lock file at `{}`.", path.display());
+
+        match fs::remove_file(&path) {
+            Ok(()) => (),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        Self::try_create(&path)
+    }
+
+    /// The lock file is written under a temporary name, and then linked to its actual path. Linking fails if the
+    /// path exists, so another migration run never sees a lock file without its contents.
+    fn try_create(path: &Path) -> io::Result<Option<LockFile>> {
+        static TEMPORARY_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let counter = TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let temporary_path = PathBuf::from(format!("{}.{}.{}", path.display(), std::process::id(), counter));
+        let contents = format!("{} {}", std::process::id(), Utc::now().to_rfc3339());
+
+        fs::write(&temporary_path, contents)?;
+
+        let linked = fs::hard_link(&temporary_path, path);
+
+        fs::remove_file(&temporary_path)?;
+
+        match linked {
+            Ok(()) => Ok(Some(LockFile { path: path.to_owned() })),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Whether the lock file was left behind: its contents can't be read, it was taken too long ago, or the
+    /// process that took it is not running anymore.
+    fn is_stale(path: &Path) -> bool {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => return false,
+        };
+
+        let mut parts = contents.trim().splitn(2, ' ');
+        let pid: Option<u32> = parts.next().and_then(|pid| pid.parse().ok());
+        let acquired_at = parts.next().and_then(|time| DateTime::parse_from_rfc3339(time).ok());
+
+        match (pid, acquired_at) {
+            (Some(pid), Some(acquired_at)) => {
+                let age = Utc::now().signed_duration_since(acquired_at).to_std();
+
+                age.map(|age| age > STALE_LOCK_FILE_AGE).unwrap_or(false) || !process_is_running(pid)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        if let Err(err) = fs::remove_file(&self.path) {
+            tracing::error!(
+                "Could not remove the migration lock file at `{}`: {}",
+                self.path.display(),
+                err
+            );
+        }
+    }
+}
+
+/// Only Linux can tell whether a process is running without platform specific system calls. Elsewhere, lock files
+/// left behind are only reclaimed once they are too old.
+#[cfg(target_os = "linux")]
+fn process_is_running(pid: u32) -> bool {
+    Path::new(&format!("/proc/{}", pid)).exists()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_is_running(_pid: u32) -> bool {
+    true
+}
+
+/// The key of the Postgres advisory lock for the schema. Advisory locks are scoped to the database, so the key is
+/// derived from the schema name to only exclude migration runs on the same schema.
+pub(crate) fn advisory_lock_key(schema_name: &str) -> i64 {
+    lock_hash(schema_name) as i64
+}
+
+/// The name of the MySQL named lock for the database. Lock names are limited to 64 characters, so the database name
+/// is hashed.
+pub(crate) fn named_lock_name(schema_name: &str) -> String {
+    format!("prisma_migrate_{:016x}", lock_hash(schema_name))
+}
+
+/// FNV-1a, a hash that is stable across processes and versions, unlike the standard library's hasher.
+fn lock_hash(schema_name: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    format!("prisma_migrate:{}", schema_name)
+        .bytes()
+        .fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock_file_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}.migration_lock", name));
+
+        if path.exists() {
+            fs::remove_file(&path).unwrap();
+        }
+
+        path
+    }
+
+    #[test]
+    fn lock_files_are_removed_when_the_lock_is_dropped() {
+        let path = lock_file_path("lock_file_drop");
+        let lock_file = LockFile::try_acquire(path.clone()).unwrap().unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with(&format!("{} ", std::process::id())));
+
+        assert!(LockFile::try_acquire(path.clone()).unwrap().is_none());
+
+        drop(lock_file);
+
+        assert!(!path.exists());
+        assert!(LockFile::try_acquire(path).unwrap().is_some());
+    }
+
+    #[test]
+    fn stale_lock_files_are_reclaimed() {
+        let path = lock_file_path("lock_file_stale");
+        let two_hours_ago = Utc::now() - chrono::Duration::hours(2);
+
+        for contents in &[
+            String::new(),
+            format!("{} {}", std::process::id(), two_hours_ago.to_rfc3339()),
+        ] {
+            fs::write(&path, contents).unwrap();
+
+            let lock_file = LockFile::try_acquire(path.clone()).unwrap();
+
+            assert!(lock_file.is_some());
+        }
+    }
+
+    #[test]
+    fn lock_files_of_running_processes_are_not_reclaimed() {
+        let path = lock_file_path("lock_file_running");
+
+        fs::write(&path, format!("{} {}", std::process::id(), Utc::now().to_rfc3339())).unwrap();
+
+        assert!(LockFile::try_acquire(path.clone()).unwrap().is_none());
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    sql_migration::{expanded_alter_column::*, AlterColumn, AlterTable, TableChange},
+    sql_schema_differ::{ColumnDiffer, DiffingOptions},
+    sql_schema_helpers::{find_column, SqlSchemaExt},
+    DatabaseInfo,
+};
+use quaint::prelude::SqlFamily;
+use sql_schema_describer::SqlSchema;
+use std::time::Duration;
+
+/// Connection string parameter enabling the online migration mode.
+const ONLINE_PARAM: &str = "online_migrations";
+
+/// Connection string parameter holding the lock timeout of the migration statements in milliseconds.
+const LOCK_TIMEOUT_PARAM: &str = "lock_timeout";
+
+/// Connection string parameter holding the statement timeout of the migration statements in milliseconds.
+const STATEMENT_TIMEOUT_PARAM: &str = "statement_timeout";
+
+/// The lock timeout in online mode, unless the connection string sets one. Migration statements waiting for a lock
+/// block the writes queued behind them, so they should rather fail early.
+const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Settings of the online migration mode, for migrating tables that are written to while the migration runs.
+///
+/// In online mode, migration steps are rendered to avoid locking tables for writes:
+///
+/// - Postgres builds and drops indexes `CONCURRENTLY`, and makes columns required by validating a `NOT VALID` check
+///   constraint first, so `SET NOT NULL` doesn't need to scan the table under lock.
+/// - MySQL runs `ALTER TABLE` and `CREATE INDEX` with `ALGORITHM=INPLACE, LOCK=NONE` when all the changes support
+///   it.
+///
+/// Steps that still lock tables for writes are reported as warnings by the destructive changes checker.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OnlineMigrations {
+    enabled: bool,
+    lock_timeout: Option<Duration>,
+    statement_timeout: Option<Duration>,
+}
+
+impl OnlineMigrations {
+    /// Reads the settings from the `online_migrations`, `lock_timeout` and `statement_timeout` connection string
+    /// parameters. They are ignored on SQLite, where there are no concurrent writers to protect.
+    pub(crate) fn from_url(url: &str, sql_family: SqlFamily) -> Self {
+        if !matches!(sql_family, SqlFamily::Postgres | SqlFamily::Mysql) {
+            return Self::default();
+        }
+
+        let millis = |param| {
+            datamodel::url_parameter(url, param)
+                .and_then(|millis| millis.parse().ok())
+                .map(Duration::from_millis)
+        };
+
+        OnlineMigrations {
+            enabled: datamodel::url_parameter(url, ONLINE_PARAM)
+                .and_then(|flag| flag.parse().ok())
+                .unwrap_or(false),
+            lock_timeout: millis(LOCK_TIMEOUT_PARAM),
+            statement_timeout: millis(STATEMENT_TIMEOUT_PARAM),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn lock_timeout(&self) -> Option<Duration> {
+        match self.lock_timeout {
+            Some(lock_timeout) => Some(lock_timeout),
+            None if self.enabled => Some(DEFAULT_LOCK_TIMEOUT),
+            None => None,
+        }
+    }
+
+    /// The statements setting the timeouts for the session of the migration connection. MySQL lock timeouts are in
+    /// whole seconds, and only MariaDB has a statement timeout that applies to DDL statements.
+    pub(crate) fn session_statements(&self, sql_family: SqlFamily, is_mariadb: bool) -> Vec<String> {
+        let mut statements = Vec::new();
+
+        match sql_family {
+            SqlFamily::Postgres => {
+                if let Some(lock_timeout) = self.lock_timeout() {
+                    statements.push(format!("SET lock_timeout = '{}ms'", lock_timeout.as_millis()));
+                }
+
+                if let Some(statement_timeout) = self.statement_timeout {
+                    statements.push(format!("SET statement_timeout = '{}ms'", statement_timeout.as_millis()));
+                }
+            }
+            SqlFamily::Mysql => {
+                if let Some(lock_timeout) = self.lock_timeout() {
+                    statements.push(format!(
+                        "SET SESSION lock_wait_timeout = {}",
+                        whole_seconds(lock_timeout)
+                    ));
+                }
+
+                match self.statement_timeout {
+                    Some(statement_timeout) if is_mariadb => statements.push(format!(
+                        "SET SESSION max_statement_time = {}",
+                        statement_timeout.as_secs_f64()
+                    )),
+                    _ => (),
+                }
+            }
+            SqlFamily::Sqlite | SqlFamily::Mssql => (),
+        }
+
+        statements
+    }
+}
+
+/// PostgreSQL silently truncates identifiers to 63 bytes.
+const POSTGRES_MAX_IDENTIFIER_LENGTH: usize = 63;
+
+/// The name of the check constraint proving that the column has no null values before it is made required. Names
+/// over the PostgreSQL identifier limit are truncated, and end with a hash of the full name to keep them distinct.
+pub(crate) fn not_null_constraint_name(table_name: &str, column_name: &str) -> String {
+    const SUFFIX: &str = "_not_null";
+
+    let name = format!("{}_{}", table_name, column_name);
+
+    if name.len() + SUFFIX.len() <= POSTGRES_MAX_IDENTIFIER_LENGTH {
+        return format!("{}{}", name, SUFFIX);
+    }
+
+    let hash = format!("_{:08x}", name_hash(&name));
+    let mut end = POSTGRES_MAX_IDENTIFIER_LENGTH - hash.len() - SUFFIX.len();
+
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!("{}{}{}", &name[..end], hash, SUFFIX)
+}
+
+/// 32 bit FNV-1a, a hash that is stable across processes and versions, so rendered migrations don't change.
+fn name_hash(name: &str) -> u32 {
+    const OFFSET_BASIS: u32 = 0x811c_9dc5;
+    const PRIME: u32 = 0x0100_0193;
+
+    name.bytes()
+        .fold(OFFSET_BASIS, |hash, byte| (hash ^ u32::from(byte)).wrapping_mul(PRIME))
+}
+
+/// Rounds up to whole seconds, with a minimum of one second.
+fn whole_seconds(duration: Duration) -> u64 {
+    let seconds = duration.as_secs() + if duration.subsec_nanos() > 0 { 1 } else { 0 };
+
+    seconds.max(1)
+}
+
+/// The changes of an `ALTER TABLE` that lock the table for writes while the statement runs, even in online mode.
+///
+/// On Postgres, these are the changes rewriting the table or building an index under an exclusive lock. On MySQL,
+/// these are the changes that can't run with `ALGORITHM=INPLACE, LOCK=NONE`, so the statement is rendered without
+/// it when there are any.
+pub(crate) fn locking_table_changes(
+    alter_table: &AlterTable,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+    database_info: &DatabaseInfo,
+) -> Vec<String> {
+    let diffing_options = DiffingOptions::from_database_info(database_info);
+    let sql_family = database_info.sql_family();
+    let table_name = alter_table.table.name.as_str();
+    let adds_primary_key = alter_table
+        .changes
+        .iter()
+        .any(|change| matches!(change, TableChange::AddPrimaryKey { .. }));
+    let mut locking_changes = Vec::new();
+
+    for change in &alter_table.changes {
+        match (sql_family, change) {
+            (SqlFamily::Postgres, TableChange::AddPrimaryKey { .. }) => {
+                locking_changes.push("add a primary key".to_owned())
+            }
+            // Replacing the primary key in the same statement is done in place, dropping it is not.
+            (SqlFamily::Mysql, TableChange::DropPrimaryKey { .. }) if !adds_primary_key => {
+                locking_changes.push("drop the primary key".to_owned())
+            }
+            (SqlFamily::Mysql, TableChange::AddColumn(add_column)) if add_column.column.auto_increment => {
+                locking_changes.push(format!("add the autoincrementing column `{}`", add_column.column.name))
+            }
+            (_, TableChange::AlterColumn(AlterColumn { name, column })) => {
+                let previous = current_schema
+                    .table_ref(table_name)
+                    .and_then(|table| table.column(name));
+                let next = find_column(next_schema, table_name, &column.name);
+
+                let (previous, next) = match (previous, next) {
+                    (Some(previous), Some(next)) => (previous, next),
+                    _ => continue,
+                };
+
+                let differ = ColumnDiffer {
+                    diffing_options: &diffing_options,
+                    previous,
+                    next,
+                };
+
+                let rewrites_table = match sql_family {
+                    // Columns that can't be cast are dropped and recreated, which doesn't rewrite the table.
+                    SqlFamily::Postgres => expand_postgres_alter_column(&differ)
+                        .map(|steps| steps.iter().any(|step| matches!(step, PostgresAlterColumn::SetType(_))))
+                        .unwrap_or(false),
+                    SqlFamily::Mysql => differ.all_changes().type_changed(),
+                    SqlFamily::Sqlite | SqlFamily::Mssql => false,
+                };
+
+                if rewrites_table {
+                    locking_changes.push(format!("change the type of the column `{}`", name));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    locking_changes
+}
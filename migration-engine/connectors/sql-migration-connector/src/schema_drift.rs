@@ -0,0 +1,140 @@
+//! Schema drift is the difference between the database schema the applied migrations lead to and
+//! the schema of the live database. It is described by the steps migrating the live database to
+//! the expected schema.
+
+use crate::{
+    sql_migration::*,
+    sql_schema_calculator::SqlSchemaCalculator,
+    sql_schema_differ::{DiffingOptions, SqlSchemaDiffer},
+    Component, SqlMigrationConnector, SqlResult,
+};
+use datamodel::Datamodel;
+use migration_connector::SchemaDrift;
+
+/// Diffs the described live database against the schema calculated from the datamodel.
+pub(crate) async fn check_drift(connector: &SqlMigrationConnector, datamodel: &Datamodel) -> SqlResult<SchemaDrift> {
+    let database_info = connector.database_info();
+    let live_database_schema = connector.describe().await?;
+    let expected_database_schema = SqlSchemaCalculator::calculate(datamodel, database_info)?;
+
+    let steps = SqlSchemaDiffer::diff(
+        &live_database_schema,
+        &expected_database_schema,
+        database_info.sql_family(),
+        &DiffingOptions::from_database_info(database_info),
+    )
+    .into_steps();
+
+    Ok(SchemaDrift {
+        summary: steps.iter().flat_map(describe_step).collect(),
+        steps: steps
+            .iter()
+            .map(|step| serde_json::to_value(step).unwrap_or_else(|_| serde_json::json!({})))
+            .collect(),
+    })
+}
+
+/// Describes the differences a step migrating the live database to the expected schema makes up
+/// for.
+fn describe_step(step: &SqlMigrationStep) -> Vec<String> {
+    match step {
+        SqlMigrationStep::CreateTable(CreateTable { table }) => {
+            vec![format!("The `{}` table is missing from the database.", table.name)]
+        }
+        SqlMigrationStep::DropTable(DropTable { name }) => vec![format!(
+            "The database has a `{}` table that is not in the migrations.",
+            name
+        )],
+        SqlMigrationStep::AlterTable(AlterTable { table, changes }) => changes
+            .iter()
+            .map(|change| describe_table_change(&table.name, change))
+            .collect(),
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => vec![format!(
+            "The index `{}` on the `{}` table is missing from the database.",
+            index.name, table
+        )],
+        SqlMigrationStep::DropIndex(DropIndex { table, name }) => vec![format!(
+            "The `{}` table has an index `{}` that is not in the migrations.",
+            table, name
+        )],
+        SqlMigrationStep::AlterIndex(AlterIndex {
+            table,
+            index_name,
+            index_new_name,
+        }) => vec![format!(
+            "The index `{}` on the `{}` table is named `{}` in the migrations.",
+            index_name, table, index_new_name
+        )],
+        SqlMigrationStep::AddForeignKey(AddForeignKey { table, foreign_key }) => vec![format!(
+            "The foreign key on the column(s) {} of the `{}` table is missing from the database.",
+            foreign_key
+                .columns
+                .iter()
+                .map(|column| format!("`{}`", column))
+                .collect::<Vec<_>>()
+                .join(", "),
+            table
+        )],
+        SqlMigrationStep::DropForeignKey(DropForeignKey { table, constraint_name }) => vec![format!(
+            "The `{}` table has a foreign key `{}` that is not in the migrations.",
+            table, constraint_name
+        )],
+        SqlMigrationStep::CreateEnum(CreateEnum { name, .. }) => {
+            vec![format!("The `{}` enum is missing from the database.", name)]
+        }
+        SqlMigrationStep::DropEnum(DropEnum { name }) => vec![format!(
+            "The database has a `{}` enum that is not in the migrations.",
+            name
+        )],
+        SqlMigrationStep::CreateSequence(CreateSequence { name }) => {
+            vec![format!("The `{}` sequence is missing from the database.", name)]
+        }
+        SqlMigrationStep::AlterEnum(AlterEnum {
+            name,
+            created_variants,
+            dropped_variants,
+        }) => created_variants
+            .iter()
+            .map(|variant| format!("The value `{}` is missing from the `{}` enum.", variant, name))
+            .chain(dropped_variants.iter().map(|variant| {
+                format!(
+                    "The `{}` enum has a value `{}` that is not in the migrations.",
+                    name, variant
+                )
+            }))
+            .collect(),
+        // The differ doesn't produce renames, raw SQL or data migrations.
+        SqlMigrationStep::RenameTable { .. }
+        | SqlMigrationStep::RenameColumn { .. }
+        | SqlMigrationStep::RawSql { .. }
+        | SqlMigrationStep::DataMigration { .. } => Vec::new(),
+    }
+}
+
+fn describe_table_change(table: &str, change: &TableChange) -> String {
+    match change {
+        TableChange::AddColumn(AddColumn { column }) => {
+            format!("The column `{}` is missing from the `{}` table.", column.name, table)
+        }
+        TableChange::DropColumn(DropColumn { name }) => format!(
+            "The `{}` table has a column `{}` that is not in the migrations.",
+            table, name
+        ),
+        TableChange::AlterColumn(AlterColumn { name, .. }) => format!(
+            "The column `{}` on the `{}` table differs from the migrations.",
+            name, table
+        ),
+        TableChange::DropPrimaryKey { .. } => {
+            format!("The primary key of the `{}` table differs from the migrations.", table)
+        }
+        TableChange::AddPrimaryKey { columns } => format!(
+            "The primary key of the `{}` table should be on the column(s) {}.",
+            table,
+            columns
+                .iter()
+                .map(|column| format!("`{}`", column))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
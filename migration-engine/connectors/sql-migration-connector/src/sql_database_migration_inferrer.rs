@@ -7,7 +7,7 @@ use datamodel::*;
 use migration_connector::steps::MigrationStep;
 use migration_connector::*;
 use sql_schema_describer::*;
-use sql_schema_differ::DiffingOptions;
+use sql_schema_differ::{DiffingOptions, SqlRenames};
 
 pub struct SqlDatabaseMigrationInferrer<'a> {
     pub connector: &'a crate::SqlMigrationConnector,
@@ -23,16 +23,69 @@ impl Component for SqlDatabaseMigrationInferrer<'_> {
 impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_> {
     async fn infer(
         &self,
-        _previous: &Datamodel,
+        previous: &Datamodel,
+        next: &Datamodel,
+        _steps: &[MigrationStep],
+        renames: &[Rename],
+    ) -> ConnectorResult<SqlMigration> {
+        let fut = async {
+            let current_database_schema: SqlSchema = self.describe().await?;
+            let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
+            let renames = SqlRenames::resolve(
+                renames,
+                previous,
+                next,
+                &current_database_schema,
+                &expected_database_schema,
+            );
+
+            infer(
+                &current_database_schema,
+                &[],
+                &expected_database_schema,
+                &renames,
+                self.schema_name(),
+                self.sql_family(),
+                self.database_info(),
+            )
+        };
+
+        catch(&self.connection_info(), fut).await
+    }
+
+    async fn infer_with_data_migrations(
+        &self,
+        previous: &Datamodel,
         next: &Datamodel,
         _steps: &[MigrationStep],
+        renames: &[Rename],
+        checkpoints: &[DataMigrationCheckpoint],
     ) -> ConnectorResult<SqlMigration> {
         let fut = async {
             let current_database_schema: SqlSchema = self.describe().await?;
             let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
+            let renames = SqlRenames::resolve(
+                renames,
+                previous,
+                next,
+                &current_database_schema,
+                &expected_database_schema,
+            );
+            let checkpoints = checkpoints
+                .iter()
+                .map(|checkpoint| {
+                    let checkpoint_schema =
+                        SqlSchemaCalculator::calculate(&checkpoint.datamodel, self.database_info())?;
+
+                    Ok((checkpoint_schema, checkpoint.scripts.as_slice()))
+                })
+                .collect::<SqlResult<Vec<_>>>()?;
+
             infer(
                 &current_database_schema,
+                &checkpoints,
                 &expected_database_schema,
+                &renames,
                 self.schema_name(),
                 self.sql_family(),
                 self.database_info(),
@@ -47,13 +100,24 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         previous: &Datamodel,
         next: &Datamodel,
         _steps: &[MigrationStep],
+        renames: &[Rename],
     ) -> ConnectorResult<SqlMigration> {
         let result: SqlResult<SqlMigration> = (|| {
             let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous, self.database_info())?;
             let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
+            let renames = SqlRenames::resolve(
+                renames,
+                previous,
+                next,
+                &current_database_schema,
+                &expected_database_schema,
+            );
+
             infer(
                 &current_database_schema,
+                &[],
                 &expected_database_schema,
+                &renames,
                 self.schema_name(),
                 self.sql_family(),
                 self.database_info(),
@@ -62,35 +126,96 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
 
         result.map_err(|sql_error| sql_error.into_connector_error(self.connection_info()))
     }
+
+    fn infer_down(&self, database_migration: &SqlMigration) -> ConnectorResult<SqlMigration> {
+        // The `before` schema of the migration has its renames applied already, the down migration leads back to
+        // the schema as it was before them.
+        let renames = SqlRenames::from_steps(&database_migration.original_steps).inverse();
+        let previous_database_schema = renames.apply(&database_migration.before);
+
+        infer(
+            &database_migration.after,
+            &[],
+            &previous_database_schema,
+            &renames,
+            self.schema_name(),
+            self.sql_family(),
+            self.database_info(),
+        )
+        .map_err(|sql_error| sql_error.into_connector_error(self.connection_info()))
+    }
+
+    fn migrate_to_the_same_schema(&self, database_migration: &SqlMigration, other: &SqlMigration) -> bool {
+        database_migration.after == other.after
+    }
 }
 
+/// Infer the migration from the current to the expected schema. The scripts of the data migrations run once the
+/// database has been migrated to the schema of their checkpoint.
 fn infer(
     current_database_schema: &SqlSchema,
+    data_migration_checkpoints: &[(SqlSchema, &[String])],
     expected_database_schema: &SqlSchema,
+    renames: &SqlRenames,
     schema_name: &str,
     sql_family: SqlFamily,
     database_info: &DatabaseInfo,
 ) -> SqlResult<SqlMigration> {
-    let (original_steps, corrected_steps) = infer_database_migration_steps_and_fix(
-        &current_database_schema,
+    // Everything but the renames is migrated from the schema as it is after the renames.
+    let renamed_database_schema = renames.apply(current_database_schema);
+
+    let mut original_steps: Vec<SqlMigrationStep> = renames.steps().collect();
+    let mut corrected_steps: Vec<SqlMigrationStep> = renames.steps().collect();
+    let mut checkpoint_database_schema = renamed_database_schema.clone();
+    let mut checkpoints = Vec::with_capacity(data_migration_checkpoints.len());
+
+    for (schema, scripts) in data_migration_checkpoints {
+        let next_checkpoint_database_schema = renames.apply(schema);
+        let (original, corrected) = infer_database_migration_steps_and_fix(
+            &checkpoint_database_schema,
+            &next_checkpoint_database_schema,
+            &schema_name,
+            sql_family,
+            database_info,
+        )?;
+        let data_migration_steps = scripts
+            .iter()
+            .map(|script| SqlMigrationStep::DataMigration { script: script.clone() });
+
+        original_steps.extend(original.into_iter().chain(data_migration_steps.clone()));
+        corrected_steps.extend(corrected.into_iter().chain(data_migration_steps));
+        checkpoints.push(SqlMigrationCheckpoint {
+            steps_count: corrected_steps.len(),
+            schema: next_checkpoint_database_schema.clone(),
+        });
+        checkpoint_database_schema = next_checkpoint_database_schema;
+    }
+
+    let (original, corrected) = infer_database_migration_steps_and_fix(
+        &checkpoint_database_schema,
         &expected_database_schema,
         &schema_name,
         sql_family,
         database_info,
     )?;
+    original_steps.extend(original);
+    corrected_steps.extend(corrected);
+
+    // Data migrations are not reverted, the rollback migrates the schema back directly.
     let (_, rollback) = infer_database_migration_steps_and_fix(
         &expected_database_schema,
-        &current_database_schema,
+        &renamed_database_schema,
         &schema_name,
         sql_family,
         database_info,
     )?;
     Ok(SqlMigration {
-        before: current_database_schema.clone(),
+        before: renamed_database_schema,
         after: expected_database_schema.clone(),
         original_steps,
         corrected_steps,
-        rollback,
+        rollback: rollback.into_iter().chain(renames.inverse_steps()).collect(),
+        checkpoints,
     })
 }
 
@@ -20,15 +20,40 @@ impl crate::component::Component for SqlDatabaseStepApplier<'_> {
 impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
     async fn apply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
         let renderer = self.renderer();
-        let fut = self
-            .apply_next_step(
-                &database_migration.corrected_steps,
-                index,
-                renderer.as_ref(),
-                &database_migration.before,
-                &database_migration.after,
-            )
-            .instrument(tracing::debug_span!("ApplySqlStep", index));
+        let (current_schema, next_schema) = database_migration.schemas_for_step(index);
+        let in_transaction = runs_in_single_transaction(database_migration, self.database_info());
+
+        let fut = async {
+            if in_transaction && index == 0 {
+                self.conn().raw_cmd("BEGIN").await?;
+            }
+
+            let result = self
+                .apply_next_step(
+                    &database_migration.corrected_steps,
+                    index,
+                    renderer.as_ref(),
+                    current_schema,
+                    next_schema,
+                    in_transaction,
+                )
+                .await;
+
+            match result {
+                Ok(false) if in_transaction => {
+                    self.conn().raw_cmd("COMMIT").await?;
+
+                    Ok(false)
+                }
+                Err(err) if in_transaction => {
+                    self.conn().raw_cmd("ROLLBACK").await.ok();
+
+                    Err(err)
+                }
+                result => result,
+            }
+        }
+        .instrument(tracing::debug_span!("ApplySqlStep", index));
 
         crate::catch(self.connection_info(), fut).await
     }
@@ -42,6 +67,7 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
                 renderer.as_ref(),
                 &database_migration.after,
                 &database_migration.before,
+                false,
             )
             .instrument(tracing::debug_span!("UnapplySqlStep", index));
 
@@ -52,13 +78,11 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
         &self,
         database_migration: &SqlMigration,
     ) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>> {
-        render_steps_pretty(
-            &database_migration,
-            self.renderer().as_ref(),
-            self.database_info(),
-            &database_migration.before,
-            &database_migration.after,
-        )
+        render_steps_pretty(&database_migration, self.renderer().as_ref(), self.database_info())
+    }
+
+    fn render_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        render_script(&database_migration, self.renderer().as_ref(), self.database_info())
     }
 }
 
@@ -70,6 +94,7 @@ impl SqlDatabaseStepApplier<'_> {
         renderer: &(dyn SqlRenderer + Send + Sync),
         current_schema: &SqlSchema,
         next_schema: &SqlSchema,
+        in_transaction: bool,
     ) -> SqlResult<bool> {
         let has_this_one = steps.get(index).is_some();
         if !has_this_one {
@@ -79,6 +104,12 @@ impl SqlDatabaseStepApplier<'_> {
         let step = &steps[index];
         tracing::debug!(?step);
 
+        if let SqlMigrationStep::DataMigration { script } = step {
+            self.run_data_migration(script, in_transaction).await?;
+
+            return Ok(steps.get(index + 1).is_some());
+        }
+
         for sql_string in render_raw_sql(&step, renderer, self.database_info(), current_schema, next_schema)
             .map_err(|err: anyhow::Error| SqlError::Generic(err))?
         {
@@ -91,21 +122,57 @@ impl SqlDatabaseStepApplier<'_> {
         Ok(has_more)
     }
 
+    /// Runs a data migration script in a transaction, so a failing script leaves no partial changes behind. MySQL
+    /// commits schema changes implicitly, so they are kept if the script makes any. The scripts of migrations
+    /// running in a single transaction run in that transaction instead.
+    async fn run_data_migration(&self, script: &str, in_transaction: bool) -> SqlResult<()> {
+        tracing::debug!(%script, "Running a data migration");
+
+        if in_transaction {
+            return Ok(self.conn().raw_cmd(script).await?);
+        }
+
+        self.conn().raw_cmd("BEGIN").await?;
+
+        match self.conn().raw_cmd(script).await {
+            Ok(()) => {
+                self.conn().raw_cmd("COMMIT").await?;
+
+                Ok(())
+            }
+            Err(err) => {
+                self.conn().raw_cmd("ROLLBACK").await.ok();
+
+                Err(err.into())
+            }
+        }
+    }
+
     fn renderer<'a>(&'a self) -> Box<dyn SqlRenderer + Send + Sync + 'a> {
         SqlRenderer::for_family(&self.sql_family())
     }
 }
 
+/// Whether the steps and data migration scripts of the migration run in a single transaction, so a
+/// failing script rolls back the schema changes of the migration as well. This is only the case on
+/// Postgres: MySQL commits schema changes implicitly, and SQLite ignores turning off foreign keys for
+/// redefining tables inside of a transaction. Concurrent index builds of the online migration mode
+/// can't run in a transaction either.
+fn runs_in_single_transaction(database_migration: &SqlMigration, database_info: &DatabaseInfo) -> bool {
+    database_info.sql_family() == SqlFamily::Postgres
+        && !database_info.online_migrations().is_enabled()
+        && database_migration.has_data_migrations()
+}
+
 fn render_steps_pretty(
     database_migration: &SqlMigration,
     renderer: &(dyn SqlRenderer + Send + Sync),
     database_info: &DatabaseInfo,
-    current_schema: &SqlSchema,
-    next_schema: &SqlSchema,
 ) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>> {
     let mut steps = Vec::with_capacity(database_migration.corrected_steps.len());
 
-    for step in &database_migration.corrected_steps {
+    for (index, step) in database_migration.corrected_steps.iter().enumerate() {
+        let (current_schema, next_schema) = database_migration.schemas_for_step(index);
         let sql = render_raw_sql(&step, renderer, database_info, current_schema, next_schema)
             .map_err(|err: anyhow::Error| {
                 ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err.into()))
@@ -123,6 +190,66 @@ fn render_steps_pretty(
     Ok(steps)
 }
 
+fn render_script(
+    database_migration: &SqlMigration,
+    renderer: &(dyn SqlRenderer + Send + Sync),
+    database_info: &DatabaseInfo,
+) -> ConnectorResult<String> {
+    let mut script = String::new();
+    let in_transaction = runs_in_single_transaction(database_migration, database_info);
+
+    // The timeouts of the online migration mode are set for the session of the migration connection.
+    let session_statements = database_info
+        .online_migrations()
+        .session_statements(database_info.sql_family(), database_info.is_mariadb());
+
+    push_script_statements(&mut script, &session_statements);
+
+    if in_transaction {
+        push_script_statements(&mut script, &["BEGIN".to_owned()]);
+    }
+
+    for (index, step) in database_migration.corrected_steps.iter().enumerate() {
+        let (current_schema, next_schema) = database_migration.schemas_for_step(index);
+        let statements = render_raw_sql(&step, renderer, database_info, current_schema, next_schema).map_err(
+            |err: anyhow::Error| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err.into())),
+        )?;
+
+        match step {
+            // Data migrations run in a transaction of their own, see `run_data_migration`.
+            SqlMigrationStep::DataMigration { .. } if !in_transaction => {
+                let statements: Vec<String> = std::iter::once("BEGIN".to_owned())
+                    .chain(statements)
+                    .chain(std::iter::once("COMMIT".to_owned()))
+                    .collect();
+
+                push_script_statements(&mut script, &statements)
+            }
+            _ if statements.is_empty() => (),
+            _ => push_script_statements(&mut script, &statements),
+        }
+    }
+
+    if in_transaction {
+        push_script_statements(&mut script, &["COMMIT".to_owned()]);
+    }
+
+    Ok(script)
+}
+
+/// Appends the statements of a step to the script, terminated by semicolons and separated from
+/// the previous step by an empty line.
+fn push_script_statements(script: &mut String, statements: &[String]) {
+    if !script.is_empty() {
+        script.push('\n');
+    }
+
+    for statement in statements {
+        script.push_str(statement.trim_end().trim_end_matches(';'));
+        script.push_str(";\n");
+    }
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlRenderer + Send + Sync),
@@ -132,10 +259,12 @@ fn render_raw_sql(
 ) -> Result<Vec<String>, anyhow::Error> {
     let sql_family = renderer.sql_family();
     let schema_name = database_info.connection_info().schema_name().to_string();
+    let online = database_info.online_migrations().is_enabled();
 
     match step {
         SqlMigrationStep::CreateEnum(create_enum) => render_create_enum(renderer, create_enum),
         SqlMigrationStep::DropEnum(drop_enum) => render_drop_enum(renderer, drop_enum),
+        SqlMigrationStep::CreateSequence(create_sequence) => render_create_sequence(renderer, create_sequence),
         SqlMigrationStep::AlterEnum(alter_enum) => match renderer.sql_family() {
             SqlFamily::Postgres => postgres_alter_enum(alter_enum, next_schema, &schema_name)?.into(),
             SqlFamily::Mysql => mysql_alter_enum(alter_enum, next_schema, &schema_name),
@@ -216,6 +345,33 @@ fn render_raw_sql(
                 new_name
             )])
         }
+        SqlMigrationStep::RenameColumn { table, name, new_name } => match sql_family {
+            // MySQL before version 8 and MariaDB only rename columns as part of a full column
+            // definition.
+            SqlFamily::Mysql if !database_info.is_mysql_8() => {
+                let column = find_column(current_schema, table, new_name).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invariant violation: could not find column `{}` on table `{}` in current schema.",
+                        new_name,
+                        table
+                    )
+                })?;
+
+                Ok(vec![format!(
+                    "ALTER TABLE {} CHANGE {} {};",
+                    renderer.quote_with_schema(&schema_name, &table),
+                    renderer.quote(name),
+                    renderer.render_column(&schema_name, column, false)
+                )])
+            }
+            SqlFamily::Mysql | SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                renderer.quote_with_schema(&schema_name, &table),
+                renderer.quote(name),
+                renderer.quote(new_name)
+            )]),
+            SqlFamily::Mssql => todo!("Greetings from Redmond"),
+        },
         SqlMigrationStep::AddForeignKey(AddForeignKey { table, foreign_key }) => match sql_family {
             SqlFamily::Sqlite => Ok(Vec::new()),
             _ => {
@@ -257,8 +413,10 @@ fn render_raw_sql(
             SqlFamily::Mssql => todo!("Greetings from Redmond"),
         },
 
-        SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+        SqlMigrationStep::AlterTable(alter_table) => {
+            let AlterTable { table, changes } = alter_table;
             let mut lines = Vec::new();
+            let mut not_null_constraints = Vec::new();
             for change in changes {
                 match change {
                     TableChange::DropPrimaryKey { constraint_name } => match renderer.sql_family() {
@@ -291,14 +449,30 @@ fn render_raw_sql(
                         lines.push(format!("DROP COLUMN {}", name));
                     }
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
+                        let previous_column = current_schema.table_ref(&table.name).unwrap().column(&name).unwrap();
+                        let next_column = find_column(next_schema, &table.name, &column.name)
+                            .expect("Invariant violation: could not find column referred to in AlterColumn.");
+
                         match safe_alter_column(
                             renderer,
-                            current_schema.table_ref(&table.name).unwrap().column(&name).unwrap(),
-                            find_column(next_schema, &table.name, &column.name)
-                                .expect("Invariant violation: could not find column referred to in AlterColumn."),
+                            previous_column,
+                            next_column,
                             &DiffingOptions::from_database_info(database_info),
                         )? {
                             Some(safe_sql) => {
+                                // `SET NOT NULL` scans the table under an exclusive lock, unless a validated
+                                // check constraint already proves that the column has no null values.
+                                if online
+                                    && sql_family == SqlFamily::Postgres
+                                    && previous_column.arity().is_nullable()
+                                    && next_column.arity().is_required()
+                                {
+                                    not_null_constraints.push((
+                                        online_migrations::not_null_constraint_name(&table.name, &column.name),
+                                        next_column.name(),
+                                    ));
+                                }
+
                                 for line in safe_sql {
                                     lines.push(line)
                                 }
@@ -323,21 +497,62 @@ fn render_raw_sql(
                 return Ok(Vec::new());
             }
 
-            Ok(vec![format!(
-                "ALTER TABLE {} {};",
-                renderer.quote_with_schema(&schema_name, &table.name),
-                lines.join(",\n")
-            )])
+            let table_name = renderer.quote_with_schema(&schema_name, &table.name);
+
+            if online
+                && sql_family == SqlFamily::Mysql
+                && online_migrations::locking_table_changes(alter_table, current_schema, next_schema, database_info)
+                    .is_empty()
+            {
+                lines.push("ALGORITHM=INPLACE, LOCK=NONE".to_owned());
+            }
+
+            let mut statements = Vec::with_capacity(1 + not_null_constraints.len() * 3);
+
+            for (constraint_name, column_name) in &not_null_constraints {
+                statements.push(format!(
+                    "ALTER TABLE {} ADD CONSTRAINT {} CHECK ({} IS NOT NULL) NOT VALID;",
+                    table_name,
+                    renderer.quote(constraint_name),
+                    renderer.quote(column_name),
+                ));
+                statements.push(format!(
+                    "ALTER TABLE {} VALIDATE CONSTRAINT {};",
+                    table_name,
+                    renderer.quote(constraint_name)
+                ));
+            }
+
+            statements.push(format!("ALTER TABLE {} {};", table_name, lines.join(",\n")));
+
+            for (constraint_name, _) in &not_null_constraints {
+                statements.push(format!(
+                    "ALTER TABLE {} DROP CONSTRAINT {};",
+                    table_name,
+                    renderer.quote(constraint_name)
+                ));
+            }
+
+            Ok(statements)
         }
         SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
             Ok(vec![render_create_index(renderer, database_info, table, index)])
         }
         SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
+            SqlFamily::Mysql if online => Ok(vec![format!(
+                "DROP INDEX {} ON {} ALGORITHM=INPLACE LOCK=NONE",
+                renderer.quote(&name),
+                renderer.quote_with_schema(&schema_name, &table),
+            )]),
             SqlFamily::Mysql => Ok(vec![format!(
                 "DROP INDEX {} ON {}",
                 renderer.quote(&name),
                 renderer.quote_with_schema(&schema_name, &table),
             )]),
+            SqlFamily::Postgres if online => Ok(vec![format!(
+                "DROP INDEX CONCURRENTLY {}",
+                renderer.quote_with_schema(&schema_name, &name)
+            )]),
             SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
                 "DROP INDEX {}",
                 renderer.quote_with_schema(&schema_name, &name)
@@ -396,6 +611,7 @@ fn render_raw_sql(
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
         },
         SqlMigrationStep::RawSql { raw } => Ok(vec![raw.to_owned()]),
+        SqlMigrationStep::DataMigration { script } => Ok(vec![script.to_owned()]),
     }
 }
 
@@ -424,13 +640,22 @@ fn render_create_index(
             .to_string(),
     };
     let columns = columns.iter().map(|c| renderer.quote(c));
+    let online = database_info.online_migrations().is_enabled();
+
+    let (concurrently, algorithm) = match sql_family {
+        SqlFamily::Postgres if online => (" CONCURRENTLY", ""),
+        SqlFamily::Mysql if online => ("", " ALGORITHM=INPLACE LOCK=NONE"),
+        _ => ("", ""),
+    };
 
     format!(
-        "CREATE {} INDEX {} ON {}({})",
+        "CREATE {} INDEX{} {} ON {}({}){}",
         index_type,
+        concurrently,
         index_name,
         table_reference,
-        columns.join(",")
+        columns.join(","),
+        algorithm,
     )
 }
 
@@ -486,6 +711,18 @@ fn safe_alter_column(
                 ),
                 PostgresAlterColumn::DropNotNull => format!("{} DROP NOT NULL", &alter_column_prefix),
                 PostgresAlterColumn::SetNotNull => format!("{} SET NOT NULL", &alter_column_prefix),
+                // Strings are not cast to ints implicitly.
+                PostgresAlterColumn::SetType(ty)
+                    if differ.previous.column_type_family() == &ColumnTypeFamily::String
+                        && ty.family == ColumnTypeFamily::Int =>
+                {
+                    format!(
+                        "{prefix} SET DATA TYPE {tpe} USING {column}::{tpe}",
+                        prefix = &alter_column_prefix,
+                        tpe = postgres_render_column_type(&ty),
+                        column = renderer.quote(differ.previous.name()),
+                    )
+                }
                 PostgresAlterColumn::SetType(ty) => format!(
                     "{} SET DATA TYPE {}",
                     &alter_column_prefix,
@@ -571,6 +808,23 @@ fn render_drop_enum(
     }
 }
 
+fn render_create_sequence(
+    renderer: &(dyn SqlRenderer + Send + Sync),
+    create_sequence: &CreateSequence,
+) -> Result<Vec<String>, anyhow::Error> {
+    match renderer.sql_family() {
+        SqlFamily::Postgres => {
+            let sql = format!(
+                "CREATE SEQUENCE IF NOT EXISTS {sequence_name}",
+                sequence_name = Quoted::postgres_ident(&create_sequence.name),
+            );
+
+            Ok(vec![sql])
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
 fn postgres_alter_enum(
     alter_enum: &AlterEnum,
     next_schema: &SqlSchema,
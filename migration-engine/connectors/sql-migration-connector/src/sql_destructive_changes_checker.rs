@@ -2,19 +2,24 @@ mod check;
 mod database_inspection_results;
 mod destructive_change_checker_flavour;
 mod destructive_check_plan;
+mod irreversible_step_check;
 mod unexecutable_step_check;
 mod warning_check;
 
 pub(crate) use destructive_change_checker_flavour::DestructiveChangeCheckerFlavour;
 
 use crate::{
+    online_migrations,
+    sql_renderer::SqlRenderer,
     sql_schema_differ::{ColumnDiffer, DiffingOptions},
-    sql_schema_helpers::SqlSchemaExt,
-    AddColumn, AlterColumn, Component, DropColumn, DropTable, SqlMigration, SqlMigrationStep, SqlResult, TableChange,
+    sql_schema_helpers::{walk_columns, SqlSchemaExt},
+    AddColumn, AddForeignKey, AlterColumn, AlterEnum, Component, DropColumn, DropTable, SqlMigration, SqlMigrationStep,
+    SqlResult, TableChange,
 };
 use destructive_check_plan::DestructiveCheckPlan;
+use irreversible_step_check::IrreversibleStepCheck;
 use migration_connector::{ConnectorResult, DestructiveChangeDiagnostics, DestructiveChangesChecker};
-use sql_schema_describer::SqlSchema;
+use sql_schema_describer::{ColumnTypeFamily, SqlSchema};
 use unexecutable_step_check::UnexecutableStepCheck;
 use warning_check::SqlMigrationWarningCheck;
 
@@ -44,9 +49,13 @@ impl SqlDestructiveChangesChecker<'_> {
         plan.push_warning(SqlMigrationWarningCheck::NonEmptyTableDrop {
             table: table_name.to_owned(),
         });
+        plan.push_irreversible(IrreversibleStepCheck::DroppedTable {
+            table: table_name.to_owned(),
+        });
     }
 
-    /// Emit a warning when we drop a column that contains non-null values.
+    /// Emit a warning when we drop a column that contains non-null values, and flag the drop as
+    /// irreversible.
     fn check_column_drop(
         &self,
         drop_column: &DropColumn,
@@ -57,6 +66,10 @@ impl SqlDestructiveChangesChecker<'_> {
             table: table.name.clone(),
             column: drop_column.name.clone(),
         });
+        plan.push_irreversible(IrreversibleStepCheck::DroppedColumn {
+            table: table.name.clone(),
+            column: drop_column.name.clone(),
+        });
     }
 
     /// Columns cannot be added when all of the following holds:
@@ -115,6 +128,34 @@ impl SqlDestructiveChangesChecker<'_> {
         }
     }
 
+    /// Removing enum values is unexecutable when rows of a table still use them.
+    fn check_alter_enum(&self, alter_enum: &AlterEnum, before: &SqlSchema, plan: &mut DestructiveCheckPlan) {
+        if alter_enum.dropped_variants.is_empty() {
+            return;
+        }
+
+        let enum_columns = walk_columns(before).filter(|column| match &column.column_type().family {
+            ColumnTypeFamily::Enum(enum_name) => enum_name == &alter_enum.name,
+            _ => false,
+        });
+
+        for column in enum_columns {
+            let table = column.table().table;
+
+            plan.push_unexecutable(UnexecutableStepCheck::DeletedUsedEnumValues {
+                r#enum: alter_enum.name.clone(),
+                values: alter_enum.dropped_variants.clone(),
+                table: table.name.clone(),
+                column: column.name().to_owned(),
+                identifying_columns: table
+                    .primary_key
+                    .as_ref()
+                    .map(|primary_key| primary_key.columns.clone())
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
     #[tracing::instrument(skip(self, steps, before), target = "SqlDestructiveChangeChecker::check")]
     async fn check_impl(
         &self,
@@ -123,6 +164,7 @@ impl SqlDestructiveChangesChecker<'_> {
         after: &SqlSchema,
     ) -> SqlResult<DestructiveChangeDiagnostics> {
         let mut plan = DestructiveCheckPlan::new();
+        let online = self.database_info().online_migrations().is_enabled();
 
         for step in steps {
             match step {
@@ -167,20 +209,52 @@ impl SqlDestructiveChangesChecker<'_> {
                                 _ => (),
                             }
                         }
+
+                        if online {
+                            let locking_changes = online_migrations::locking_table_changes(
+                                alter_table,
+                                before,
+                                after,
+                                self.database_info(),
+                            );
+
+                            for change in locking_changes {
+                                plan.push_warning(SqlMigrationWarningCheck::LockingChange {
+                                    table: alter_table.table.name.clone(),
+                                    change,
+                                })
+                            }
+                        }
                     }
                 }
+                // Validating a new foreign key on an existing table blocks writes to it.
+                SqlMigrationStep::AddForeignKey(AddForeignKey { table, .. })
+                    if online && before.table_ref(table).is_some() =>
+                {
+                    plan.push_warning(SqlMigrationWarningCheck::LockingChange {
+                        table: table.clone(),
+                        change: "add a foreign key".to_owned(),
+                    })
+                }
+                // Renames come first in a migration, the following steps use the new names.
+                SqlMigrationStep::RenameTable { name, new_name } => plan.push_table_rename(name, new_name),
+                SqlMigrationStep::RenameColumn { table, name, new_name } => {
+                    plan.push_column_rename(table, name, new_name)
+                }
                 // Here, check for each table we are going to delete if it is empty. If
                 // not, return a warning.
                 SqlMigrationStep::DropTable(DropTable { name }) => {
                     self.check_table_drop(name, &mut plan);
                 }
+                SqlMigrationStep::AlterEnum(alter_enum) => self.check_alter_enum(alter_enum, before, &mut plan),
                 // SqlMigrationStep::CreateIndex(CreateIndex { table, index }) if index.is_unique() => todo!(),
                 // do nothing
                 _ => (),
             }
         }
 
-        let mut diagnostics = plan.execute(self.schema_name(), self.conn()).await?;
+        let renderer = SqlRenderer::for_family(&self.sql_family());
+        let mut diagnostics = plan.execute(self.schema_name(), renderer.as_ref(), self.conn()).await?;
 
         // Temporary, for better reporting.
         diagnostics.warn_about_unexecutable_migrations();
@@ -14,6 +14,12 @@ pub(super) trait Check {
         None
     }
 
+    /// Indicates that the values the migration can't keep should be counted and sampled for the
+    /// returned table and column.
+    fn needed_incompatible_values(&self) -> Option<IncompatibleValuesInspection<'_>> {
+        None
+    }
+
     /// This function will always be called for every check in a migration. Each change must check
     /// for the data it needs in the database inspection results. If there is no data, it should
     /// assume the current state of the database could not be inspected and warn with a best effort
@@ -25,3 +31,24 @@ pub(super) trait Check {
     /// safe.
     fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String>;
 }
+
+/// The inspection of the values of a column that a migration step can't keep.
+#[derive(Debug)]
+pub(super) struct IncompatibleValuesInspection<'a> {
+    pub(super) table: &'a str,
+    pub(super) column: &'a str,
+    /// The columns identifying the sampled rows, usually the primary key.
+    pub(super) identifying_columns: &'a [String],
+    pub(super) values: IncompatibleValues<'a>,
+}
+
+/// The values of a column that a migration step can't keep.
+#[derive(Debug, Clone)]
+pub(crate) enum IncompatibleValues<'a> {
+    /// Values that can't be cast to an integer.
+    NotIntegers,
+    /// Strings longer than the given number of characters.
+    LongerThan(i64),
+    /// Any of the given enum values.
+    EnumValues(&'a [String]),
+}
@@ -7,6 +7,17 @@ pub(super) struct DatabaseInspectionResults {
     row_counts: HashMap<String, i64>,
     /// HashMap from (table name, column name) to non-null values count.
     value_counts: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
+    /// HashMap from (table name, column name) to the values the migration can't keep.
+    incompatible_values: HashMap<(String, String), IncompatibleRows>,
+}
+
+/// The rows with values a migration step can't keep.
+#[derive(Debug)]
+pub(super) struct IncompatibleRows {
+    /// The number of rows.
+    pub(super) count: i64,
+    /// A few of the rows, rendered for the user.
+    pub(super) samples: Vec<String>,
 }
 
 impl DatabaseInspectionResults {
@@ -30,4 +41,12 @@ impl DatabaseInspectionResults {
     pub(super) fn set_value_count(&mut self, table: Cow<'static, str>, column: Cow<'static, str>, count: i64) {
         self.value_counts.insert((table, column), count);
     }
+
+    pub(super) fn get_incompatible_rows(&self, table: &str, column: &str) -> Option<&IncompatibleRows> {
+        self.incompatible_values.get(&(table.to_owned(), column.to_owned()))
+    }
+
+    pub(super) fn set_incompatible_rows(&mut self, table: String, column: String, rows: IncompatibleRows) {
+        self.incompatible_values.insert((table, column), rows);
+    }
 }
@@ -2,12 +2,49 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
-use super::DestructiveCheckPlan;
+use super::{check::IncompatibleValues, unexecutable_step_check::UnexecutableStepCheck, DestructiveCheckPlan};
 use crate::sql_schema_differ::ColumnDiffer;
-use sql_schema_describer::Table;
+use sql_schema_describer::{ColumnTypeFamily, Table};
 
 /// Flavour-specific destructive change checks.
 pub(crate) trait DestructiveChangeCheckerFlavour {
     /// Check for potential destructive or unexecutable alter column steps.
     fn check_alter_column(&self, previous_table: &Table, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan);
 }
+
+/// The check for the values the new type of the column can't hold, if the type change narrows the
+/// column. `default_string_length` is the length of string columns without an explicit maximum
+/// length, if they are limited.
+fn narrowed_column_type_check(
+    previous_table: &Table,
+    columns: &ColumnDiffer<'_>,
+    default_string_length: Option<i64>,
+) -> Option<UnexecutableStepCheck> {
+    let previous_type = &columns.previous.column.tpe;
+    let next_type = &columns.next.column.tpe;
+
+    let incompatible_values = match (&previous_type.family, &next_type.family) {
+        (ColumnTypeFamily::String, ColumnTypeFamily::Int) => IncompatibleValues::NotIntegers,
+        (ColumnTypeFamily::String, ColumnTypeFamily::String) => {
+            let previous_length = previous_type.character_maximum_length.or(default_string_length);
+            let next_length = next_type.character_maximum_length.or(default_string_length)?;
+
+            match previous_length {
+                Some(previous_length) if previous_length <= next_length => return None,
+                _ => IncompatibleValues::LongerThan(next_length),
+            }
+        }
+        _ => return None,
+    };
+
+    Some(UnexecutableStepCheck::NarrowedColumnType {
+        table: previous_table.name.clone(),
+        column: columns.next.name().to_owned(),
+        identifying_columns: previous_table
+            .primary_key
+            .as_ref()
+            .map(|primary_key| primary_key.columns.clone())
+            .unwrap_or_default(),
+        incompatible_values,
+    })
+}
@@ -1,4 +1,4 @@
-use super::DestructiveChangeCheckerFlavour;
+use super::{narrowed_column_type_check, DestructiveChangeCheckerFlavour};
 use crate::{
     expanded_alter_column::{expand_mysql_alter_column, MysqlAlterColumn},
     flavour::MysqlFlavour,
@@ -10,6 +10,9 @@ use crate::{
 };
 use sql_schema_describer::Table;
 
+/// The length of the VARCHAR columns the connector creates for strings.
+const STRING_COLUMN_LENGTH: i64 = 191;
+
 impl DestructiveChangeCheckerFlavour for MysqlFlavour {
     fn check_alter_column(&self, previous_table: &Table, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan) {
         match expand_mysql_alter_column(columns) {
@@ -20,6 +23,11 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
 
             // Otherwise, case by case.
             MysqlAlterColumn::Modify { .. } => {
+                // The column is redefined with its new type, which may not hold all the existing values.
+                if let Some(check) = narrowed_column_type_check(previous_table, columns, Some(STRING_COLUMN_LENGTH)) {
+                    plan.push_unexecutable(check);
+                }
+
                 // Column went from optional to required. This is unexecutable unless the table is
                 // empty or the column has no existing NULLs.
                 if columns.all_changes().arity_changed() && columns.next.column.tpe.arity.is_required() {
@@ -1,4 +1,4 @@
-use super::DestructiveChangeCheckerFlavour;
+use super::{narrowed_column_type_check, DestructiveChangeCheckerFlavour};
 use crate::{
     expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
     flavour::PostgresFlavour,
@@ -33,6 +33,10 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
                                 column: columns.previous.name().to_owned(),
                             })
                         } else {
+                            if let Some(check) = narrowed_column_type_check(previous_table, columns, None) {
+                                plan.push_unexecutable(check);
+                            }
+
                             plan.push_warning(SqlMigrationWarningCheck::AlterColumn {
                                 table: previous_table.name.clone(),
                                 column: columns.previous.name().to_owned(),
@@ -72,6 +76,6 @@ fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
         Some(DefaultValue::VALUE(_)) => true,
         Some(DefaultValue::DBGENERATED(expr)) => !expr.is_empty(),
         Some(DefaultValue::NOW) => true,
-        Some(DefaultValue::SEQUENCE(_)) => false,
+        Some(DefaultValue::SEQUENCE(_)) => true,
     }
 }
@@ -1,15 +1,27 @@
 use super::{
-    check::Check, database_inspection_results::DatabaseInspectionResults,
-    unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
+    check::{Check, IncompatibleValues, IncompatibleValuesInspection},
+    database_inspection_results::{DatabaseInspectionResults, IncompatibleRows},
+    irreversible_step_check::IrreversibleStepCheck,
+    unexecutable_step_check::UnexecutableStepCheck,
+    warning_check::SqlMigrationWarningCheck,
 };
-use crate::{SqlError, SqlResult};
-use migration_connector::{DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration};
+use crate::{
+    sql_renderer::{IteratorJoin, Quoted, SqlRenderer},
+    SqlError, SqlFamily, SqlResult,
+};
+use migration_connector::{DestructiveChangeDiagnostics, IrreversibleStep, MigrationWarning, UnexecutableMigration};
 use quaint::prelude::Queryable;
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 use tokio::time::{timeout, Elapsed};
 
 const DESTRUCTIVE_TIMEOUT_DURATION: Duration = Duration::from_secs(60);
 
+/// The number of rows with incompatible values shown to the user.
+const INCOMPATIBLE_ROWS_SAMPLE_SIZE: usize = 3;
+
+/// Integers, as the databases parse them from strings.
+const INTEGER_PATTERN: &str = "^[[:space:]]*[+-]?[0-9]+[[:space:]]*$";
+
 /// A DestructiveCheckPlan is the collection of destructive change checks
 /// ([Check](trait.Check.html)) for a given migration. It has an `execute` method that performs
 /// database inspection and renders user-facing messages based on the checks.
@@ -17,6 +29,12 @@ const DESTRUCTIVE_TIMEOUT_DURATION: Duration = Duration::from_secs(60);
 pub(crate) struct DestructiveCheckPlan {
     warnings: Vec<SqlMigrationWarningCheck>,
     unexecutable_migrations: Vec<UnexecutableStepCheck>,
+    irreversible_steps: Vec<IrreversibleStepCheck>,
+    /// The current names of the tables renamed by the migration, by new name. The checks refer to
+    /// the new names, but the database is inspected before the renames.
+    renamed_tables: HashMap<String, String>,
+    /// The current names of the columns renamed by the migration, by new table and column name.
+    renamed_columns: HashMap<(String, String), String>,
 }
 
 impl DestructiveCheckPlan {
@@ -24,9 +42,21 @@ impl DestructiveCheckPlan {
         DestructiveCheckPlan {
             warnings: Vec::new(),
             unexecutable_migrations: Vec::new(),
+            irreversible_steps: Vec::new(),
+            renamed_tables: HashMap::new(),
+            renamed_columns: HashMap::new(),
         }
     }
 
+    pub(super) fn push_table_rename(&mut self, name: &str, new_name: &str) {
+        self.renamed_tables.insert(new_name.to_owned(), name.to_owned());
+    }
+
+    pub(super) fn push_column_rename(&mut self, table: &str, name: &str, new_name: &str) {
+        self.renamed_columns
+            .insert((table.to_owned(), new_name.to_owned()), name.to_owned());
+    }
+
     pub(super) fn push_warning(&mut self, warning: SqlMigrationWarningCheck) {
         self.warnings.push(warning)
     }
@@ -35,26 +65,37 @@ impl DestructiveCheckPlan {
         self.unexecutable_migrations.push(unexecutable_migration)
     }
 
+    pub(super) fn push_irreversible(&mut self, irreversible_step: IrreversibleStepCheck) {
+        self.irreversible_steps.push(irreversible_step)
+    }
+
     /// Inspect the current database state to qualify and render destructive change warnings and
     /// errors.
     ///
     /// For example, dropping a table that has 0 rows can be considered safe.
-    #[tracing::instrument(skip(conn, schema_name), level = "debug")]
+    #[tracing::instrument(skip(conn, schema_name, renderer), level = "debug")]
     pub(super) async fn execute(
         &mut self,
         schema_name: &str,
+        renderer: &(dyn SqlRenderer + Send + Sync),
         conn: &dyn Queryable,
     ) -> SqlResult<DestructiveChangeDiagnostics> {
         let mut results = DatabaseInspectionResults::default();
 
         let inspection = async {
             for unexecutable in &self.unexecutable_migrations {
-                self.inspect_for_check(unexecutable, &mut results, schema_name, conn)
+                self.inspect_for_check(unexecutable, &mut results, schema_name, renderer, conn)
                     .await?;
             }
 
             for warning in &self.warnings {
-                self.inspect_for_check(warning, &mut results, schema_name, conn).await?;
+                self.inspect_for_check(warning, &mut results, schema_name, renderer, conn)
+                    .await?;
+            }
+
+            for irreversible in &self.irreversible_steps {
+                self.inspect_for_check(irreversible, &mut results, schema_name, renderer, conn)
+                    .await?;
             }
 
             Ok::<(), SqlError>(())
@@ -82,6 +123,14 @@ impl DestructiveCheckPlan {
             }
         }
 
+        for irreversible in &self.irreversible_steps {
+            if let Some(message) = irreversible.evaluate(&results) {
+                diagnostics
+                    .irreversible_steps
+                    .push(IrreversibleStep { description: message })
+            }
+        }
+
         Ok(diagnostics)
     }
 
@@ -91,24 +140,146 @@ impl DestructiveCheckPlan {
         check: &(dyn Check + Send + Sync + 'static),
         results: &mut DatabaseInspectionResults,
         schema_name: &str,
+        renderer: &(dyn SqlRenderer + Send + Sync),
         conn: &dyn Queryable,
     ) -> SqlResult<()> {
         if let Some(table) = check.needed_table_row_count() {
             if results.get_row_count(table).is_none() {
-                let count = count_rows_in_table(table, schema_name, conn).await?;
+                let count = count_rows_in_table(self.current_table_name(table), schema_name, conn).await?;
                 results.set_row_count(table.to_owned(), count)
             }
         }
 
         if let Some((table, column)) = check.needed_column_value_count() {
             if let (_, None) = results.get_row_and_non_null_value_count(table, column) {
-                let count = count_values_in_column(column, table, schema_name, conn).await?;
+                let count = count_values_in_column(
+                    self.current_column_name(table, column),
+                    self.current_table_name(table),
+                    schema_name,
+                    conn,
+                )
+                .await?;
                 results.set_value_count(table.to_owned().into(), column.to_owned().into(), count);
             }
         }
 
+        if let Some(inspection) = check.needed_incompatible_values() {
+            if results
+                .get_incompatible_rows(inspection.table, inspection.column)
+                .is_none()
+            {
+                // When the rows can't be inspected, the check falls back to a generic warning.
+                if let Some(rows) = self
+                    .find_incompatible_rows(&inspection, schema_name, renderer, conn)
+                    .await?
+                {
+                    results.set_incompatible_rows(inspection.table.to_owned(), inspection.column.to_owned(), rows);
+                }
+            }
+        }
+
         Ok(())
     }
+
+    /// Count the rows with values the migration can't keep, and sample a few of them. Returns `None`
+    /// when the incompatible values can't be matched on the database.
+    async fn find_incompatible_rows(
+        &self,
+        inspection: &IncompatibleValuesInspection<'_>,
+        schema_name: &str,
+        renderer: &(dyn SqlRenderer + Send + Sync),
+        conn: &dyn Queryable,
+    ) -> SqlResult<Option<IncompatibleRows>> {
+        let table = renderer.quote_with_schema(schema_name, self.current_table_name(inspection.table));
+        let column = self.current_column_name(inspection.table, inspection.column);
+        let condition = match render_incompatible_values_condition(renderer, column, &inspection.values) {
+            Some(condition) => condition,
+            None => return Ok(None),
+        };
+
+        let count_query = format!("SELECT COUNT(*) FROM {} WHERE {}", table, condition);
+        let count = conn
+            .query_raw(&count_query, &[])
+            .await?
+            .first()
+            .and_then(|row| row.at(0).and_then(|count| count.as_i64()))
+            .ok_or_else(|| {
+                SqlError::Generic(anyhow::anyhow!(
+                    "No count was returned when checking for incompatible values in the `{}` column of the `{}` table.",
+                    inspection.column,
+                    inspection.table
+                ))
+            })?;
+
+        if count == 0 {
+            return Ok(Some(IncompatibleRows {
+                count,
+                samples: Vec::new(),
+            }));
+        }
+
+        // The sampled columns, by name in the migration and current name.
+        let sampled_columns: Vec<(&str, &str)> = inspection
+            .identifying_columns
+            .iter()
+            .map(String::as_str)
+            .filter(|identifying_column| *identifying_column != inspection.column)
+            .chain(std::iter::once(inspection.column))
+            .map(|sampled_column| {
+                (
+                    sampled_column,
+                    self.current_column_name(inspection.table, sampled_column),
+                )
+            })
+            .collect();
+
+        let sample_query = format!(
+            "SELECT {columns} FROM {table} WHERE {condition} LIMIT {limit}",
+            columns = sampled_columns
+                .iter()
+                .map(|(_, current_name)| render_text_cast(renderer, current_name))
+                .join(", "),
+            table = table,
+            condition = condition,
+            limit = INCOMPATIBLE_ROWS_SAMPLE_SIZE,
+        );
+
+        let samples = conn
+            .query_raw(&sample_query, &[])
+            .await?
+            .into_iter()
+            .map(|row| {
+                let values = sampled_columns
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, (name, _))| {
+                        let value = row
+                            .at(idx)
+                            .and_then(|value| value.as_str())
+                            .map(|value| format!("'{}'", value))
+                            .unwrap_or_else(|| "NULL".to_owned());
+
+                        format!("{}: {}", name, value)
+                    })
+                    .join(", ");
+
+                format!("({})", values)
+            })
+            .collect();
+
+        Ok(Some(IncompatibleRows { count, samples }))
+    }
+
+    fn current_table_name<'a>(&'a self, table: &'a str) -> &'a str {
+        self.renamed_tables.get(table).map(String::as_str).unwrap_or(table)
+    }
+
+    fn current_column_name<'a>(&'a self, table: &'a str, column: &'a str) -> &'a str {
+        self.renamed_columns
+            .get(&(table.to_owned(), column.to_owned()))
+            .map(String::as_str)
+            .unwrap_or(column)
+    }
 }
 
 async fn count_rows_in_table(table_name: &str, schema_name: &str, conn: &dyn Queryable) -> SqlResult<i64> {
@@ -167,3 +338,35 @@ async fn count_values_in_column(
 
     Ok(values_count)
 }
+
+/// Render the column, cast to a string.
+fn render_text_cast(renderer: &dyn SqlRenderer, column_name: &str) -> String {
+    match renderer.sql_family() {
+        SqlFamily::Mysql => format!("CAST({} AS CHAR)", renderer.quote(column_name)),
+        _ => format!("CAST({} AS TEXT)", renderer.quote(column_name)),
+    }
+}
+
+/// Render the condition matching the rows whose value in the column is one of the incompatible
+/// values, or `None` when the database can't match them. NULLs are never matched.
+fn render_incompatible_values_condition(
+    renderer: &dyn SqlRenderer,
+    column_name: &str,
+    values: &IncompatibleValues<'_>,
+) -> Option<String> {
+    let text = render_text_cast(renderer, column_name);
+
+    let condition = match (values, renderer.sql_family()) {
+        (IncompatibleValues::NotIntegers, SqlFamily::Postgres) => format!("{} !~ '{}'", text, INTEGER_PATTERN),
+        (IncompatibleValues::NotIntegers, SqlFamily::Mysql) => format!("{} NOT REGEXP '{}'", text, INTEGER_PATTERN),
+        (IncompatibleValues::NotIntegers, _) => return None,
+        (IncompatibleValues::LongerThan(length), _) => format!("CHAR_LENGTH({}) > {}", text, length),
+        (IncompatibleValues::EnumValues(values), _) => format!(
+            "{} IN ({})",
+            text,
+            values.iter().map(|value| Quoted::Single(value)).join(", ")
+        ),
+    };
+
+    Some(condition)
+}
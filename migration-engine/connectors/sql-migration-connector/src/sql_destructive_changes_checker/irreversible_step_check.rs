@@ -0,0 +1,42 @@
+use super::{check::Check, database_inspection_results::DatabaseInspectionResults};
+
+/// Steps the down migration can't revert completely: it recreates what they drop, but not the data
+/// that was in it.
+#[derive(Debug)]
+pub(super) enum IrreversibleStepCheck {
+    DroppedColumn { table: String, column: String },
+    DroppedTable { table: String },
+}
+
+impl Check for IrreversibleStepCheck {
+    fn needed_table_row_count(&self) -> Option<&str> {
+        match self {
+            IrreversibleStepCheck::DroppedTable { table } | IrreversibleStepCheck::DroppedColumn { table, .. } => {
+                Some(table)
+            }
+        }
+    }
+
+    fn needed_column_value_count(&self) -> Option<(&str, &str)> {
+        match self {
+            IrreversibleStepCheck::DroppedColumn { table, column } => Some((table, column)),
+            IrreversibleStepCheck::DroppedTable { .. } => None,
+        }
+    }
+
+    fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String> {
+        match self {
+            IrreversibleStepCheck::DroppedTable { table } => match database_check_results.get_row_count(table) {
+                Some(0) => None, // an empty table can be recreated as it was
+                Some(rows_count) => Some(format!("Dropping the `{table_name}` table can't be reverted: unapplying the migration recreates the table, but not its {rows_count} rows.", table_name = table, rows_count = rows_count)),
+                None => Some(format!("Dropping the `{}` table can't be reverted: unapplying the migration recreates the table, but not the data it contains.", table)),
+            },
+            IrreversibleStepCheck::DroppedColumn { table, column } => match database_check_results.get_row_and_non_null_value_count(table, column) {
+                (Some(0), _) => None, // a column of an empty table can be recreated as it was
+                (_, Some(0)) => None, // so can a column that only contains null values
+                (_, Some(value_count)) => Some(format!("Dropping the column `{column_name}` on the `{table_name}` table can't be reverted: unapplying the migration recreates the column, but not its {value_count} non-null values.", column_name = column, table_name = table, value_count = value_count)),
+                (_, _) => Some(format!("Dropping the column `{column_name}` on the `{table_name}` table can't be reverted: unapplying the migration recreates the column, but not the data it contains.", column_name = column, table_name = table)),
+            },
+        }
+    }
+}
@@ -1,20 +1,35 @@
-use super::{check::Check, database_inspection_results::DatabaseInspectionResults};
+use super::{
+    check::{Check, IncompatibleValues, IncompatibleValuesInspection},
+    database_inspection_results::DatabaseInspectionResults,
+};
 
 #[derive(Debug)]
 pub(crate) enum UnexecutableStepCheck {
     AddedRequiredFieldToTable { table: String, column: String },
     MadeOptionalFieldRequired { table: String, column: String },
     MadeScalarFieldIntoArrayField { table: String, column: String },
+    /// The type of the column changed to a type that can't hold all the values of the previous
+    /// type.
+    NarrowedColumnType {
+        table: String,
+        column: String,
+        /// The columns identifying the rows, to sample the incompatible ones.
+        identifying_columns: Vec<String>,
+        incompatible_values: IncompatibleValues<'static>,
+    },
+    DeletedUsedEnumValues {
+        r#enum: String,
+        values: Vec<String>,
+        table: String,
+        column: String,
+        /// The columns identifying the rows, to sample the incompatible ones.
+        identifying_columns: Vec<String>,
+    },
     // TODO:
     // AddedUnimplementableUniqueConstraint {
     //     table: String,
     //     constrained_columns: Vec<String>,
     // },
-    // DeletedUsedEnumValue {
-    //     r#enum: String,
-    //     value: String,
-    //     uses_count: Option<u64>,
-    // },
     // PrimaryKeyChanged {
     //     table: String,
     // },
@@ -26,6 +41,9 @@ impl Check for UnexecutableStepCheck {
             UnexecutableStepCheck::MadeOptionalFieldRequired { table, column: _ }
             | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { table, column: _ }
             | UnexecutableStepCheck::AddedRequiredFieldToTable { table, column: _ } => Some(table),
+            UnexecutableStepCheck::NarrowedColumnType { .. } | UnexecutableStepCheck::DeletedUsedEnumValues { .. } => {
+                None
+            }
         }
     }
 
@@ -33,7 +51,40 @@ impl Check for UnexecutableStepCheck {
         match self {
             UnexecutableStepCheck::MadeOptionalFieldRequired { table, column }
             | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { table, column } => Some((table, column)),
-            UnexecutableStepCheck::AddedRequiredFieldToTable { .. } => None,
+            UnexecutableStepCheck::AddedRequiredFieldToTable { .. }
+            | UnexecutableStepCheck::NarrowedColumnType { .. }
+            | UnexecutableStepCheck::DeletedUsedEnumValues { .. } => None,
+        }
+    }
+
+    fn needed_incompatible_values(&self) -> Option<IncompatibleValuesInspection<'_>> {
+        match self {
+            UnexecutableStepCheck::NarrowedColumnType {
+                table,
+                column,
+                identifying_columns,
+                incompatible_values,
+            } => Some(IncompatibleValuesInspection {
+                table,
+                column,
+                identifying_columns,
+                values: incompatible_values.clone(),
+            }),
+            UnexecutableStepCheck::DeletedUsedEnumValues {
+                values,
+                table,
+                column,
+                identifying_columns,
+                ..
+            } => Some(IncompatibleValuesInspection {
+                table,
+                column,
+                identifying_columns,
+                values: IncompatibleValues::EnumValues(values),
+            }),
+            UnexecutableStepCheck::AddedRequiredFieldToTable { .. }
+            | UnexecutableStepCheck::MadeOptionalFieldRequired { .. }
+            | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { .. } => None,
         }
     }
 
@@ -99,16 +150,79 @@ impl Check for UnexecutableStepCheck {
 
                 }
             }
+            UnexecutableStepCheck::NarrowedColumnType {
+                table,
+                column,
+                incompatible_values,
+                ..
+            } => {
+                let description = match incompatible_values {
+                    IncompatibleValues::NotIntegers => "values that are not integers".to_owned(),
+                    IncompatibleValues::LongerThan(length) => format!("values longer than {} characters", length),
+                    IncompatibleValues::EnumValues(_) => "values that are not in the enum".to_owned(),
+                };
+                let message = |details| {
+                    format!(
+                        "Changed the type of the column `{column}` on the `{table}` table. {details}",
+                        column = column,
+                        table = table,
+                        details = details
+                    )
+                };
+
+                match database_checks.get_incompatible_rows(table, column) {
+                    Some(rows) if rows.count == 0 => None,
+                    Some(rows) => Some(message(format_args!(
+                        "There are {count} rows with {description} in that column, this migration step cannot be executed. For example: {samples}.",
+                        count = rows.count,
+                        description = description,
+                        samples = rows.samples.join(", "),
+                    ))),
+                    None => Some(message(format_args!(
+                        "If there are {} in that column, this migration step will fail.", description
+                    ))),
+                }
+            }
+            UnexecutableStepCheck::DeletedUsedEnumValues {
+                r#enum,
+                values,
+                table,
+                column,
+                ..
+            } => {
+                let values = values
+                    .iter()
+                    .map(|value| format!("`{}`", value))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let message = |details| {
+                    format!(
+                        "You removed the value(s) {values} of the `{enum_name}` enum. {details}",
+                        values = values,
+                        enum_name = r#enum,
+                        details = details
+                    )
+                };
+
+                match database_checks.get_incompatible_rows(table, column) {
+                    Some(rows) if rows.count == 0 => None,
+                    Some(rows) => Some(message(format_args!(
+                        "There are {count} rows of the `{table}` table using them in the `{column}` column, this migration step cannot be executed. For example: {samples}.",
+                        count = rows.count,
+                        table = table,
+                        column = column,
+                        samples = rows.samples.join(", "),
+                    ))),
+                    None => Some(message(format_args!(
+                        "If rows of the `{table}` table use them in the `{column}` column, this migration step will fail.",
+                        table = table,
+                        column = column,
+                    ))),
+                }
+            }
             // TODO
             //
             // SqlUnexecutableMigration::AddedUnimplementableUniqueConstraint { table, constrained_columns } => write!(f, "Added a unique constraint that would not hold given existing data on `{table}`.{constrained_columns:?}", table = table, constrained_columns = constrained_columns)?,
-            // SqlUnexecutableMigration::DeletedUsedEnumValue {
-            //     r#enum,
-            //     value,
-            //     uses_count,
-            // } => {
-            //     write!(f, "You deleted the value `{value}` of the `{enum_name}` enum, but it is still used `{uses_count:?}` times in the database. (TODO: say which tables)", value = value, enum_name = r#enum, uses_count = uses_count)?
-            // }
             // SqlUnexecutableMigration::PrimaryKeyChanged { table } => write!(
             //     f,
             //     "The id field(s) for table {table} changed. This is currently not supported by prisma
@@ -7,13 +7,15 @@ pub(super) enum SqlMigrationWarningCheck {
     AlterColumn { table: String, column: String },
     ForeignKeyDefaultValueRemoved { table: String, column: String },
     PrimaryKeyChange { table: String },
+    LockingChange { table: String, change: String },
 }
 
 impl Check for SqlMigrationWarningCheck {
     fn needed_table_row_count(&self) -> Option<&str> {
         match self {
             SqlMigrationWarningCheck::NonEmptyTableDrop { table }
-            | SqlMigrationWarningCheck::PrimaryKeyChange { table } => Some(table),
+            | SqlMigrationWarningCheck::PrimaryKeyChange { table }
+            | SqlMigrationWarningCheck::LockingChange { table, .. } => Some(table),
             SqlMigrationWarningCheck::NonEmptyColumnDrop { .. }
             | SqlMigrationWarningCheck::AlterColumn { .. }
             | SqlMigrationWarningCheck::ForeignKeyDefaultValueRemoved { .. } => None,
@@ -26,7 +28,8 @@ impl Check for SqlMigrationWarningCheck {
             | SqlMigrationWarningCheck::AlterColumn { table, column } => Some((table, column)),
             SqlMigrationWarningCheck::ForeignKeyDefaultValueRemoved { .. }
             | SqlMigrationWarningCheck::NonEmptyTableDrop { .. }
-            | SqlMigrationWarningCheck::PrimaryKeyChange { .. } => None,
+            | SqlMigrationWarningCheck::PrimaryKeyChange { .. }
+            | SqlMigrationWarningCheck::LockingChange { .. } => None,
         }
     }
 
@@ -55,6 +58,11 @@ impl Check for SqlMigrationWarningCheck {
                 Some(0) => None,
                 _ => Some(format!("The migration will change the primary key for the `{table}` table. If it partially fails, the table could be left without primary key constraint.", table = table)),
             }
+            SqlMigrationWarningCheck::LockingChange { table, change } => match database_check_results.get_row_count(table) {
+                Some(0) => None, // locking an empty table is quick
+                Some(rows_count) => Some(format!("The migration will {change} on the `{table_name}` table ({rows_count} rows), which blocks writes to the table while it runs, even in online mode.", change = change, table_name = table, rows_count = rows_count)),
+                None => Some(format!("The migration will {change} on the `{table_name}` table, which blocks writes to the table while it runs, even in online mode.", change = change, table_name = table)),
+            },
         }
     }
 }
@@ -6,6 +6,7 @@ use sql_schema_describer::{Column, ForeignKey, Index, SqlSchema, Table};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SqlMigration {
+    /// The schema before the migration, with the renames of the migration already applied.
     pub before: SqlSchema,
     pub after: SqlSchema,
     pub original_steps: Vec<SqlMigrationStep>,
@@ -14,6 +15,18 @@ pub struct SqlMigration {
     /// by the database.
     pub corrected_steps: Vec<SqlMigrationStep>,
     pub rollback: Vec<SqlMigrationStep>,
+    /// The schemas the database is migrated to before the data migrations run, in order. Empty for
+    /// migrations without data migrations.
+    #[serde(default)]
+    pub checkpoints: Vec<SqlMigrationCheckpoint>,
+}
+
+/// The corrected steps before a data migration migrate the database to the schema of its checkpoint.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SqlMigrationCheckpoint {
+    /// The number of corrected steps up to and including the scripts of the data migration.
+    pub steps_count: usize,
+    pub schema: SqlSchema,
 }
 
 impl SqlMigration {
@@ -24,8 +37,31 @@ impl SqlMigration {
             original_steps: Vec::new(),
             corrected_steps: Vec::new(),
             rollback: Vec::new(),
+            checkpoints: Vec::new(),
         }
     }
+
+    /// The schemas the corrected step at `index` migrates the database from and to.
+    pub(crate) fn schemas_for_step(&self, index: usize) -> (&SqlSchema, &SqlSchema) {
+        let mut current_schema = &self.before;
+
+        for checkpoint in &self.checkpoints {
+            if index < checkpoint.steps_count {
+                return (current_schema, &checkpoint.schema);
+            }
+
+            current_schema = &checkpoint.schema;
+        }
+
+        (current_schema, &self.after)
+    }
+
+    pub(crate) fn has_data_migrations(&self) -> bool {
+        self.corrected_steps.iter().any(|step| match step {
+            SqlMigrationStep::DataMigration { .. } => true,
+            _ => false,
+        })
+    }
 }
 
 impl DatabaseMigrationMarker for SqlMigration {
@@ -41,14 +77,29 @@ pub enum SqlMigrationStep {
     AlterTable(AlterTable),
     DropForeignKey(DropForeignKey),
     DropTable(DropTable),
-    RenameTable { name: String, new_name: String },
-    RawSql { raw: String },
+    RenameTable {
+        name: String,
+        new_name: String,
+    },
+    RenameColumn {
+        table: String,
+        name: String,
+        new_name: String,
+    },
+    RawSql {
+        raw: String,
+    },
+    /// A user-supplied data migration script. It runs in a transaction of its own.
+    DataMigration {
+        script: String,
+    },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
+    CreateSequence(CreateSequence),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -129,6 +180,11 @@ pub struct CreateEnum {
     pub variants: Vec<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateSequence {
+    pub name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropEnum {
     pub name: String,
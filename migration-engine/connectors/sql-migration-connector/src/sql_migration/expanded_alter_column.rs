@@ -81,6 +81,11 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer<'_>) -> Option
                 (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
                     changes.push(PostgresAlterColumn::SetType(columns.next.column_type().clone()))
                 }
+                // Strings holding integers can be cast to ints. The destructive change checker
+                // checks that they do.
+                (ColumnTypeFamily::String, ColumnTypeFamily::Int) => {
+                    changes.push(PostgresAlterColumn::SetType(columns.next.column_type().clone()))
+                }
                 _ => return None,
             },
             ColumnChange::Renaming => unreachable!("column renaming"),
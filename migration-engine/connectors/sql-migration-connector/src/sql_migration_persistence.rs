@@ -1,4 +1,4 @@
-use crate::{Component, SqlError};
+use crate::{sql_renderer::SqlRenderer, Component, SqlError};
 use barrel::types;
 use chrono::*;
 use futures::TryFutureExt;
@@ -46,7 +46,24 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
 
             self.conn().raw_cmd(&sql_str).await.ok();
 
-            Ok(())
+            // Migration tables created before down migrations and data migrations were stored lack the columns for
+            // them.
+            let schema = self.describe().await?;
+            let missing_columns = schema
+                .get_table(MIGRATION_TABLE_NAME)
+                .map(|table| {
+                    [DOWN_DATABASE_MIGRATION_COLUMN, DATA_MIGRATIONS_COLUMN]
+                        .iter()
+                        .filter(|column| !table.has_column(column))
+                        .collect()
+                })
+                .unwrap_or_else(Vec::new);
+
+            for column in missing_columns {
+                self.conn().raw_cmd(&self.add_nullable_text_column_sql(column)).await?;
+            }
+
+            Ok::<(), SqlError>(())
         };
 
         crate::catch(self.connection_info(), fut).await
@@ -66,6 +83,42 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
         .await
     }
 
+    async fn lock(&self) -> Result<(), ConnectorError> {
+        let timeout = self.connector.migration_lock_timeout;
+        let started_at = std::time::Instant::now();
+
+        loop {
+            let acquired = crate::catch(
+                self.connection_info(),
+                self.flavour()
+                    .try_acquire_migration_lock(self.conn(), self.schema_name()),
+            )
+            .await?;
+
+            if acquired {
+                return Ok(());
+            }
+
+            if started_at.elapsed() >= timeout {
+                return Err(crate::MigrationLockTimeout {
+                    database_name: self.schema_name().to_owned(),
+                    timeout,
+                }
+                .into());
+            }
+
+            tokio::time::delay_for(crate::migration_lock::LOCK_POLLING_INTERVAL).await;
+        }
+    }
+
+    async fn unlock(&self) -> Result<(), ConnectorError> {
+        crate::catch(
+            self.connection_info(),
+            self.flavour().release_migration_lock(self.conn(), self.schema_name()),
+        )
+        .await
+    }
+
     async fn last_two_migrations(&self) -> ConnectorResult<(Option<Migration>, Option<Migration>)> {
         crate::catch(
             self.connection_info(),
@@ -99,20 +152,8 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
 
     async fn create(&self, migration: Migration) -> Result<Migration, ConnectorError> {
         let mut cloned = migration.clone();
-        let model_steps_json = serde_json::to_string(&migration.datamodel_steps).unwrap();
-        let database_migration_json = serde_json::to_string(&migration.database_migration).unwrap();
-        let errors_json = serde_json::to_string(&migration.errors).unwrap();
-
-        let insert = Insert::single_into(self.table())
-            .value(DATAMODEL_COLUMN, migration.datamodel_string)
-            .value(NAME_COLUMN, migration.name)
-            .value(STATUS_COLUMN, migration.status.code())
-            .value(APPLIED_COLUMN, migration.applied)
-            .value(ROLLED_BACK_COLUMN, migration.rolled_back)
-            .value(DATAMODEL_STEPS_COLUMN, model_steps_json)
-            .value(DATABASE_MIGRATION_COLUMN, database_migration_json)
-            .value(ERRORS_COLUMN, errors_json)
-            .value(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
+        let insert = self
+            .migration_insert(&migration)
             .value(FINISHED_AT_COLUMN, Option::<DateTime<Utc>>::None);
 
         match self.sql_family() {
@@ -136,6 +177,57 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
         Ok(cloned)
     }
 
+    async fn squash(&self, squashed_migrations: &[Migration], migration: Migration) -> ConnectorResult<Migration> {
+        crate::catch(self.connection_info(), async {
+            let first_revision = squashed_migrations.iter().map(|migration| migration.revision).min();
+            let last_revision = squashed_migrations.iter().map(|migration| migration.revision).max();
+
+            let (first_revision, last_revision) = match (first_revision, last_revision) {
+                (Some(first_revision), Some(last_revision)) => (first_revision, last_revision),
+                _ => return Ok(migration),
+            };
+
+            let mut migration = migration;
+            migration.revision = first_revision;
+
+            let delete = Delete::from_table(self.table()).so_that(
+                REVISION_COLUMN
+                    .greater_than_or_equals(first_revision)
+                    .and(REVISION_COLUMN.less_than_or_equals(last_revision)),
+            );
+            let finished_at_value = match migration.finished_at {
+                Some(finished_at) => self.convert_datetime(finished_at),
+                None => Value::from(Option::<DateTime<Utc>>::None),
+            };
+            let insert = self
+                .migration_insert(&migration)
+                .value(REVISION_COLUMN, first_revision)
+                .value(FINISHED_AT_COLUMN, finished_at_value);
+
+            self.conn().raw_cmd("BEGIN").await?;
+
+            let result = async {
+                self.conn().query(delete.into()).await?;
+                self.conn().query(insert.into()).await?;
+
+                Ok::<(), QuaintError>(())
+            }
+            .await;
+
+            match result {
+                Ok(()) => self.conn().raw_cmd("COMMIT").await?,
+                Err(err) => {
+                    self.conn().raw_cmd("ROLLBACK").await.ok();
+
+                    return Err(err.into());
+                }
+            }
+
+            Ok(migration)
+        })
+        .await
+    }
+
     async fn update(&self, params: &MigrationUpdateParams) -> Result<(), ConnectorError> {
         crate::catch(self.connection_info(), async {
             let finished_at_value = match params.finished_at {
@@ -207,6 +299,11 @@ fn migration_table_setup(
     t.add_column(ROLLED_BACK_COLUMN, types::integer());
     t.add_column(DATAMODEL_STEPS_COLUMN, unlimited_text_type.clone());
     t.add_column(DATABASE_MIGRATION_COLUMN, unlimited_text_type.clone());
+    t.add_column(
+        DOWN_DATABASE_MIGRATION_COLUMN,
+        unlimited_text_type.clone().nullable(true),
+    );
+    t.add_column(DATA_MIGRATIONS_COLUMN, unlimited_text_type.clone().nullable(true));
     t.add_column(ERRORS_COLUMN, unlimited_text_type.clone());
     t.add_column(STARTED_AT_COLUMN, datetime_type.clone());
     t.add_column(FINISHED_AT_COLUMN, datetime_type.clone().nullable(true));
@@ -223,6 +320,46 @@ impl<'a> SqlMigrationPersistence<'a> {
         }
     }
 
+    /// The insert of a migration record, without its `finished_at` timestamp.
+    fn migration_insert(&self, migration: &Migration) -> SingleRowInsert<'_> {
+        let model_steps_json = serde_json::to_string(&migration.datamodel_steps).unwrap();
+        let database_migration_json = serde_json::to_string(&migration.database_migration).unwrap();
+        let down_database_migration_json = migration
+            .down_database_migration
+            .as_ref()
+            .map(|down_database_migration| serde_json::to_string(down_database_migration).unwrap());
+        let data_migrations_json = serde_json::to_string(&migration.data_migrations).unwrap();
+        let errors_json = serde_json::to_string(&migration.errors).unwrap();
+
+        Insert::single_into(self.table())
+            .value(DATAMODEL_COLUMN, migration.datamodel_string.clone())
+            .value(NAME_COLUMN, migration.name.clone())
+            .value(STATUS_COLUMN, migration.status.code())
+            .value(APPLIED_COLUMN, migration.applied)
+            .value(ROLLED_BACK_COLUMN, migration.rolled_back)
+            .value(DATAMODEL_STEPS_COLUMN, model_steps_json)
+            .value(DATABASE_MIGRATION_COLUMN, database_migration_json)
+            .value(DOWN_DATABASE_MIGRATION_COLUMN, down_database_migration_json)
+            .value(DATA_MIGRATIONS_COLUMN, data_migrations_json)
+            .value(ERRORS_COLUMN, errors_json)
+            .value(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
+    }
+
+    fn add_nullable_text_column_sql(&self, column: &str) -> String {
+        let unlimited_text_type = match self.sql_family() {
+            SqlFamily::Mysql => "LONGTEXT",
+            _ => "TEXT",
+        };
+        let renderer = SqlRenderer::for_family(&self.sql_family());
+
+        format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            renderer.quote_with_schema(self.schema_name(), MIGRATION_TABLE_NAME),
+            renderer.quote(column),
+            unlimited_text_type
+        )
+    }
+
     fn convert_datetime(&self, datetime: DateTime<Utc>) -> Value<'_> {
         match self.sql_family() {
             SqlFamily::Sqlite => Value::integer(datetime.timestamp_millis()),
@@ -258,6 +395,11 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
             let datamodel_steps_json: String = row[DATAMODEL_STEPS_COLUMN].to_string().unwrap();
 
             let database_migration_string: String = row[DATABASE_MIGRATION_COLUMN].to_string().unwrap();
+            let down_database_migration_string: Option<String> = row
+                .get(DOWN_DATABASE_MIGRATION_COLUMN)
+                .and_then(|value| value.to_string());
+            let data_migrations_string: Option<String> =
+                row.get(DATA_MIGRATIONS_COLUMN).and_then(|value| value.to_string());
             let errors_json: String = row[ERRORS_COLUMN].to_string().unwrap();
 
             let finished_at = match &row[FINISHED_AT_COLUMN] {
@@ -270,6 +412,15 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
 
             let database_migration_json =
                 serde_json::from_str(&database_migration_string).expect("Error parsing the database migration steps");
+            let down_database_migration_json = down_database_migration_string.map(|down_database_migration| {
+                serde_json::from_str(&down_database_migration).expect("Error parsing the down database migration steps")
+            });
+            // Migrations persisted before data migrations were stored have none.
+            let data_migrations = data_migrations_string
+                .map(|data_migrations| {
+                    serde_json::from_str(&data_migrations).expect("Error parsing the data migrations")
+                })
+                .unwrap_or_else(Vec::new);
             let errors: Vec<String> = serde_json::from_str(&errors_json).unwrap();
 
             Migration {
@@ -281,6 +432,8 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
                 rolled_back: row[ROLLED_BACK_COLUMN].as_i64().unwrap() as usize,
                 datamodel_steps,
                 database_migration: database_migration_json,
+                down_database_migration: down_database_migration_json,
+                data_migrations,
                 errors,
                 started_at: convert_parameterized_date_value(&row[STARTED_AT_COLUMN]),
                 finished_at,
@@ -298,6 +451,8 @@ static APPLIED_COLUMN: &str = "applied";
 static ROLLED_BACK_COLUMN: &str = "rolled_back";
 static DATAMODEL_STEPS_COLUMN: &str = "datamodel_steps";
 static DATABASE_MIGRATION_COLUMN: &str = "database_migration";
+static DOWN_DATABASE_MIGRATION_COLUMN: &str = "down_database_migration";
+static DATA_MIGRATIONS_COLUMN: &str = "data_migrations";
 static ERRORS_COLUMN: &str = "errors";
 static STARTED_AT_COLUMN: &str = "started_at";
 static FINISHED_AT_COLUMN: &str = "finished_at";
@@ -99,6 +99,7 @@ pub(crate) fn render_column_type(column: &ColumnRef<'_>) -> anyhow::Result<Cow<'
             Ok(format!("ENUM({})", variants).into())
         }
         ColumnTypeFamily::Json => Ok("json".into()),
+        ColumnTypeFamily::Unsupported(native_type) => Ok(native_type.clone().into()),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
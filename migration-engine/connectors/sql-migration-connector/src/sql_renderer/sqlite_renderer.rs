@@ -81,6 +81,7 @@ impl SqliteRenderer {
             ColumnTypeFamily::Float => format!("REAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
             ColumnTypeFamily::String => format!("TEXT"),
+            ColumnTypeFamily::Unsupported(native_type) => native_type.clone(),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
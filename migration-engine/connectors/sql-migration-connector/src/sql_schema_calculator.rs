@@ -47,7 +47,7 @@ impl<'a> SqlSchemaCalculator<'a> {
         }
 
         let enums = self.calculate_enums();
-        let sequences = Vec::new();
+        let sequences = self.calculate_sequences();
 
         Ok(sql::SqlSchema {
             tables,
@@ -93,6 +93,33 @@ impl<'a> SqlSchemaCalculator<'a> {
         }
     }
 
+    fn calculate_sequences(&self) -> Vec<sql::Sequence> {
+        match self.database_info.sql_family() {
+            SqlFamily::Postgres => {
+                let mut sequences: Vec<sql::Sequence> = Vec::new();
+
+                for field in datamodel_helpers::walk_fields(&self.data_model) {
+                    let name = match field.default_value() {
+                        Some(DefaultValue::Expression(generator)) => generator.sequence_name(),
+                        _ => None,
+                    };
+
+                    // Several fields can share a sequence.
+                    if let Some(name) = name.filter(|name| !sequences.iter().any(|sequence| sequence.name == *name)) {
+                        sequences.push(sql::Sequence {
+                            name: name.to_owned(),
+                            initial_value: 1,
+                            allocation_size: 1,
+                        });
+                    }
+                }
+
+                sequences
+            }
+            _ => Vec::new(),
+        }
+    }
+
     fn calculate_model_tables<'iter>(
         &'iter self,
     ) -> impl Iterator<Item = SqlResult<(ModelRef<'a>, sql::Table)>> + 'iter {
@@ -115,6 +142,18 @@ impl<'a> SqlSchemaCalculator<'a> {
                             auto_increment: false,
                         })
                     }
+                    TypeRef::CompositeType => Some(sql::Column {
+                        name: f.db_name().to_owned(),
+                        tpe: composite_type_column_type(&f),
+                        default: None,
+                        auto_increment: false,
+                    }),
+                    TypeRef::Unsupported(native_type) => Some(sql::Column {
+                        name: f.db_name().to_owned(),
+                        tpe: unsupported_column_type(&f, native_type),
+                        default: migration_value_new(&f),
+                        auto_increment: false,
+                    }),
                     _ => None,
                 })
                 .collect();
@@ -326,7 +365,14 @@ fn migration_value_new(field: &FieldRef<'_>) -> Option<sql_schema_describer::Def
         dml::DefaultValue::Expression(expression) if expression.name == "dbgenerated" && expression.args.is_empty() => {
             return Some(sql_schema_describer::DefaultValue::DBGENERATED(String::new()))
         }
-        dml::DefaultValue::Expression(_) => return None,
+        dml::DefaultValue::Expression(expression) => {
+            return expression.sequence_name().map(|sequence_name| {
+                sql_schema_describer::DefaultValue::SEQUENCE(format!(
+                    "nextval('\"{}\"'::regclass)",
+                    sequence_name.replace('"', "\"\"").replace('\'', "''")
+                ))
+            })
+        }
     };
 
     Some(sql_schema_describer::DefaultValue::VALUE(value.clone()))
@@ -348,6 +394,27 @@ fn column_type(field: &FieldRef<'_>) -> sql::ColumnType {
     column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
 }
 
+/// Lists of composite types are stored as a JSON array in a nullable column.
+fn composite_type_column_type(field: &FieldRef<'_>) -> sql::ColumnType {
+    let arity = match field.arity() {
+        FieldArity::List => sql::ColumnArity::Nullable,
+        arity => column_arity(arity),
+    };
+
+    sql::ColumnType::pure(sql::ColumnTypeFamily::Json, arity)
+}
+
+/// Columns of unsupported types keep the native type from the datamodel.
+fn unsupported_column_type(field: &FieldRef<'_>, native_type: &str) -> sql::ColumnType {
+    sql::ColumnType {
+        data_type: native_type.to_owned(),
+        full_data_type: native_type.to_owned(),
+        character_maximum_length: None,
+        family: sql::ColumnTypeFamily::Unsupported(native_type.to_owned()),
+        arity: column_arity(field.arity()),
+    }
+}
+
 fn scalar_type_for_field(field: &FieldRef<'_>) -> ScalarType {
     match field.field_type() {
         TypeRef::Base(ref scalar) => *scalar,
@@ -1,9 +1,11 @@
 mod column;
 mod enums;
 mod index;
+mod renames;
 mod table;
 
 pub(crate) use column::{ColumnChange, ColumnChanges, ColumnDiffer};
+pub(crate) use renames::SqlRenames;
 pub(crate) use table::TableDiffer;
 
 use crate::*;
@@ -63,13 +65,15 @@ pub struct SqlSchemaDiff {
     pub create_enums: Vec<CreateEnum>,
     pub drop_enums: Vec<DropEnum>,
     pub alter_enums: Vec<AlterEnum>,
+    pub create_sequences: Vec<CreateSequence>,
 }
 
 impl SqlSchemaDiff {
     /// Translate the diff into steps that should be executed in order. The general idea in the
     /// ordering of steps is to drop obsolete constraints first, alter/create tables, then add the new constraints.
     pub fn into_steps(self) -> Vec<SqlMigrationStep> {
-        wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum)
+        wrap_as_step(self.create_sequences, SqlMigrationStep::CreateSequence)
+            .chain(wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum))
             .chain(wrap_as_step(self.alter_enums, SqlMigrationStep::AlterEnum))
             .chain(wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex))
             .chain(wrap_as_step(self.drop_foreign_keys, SqlMigrationStep::DropForeignKey))
@@ -121,6 +125,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             create_enums: self.create_enums(),
             drop_enums: self.drop_enums(),
             alter_enums: self.alter_enums(),
+            create_sequences: self.create_sequences(),
         }
     }
 
@@ -318,6 +323,19 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .collect()
     }
 
+    /// Sequences are never dropped: the datamodel only knows the sequences used in
+    /// `sequence()` defaults, not the ones backing autoincrementing columns.
+    fn create_sequences(&self) -> Vec<CreateSequence> {
+        self.next
+            .sequences
+            .iter()
+            .filter(|next| self.previous.get_sequence(&next.name).is_none())
+            .map(|sequence| CreateSequence {
+                name: sequence.name.clone(),
+            })
+            .collect()
+    }
+
     fn drop_enums(&self) -> Vec<DropEnum> {
         self.dropped_enums()
             .map(|r#enum| DropEnum {
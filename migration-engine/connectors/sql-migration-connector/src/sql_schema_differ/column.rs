@@ -1,5 +1,5 @@
 use crate::sql_schema_helpers::ColumnRef;
-use sql_schema_describer::{ColumnTypeFamily, DefaultValue};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -60,6 +60,14 @@ impl<'a> ColumnDiffer<'a> {
             return false;
         }
 
+        // Columns of unsupported types are compared by their native types, as the describers
+        // sort some of them into families the datamodel doesn't know about.
+        if let (ColumnTypeFamily::Unsupported(_), _) | (_, ColumnTypeFamily::Unsupported(_)) =
+            (self.previous.column_type_family(), self.next.column_type_family())
+        {
+            return !native_type(&self.previous).eq_ignore_ascii_case(native_type(&self.next));
+        }
+
         self.previous.column_type_family() != self.next.column_type_family()
     }
 
@@ -77,27 +85,31 @@ impl<'a> ColumnDiffer<'a> {
 
         match (&self.previous.default(), &self.next.default()) {
             (Some(DefaultValue::VALUE(prev)), Some(DefaultValue::VALUE(next))) => prev == next,
-            (Some(DefaultValue::VALUE(_)), Some(DefaultValue::SEQUENCE(_))) => true,
+            (Some(DefaultValue::VALUE(_)), Some(DefaultValue::SEQUENCE(_))) => false,
             (Some(DefaultValue::VALUE(_)), Some(DefaultValue::NOW)) => false,
             (Some(DefaultValue::VALUE(_)), None) => false,
 
             (Some(DefaultValue::NOW), Some(DefaultValue::NOW)) => true,
-            (Some(DefaultValue::NOW), Some(DefaultValue::SEQUENCE(_))) => true,
+            (Some(DefaultValue::NOW), Some(DefaultValue::SEQUENCE(_))) => false,
             (Some(DefaultValue::NOW), None) => false,
             (Some(DefaultValue::NOW), Some(DefaultValue::VALUE(_))) => false,
 
-            (Some(DefaultValue::DBGENERATED(_)), Some(DefaultValue::SEQUENCE(_))) => true,
+            (Some(DefaultValue::DBGENERATED(prev)), Some(DefaultValue::SEQUENCE(next))) => {
+                sequence_defaults_match(prev, next)
+            }
             (Some(DefaultValue::DBGENERATED(_)), Some(DefaultValue::VALUE(_))) => false,
             (Some(DefaultValue::DBGENERATED(_)), Some(DefaultValue::NOW)) => false,
             (Some(DefaultValue::DBGENERATED(_)), None) => false,
 
-            (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::SEQUENCE(_))) => true,
+            (Some(DefaultValue::SEQUENCE(prev)), Some(DefaultValue::SEQUENCE(next))) => {
+                sequence_defaults_match(prev, next)
+            }
             (Some(DefaultValue::SEQUENCE(_)), None) => false,
             (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::VALUE(_))) => false,
             (Some(DefaultValue::SEQUENCE(_)), Some(DefaultValue::NOW)) => false,
 
             (None, None) => true,
-            (None, Some(DefaultValue::SEQUENCE(_))) => true,
+            (None, Some(DefaultValue::SEQUENCE(_))) => false,
             (None, Some(DefaultValue::VALUE(_))) => false,
             (None, Some(DefaultValue::NOW)) => false,
 
@@ -107,6 +119,22 @@ impl<'a> ColumnDiffer<'a> {
     }
 }
 
+/// Whether two `nextval()` defaults use the same sequence. Postgres only quotes sequence names in
+/// `nextval()` defaults when it has to.
+fn sequence_defaults_match(previous: &str, next: &str) -> bool {
+    previous.replace('"', "") == next.replace('"', "")
+}
+
+/// The native type of a column. Postgres prefixes the element types of arrays with an underscore.
+fn native_type<'a>(column: &ColumnRef<'a>) -> &'a str {
+    let full_data_type = column.column_type().full_data_type.as_str();
+
+    match column.arity() {
+        ColumnArity::List => full_data_type.trim_start_matches('_'),
+        _ => full_data_type,
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ColumnChange {
     Renaming,
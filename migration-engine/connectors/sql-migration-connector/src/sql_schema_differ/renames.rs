@@ -0,0 +1,262 @@
+use crate::SqlMigrationStep;
+use datamodel::{Datamodel, WithDatabaseName};
+use migration_connector::{previous_model_name, Rename};
+use sql_schema_describer::SqlSchema;
+
+/// The renames of a migration, resolved to table and column names.
+///
+/// Renames are migrated before anything else. The rest of the migration is inferred from the
+/// previous schema with the renames already applied, so all the following steps refer to the
+/// renamed tables and columns by their new names.
+#[derive(Debug, Default)]
+pub(crate) struct SqlRenames {
+    tables: Vec<TableRename>,
+    columns: Vec<ColumnRename>,
+}
+
+#[derive(Debug)]
+struct TableRename {
+    previous: String,
+    next: String,
+}
+
+#[derive(Debug)]
+struct ColumnRename {
+    /// The new name of the table.
+    table: String,
+    previous: String,
+    next: String,
+}
+
+impl SqlRenames {
+    /// Resolves the model and field renames to the database names in the previous and next
+    /// datamodels. Renames that don't change a database name, or that don't match the tables and
+    /// columns of the schemas, are left out.
+    pub(crate) fn resolve(
+        renames: &[Rename],
+        previous: &Datamodel,
+        next: &Datamodel,
+        from: &SqlSchema,
+        to: &SqlSchema,
+    ) -> Self {
+        let mut sql_renames = SqlRenames::default();
+
+        for rename in renames {
+            match rename {
+                Rename::Model {
+                    previous_name,
+                    next_name,
+                } => {
+                    let (previous_model, next_model) =
+                        match (previous.find_model(previous_name), next.find_model(next_name)) {
+                            (Some(previous_model), Some(next_model)) => (previous_model, next_model),
+                            _ => continue,
+                        };
+
+                    let previous_table = previous_model.final_database_name();
+                    let next_table = next_model.final_database_name();
+
+                    if previous_table != next_table
+                        && from.has_table(previous_table)
+                        && !from.has_table(next_table)
+                        && to.has_table(next_table)
+                        && !to.has_table(previous_table)
+                    {
+                        sql_renames.tables.push(TableRename {
+                            previous: previous_table.to_owned(),
+                            next: next_table.to_owned(),
+                        });
+                    }
+                }
+                Rename::Field {
+                    model,
+                    previous_name,
+                    next_name,
+                } => {
+                    let previous_model = previous.find_model(previous_model_name(model, renames));
+                    let next_model = next.find_model(model);
+
+                    let (previous_model, next_model) = match (previous_model, next_model) {
+                        (Some(previous_model), Some(next_model)) => (previous_model, next_model),
+                        _ => continue,
+                    };
+
+                    let (previous_field, next_field) = match (
+                        previous_model.find_field(previous_name),
+                        next_model.find_field(next_name),
+                    ) {
+                        (Some(previous_field), Some(next_field)) => (previous_field, next_field),
+                        _ => continue,
+                    };
+
+                    let previous_column = previous_field.final_database_name();
+                    let next_column = next_field.final_database_name();
+                    let previous_table = from.get_table(previous_model.final_database_name());
+                    let next_table = to.get_table(next_model.final_database_name());
+
+                    match (previous_table, next_table) {
+                        (Some(previous_table), Some(next_table))
+                            if previous_column != next_column
+                                && previous_table.has_column(previous_column)
+                                && !previous_table.has_column(next_column)
+                                && next_table.has_column(next_column)
+                                && !next_table.has_column(previous_column) =>
+                        {
+                            sql_renames.columns.push(ColumnRename {
+                                table: next_table.name.clone(),
+                                previous: previous_column.to_owned(),
+                                next: next_column.to_owned(),
+                            })
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        sql_renames
+    }
+
+    /// The renames migrated by the rename steps at the start of a migration.
+    pub(crate) fn from_steps(steps: &[SqlMigrationStep]) -> Self {
+        let mut sql_renames = SqlRenames::default();
+
+        for step in steps {
+            match step {
+                SqlMigrationStep::RenameTable { name, new_name } => sql_renames.tables.push(TableRename {
+                    previous: name.clone(),
+                    next: new_name.clone(),
+                }),
+                SqlMigrationStep::RenameColumn { table, name, new_name } => sql_renames.columns.push(ColumnRename {
+                    table: table.clone(),
+                    previous: name.clone(),
+                    next: new_name.clone(),
+                }),
+                _ => break,
+            }
+        }
+
+        sql_renames
+    }
+
+    /// The renames migrating back to the previous names.
+    pub(crate) fn inverse(&self) -> Self {
+        let tables = self
+            .tables
+            .iter()
+            .map(|rename| TableRename {
+                previous: rename.next.clone(),
+                next: rename.previous.clone(),
+            })
+            .collect();
+
+        let columns = self
+            .columns
+            .iter()
+            .map(|rename| {
+                let table = self
+                    .tables
+                    .iter()
+                    .find(|table_rename| table_rename.next == rename.table)
+                    .map(|table_rename| table_rename.previous.clone())
+                    .unwrap_or_else(|| rename.table.clone());
+
+                ColumnRename {
+                    table,
+                    previous: rename.next.clone(),
+                    next: rename.previous.clone(),
+                }
+            })
+            .collect();
+
+        SqlRenames { tables, columns }
+    }
+
+    /// The schema with the renames applied, including in the indexes, primary keys and foreign
+    /// keys referring to the renamed tables and columns.
+    pub(crate) fn apply(&self, schema: &SqlSchema) -> SqlSchema {
+        let mut schema = schema.clone();
+
+        for rename in &self.tables {
+            for table in schema.tables.iter_mut() {
+                if table.name == rename.previous {
+                    table.name = rename.next.clone();
+                }
+
+                for foreign_key in table.foreign_keys.iter_mut() {
+                    if foreign_key.referenced_table == rename.previous {
+                        foreign_key.referenced_table = rename.next.clone();
+                    }
+                }
+            }
+        }
+
+        for rename in &self.columns {
+            for table in schema.tables.iter_mut() {
+                if table.name == rename.table {
+                    for column in table.columns.iter_mut().filter(|column| column.name == rename.previous) {
+                        column.name = rename.next.clone();
+                    }
+
+                    for index in table.indices.iter_mut() {
+                        rename_in(&mut index.columns, rename);
+                    }
+
+                    if let Some(primary_key) = table.primary_key.as_mut() {
+                        rename_in(&mut primary_key.columns, rename);
+                    }
+
+                    for foreign_key in table.foreign_keys.iter_mut() {
+                        rename_in(&mut foreign_key.columns, rename);
+                    }
+                }
+
+                for foreign_key in table.foreign_keys.iter_mut() {
+                    if foreign_key.referenced_table == rename.table {
+                        rename_in(&mut foreign_key.referenced_columns, rename);
+                    }
+                }
+            }
+        }
+
+        schema
+    }
+
+    /// The steps renaming the tables, then the columns.
+    pub(crate) fn steps(&self) -> impl Iterator<Item = SqlMigrationStep> + '_ {
+        let tables = self.tables.iter().map(|rename| SqlMigrationStep::RenameTable {
+            name: rename.previous.clone(),
+            new_name: rename.next.clone(),
+        });
+
+        let columns = self.columns.iter().map(|rename| SqlMigrationStep::RenameColumn {
+            table: rename.table.clone(),
+            name: rename.previous.clone(),
+            new_name: rename.next.clone(),
+        });
+
+        tables.chain(columns)
+    }
+
+    /// The steps undoing the renames: the columns are renamed back before the tables.
+    pub(crate) fn inverse_steps(&self) -> impl Iterator<Item = SqlMigrationStep> + '_ {
+        let columns = self.columns.iter().map(|rename| SqlMigrationStep::RenameColumn {
+            table: rename.table.clone(),
+            name: rename.next.clone(),
+            new_name: rename.previous.clone(),
+        });
+
+        let tables = self.tables.iter().map(|rename| SqlMigrationStep::RenameTable {
+            name: rename.next.clone(),
+            new_name: rename.previous.clone(),
+        });
+
+        columns.chain(tables)
+    }
+}
+
+fn rename_in(columns: &mut [String], rename: &ColumnRename) {
+    for column in columns.iter_mut().filter(|column| **column == rename.previous) {
+        *column = rename.next.clone();
+    }
+}
@@ -44,15 +44,18 @@ where
 #[async_trait::async_trait]
 pub trait GenericApi: Send + Sync + 'static {
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput>;
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput>;
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
     ) -> CoreResult<MigrationStepsResultOutput>;
     async fn calculate_datamodel(&self, input: &CalculateDatamodelInput) -> CoreResult<CalculateDatamodelOutput>;
+    async fn check_drift(&self, input: &serde_json::Value) -> CoreResult<CheckDriftOutput>;
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>>;
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput>;
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a>;
     fn connector_type(&self) -> &'static str;
@@ -81,6 +84,15 @@ where
             .await
     }
 
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput> {
+        self.handle_command::<BaselineCommand<'_>>(input)
+            .instrument(tracing::info_span!(
+                "Baseline",
+                migration_id = input.migration_id.as_str()
+            ))
+            .await
+    }
+
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
@@ -96,6 +108,12 @@ where
             .await
     }
 
+    async fn check_drift(&self, input: &serde_json::Value) -> CoreResult<CheckDriftOutput> {
+        self.handle_command::<CheckDriftCommand>(input)
+            .instrument(tracing::info_span!("CheckDrift"))
+            .await
+    }
+
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput> {
         self.handle_command::<InferMigrationStepsCommand<'_>>(input)
             .instrument(tracing::info_span!(
@@ -126,6 +144,16 @@ where
             .await
     }
 
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_command::<SquashMigrationsCommand<'_>>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                from_migration_id = input.from_migration_id.as_str(),
+                to_migration_id = input.to_migration_id.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
         self.handle_command::<UnapplyMigrationCommand<'_>>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
@@ -21,6 +21,9 @@ enum RpcCommand {
     Reset,
     CalculateDatamodel,
     CalculateDatabaseSteps,
+    Baseline,
+    SquashMigrations,
+    CheckDrift,
 }
 
 impl RpcCommand {
@@ -34,6 +37,9 @@ impl RpcCommand {
             RpcCommand::Reset => "reset",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::Baseline => "baseline",
+            RpcCommand::SquashMigrations => "squashMigrations",
+            RpcCommand::CheckDrift => "checkDrift",
         }
     }
 }
@@ -47,6 +53,9 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::Reset,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::Baseline,
+    RpcCommand::SquashMigrations,
+    RpcCommand::CheckDrift,
 ];
 
 impl RpcApi {
@@ -146,6 +155,15 @@ impl RpcApi {
                 let input: CalculateDatabaseStepsInput = params.clone().parse()?;
                 render(executor.calculate_database_steps(&input).await?)
             }
+            RpcCommand::Baseline => {
+                let input: BaselineInput = params.clone().parse()?;
+                render(executor.baseline(&input).await?)
+            }
+            RpcCommand::SquashMigrations => {
+                let input: SquashMigrationsInput = params.clone().parse()?;
+                render(executor.squash_migrations(&input).await?)
+            }
+            RpcCommand::CheckDrift => render(executor.check_drift(&serde_json::Value::Null).await?),
         }
     }
 }
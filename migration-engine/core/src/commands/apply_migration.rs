@@ -25,12 +25,15 @@ impl<'a> MigrationCommand for ApplyMigrationCommand<'a> {
         let connector = engine.connector();
         let migration_persistence = connector.migration_persistence();
 
-        match migration_persistence.last().await? {
-            Some(ref last_migration) if last_migration.is_watch_migration() && !cmd.input.is_watch_migration() => {
-                cmd.handle_transition_out_of_watch_mode(&engine).await
+        super::with_migration_lock(migration_persistence.as_ref(), async {
+            match migration_persistence.last().await? {
+                Some(ref last_migration) if last_migration.is_watch_migration() && !cmd.input.is_watch_migration() => {
+                    cmd.handle_transition_out_of_watch_mode(&engine).await
+                }
+                _ => cmd.handle_normal_migration(&engine).await,
             }
-            _ => cmd.handle_normal_migration(&engine).await,
-        }
+        })
+        .await
     }
 }
 
@@ -62,8 +65,13 @@ impl<'a> ApplyMigrationCommand<'a> {
             .datamodel_calculator()
             .infer(&last_non_watch_datamodel, self.input.steps.as_slice())?;
 
-        self.handle_migration(&engine, current_datamodel, next_datamodel_ast)
-            .await
+        self.handle_migration(
+            &engine,
+            current_datamodel,
+            &last_non_watch_datamodel,
+            next_datamodel_ast,
+        )
+        .await
     }
 
     async fn handle_normal_migration<C, D>(
@@ -102,7 +110,7 @@ impl<'a> ApplyMigrationCommand<'a> {
             .datamodel_calculator()
             .infer(&current_datamodel_ast, self.input.steps.as_slice())?;
 
-        self.handle_migration(&engine, current_datamodel, next_datamodel_ast)
+        self.handle_migration(&engine, current_datamodel, &current_datamodel_ast, next_datamodel_ast)
             .await
     }
 
@@ -110,6 +118,7 @@ impl<'a> ApplyMigrationCommand<'a> {
         &self,
         engine: &MigrationEngine<C, D>,
         current_datamodel: Datamodel,
+        steps_schema_ast: &SchemaAst,
         next_schema_ast: SchemaAst,
     ) -> CommandResult<MigrationStepsResultOutput>
     where
@@ -121,10 +130,35 @@ impl<'a> ApplyMigrationCommand<'a> {
             datamodel::lift_ast_to_datamodel(&next_schema_ast).map_err(CommandError::ProducedBadDatamodel)?;
         let migration_persistence = connector.migration_persistence();
 
-        let database_migration = connector
-            .database_migration_inferrer()
-            .infer(&current_datamodel, &next_datamodel, &self.input.steps)
-            .await?;
+        let renames = super::renames_to_migrate(
+            self.input.renames.as_ref(),
+            &current_datamodel,
+            &next_datamodel,
+            &self.input.steps,
+        )?;
+
+        let data_migrations = self.input.data_migrations.clone().unwrap_or_else(Vec::new);
+
+        let database_migration = if data_migrations.is_empty() {
+            connector
+                .database_migration_inferrer()
+                .infer(&current_datamodel, &next_datamodel, &self.input.steps, &renames)
+                .await?
+        } else {
+            let checkpoints =
+                super::data_migration_checkpoints(engine, &data_migrations, steps_schema_ast, &self.input.steps)?;
+
+            connector
+                .database_migration_inferrer()
+                .infer_with_data_migrations(
+                    &current_datamodel,
+                    &next_datamodel,
+                    &self.input.steps,
+                    &renames,
+                    &checkpoints,
+                )
+                .await?
+        };
 
         let database_steps_json_pretty = connector
             .database_migration_step_applier()
@@ -132,6 +166,10 @@ impl<'a> ApplyMigrationCommand<'a> {
 
         tracing::trace!(?database_steps_json_pretty);
 
+        let down_database_migration = connector
+            .database_migration_inferrer()
+            .infer_down(&database_migration)?;
+
         let database_migration_json = database_migration.serialize();
 
         let migration = Migration::new(NewMigration {
@@ -140,6 +178,8 @@ impl<'a> ApplyMigrationCommand<'a> {
             datamodel_string: datamodel::render_schema_ast_to_string(&next_schema_ast)
                 .map_err(CommandError::ProducedBadDatamodel)?,
             database_migration: database_migration_json,
+            down_database_migration: down_database_migration.serialize(),
+            data_migrations,
         });
 
         let diagnostics = connector
@@ -168,6 +208,7 @@ impl<'a> ApplyMigrationCommand<'a> {
             warnings,
             errors,
             unexecutable_migrations,
+            irreversible_steps,
         } = diagnostics;
 
         Ok(MigrationStepsResultOutput {
@@ -177,6 +218,9 @@ impl<'a> ApplyMigrationCommand<'a> {
             errors,
             warnings,
             general_errors: Vec::new(),
+            renames,
+            irreversible_steps,
+            script: None,
             unexecutable_migrations,
         })
     }
@@ -188,6 +232,12 @@ pub struct ApplyMigrationInput {
     pub migration_id: String,
     pub steps: Vec<MigrationStep>,
     pub force: Option<bool>,
+    /// The renames to migrate instead of the detected ones, as confirmed by the user. An empty list
+    /// migrates without renames.
+    pub renames: Option<Vec<Rename>>,
+    /// Scripts migrating data between the steps, for example before a step drops the column they
+    /// read from.
+    pub data_migrations: Option<Vec<DataMigration>>,
 }
 
 impl IsWatchMigration for ApplyMigrationInput {
@@ -0,0 +1,99 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::ast::SchemaAst;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+/// Record the current state of a database that was not created with migrations as an applied
+/// migration, so the following migrations are inferred from it instead of from an empty schema.
+pub struct BaselineCommand<'a> {
+    input: &'a BaselineInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for BaselineCommand<'a> {
+    type Input = BaselineInput;
+    type Output = BaselineOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let cmd = BaselineCommand { input };
+        tracing::debug!("{:?}", cmd.input);
+
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+
+        super::with_migration_lock(migration_persistence.as_ref(), async {
+            if !migration_persistence.load_all().await?.is_empty() {
+                return Err(CommandError::Input(anyhow::anyhow!(
+                    "The database already has a migration history, only databases without migrations can be baselined."
+                )));
+            }
+
+            let introspected_datamodel = connector.introspect().await?;
+            let datamodel_string = datamodel::render_datamodel_to_string(&introspected_datamodel)
+                .map_err(CommandError::ProducedBadDatamodel)?;
+            let introspected_datamodel_ast =
+                datamodel::parse_schema_ast(&datamodel_string).map_err(CommandError::ProducedBadDatamodel)?;
+
+            let datamodel_steps = engine
+                .datamodel_migration_steps_inferrer()
+                .infer(&SchemaAst::empty(), &introspected_datamodel_ast);
+
+            // The database already is in the state of the introspected datamodel: the migration has no steps to apply.
+            let database_migration = connector
+                .database_migration_inferrer()
+                .infer_from_datamodels(&introspected_datamodel, &introspected_datamodel, &datamodel_steps, &[])
+                .await?;
+            let down_database_migration = connector
+                .database_migration_inferrer()
+                .infer_down(&database_migration)?;
+
+            let migration = Migration::new(NewMigration {
+                name: cmd.input.migration_id.clone(),
+                datamodel_steps: datamodel_steps.clone(),
+                datamodel_string: datamodel_string.clone(),
+                database_migration: database_migration.serialize(),
+                down_database_migration: down_database_migration.serialize(),
+                data_migrations: Vec::new(),
+            });
+
+            let saved_migration = migration_persistence.create(migration).await?;
+
+            connector
+                .migration_applier()
+                .apply(&saved_migration, &database_migration)
+                .await?;
+
+            tracing::info!(
+                "Baselined the database with the `{}` migration.",
+                cmd.input.migration_id
+            );
+
+            Ok(BaselineOutput {
+                datamodel: datamodel_string,
+                datamodel_steps,
+            })
+        })
+        .await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineInput {
+    /// The id of the migration recording the current state of the database.
+    pub migration_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineOutput {
+    /// The introspected datamodel the following migrations start from.
+    pub datamodel: String,
+    /// The steps from an empty datamodel to the introspected datamodel, recorded as the steps of the migration.
+    pub datamodel_steps: Vec<MigrationStep>,
+}
@@ -46,15 +46,18 @@ impl<'a> MigrationCommand for CalculateDatabaseStepsCommand<'a> {
         let next_datamodel =
             datamodel::lift_ast_to_datamodel(&next_datamodel_ast).map_err(CommandError::ProducedBadDatamodel)?;
 
+        let renames = detect_renames(&assumed_datamodel, &next_datamodel, &steps_to_apply);
+
         let database_migration = connector
             .database_migration_inferrer()
-            .infer(&assumed_datamodel, &next_datamodel, &steps_to_apply)
+            .infer(&assumed_datamodel, &next_datamodel, &steps_to_apply, &renames)
             .await?;
 
         let DestructiveChangeDiagnostics {
             warnings,
             errors: _,
             unexecutable_migrations,
+            irreversible_steps,
         } = connector
             .destructive_changes_checker()
             .check(&database_migration)
@@ -64,6 +67,16 @@ impl<'a> MigrationCommand for CalculateDatabaseStepsCommand<'a> {
             .database_migration_step_applier()
             .render_steps_pretty(&database_migration)?;
 
+        let script = if cmd.input.render_script.unwrap_or(false) {
+            Some(
+                connector
+                    .database_migration_step_applier()
+                    .render_script(&database_migration)?,
+            )
+        } else {
+            None
+        };
+
         Ok(MigrationStepsResultOutput {
             datamodel: datamodel::render_schema_ast_to_string(&next_datamodel_ast).unwrap(),
             datamodel_steps: steps_to_apply.to_vec(),
@@ -71,6 +84,9 @@ impl<'a> MigrationCommand for CalculateDatabaseStepsCommand<'a> {
             errors: Vec::new(),
             warnings,
             general_errors: Vec::new(),
+            renames,
+            irreversible_steps,
+            script,
             unexecutable_migrations,
         })
     }
@@ -107,4 +123,6 @@ impl CalculateDatabaseStepsCommand<'_> {
 pub struct CalculateDatabaseStepsInput {
     pub steps_to_apply: Vec<MigrationStep>,
     pub assume_to_be_applied: Option<Vec<MigrationStep>>,
+    /// Render the SQL script applying the migration would run, without applying it.
+    pub render_script: Option<bool>,
 }
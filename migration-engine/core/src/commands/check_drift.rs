@@ -0,0 +1,59 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::Datamodel;
+use migration_connector::*;
+use serde::Serialize;
+
+/// Compare the live database with the database schema the applied migrations lead to, to detect
+/// changes made to the database outside of migrations.
+pub struct CheckDriftCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for CheckDriftCommand {
+    type Input = serde_json::Value;
+    type Output = CheckDriftOutput;
+
+    async fn execute<C, D>(_input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let last_migration = connector.migration_persistence().last().await?;
+
+        let expected_datamodel = last_migration
+            .as_ref()
+            .map(|migration| migration.parse_datamodel())
+            .unwrap_or_else(|| Ok(Datamodel::empty()))
+            .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+
+        let drift = connector.check_drift(&expected_datamodel).await?;
+
+        if !drift.is_empty() {
+            tracing::warn!(
+                "The database schema has drifted from the migrations: {:?}",
+                drift.summary
+            );
+        }
+
+        Ok(CheckDriftOutput {
+            migration_id: last_migration.map(|migration| migration.name),
+            drift_detected: !drift.is_empty(),
+            steps: drift.steps,
+            summary: drift.summary,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckDriftOutput {
+    /// The last applied migration. The database schema is compared with the schema of its
+    /// datamodel.
+    pub migration_id: Option<String>,
+    pub drift_detected: bool,
+    /// The steps that would migrate the live database back to the schema of the migrations.
+    pub steps: Vec<serde_json::Value>,
+    /// A readable description of the differences.
+    pub summary: Vec<String>,
+}
@@ -59,20 +59,28 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             .datamodel_migration_steps_inferrer()
             .infer(&assumed_datamodel_ast, &next_datamodel_ast);
 
+        let renames = super::renames_to_migrate(
+            cmd.input.renames.as_ref(),
+            &assumed_datamodel,
+            &next_datamodel,
+            &model_migration_steps,
+        )?;
+
         let database_migration = database_migration_inferrer
-            .infer(&assumed_datamodel, &next_datamodel, &model_migration_steps)
+            .infer(&assumed_datamodel, &next_datamodel, &model_migration_steps, &renames)
             .await?;
 
         let DestructiveChangeDiagnostics {
             warnings,
             errors: _,
             unexecutable_migrations,
+            irreversible_steps,
         } = connector
             .destructive_changes_checker()
             .check(&database_migration)
             .await?;
 
-        let (returned_datamodel_steps, returned_database_migration) =
+        let (returned_datamodel_steps, returned_database_migration, returned_renames) =
             if !cmd.input.is_watch_migration() && last_migration.map(|mig| mig.is_watch_migration()).unwrap_or(false) {
                 // Transition out of watch mode
                 let last_non_watch_applied_migration = migration_persistence.last_non_watch_applied_migration().await?;
@@ -88,22 +96,43 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
                 let datamodel_steps = engine
                     .datamodel_migration_steps_inferrer()
                     .infer(&last_non_watch_datamodel_ast, &next_datamodel_ast);
+                let full_renames = super::renames_to_migrate(
+                    cmd.input.renames.as_ref(),
+                    &last_non_watch_datamodel,
+                    &next_datamodel,
+                    &datamodel_steps,
+                )?;
 
                 // The database migration since the last non-watch migration, so we can render all the steps applied
                 // in watch mode to the migrations folder.
                 let full_database_migration = database_migration_inferrer
-                    .infer_from_datamodels(&last_non_watch_datamodel, &next_datamodel, &datamodel_steps)
+                    .infer_from_datamodels(
+                        &last_non_watch_datamodel,
+                        &next_datamodel,
+                        &datamodel_steps,
+                        &full_renames,
+                    )
                     .await?;
 
-                (datamodel_steps, full_database_migration)
+                (datamodel_steps, full_database_migration, full_renames)
             } else {
-                (model_migration_steps, database_migration)
+                (model_migration_steps, database_migration, renames)
             };
 
         let database_steps = connector
             .database_migration_step_applier()
             .render_steps_pretty(&returned_database_migration)?;
 
+        let script = if cmd.input.render_script.unwrap_or(false) {
+            Some(
+                connector
+                    .database_migration_step_applier()
+                    .render_script(&returned_database_migration)?,
+            )
+        } else {
+            None
+        };
+
         debug!(?returned_datamodel_steps);
 
         Ok(MigrationStepsResultOutput {
@@ -113,6 +142,9 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             errors: version_check_errors,
             warnings,
             general_errors: vec![],
+            renames: returned_renames,
+            irreversible_steps,
+            script,
             unexecutable_migrations,
         })
     }
@@ -171,6 +203,11 @@ pub struct InferMigrationStepsInput {
     /// These steps must be provided and correct for migration inferrence to work.
     pub assume_to_be_applied: Option<Vec<MigrationStep>>,
     pub assume_applied_migrations: Option<Vec<AppliedMigration>>,
+    /// The renames to migrate instead of the detected ones, as confirmed by the user. An empty list
+    /// migrates without renames.
+    pub renames: Option<Vec<Rename>>,
+    /// Render the SQL script applying the migration would run, without applying it.
+    pub render_script: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
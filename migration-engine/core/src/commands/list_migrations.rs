@@ -54,10 +54,23 @@ where
         None => vec![],
     };
 
+    let down_database_steps_json = match migration
+        .down_database_migration
+        .and_then(|json| connector.deserialize_database_migration(json))
+    {
+        Some(down_database_migration) => Some(
+            connector
+                .database_migration_step_applier()
+                .render_steps_pretty(&down_database_migration)?,
+        ),
+        None => None,
+    };
+
     Ok(ListMigrationsOutput {
         id: migration.name,
         datamodel_steps: migration.datamodel_steps,
         database_steps: database_steps_json,
+        down_database_steps: down_database_steps_json,
         status: migration.status,
         datamodel: migration.datamodel_string,
     })
@@ -69,6 +82,9 @@ pub struct ListMigrationsOutput {
     pub id: String,
     pub datamodel_steps: Vec<MigrationStep>,
     pub database_steps: Vec<PrettyDatabaseMigrationStep>,
+    /// The steps run when the migration is unapplied. Migrations persisted before down migrations were stored
+    /// don't have any.
+    pub down_database_steps: Option<Vec<PrettyDatabaseMigrationStep>>,
     pub status: MigrationStatus,
     pub datamodel: String,
 }
@@ -38,6 +38,7 @@ impl<'a> MigrationCommand for MigrationProgressCommand<'a> {
             steps: migration.datamodel_steps.len(),
             applied: migration.applied,
             rolled_back: migration.rolled_back,
+            data_migrations: migration.data_migrations,
             errors: migration.errors,
             started_at: migration.started_at,
             finished_at: migration.finished_at,
@@ -58,6 +59,9 @@ pub struct MigrationProgressOutput {
     steps: usize,
     applied: usize,
     rolled_back: usize,
+    /// The data migrations of the migration. They are applied as database steps, so they are
+    /// counted in `applied`.
+    data_migrations: Vec<DataMigration>,
     errors: Vec<String>,
     started_at: DateTime<Utc>,
     finished_at: Option<DateTime<Utc>>,
@@ -1,27 +1,39 @@
 mod apply_migration;
+mod baseline;
 mod calculate_database_steps;
 mod calculate_datamodel;
+mod check_drift;
 mod command;
 mod infer_migration_steps;
 mod list_migrations;
 mod migration_progress;
 mod reset;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply_migration::*;
+pub use baseline::*;
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
+pub use check_drift::*;
 pub use command::*;
 pub use infer_migration_steps::*;
 pub use list_migrations::*;
 pub use migration_progress::*;
 pub use reset::*;
+pub use squash_migrations::*;
 pub use unapply_migration::*;
 
+use crate::migration_engine::MigrationEngine;
+use datamodel::{ast::SchemaAst, Datamodel};
+use futures::FutureExt;
 use migration_connector::{
-    MigrationError, MigrationStep, MigrationWarning, PrettyDatabaseMigrationStep, UnexecutableMigration,
+    DataMigration, DataMigrationCheckpoint, DatabaseMigrationMarker, IrreversibleStep, MigrationConnector,
+    MigrationError, MigrationPersistence, MigrationStep, MigrationWarning, PrettyDatabaseMigrationStep, Rename,
+    UnexecutableMigration,
 };
 use serde::{Deserialize, Serialize};
+use std::{future::Future, panic::AssertUnwindSafe};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -32,6 +44,82 @@ pub struct MigrationStepsResultOutput {
     pub warnings: Vec<MigrationWarning>,
     pub errors: Vec<MigrationError>,
     pub general_errors: Vec<String>,
+    /// The models and fields the migration renames instead of dropping and recreating them.
+    pub renames: Vec<Rename>,
+    /// The steps that unapplying the migration can't revert completely, because they drop data.
+    pub irreversible_steps: Vec<IrreversibleStep>,
+    /// The SQL script running the exact statements applying the migration runs, when requested
+    /// with `renderScript`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
     #[serde(skip)]
     pub unexecutable_migrations: Vec<UnexecutableMigration>,
 }
+
+/// Runs `command` holding the migration lock, so other migration runs can't change the migration
+/// state between the command reading it and applying the migration inferred from it. The lock is
+/// released when the command fails or panics too.
+async fn with_migration_lock<T>(
+    migration_persistence: &dyn MigrationPersistence,
+    command: impl Future<Output = CommandResult<T>>,
+) -> CommandResult<T> {
+    migration_persistence.lock().await?;
+    let result = AssertUnwindSafe(command).catch_unwind().await;
+    let unlock_result = migration_persistence.unlock().await;
+
+    let output = match result {
+        Ok(output) => output?,
+        Err(panic) => std::panic::resume_unwind(panic),
+    };
+
+    unlock_result?;
+
+    Ok(output)
+}
+
+/// The renames to migrate: the renames confirmed by the user if there are any, the renames detected
+/// from the datamodel steps otherwise. An empty list of confirmed renames disables renaming.
+fn renames_to_migrate(
+    confirmed: Option<&Vec<Rename>>,
+    previous: &Datamodel,
+    next: &Datamodel,
+    steps: &[MigrationStep],
+) -> CommandResult<Vec<Rename>> {
+    match confirmed {
+        Some(renames) => {
+            migration_connector::validate_renames(renames, previous, next)
+                .map_err(|err| CommandError::Input(anyhow::anyhow!(err)))?;
+
+            Ok(renames.clone())
+        }
+        None => Ok(migration_connector::detect_renames(previous, next, steps)),
+    }
+}
+
+/// The datamodels the data migrations run at, computed by applying the steps preceding them to the
+/// datamodel the steps start from.
+fn data_migration_checkpoints<C, D>(
+    engine: &MigrationEngine<C, D>,
+    data_migrations: &[DataMigration],
+    previous: &SchemaAst,
+    steps: &[MigrationStep],
+) -> CommandResult<Vec<DataMigrationCheckpoint>>
+where
+    C: MigrationConnector<DatabaseMigration = D>,
+    D: DatabaseMigrationMarker + Send + Sync + 'static,
+{
+    let groups = migration_connector::group_data_migrations(data_migrations, steps.len())?;
+
+    groups
+        .into_iter()
+        .map(|(preceding_steps_count, scripts)| {
+            let checkpoint_ast = engine
+                .datamodel_calculator()
+                .infer(previous, &steps[..preceding_steps_count])?;
+            let datamodel =
+                datamodel::lift_ast_to_datamodel(&checkpoint_ast).map_err(CommandError::ProducedBadDatamodel)?;
+
+            Ok(DataMigrationCheckpoint { datamodel, scripts })
+        })
+        .collect()
+}
@@ -0,0 +1,212 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::ast::SchemaAst;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+/// Merge a contiguous range of applied migrations into a single migration with the same end state,
+/// to keep the migration history short.
+pub struct SquashMigrationsCommand<'a> {
+    input: &'a SquashMigrationsInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for SquashMigrationsCommand<'a> {
+    type Input = SquashMigrationsInput;
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let cmd = SquashMigrationsCommand { input };
+        tracing::debug!("{:?}", cmd.input);
+
+        let migration_persistence = engine.connector().migration_persistence();
+
+        super::with_migration_lock(migration_persistence.as_ref(), cmd.squash(engine)).await
+    }
+}
+
+impl SquashMigrationsCommand<'_> {
+    async fn squash<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<SquashMigrationsOutput>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+        let migrations = migration_persistence.load_all().await?;
+
+        let find_applied_migration = |migration_id: &str| {
+            migrations
+                .iter()
+                .rposition(|migration| {
+                    migration.name == migration_id && migration.status == MigrationStatus::MigrationSuccess
+                })
+                .ok_or_else(|| {
+                    CommandError::Input(anyhow::anyhow!(
+                        "There is no applied migration with the id `{}`.",
+                        migration_id
+                    ))
+                })
+        };
+
+        let first_index = find_applied_migration(&self.input.from_migration_id)?;
+        let last_index = find_applied_migration(&self.input.to_migration_id)?;
+
+        if first_index > last_index {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The migration `{}` was applied after the migration `{}`.",
+                self.input.from_migration_id,
+                self.input.to_migration_id
+            )));
+        }
+
+        let squashed_migrations = &migrations[first_index..=last_index];
+
+        if let Some(migration) = squashed_migrations
+            .iter()
+            .find(|migration| migration.status != MigrationStatus::MigrationSuccess)
+        {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "Only applied migrations can be squashed, but the migration `{}` is {:?}.",
+                migration.name,
+                migration.status
+            )));
+        }
+
+        // Data migrations run between the steps of their migration, which don't exist anymore once squashed.
+        if let Some(migration) = squashed_migrations
+            .iter()
+            .find(|migration| !migration.data_migrations.is_empty())
+        {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "Migrations with data migrations can't be squashed, but the migration `{}` has data migrations.",
+                migration.name
+            )));
+        }
+
+        let first_migration = &squashed_migrations[0];
+        let last_migration = &squashed_migrations[squashed_migrations.len() - 1];
+
+        let previous_migration = migrations[..first_index]
+            .iter()
+            .rev()
+            .find(|migration| migration.status == MigrationStatus::MigrationSuccess);
+        let previous_datamodel_ast = previous_migration
+            .map(|migration| migration.parse_schema_ast())
+            .unwrap_or_else(|| Ok(SchemaAst::empty()))
+            .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+        let next_datamodel_ast = last_migration
+            .parse_schema_ast()
+            .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+
+        let datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&previous_datamodel_ast, &next_datamodel_ast);
+
+        // The squashed steps must lead to the same datamodel as the squashed migrations.
+        let previous_datamodel =
+            datamodel::lift_ast_to_datamodel(&previous_datamodel_ast).map_err(CommandError::ProducedBadDatamodel)?;
+        let next_datamodel =
+            datamodel::lift_ast_to_datamodel(&next_datamodel_ast).map_err(CommandError::ProducedBadDatamodel)?;
+        let calculated_datamodel_ast = engine
+            .datamodel_calculator()
+            .infer(&previous_datamodel_ast, &datamodel_steps)?;
+        let calculated_datamodel =
+            datamodel::lift_ast_to_datamodel(&calculated_datamodel_ast).map_err(CommandError::ProducedBadDatamodel)?;
+
+        if calculated_datamodel != next_datamodel {
+            return Err(CommandError::Generic(anyhow::anyhow!(
+                "The squashed datamodel steps don't lead to the datamodel of the migration `{}`.",
+                last_migration.name
+            )));
+        }
+
+        let database_migration_inferrer = connector.database_migration_inferrer();
+        let database_migration = database_migration_inferrer
+            .infer_from_datamodels(&previous_datamodel, &next_datamodel, &datamodel_steps, &[])
+            .await?;
+        let down_database_migration = database_migration_inferrer.infer_down(&database_migration)?;
+
+        // So must the squashed database migration lead to the database schema the last squashed migration led to.
+        let last_database_migration = connector
+            .deserialize_database_migration(last_migration.database_migration.clone())
+            .ok_or_else(|| {
+                CommandError::Generic(anyhow::anyhow!(
+                    "The database migration of the migration `{}` could not be read.",
+                    last_migration.name
+                ))
+            })?;
+
+        if !database_migration_inferrer.migrate_to_the_same_schema(&database_migration, &last_database_migration) {
+            return Err(CommandError::Generic(anyhow::anyhow!(
+                "The squashed database migration doesn't lead to the database schema of the migration `{}`.",
+                last_migration.name
+            )));
+        }
+
+        let applied_database_steps = connector
+            .database_migration_step_applier()
+            .render_steps_pretty(&database_migration)?
+            .len();
+
+        let mut migration = Migration::new(NewMigration {
+            name: self
+                .input
+                .migration_id
+                .clone()
+                .unwrap_or_else(|| last_migration.name.clone()),
+            datamodel_steps: datamodel_steps.clone(),
+            datamodel_string: last_migration.datamodel_string.clone(),
+            database_migration: database_migration.serialize(),
+            down_database_migration: down_database_migration.serialize(),
+            data_migrations: Vec::new(),
+        });
+        migration.status = MigrationStatus::MigrationSuccess;
+        migration.applied = applied_database_steps;
+        migration.started_at = first_migration.started_at;
+        migration.finished_at = last_migration.finished_at;
+
+        let squashed_migration = migration_persistence.squash(squashed_migrations, migration).await?;
+
+        tracing::info!(
+            "Squashed {} migrations into the `{}` migration.",
+            squashed_migrations.len(),
+            squashed_migration.name
+        );
+
+        Ok(SquashMigrationsOutput {
+            migration_id: squashed_migration.name,
+            squashed_migration_ids: squashed_migrations
+                .iter()
+                .map(|migration| migration.name.clone())
+                .collect(),
+            datamodel_steps,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The id of the first migration to squash.
+    pub from_migration_id: String,
+    /// The id of the last migration to squash.
+    pub to_migration_id: String,
+    /// The id of the squashed migration. Defaults to the id of the last squashed migration.
+    pub migration_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    /// The id of the squashed migration.
+    pub migration_id: String,
+    /// The ids of the migrations that were squashed, in the order they were applied in.
+    pub squashed_migration_ids: Vec<String>,
+    /// The datamodel steps of the squashed migration.
+    pub datamodel_steps: Vec<MigrationStep>,
+}
@@ -23,70 +23,111 @@ impl<'a> MigrationCommand for UnapplyMigrationCommand<'a> {
         debug!("{:?}", cmd.input);
         let connector = engine.connector();
 
-        let result = match connector.migration_persistence().last_two_migrations().await? {
-            (None, _) => UnapplyMigrationOutput {
-                rolled_back: "not-applicable".to_string(),
-                active: None,
-                errors: vec!["There is no last migration that can be rolled back.".to_string()],
-                warnings: Vec::new(),
-            },
-            (Some(migration_to_rollback), second_to_last) => {
-                let schema_ast_before_last_migration = second_to_last
-                    .as_ref()
-                    .map(|migration| migration.parse_schema_ast())
-                    .unwrap_or_else(|| Ok(SchemaAst::empty()))
-                    .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
-                let schema_before_last_migration = second_to_last
-                    .as_ref()
-                    .map(|migration| migration.parse_datamodel())
-                    .unwrap_or_else(|| Ok(Datamodel::empty()))
-                    .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
-
-                let last_schema_ast = migration_to_rollback
-                    .parse_schema_ast()
-                    .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
-                let last_schema = migration_to_rollback
-                    .parse_datamodel()
-                    .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
-
-                // Generate backwards datamodel steps.
-                let datamodel_migration =
-                    crate::migration::datamodel_differ::diff(&last_schema_ast, &schema_ast_before_last_migration);
-
-                let database_migration = connector
-                    .database_migration_inferrer()
-                    .infer(&last_schema, &schema_before_last_migration, &datamodel_migration)
-                    .await?;
-
-                let destructive_changes_checker = connector.destructive_changes_checker();
-
-                let warnings = destructive_changes_checker.check(&database_migration).await?;
-
-                match (warnings.has_warnings(), input.force) {
-                    (false, _) | (true, None) | (true, Some(true)) => {
-                        connector
-                            .migration_applier()
-                            .unapply(&migration_to_rollback, &database_migration)
-                            .await?;
+        let migration_persistence = connector.migration_persistence();
+
+        super::with_migration_lock(migration_persistence.as_ref(), async {
+            let result = match migration_persistence.last_two_migrations().await? {
+                (None, _) => UnapplyMigrationOutput {
+                    rolled_back: "not-applicable".to_string(),
+                    active: None,
+                    errors: vec!["There is no last migration that can be rolled back.".to_string()],
+                    warnings: Vec::new(),
+                },
+                (Some(migration_to_rollback), second_to_last) => {
+                    let stored_down_migration = migration_to_rollback
+                        .down_database_migration
+                        .clone()
+                        .and_then(|json| connector.deserialize_database_migration(json));
+
+                    let database_migration = match stored_down_migration {
+                        Some(down_migration) => down_migration,
+                        // Migrations persisted before down migrations were stored are reverted by migrating to the
+                        // datamodel of the previous migration.
+                        None => {
+                            infer_down_migration(connector, &migration_to_rollback, second_to_last.as_ref()).await?
+                        }
+                    };
+
+                    let destructive_changes_checker = connector.destructive_changes_checker();
+
+                    let warnings = destructive_changes_checker.check(&database_migration).await?;
+
+                    match (warnings.has_warnings(), input.force) {
+                        (false, _) | (true, None) | (true, Some(true)) => {
+                            connector
+                                .migration_applier()
+                                .unapply(&migration_to_rollback, &database_migration)
+                                .await?;
+                        }
+                        (true, Some(false)) => (),
                     }
-                    (true, Some(false)) => (),
-                }
 
-                let new_active_migration = connector.migration_persistence().last().await?.map(|m| m.name);
+                    let new_active_migration = migration_persistence.last().await?.map(|m| m.name);
 
-                UnapplyMigrationOutput {
-                    rolled_back: migration_to_rollback.name,
-                    active: new_active_migration,
-                    errors: Vec::new(),
-                    warnings: warnings.warnings,
+                    UnapplyMigrationOutput {
+                        rolled_back: migration_to_rollback.name,
+                        active: new_active_migration,
+                        errors: Vec::new(),
+                        warnings: warnings.warnings,
+                    }
                 }
-            }
-        };
+            };
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
 }
 
+async fn infer_down_migration<C, D>(
+    connector: &C,
+    migration_to_rollback: &Migration,
+    second_to_last: Option<&Migration>,
+) -> CommandResult<D>
+where
+    C: MigrationConnector<DatabaseMigration = D>,
+    D: DatabaseMigrationMarker + 'static,
+{
+    let schema_ast_before_last_migration = second_to_last
+        .map(|migration| migration.parse_schema_ast())
+        .unwrap_or_else(|| Ok(SchemaAst::empty()))
+        .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+    let schema_before_last_migration = second_to_last
+        .map(|migration| migration.parse_datamodel())
+        .unwrap_or_else(|| Ok(Datamodel::empty()))
+        .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+
+    let last_schema_ast = migration_to_rollback
+        .parse_schema_ast()
+        .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+    let last_schema = migration_to_rollback
+        .parse_datamodel()
+        .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+
+    // Generate backwards datamodel steps.
+    let datamodel_migration =
+        crate::migration::datamodel_differ::diff(&last_schema_ast, &schema_ast_before_last_migration);
+
+    // The datamodel differ doesn't detect renames, they are taken from the migration instead.
+    let renames = invert_renames(&detect_renames(
+        &schema_before_last_migration,
+        &last_schema,
+        &migration_to_rollback.datamodel_steps,
+    ));
+
+    let database_migration = connector
+        .database_migration_inferrer()
+        .infer(
+            &last_schema,
+            &schema_before_last_migration,
+            &datamodel_migration,
+            &renames,
+        )
+        .await?;
+
+    Ok(database_migration)
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UnapplyMigrationInput {
@@ -45,6 +45,12 @@ fn apply_step(datamodel: &mut ast::SchemaAst, step: &MigrationStep) -> Result<()
         MigrationStep::CreateField(create_field) => apply_create_field(datamodel, create_field)?,
         MigrationStep::UpdateField(update_field) => apply_update_field(datamodel, update_field)?,
         MigrationStep::DeleteField(delete_field) => apply_delete_field(datamodel, delete_field)?,
+        MigrationStep::CreateCompositeType(create_composite_type) => {
+            apply_create_composite_type(datamodel, create_composite_type)?
+        }
+        MigrationStep::DeleteCompositeType(delete_composite_type) => {
+            apply_delete_composite_type(datamodel, delete_composite_type)?
+        }
         MigrationStep::CreateTypeAlias(create_type_alias) => apply_create_type_alias(datamodel, create_type_alias)?,
         MigrationStep::UpdateTypeAlias(update_type_alias) => apply_update_type_alias(datamodel, update_type_alias)?,
         MigrationStep::DeleteTypeAlias(delete_type_alias) => apply_delete_type_alias(datamodel, delete_type_alias)?,
@@ -146,7 +152,7 @@ fn apply_create_enum(datamodel: &mut ast::SchemaAst, step: &steps::CreateEnum) -
 }
 
 fn apply_create_field(datamodel: &mut ast::SchemaAst, step: &steps::CreateField) -> Result<(), CalculatorError> {
-    if let Some(_) = datamodel.find_field(&step.model, &step.field) {
+    if let Some(_) = find_fields(datamodel, &step.model).and_then(|fields| find_field(fields, &step.field)) {
         return Err(format_err!(
             "The field {} on model {} already exists in this Datamodel. It is not possible to create it once more.",
             &step.field,
@@ -155,8 +161,7 @@ fn apply_create_field(datamodel: &mut ast::SchemaAst, step: &steps::CreateField)
         .into());
     }
 
-    let model = datamodel
-        .find_model_mut(&step.model)
+    let fields = find_fields_mut(datamodel, &step.model)
         .ok_or_else(|| format_err!("CreateField on unknown model: `{}`", step.model))?;
 
     let steps::CreateField {
@@ -175,7 +180,7 @@ fn apply_create_field(datamodel: &mut ast::SchemaAst, step: &steps::CreateField)
         directives: Vec::new(),
         is_commented_out: false,
     };
-    model.fields.push(field);
+    fields.push(field);
 
     Ok(())
 }
@@ -249,22 +254,24 @@ fn apply_delete_model(datamodel: &mut ast::SchemaAst, step: &steps::DeleteModel)
 }
 
 fn apply_update_field(datamodel: &mut ast::SchemaAst, step: &steps::UpdateField) -> Result<(), CalculatorError> {
-    if let None = datamodel.find_model(&step.model) {
-        return Err(format_err!(
-            "The model {} does not exist in this Datamodel. It is not possible to update a field in it.",
-            &step.model
-        )
-        .into());
-    }
-
-    let field = datamodel.find_field_mut(&step.model, &step.field).ok_or_else(|| {
+    let fields = find_fields_mut(datamodel, &step.model).ok_or_else(|| {
         format_err!(
-            "The field {} on model {} does not exist in this Datamodel. It is not possible to update it.",
-            &step.field,
+            "The model {} does not exist in this Datamodel. It is not possible to update a field in it.",
             &step.model
         )
     })?;
 
+    let field = fields
+        .iter_mut()
+        .find(|field| field.name.name == step.field)
+        .ok_or_else(|| {
+            format_err!(
+                "The field {} on model {} does not exist in this Datamodel. It is not possible to update it.",
+                &step.field,
+                &step.model
+            )
+        })?;
+
     apply_field_update(field, &step.arity.map(|x| x.into()), update_field_arity);
     apply_field_update(field, &step.tpe, update_field_type);
     apply_field_update(field, &step.new_name, update_field_name);
@@ -291,14 +298,14 @@ fn update_field_name(field: &mut ast::Field, new_name: &String) {
 }
 
 fn apply_delete_field(datamodel: &mut ast::SchemaAst, step: &steps::DeleteField) -> Result<(), CalculatorError> {
-    datamodel.find_model(&step.model).ok_or_else(|| {
+    let fields = find_fields_mut(datamodel, &step.model).ok_or_else(|| {
         format_err!(
             "The model {} does not exist in this Datamodel. It is not possible to delete a field in it.",
             &step.model
         )
     })?;
 
-    datamodel.find_field(&step.model, &step.field).ok_or_else(|| {
+    find_field(fields, &step.field).ok_or_else(|| {
         format_err!(
             "The field {} on model {} does not exist in this Datamodel. It is not possible to delete it.",
             &step.field,
@@ -306,15 +313,9 @@ fn apply_delete_field(datamodel: &mut ast::SchemaAst, step: &steps::DeleteField)
         )
     })?;
 
-    let model = datamodel.find_model_mut(&step.model).unwrap();
-
-    let new_fields: Vec<_> = model
-        .fields
-        .drain(..)
-        .filter(|field| field.name.name != step.field)
-        .collect();
+    let new_fields: Vec<_> = fields.drain(..).filter(|field| field.name.name != step.field).collect();
 
-    model.fields = new_fields;
+    *fields = new_fields;
 
     Ok(())
 }
@@ -466,6 +467,55 @@ fn apply_delete_directive_argument(datamodel: &mut ast::SchemaAst, step: &steps:
     argument_container.set_arguments(new_arguments)
 }
 
+fn apply_create_composite_type(
+    datamodel: &mut ast::SchemaAst,
+    step: &steps::CreateCompositeType,
+) -> Result<(), CalculatorError> {
+    if let Some(_) = datamodel.find_composite_type(&step.composite_type) {
+        return Err(format_err!(
+            "The composite type {} already exists in this Datamodel. It is not possible to create it once more.",
+            &step.composite_type
+        )
+        .into());
+    }
+
+    let composite_type = ast::CompositeType {
+        documentation: None,
+        name: new_ident(step.composite_type.clone()),
+        span: new_span(),
+        fields: vec![],
+    };
+
+    datamodel.tops.push(ast::Top::CompositeType(composite_type));
+
+    Ok(())
+}
+
+fn apply_delete_composite_type(
+    datamodel: &mut ast::SchemaAst,
+    step: &steps::DeleteCompositeType,
+) -> Result<(), CalculatorError> {
+    datamodel.find_composite_type(&step.composite_type).ok_or_else(|| {
+        format_err!(
+            "The composite type {} does not exist in this Datamodel. It is not possible to delete it.",
+            &step.composite_type
+        )
+    })?;
+
+    let new_tops = datamodel
+        .tops
+        .drain(..)
+        .filter(|top| match top {
+            ast::Top::CompositeType(composite_type) => composite_type.name.name != step.composite_type,
+            _ => true,
+        })
+        .collect();
+
+    datamodel.tops = new_tops;
+
+    Ok(())
+}
+
 fn apply_create_type_alias(
     datamodel: &mut ast::SchemaAst,
     step: &steps::CreateTypeAlias,
@@ -533,6 +583,31 @@ fn apply_delete_type_alias(
     Ok(())
 }
 
+/// The fields of the model or composite type with the name. Field steps use the name of a
+/// composite type as the model.
+fn find_fields<'a>(datamodel: &'a ast::SchemaAst, model: &str) -> Option<&'a Vec<ast::Field>> {
+    match datamodel.find_model(model) {
+        Some(model) => Some(&model.fields),
+        None => datamodel
+            .find_composite_type(model)
+            .map(|composite_type| &composite_type.fields),
+    }
+}
+
+fn find_fields_mut<'a>(datamodel: &'a mut ast::SchemaAst, model: &str) -> Option<&'a mut Vec<ast::Field>> {
+    if datamodel.find_model(model).is_some() {
+        return datamodel.find_model_mut(model).map(|model| &mut model.fields);
+    }
+
+    datamodel
+        .find_composite_type_mut(model)
+        .map(|composite_type| &mut composite_type.fields)
+}
+
+fn find_field<'a>(fields: &'a [ast::Field], field: &str) -> Option<&'a ast::Field> {
+    fields.iter().find(|f| f.name.name == field)
+}
+
 fn new_ident(name: String) -> ast::Identifier {
     ast::Identifier { name, span: new_span() }
 }
@@ -569,7 +644,12 @@ fn find_directives_mut<'a>(
     location: &steps::DirectivePath,
 ) -> Option<&'a mut Vec<ast::Directive>> {
     let directives = match location {
-        steps::DirectivePath::Field { model, field } => &mut datamodel.find_field_mut(&model, &field)?.directives,
+        steps::DirectivePath::Field { model, field } => {
+            &mut find_fields_mut(datamodel, &model)?
+                .iter_mut()
+                .find(|f| &f.name.name == field)?
+                .directives
+        }
         steps::DirectivePath::Model { model, arguments: _ } => &mut datamodel.find_model_mut(&model)?.directives,
         steps::DirectivePath::Enum { r#enum } => &mut datamodel.find_enum_mut(&r#enum)?.directives,
         steps::DirectivePath::EnumValue { r#enum, value } => {
@@ -1,5 +1,6 @@
 #![deny(rust_2018_idioms)]
 
+mod composite_types;
 mod directives;
 mod enum_values;
 mod enums;
@@ -8,6 +9,7 @@ mod models;
 mod source;
 mod top_level;
 
+use composite_types::CompositeTypeDiffer;
 use directives::DirectiveDiffer;
 use enum_values::EnumValueDiffer;
 use enums::EnumDiffer;
@@ -31,6 +33,7 @@ pub(crate) fn diff(previous: &ast::SchemaAst, next: &ast::SchemaAst) -> Vec<Migr
     push_type_aliases(&mut steps, &differ);
     push_enums(&mut steps, &differ);
     push_datasources(&mut steps, &differ);
+    push_composite_types(&mut steps, &differ);
     push_models(&mut steps, &differ);
 
     steps
@@ -263,6 +266,44 @@ fn push_deleted_sources<'a>(steps: &mut Steps, sources: impl Iterator<Item = &'a
     steps.extend(delete_source_steps);
 }
 
+fn push_composite_types(steps: &mut Steps, differ: &TopDiffer<'_>) {
+    push_created_composite_types(steps, differ.created_composite_types());
+    push_deleted_composite_types(steps, differ.deleted_composite_types());
+    push_updated_composite_types(steps, differ.composite_type_pairs());
+}
+
+fn push_created_composite_types<'a>(steps: &mut Steps, composite_types: impl Iterator<Item = &'a ast::CompositeType>) {
+    for created_type in composite_types {
+        let create_composite_type_step = steps::CreateCompositeType {
+            composite_type: created_type.name.name.clone(),
+        };
+
+        steps.push(MigrationStep::CreateCompositeType(create_composite_type_step));
+
+        push_created_fields(steps, &created_type.name.name, created_type.fields.iter());
+    }
+}
+
+fn push_deleted_composite_types<'a>(steps: &mut Steps, composite_types: impl Iterator<Item = &'a ast::CompositeType>) {
+    let delete_composite_type_steps = composite_types
+        .map(|deleted_type| steps::DeleteCompositeType {
+            composite_type: deleted_type.name.name.clone(),
+        })
+        .map(MigrationStep::DeleteCompositeType);
+
+    steps.extend(delete_composite_type_steps);
+}
+
+fn push_updated_composite_types<'a>(steps: &mut Steps, composite_types: impl Iterator<Item = CompositeTypeDiffer<'a>>) {
+    for composite_type in composite_types {
+        let type_name = &composite_type.previous.name.name;
+
+        push_created_fields(steps, type_name, composite_type.created_fields());
+        push_deleted_fields(steps, type_name, composite_type.deleted_fields());
+        push_updated_fields(steps, type_name, composite_type.field_pairs());
+    }
+}
+
 fn push_models(steps: &mut Steps, differ: &TopDiffer<'_>) {
     push_created_models(steps, differ.created_models());
     push_deleted_models(steps, differ.deleted_models());
@@ -0,0 +1,95 @@
+use super::FieldDiffer;
+use datamodel::ast;
+
+/// Implements the logic to diff a pair of [AST composite types](/datamodel/ast/struct.CompositeType.html).
+#[derive(Debug)]
+pub(crate) struct CompositeTypeDiffer<'a> {
+    pub(crate) previous: &'a ast::CompositeType,
+    pub(crate) next: &'a ast::CompositeType,
+}
+
+impl<'a> CompositeTypeDiffer<'a> {
+    /// Iterator over the fields present in `next` but not `previous`.
+    pub(crate) fn created_fields(&self) -> impl Iterator<Item = &ast::Field> {
+        self.next_fields().filter(move |next_field| {
+            self.previous_fields()
+                .find(|previous_field| fields_match(previous_field, next_field))
+                .is_none()
+        })
+    }
+
+    /// Iterator over the fields present in `previous` but not `next`.
+    pub(crate) fn deleted_fields(&self) -> impl Iterator<Item = &ast::Field> {
+        self.previous_fields().filter(move |previous_field| {
+            self.next_fields()
+                .find(|next_field| fields_match(previous_field, next_field))
+                .is_none()
+        })
+    }
+
+    /// Iterator over the fields present in both `previous` and `next`.
+    pub(crate) fn field_pairs(&self) -> impl Iterator<Item = FieldDiffer<'_>> {
+        self.previous_fields().filter_map(move |previous_field| {
+            self.next_fields()
+                .find(|next_field| fields_match(previous_field, next_field))
+                .map(|next_field| FieldDiffer {
+                    previous: previous_field,
+                    next: next_field,
+                })
+        })
+    }
+
+    fn previous_fields(&self) -> impl Iterator<Item = &ast::Field> {
+        self.previous.fields.iter()
+    }
+
+    fn next_fields(&self) -> impl Iterator<Item = &ast::Field> {
+        self.next.fields.iter()
+    }
+}
+
+fn fields_match(previous: &ast::Field, next: &ast::Field) -> bool {
+    previous.name.name == next.name.name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::TopDiffer;
+    use datamodel::ast::parser::parse;
+
+    #[test]
+    fn datamodel_differ_composite_type_methods_work() {
+        let previous = r#"
+        type Address {
+            street String
+            city   String
+        }
+        "#;
+        let previous = parse(previous).unwrap();
+        let next = r#"
+        type Address {
+            street  String
+            country String
+        }
+        "#;
+        let next = parse(next).unwrap();
+
+        let top_differ = TopDiffer {
+            previous: &previous,
+            next: &next,
+        };
+        let differ = top_differ.composite_type_pairs().next().unwrap();
+
+        let created_fields: Vec<&str> = differ.created_fields().map(|field| field.name.name.as_str()).collect();
+        assert_eq!(created_fields, &["country"]);
+
+        let deleted_fields: Vec<&str> = differ.deleted_fields().map(|field| field.name.name.as_str()).collect();
+        assert_eq!(deleted_fields, &["city"]);
+
+        let field_pairs: Vec<&str> = differ
+            .field_pairs()
+            .map(|field_differ| field_differ.previous.name.name.as_str())
+            .collect();
+        assert_eq!(field_pairs, &["street"]);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{EnumDiffer, FieldDiffer, ModelDiffer};
+use super::{CompositeTypeDiffer, EnumDiffer, FieldDiffer, ModelDiffer};
 use crate::migration::datamodel_differ::source::SourceArgumentsDiffer;
 use datamodel::ast::{self, Top};
 
@@ -93,6 +93,36 @@ impl<'a> TopDiffer<'a> {
         })
     }
 
+    /// Iterator over the composite types present in both `previous` and `next`.
+    pub(crate) fn composite_type_pairs(&self) -> impl Iterator<Item = CompositeTypeDiffer<'_>> {
+        self.previous_composite_types().filter_map(move |previous_type| {
+            self.next_composite_types()
+                .find(|next_type| composite_types_match(previous_type, next_type))
+                .map(|next_type| CompositeTypeDiffer {
+                    previous: previous_type,
+                    next: next_type,
+                })
+        })
+    }
+
+    /// Iterator over the composite types present in `next` but not `previous`.
+    pub(crate) fn created_composite_types(&self) -> impl Iterator<Item = &ast::CompositeType> {
+        self.next_composite_types().filter(move |next_type| {
+            self.previous_composite_types()
+                .find(|previous_type| composite_types_match(previous_type, next_type))
+                .is_none()
+        })
+    }
+
+    /// Iterator over the composite types present in `previous` but not `next`.
+    pub(crate) fn deleted_composite_types(&self) -> impl Iterator<Item = &ast::CompositeType> {
+        self.previous_composite_types().filter(move |previous_type| {
+            self.next_composite_types()
+                .find(|next_type| composite_types_match(previous_type, next_type))
+                .is_none()
+        })
+    }
+
     /// Iterator over the custom types present in `next` but not `previous`.
     pub(crate) fn created_type_aliases(&self) -> impl Iterator<Item = &ast::Field> {
         self.next_type_aliases().filter(move |next_type_alias| {
@@ -150,6 +180,16 @@ impl<'a> TopDiffer<'a> {
         walk_enums(self.next)
     }
 
+    /// Iterator over the composite types in `previous`.
+    fn previous_composite_types(&self) -> impl Iterator<Item = &ast::CompositeType> {
+        walk_composite_types(self.previous)
+    }
+
+    /// Iterator over the composite types in `next`.
+    fn next_composite_types(&self) -> impl Iterator<Item = &ast::CompositeType> {
+        walk_composite_types(self.next)
+    }
+
     /// Iterator over the custom types in `previous`.
     pub fn previous_type_aliases(&self) -> impl Iterator<Item = &ast::Field> {
         walk_type_aliases(self.previous)
@@ -185,6 +225,14 @@ fn models_match(previous: &ast::Model, next: &ast::Model) -> bool {
     previous.name.name == next.name.name
 }
 
+fn walk_composite_types(ast: &ast::SchemaAst) -> impl Iterator<Item = &ast::CompositeType> {
+    ast.tops.iter().filter_map(Top::as_composite_type)
+}
+
+fn composite_types_match(previous: &ast::CompositeType, next: &ast::CompositeType) -> bool {
+    previous.name.name == next.name.name
+}
+
 fn walk_type_aliases(ast: &ast::SchemaAst) -> impl Iterator<Item = &ast::Field> {
     ast.tops.iter().filter_map(Top::as_type_alias)
 }
@@ -19,7 +19,10 @@ use super::{
 use migration_connector::{MigrationPersistence, MigrationStep};
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyMigrationInput,
+    commands::{
+        ApplyMigrationInput, BaselineInput, BaselineOutput, CheckDriftOutput, SquashMigrationsInput,
+        SquashMigrationsOutput,
+    },
 };
 use quaint::prelude::{ConnectionInfo, Queryable, SqlFamily};
 use sql_migration_connector::MIGRATION_TABLE_NAME;
@@ -93,6 +96,8 @@ impl TestApi {
             migration_id: migration_id.into(),
             steps,
             force: None,
+            renames: None,
+            data_migrations: None,
         };
 
         let migration_output = self.api.apply_migration(&input).await.expect("ApplyMigration failed");
@@ -111,6 +116,32 @@ impl TestApi {
         }
     }
 
+    pub async fn baseline(&self, migration_id: &str) -> Result<BaselineOutput, anyhow::Error> {
+        let input = BaselineInput {
+            migration_id: migration_id.to_owned(),
+        };
+
+        Ok(self.api.baseline(&input).await?)
+    }
+
+    pub async fn squash_migrations(
+        &self,
+        from_migration_id: &str,
+        to_migration_id: &str,
+    ) -> Result<SquashMigrationsOutput, anyhow::Error> {
+        let input = SquashMigrationsInput {
+            from_migration_id: from_migration_id.to_owned(),
+            to_migration_id: to_migration_id.to_owned(),
+            migration_id: None,
+        };
+
+        Ok(self.api.squash_migrations(&input).await?)
+    }
+
+    pub async fn check_drift(&self) -> Result<CheckDriftOutput, anyhow::Error> {
+        Ok(self.api.check_drift(&serde_json::Value::Null).await?)
+    }
+
     pub fn infer_apply<'a>(&'a self, schema: &'a str) -> InferApply<'a> {
         InferApply::new(&self.api, schema)
     }
@@ -359,6 +390,20 @@ pub async fn postgres12_test_api(db_name: &str) -> TestApi {
     }
 }
 
+/// A Postgres 12 test API with the online migration mode enabled.
+pub async fn postgres12_online_test_api(db_name: &str) -> TestApi {
+    let url = format!("{}&online_migrations=true", postgres_12_url(db_name));
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
+    let connector = postgres_migration_connector(&url).await;
+
+    TestApi {
+        connector_name: "postgres12",
+        connection_info,
+        database: Arc::clone(&connector.database),
+        api: test_api(connector).await,
+    }
+}
+
 pub async fn sqlite_test_api(db_name: &str) -> TestApi {
     let connection_info = ConnectionInfo::from_url(&sqlite_test_url(db_name)).unwrap();
     let connector = sqlite_migration_connector(db_name).await;
@@ -1,5 +1,5 @@
 use super::super::unique_migration_id;
-use migration_connector::MigrationStep;
+use migration_connector::{DataMigration, MigrationStep, Rename};
 use migration_core::{
     api::GenericApi,
     commands::{ApplyMigrationInput, MigrationStepsResultOutput},
@@ -11,6 +11,8 @@ pub struct Apply<'a> {
     migration_id: Option<String>,
     steps: Option<Vec<MigrationStep>>,
     force: Option<bool>,
+    renames: Option<Vec<Rename>>,
+    data_migrations: Option<Vec<DataMigration>>,
 }
 
 impl Apply<'_> {
@@ -20,6 +22,8 @@ impl Apply<'_> {
             migration_id: None,
             steps: None,
             force: None,
+            renames: None,
+            data_migrations: None,
         }
     }
 
@@ -38,6 +42,16 @@ impl Apply<'_> {
         self
     }
 
+    pub fn renames(mut self, renames: Option<Vec<Rename>>) -> Self {
+        self.renames = renames;
+        self
+    }
+
+    pub fn data_migrations(mut self, data_migrations: Option<Vec<DataMigration>>) -> Self {
+        self.data_migrations = data_migrations;
+        self
+    }
+
     pub async fn send(self) -> Result<MigrationStepsResultOutput, anyhow::Error> {
         Ok(self.send_inner().await?)
     }
@@ -54,6 +68,8 @@ impl Apply<'_> {
             migration_id,
             force: self.force,
             steps: self.steps.unwrap_or_else(Vec::new),
+            renames: self.renames,
+            data_migrations: self.data_migrations,
         };
 
         self.api.apply_migration(&input).await
@@ -4,6 +4,7 @@ pub struct CalculateDatabaseSteps<'a> {
     api: &'a dyn GenericApi,
     assume_to_be_applied: Option<Vec<MigrationStep>>,
     steps_to_apply: Option<Vec<MigrationStep>>,
+    render_script: Option<bool>,
 }
 
 impl<'a> CalculateDatabaseSteps<'a> {
@@ -12,6 +13,7 @@ impl<'a> CalculateDatabaseSteps<'a> {
             api,
             assume_to_be_applied: None,
             steps_to_apply: None,
+            render_script: None,
         }
     }
 
@@ -27,11 +29,18 @@ impl<'a> CalculateDatabaseSteps<'a> {
         self
     }
 
+    pub fn render_script(mut self, render_script: Option<bool>) -> Self {
+        self.render_script = render_script;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<CalculateDatabaseStepsAssertion<'a>> {
         let api = self.api;
         let input = CalculateDatabaseStepsInput {
             assume_to_be_applied: self.assume_to_be_applied,
             steps_to_apply: self.steps_to_apply.unwrap_or_else(Vec::new),
+            render_script: self.render_script,
         };
 
         let result = self.api.calculate_database_steps(&input).await?;
@@ -12,6 +12,7 @@ pub struct Infer<'a> {
     pub(super) assume_applied_migrations: Option<Vec<AppliedMigration>>,
     pub(super) datamodel: String,
     pub(super) migration_id: Option<String>,
+    pub(super) render_script: Option<bool>,
 }
 
 impl<'a> Infer<'a> {
@@ -22,6 +23,7 @@ impl<'a> Infer<'a> {
             assume_to_be_applied: None,
             assume_applied_migrations: None,
             migration_id: None,
+            render_script: None,
         }
     }
 
@@ -40,6 +42,11 @@ impl<'a> Infer<'a> {
         self
     }
 
+    pub fn render_script(mut self, render_script: Option<bool>) -> Self {
+        self.render_script = render_script;
+        self
+    }
+
     pub async fn send_assert(self) -> anyhow::Result<InferAssertion<'a>> {
         let api = self.api;
         let result = self.send().await?;
@@ -55,6 +62,8 @@ impl<'a> Infer<'a> {
             assume_applied_migrations: self.assume_applied_migrations,
             datamodel: self.datamodel,
             migration_id,
+            renames: None,
+            render_script: self.render_script,
         };
 
         let output = self.api.infer_migration_steps(&input).await?;
@@ -52,6 +52,8 @@ impl<'a> InferApply<'a> {
             datamodel: self.schema.to_owned(),
             assume_to_be_applied: Some(Vec::new()),
             assume_applied_migrations: None,
+            renames: None,
+            render_script: None,
         };
 
         let steps = self.api.infer_migration_steps(&input).await?.datamodel_steps;
@@ -60,6 +62,8 @@ impl<'a> InferApply<'a> {
             migration_id,
             steps,
             force: self.force,
+            renames: None,
+            data_migrations: None,
         };
 
         let migration_output = self.api.apply_migration(&input).await?;
@@ -0,0 +1,65 @@
+use barrel::types;
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn check_drift_must_not_report_anything_for_a_migrated_database(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String @unique
+        }
+    "#;
+
+    api.infer_apply(dm)
+        .migration_id(Some("mig01"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let output = api.check_drift().await?;
+
+    assert_eq!(output.migration_id.as_deref(), Some("mig01"));
+    assert!(!output.drift_detected);
+    assert!(output.steps.is_empty());
+    assert!(output.summary.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn check_drift_must_report_tables_changed_by_hand(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.drop_table("Dog");
+            migration.create_table("Hotfix", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let output = api.check_drift().await?;
+
+    assert!(output.drift_detected);
+    assert_eq!(output.steps.len(), 2);
+    assert!(output
+        .summary
+        .contains(&"The `Dog` table is missing from the database.".to_owned()));
+    assert!(output
+        .summary
+        .contains(&"The database has a `Hotfix` table that is not in the migrations.".to_owned()));
+
+    Ok(())
+}
@@ -182,6 +182,56 @@ fn add_DeleteEnum_to_existing_schema() {
     test(dm1, dm2);
 }
 
+#[test]
+fn add_CreateCompositeType_to_existing_schema() {
+    let dm1 = SchemaAst::empty();
+    let dm2 = parse(
+        r#"
+        type Address {
+            street String
+            city   String?
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
+#[test]
+fn add_fields_to_existing_composite_type() {
+    let dm1 = parse(
+        r#"
+        type Address {
+            street String
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        type Address {
+            street  String
+            country String @default("Germany")
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
+#[test]
+fn add_DeleteCompositeType_to_existing_schema() {
+    let dm1 = parse(
+        r#"
+        type Address {
+            street String
+        }
+    "#,
+    );
+    let dm2 = SchemaAst::empty();
+
+    test(dm1, dm2);
+}
+
 #[test]
 fn creating_a_model_that_already_exists_must_error() {
     let dm = parse(
@@ -277,6 +327,19 @@ fn creating_an_enum_that_already_exists_must_error() {
     );
 }
 
+#[test]
+fn deleting_a_composite_type_that_does_not_exist_must_error() {
+    let dm = SchemaAst::empty();
+    let steps = &[MigrationStep::DeleteCompositeType(DeleteCompositeType {
+        composite_type: "Address".to_owned(),
+    })];
+
+    assert_eq!(
+        calculate_error(&dm, steps),
+        "The composite type Address does not exist in this Datamodel. It is not possible to delete it."
+    );
+}
+
 #[test]
 fn deleting_a_model_that_does_not_exist_must_error() {
     let dm = SchemaAst::empty();
@@ -1,5 +1,8 @@
 use migration_connector::steps::{DeleteModel, MigrationStep};
-use migration_core::api::{render_error, RpcApi};
+use migration_core::{
+    api::{render_error, GenericApi, RpcApi},
+    commands::{ApplyMigrationInput, InferMigrationStepsInput},
+};
 use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
 use quaint::prelude::*;
@@ -435,3 +438,61 @@ async fn json_fields_must_be_rejected(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn migration_lock_timeouts_must_return_a_known_error() -> TestResult {
+    let db_name = "migration_lock_timeouts_must_return_a_known_error";
+    let url = format!("{}&migration_lock_timeout=0", sqlite_test_url(db_name));
+    let api = test_api(sql_migration_connector::SqlMigrationConnector::new(&url).await?).await;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let input = InferMigrationStepsInput {
+        migration_id: "add-cats".to_owned(),
+        datamodel: dm.to_owned(),
+        assume_to_be_applied: Some(Vec::new()),
+        assume_applied_migrations: None,
+        renames: None,
+        render_script: None,
+    };
+
+    let steps = api.infer_migration_steps(&input).await?.datamodel_steps;
+
+    // Another migration run, in a process that is still running, holds the lock.
+    let lock_file_path = format!("{}.migration_lock", sqlite_test_file(db_name));
+    std::fs::write(
+        &lock_file_path,
+        format!("{} 2999-01-01T00:00:00+00:00", std::process::id()),
+    )?;
+
+    let input = ApplyMigrationInput {
+        migration_id: "add-cats".to_owned(),
+        steps,
+        force: None,
+        renames: None,
+        data_migrations: None,
+    };
+
+    let error = api.apply_migration(&input).await.map(drop).unwrap_err();
+
+    std::fs::remove_file(&lock_file_path)?;
+
+    let json_error = serde_json::to_value(&render_error(error))?;
+    let expected = json!({
+        "is_panic": false,
+        "message": "Timed out after 0ms waiting for the migration lock on the `prisma-tests` database. Another migration is being applied to it, try again once it has finished.",
+        "meta": {
+            "database_name": "prisma-tests",
+            "timeout": "0ms",
+        },
+        "error_code": "P3005"
+    });
+
+    assert_eq!(json_error, expected);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use migration_engine_tests::sql::*;
+use quaint::ast::Value;
+
+#[test_each_connector(capabilities("enums"))]
+async fn removing_enum_values_used_by_existing_rows_is_unexecutable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+            OUTRAGED
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let cat_inserts = quaint::ast::Insert::multi_into(api.render_table_name("Cat"), &["id", "mood"])
+        .values((Value::text("felix"), Value::enum_variant("HUNGRY")))
+        .values((Value::text("mittens"), Value::enum_variant("HAPPY")));
+
+    api.database().query(cat_inserts.into()).await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+
+    let enum_name = if api.sql_family().is_mysql() {
+        "Cat_mood"
+    } else {
+        "Mood"
+    };
+
+    api.infer_apply(dm2)
+        .send()
+        .await?
+        .assert_unexecutable(&[format!("You removed the value(s) `HUNGRY`, `OUTRAGED` of the `{}` enum. There are 1 rows of the `Cat` table using them in the `mood` column, this migration step cannot be executed. For example: (id: 'felix', mood: 'HUNGRY').", enum_name)])?;
+
+    let rows = api.select("Cat").column("id").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text(Some("felix"))"#], &[r#"Text(Some("mittens"))"#]]);
+
+    Ok(())
+}
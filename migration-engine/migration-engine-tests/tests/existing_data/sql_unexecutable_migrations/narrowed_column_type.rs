@@ -0,0 +1,139 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("sql"), ignore("sqlite"))]
+async fn changing_a_string_column_to_int_with_non_integer_values_is_unexecutable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("age", "ten")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .force(Some(false))
+        .send()
+        .await?
+        .assert_unexecutable(&["Changed the type of the column `age` on the `Test` table. There are 1 rows with values that are not integers in that column, this migration step cannot be executed. For example: (id: 'def', age: 'ten').".into()])?;
+
+    let rows = api.select("Test").column("id").column("age").send_debug().await?;
+    assert_eq!(
+        rows,
+        &[
+            &[r#"Text(Some("abc"))"#, r#"Text(Some("12"))"#],
+            &[r#"Text(Some("def"))"#, r#"Text(Some("ten"))"#],
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"), ignore("sqlite"))]
+async fn changing_a_string_column_to_int_with_integer_values_is_executable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("age", " -7 ")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "ghi")
+        .value("age", "+5")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "jkl")
+        .value("age", "007")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .force(Some(true))
+        .send()
+        .await?
+        .assert_executable()?
+        .assert_no_error()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn changing_a_text_column_to_a_string_with_longer_values_is_unexecutable(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        "CREATE TABLE `{}`.`Test` (id VARCHAR(191) PRIMARY KEY, name TEXT NULL)",
+        api.schema_name()
+    );
+
+    api.database().execute_raw(&create_table, &[]).await?;
+
+    let long_name = "x".repeat(192);
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("name", "George")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("name", long_name.as_str())
+        .result_raw()
+        .await?;
+
+    // The required String is a VARCHAR(191).
+    let dm = r#"
+        model Test {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(&dm)
+        .force(Some(false))
+        .send()
+        .await?
+        .assert_unexecutable(&[format!(
+            "Changed the type of the column `name` on the `Test` table. There are 1 rows with values longer than 191 characters in that column, this migration step cannot be executed. For example: (id: 'def', name: '{}').",
+            long_name
+        )])?;
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 mod existing_data;
 
-use migration_connector::MigrationWarning;
+use migration_connector::{
+    steps::{DeleteField, UpdateField, UpdateModel},
+    DataMigration, DataMigrationPosition, MigrationStep, MigrationWarning, Rename,
+};
 use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
 use prisma_value::{PrismaValue, TypeHint};
@@ -1220,3 +1223,352 @@ async fn primary_key_migrations_do_not_cause_data_loss(api: &TestApi) -> TestRes
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn mapping_a_column_to_a_new_name_renames_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String @map("nickname")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_has_column("nickname")?.assert_does_not_have_column("name")
+    })?;
+
+    let cat = api.select("Cat").column("id").column("nickname").send().await?;
+    let cat_row: Vec<quaint::Value> = cat.into_single().unwrap().into_iter().collect();
+    assert_eq!(cat_row, &[Value::text("garfield"), Value::text("Garfield")]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn renaming_a_model_and_a_field_keeps_the_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let steps = vec![
+        MigrationStep::UpdateModel(UpdateModel {
+            model: "Cat".to_owned(),
+            new_name: Some("Pet".to_owned()),
+        }),
+        MigrationStep::UpdateField(UpdateField {
+            model: "Pet".to_owned(),
+            field: "name".to_owned(),
+            new_name: Some("nickname".to_owned()),
+            tpe: None,
+            arity: None,
+        }),
+    ];
+
+    let output = api.apply().steps(Some(steps)).send().await?;
+
+    assert!(output.warnings.is_empty(), "{:?}", output.warnings);
+    assert_eq!(
+        output.renames,
+        &[
+            Rename::Model {
+                previous_name: "Cat".to_owned(),
+                next_name: "Pet".to_owned(),
+            },
+            Rename::Field {
+                model: "Pet".to_owned(),
+                previous_name: "name".to_owned(),
+                next_name: "nickname".to_owned(),
+            },
+        ]
+    );
+
+    api.assert_schema().await?.assert_table("Pet", |table| {
+        table.assert_has_column("nickname")?.assert_does_not_have_column("name")
+    })?;
+
+    let pet = api.select("Pet").column("id").column("nickname").send().await?;
+    let pet_row: Vec<quaint::Value> = pet.into_single().unwrap().into_iter().collect();
+    assert_eq!(pet_row, &[Value::text("garfield"), Value::text("Garfield")]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn renames_can_be_disabled_when_applying_a_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let steps = vec![MigrationStep::UpdateField(UpdateField {
+        model: "Cat".to_owned(),
+        field: "name".to_owned(),
+        new_name: Some("nickname".to_owned()),
+        tpe: None,
+        arity: None,
+    })];
+
+    let output = api.apply().steps(Some(steps)).renames(Some(Vec::new())).send().await?;
+
+    assert!(output.renames.is_empty());
+    assert_eq!(
+        output.warnings,
+        &[MigrationWarning {
+            description:
+                "You are about to drop the column `name` on the `Cat` table, which still contains 1 non-null values."
+                    .to_owned(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn data_migrations_run_between_the_steps_of_a_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            nickname String?
+        }
+    "#;
+
+    let steps = api.infer(dm2).send().await?.datamodel_steps;
+    let drop_name_step_index = steps
+        .iter()
+        .position(|step| matches!(step, MigrationStep::DeleteField(DeleteField { field, .. }) if field == "name"))
+        .unwrap();
+
+    let script = if api.sql_family().is_mysql() {
+        "UPDATE `Cat` SET `nickname` = `name`"
+    } else {
+        r#"UPDATE "Cat" SET "nickname" = "name""#
+    };
+    let data_migrations = vec![DataMigration {
+        script: script.to_owned(),
+        position: DataMigrationPosition::BeforeStep(drop_name_step_index),
+    }];
+
+    let output = api
+        .apply()
+        .steps(Some(steps))
+        .data_migrations(Some(data_migrations.clone()))
+        .force(Some(true))
+        .send()
+        .await?;
+
+    assert!(output.database_steps.iter().any(|step| step.raw == script));
+
+    let cat = api.select("Cat").column("id").column("nickname").send().await?;
+    let cat_row: Vec<quaint::Value> = cat.into_single().unwrap().into_iter().collect();
+    assert_eq!(cat_row, &[Value::text("garfield"), Value::text("Garfield")]);
+
+    let migration = api.migration_persistence().last().await?.unwrap();
+    assert_eq!(migration.data_migrations, data_migrations);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn data_migrations_render_each_segment_against_its_own_schemas(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String
+            nickname String?
+        }
+    "#;
+
+    let dm3 = r#"
+        model Cat {
+            id String @id
+            name String
+            nickname String
+        }
+    "#;
+
+    // The column is added as optional, filled by the data migration and then made required.
+    let add_nickname_steps = api.infer(dm2).send().await?.datamodel_steps;
+    let require_nickname_steps = api
+        .infer(dm3)
+        .assume_to_be_applied(Some(add_nickname_steps.clone()))
+        .send()
+        .await?
+        .datamodel_steps;
+
+    let script = if api.sql_family().is_mysql() {
+        "UPDATE `Cat` SET `nickname` = `name`"
+    } else {
+        r#"UPDATE "Cat" SET "nickname" = "name""#
+    };
+    let data_migrations = vec![DataMigration {
+        script: script.to_owned(),
+        position: DataMigrationPosition::BeforeStep(add_nickname_steps.len()),
+    }];
+
+    let steps = add_nickname_steps.into_iter().chain(require_nickname_steps).collect();
+
+    api.apply()
+        .steps(Some(steps))
+        .data_migrations(Some(data_migrations))
+        .force(Some(true))
+        .send()
+        .await?;
+
+    let cat = api.select("Cat").column("id").column("nickname").send().await?;
+    let cat_row: Vec<quaint::Value> = cat.into_single().unwrap().into_iter().collect();
+    assert_eq!(cat_row, &[Value::text("garfield"), Value::text("Garfield")]);
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("nickname", |column| column.assert_is_required())
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn failing_data_migrations_roll_back_the_whole_migration_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String
+            nickname String?
+        }
+    "#;
+
+    let steps = api.infer(dm2).send().await?.datamodel_steps;
+    let data_migrations = vec![DataMigration {
+        script: r#"UPDATE "Cat" SET "nickname" = "unknown_column""#.to_owned(),
+        position: DataMigrationPosition::BeforeStep(steps.len()),
+    }];
+
+    let result = api
+        .apply()
+        .steps(Some(steps))
+        .data_migrations(Some(data_migrations))
+        .force(Some(true))
+        .send()
+        .await;
+
+    assert!(result.is_err());
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_columns_count(2))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn data_migrations_positioned_out_of_bounds_are_rejected(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id String @id
+        }
+    "#;
+
+    let steps = api.infer(dm).send().await?.datamodel_steps;
+
+    for position in &[
+        DataMigrationPosition::BeforeStep(steps.len() + 1),
+        DataMigrationPosition::AfterStep(steps.len()),
+        DataMigrationPosition::AfterStep(usize::MAX),
+    ] {
+        let data_migrations = vec![DataMigration {
+            script: "SELECT 1".to_owned(),
+            position: *position,
+        }];
+
+        let error = api
+            .apply()
+            .steps(Some(steps.clone()))
+            .data_migrations(Some(data_migrations))
+            .force(Some(true))
+            .send()
+            .await
+            .map(drop)
+            .unwrap_err();
+
+        let message = format!("{:#}", error);
+
+        assert!(message.contains("is out of bounds"), "{}", message);
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use barrel::types;
+use migration_connector::{steps::MigrationStep, MigrationStatus};
 use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
 use quaint::prelude::SqlFamily;
@@ -425,3 +426,69 @@ async fn removing_a_default_from_a_non_nullable_foreign_key_column_must_warn(api
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn baselining_an_existing_database_must_record_it_as_applied(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Blog", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    api.insert("Blog").value("id", 1).result_raw().await?;
+
+    let output = api.baseline("baseline").await?;
+
+    assert!(output.datamodel.contains("model Blog"));
+
+    let migrations = api.migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name, "baseline");
+    assert_eq!(migrations[0].status, MigrationStatus::MigrationSuccess);
+
+    let dm = r#"
+        model Blog {
+            id Int @id @default(autoincrement())
+            title String?
+        }
+    "#;
+
+    // The following migrations start from the baseline instead of recreating the existing tables.
+    let output = api.infer_apply(dm).send().await?.assert_green()?.into_inner();
+
+    assert!(!output
+        .datamodel_steps
+        .iter()
+        .any(|step| matches!(step, MigrationStep::CreateModel(_))));
+
+    api.assert_schema()
+        .await?
+        .assert_table("Blog", |table| table.assert_has_column("title"))?;
+
+    let rows = api.select("Blog").column("id").send_debug().await?;
+    assert_eq!(rows, &[["Integer(Some(1))"]]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baselining_a_database_with_a_migration_history_must_fail(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Blog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let error = api.baseline("baseline").await.unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("only databases without migrations can be baselined"));
+
+    Ok(())
+}
@@ -331,3 +331,65 @@ async fn watch_migrations_must_be_returned_in_addition_to_regular_inferred_steps
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn infer_migration_steps_can_render_the_migration_script(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String @unique
+        }
+    "#;
+
+    let output = api.infer(dm).render_script(Some(true)).send().await?;
+    let script = output.script.expect("The script was not rendered.");
+
+    assert!(!output.database_steps.is_empty());
+
+    for step in &output.database_steps {
+        assert!(
+            script.contains(&step.raw),
+            "{:?} is missing from {:?}",
+            step.raw,
+            script
+        );
+    }
+
+    // Rendering the script doesn't apply the migration.
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn the_migration_script_includes_sqlite_table_redefinitions(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let script = api
+        .infer(dm2)
+        .render_script(Some(true))
+        .send()
+        .await?
+        .script
+        .expect("The script was not rendered.");
+
+    assert!(script.starts_with("PRAGMA foreign_keys=OFF;\n"));
+    assert!(script.contains(r#"ALTER TABLE "prisma-tests"."new_Cat" RENAME TO "Cat";"#));
+    assert!(script.ends_with("PRAGMA foreign_keys=ON;\n"));
+
+    Ok(())
+}
@@ -13,6 +13,8 @@ fn empty_migration(name: String) -> Migration {
         applied: 0,
         rolled_back: 0,
         database_migration: serde_json::json!({}),
+        down_database_migration: None,
+        data_migrations: Vec::new(),
         errors: Vec::new(),
         started_at: Migration::timestamp_without_nanos(),
         finished_at: None,
@@ -165,3 +167,41 @@ async fn migration_is_already_applied_must_work(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn init_is_idempotent(api: &TestApi) -> TestResult {
+    let persistence = api.migration_persistence();
+
+    persistence.init().await?;
+    persistence.init().await?;
+
+    persistence.create(empty_migration("my_migration".to_string())).await?;
+    assert_eq!(persistence.load_all().await?.len(), 1);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn init_adds_the_columns_missing_from_migration_tables_of_older_versions(api: &TestApi) -> TestResult {
+    let persistence = api.migration_persistence();
+
+    persistence.init().await?;
+
+    for column in &["down_database_migration", "data_migrations"] {
+        api.database()
+            .raw_cmd(&format!(
+                r#"ALTER TABLE "{}"."_Migration" DROP COLUMN "{}""#,
+                api.schema_name(),
+                column
+            ))
+            .await?;
+    }
+
+    persistence.init().await?;
+
+    // Creating and loading migrations reads and writes both columns.
+    persistence.create(empty_migration("my_migration".to_string())).await?;
+    assert_eq!(persistence.load_all().await?.len(), 1);
+
+    Ok(())
+}
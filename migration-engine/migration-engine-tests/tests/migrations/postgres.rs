@@ -1,5 +1,5 @@
 use migration_engine_tests::*;
-use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, DefaultValue};
 
 #[test_each_connector(tags("postgres"))]
 async fn enums_can_be_dropped_on_postgres(api: &TestApi) -> TestResult {
@@ -96,3 +96,83 @@ async fn existing_postgis_tables_must_not_be_migrated(api: &TestApi) -> TestResu
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn sequence_defaults_create_a_shared_sequence(api: &TestApi) -> TestResult {
+    let dm = format!(
+        r#"
+            {}
+
+            model Invoice {{
+                id     Int @id @default(autoincrement())
+                number Int @default(sequence("Numbers"))
+            }}
+
+            model CreditNote {{
+                id     Int @id @default(autoincrement())
+                number Int @default(sequence("Numbers"))
+            }}
+        "#,
+        api.datasource()
+    );
+
+    api.infer_apply(&dm).send().await?.assert_green()?;
+
+    let expected_default = Some(DefaultValue::DBGENERATED("nextval('\"Numbers\"'::regclass)".to_owned()));
+
+    let schema = api
+        .assert_schema()
+        .await?
+        .assert_table("Invoice", |table| {
+            table.assert_column("number", |column| column.assert_default(expected_default.clone()))
+        })?
+        .assert_table("CreditNote", |table| {
+            table.assert_column("number", |column| column.assert_default(expected_default.clone()))
+        })?
+        .into_schema();
+
+    assert!(schema.get_sequence("Numbers").is_some());
+
+    api.infer(&dm).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_the_sequence_of_a_default_is_migrated(api: &TestApi) -> TestResult {
+    let dm1 = format!(
+        r#"
+            {}
+
+            model Invoice {{
+                id     Int @id @default(autoincrement())
+                number Int @default(sequence("Numbers"))
+            }}
+        "#,
+        api.datasource()
+    );
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    let dm2 = dm1.replace("sequence(\"Numbers\")", "sequence(\"InvoiceNumbers\")");
+
+    api.infer_apply(&dm2).send().await?.assert_green()?;
+
+    let expected_default = Some(DefaultValue::DBGENERATED(
+        "nextval('\"InvoiceNumbers\"'::regclass)".to_owned(),
+    ));
+
+    let schema = api
+        .assert_schema()
+        .await?
+        .assert_table("Invoice", |table| {
+            table.assert_column("number", |column| column.assert_default(expected_default.clone()))
+        })?
+        .into_schema();
+
+    assert!(schema.get_sequence("InvoiceNumbers").is_some());
+
+    api.infer(&dm2).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
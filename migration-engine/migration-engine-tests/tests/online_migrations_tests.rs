@@ -0,0 +1,159 @@
+use migration_engine_tests::*;
+
+#[tokio::test]
+async fn indexes_are_created_concurrently_in_online_mode() -> TestResult {
+    let api = postgres12_online_test_api("indexes_are_created_concurrently_in_online_mode").await;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String @unique
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send_inner().await?;
+
+    assert!(output
+        .database_steps
+        .iter()
+        .any(|step| step.raw.starts_with("CREATE UNIQUE INDEX CONCURRENTLY")));
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_index_on_columns(&["name"], |idx| Ok(idx)))?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn making_a_column_required_in_online_mode_validates_a_check_constraint_first() -> TestResult {
+    let api =
+        postgres12_online_test_api("making_a_column_required_in_online_mode_validates_a_check_constraint_first").await;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send_inner().await?;
+    let raw: Vec<&str> = output.database_steps.iter().map(|step| step.raw.as_str()).collect();
+    let raw = raw.join("\n");
+
+    assert!(raw.contains(r#"ADD CONSTRAINT "Cat_name_not_null" CHECK ("name" IS NOT NULL) NOT VALID"#));
+    assert!(raw.contains(r#"VALIDATE CONSTRAINT "Cat_name_not_null""#));
+    assert!(raw.contains(r#"DROP CONSTRAINT "Cat_name_not_null""#));
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("name", |col| col.assert_is_required())
+    })?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn check_constraint_names_in_online_mode_fit_the_identifier_limit() -> TestResult {
+    let api = postgres12_online_test_api("check_constraint_names_in_online_mode_fit_the_identifier_limit").await;
+
+    let dm1 = r#"
+        model CatWithAVeryLongNameForTesting {
+            id Int @id
+            nameThatIsLongEnoughToExceedTheLimit String?
+            nameThatIsLongEnoughToExceedTheLimitToo String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model CatWithAVeryLongNameForTesting {
+            id Int @id
+            nameThatIsLongEnoughToExceedTheLimit String
+            nameThatIsLongEnoughToExceedTheLimitToo String
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send_inner().await?;
+    let raw: Vec<&str> = output.database_steps.iter().map(|step| step.raw.as_str()).collect();
+    let raw = raw.join("\n");
+
+    let constraint_names: Vec<&str> = raw
+        .lines()
+        .filter(|line| line.contains("VALIDATE CONSTRAINT"))
+        .map(|line| line.rsplit('"').nth(1).unwrap())
+        .collect();
+
+    assert_eq!(constraint_names.len(), 2);
+    assert_ne!(constraint_names[0], constraint_names[1]);
+
+    for name in constraint_names {
+        assert!(name.len() <= 63);
+        assert!(name.starts_with("CatWithAVeryLongNameForTesting_name"));
+        assert!(name.ends_with("_not_null"));
+    }
+
+    api.assert_schema()
+        .await?
+        .assert_table("CatWithAVeryLongNameForTesting", |table| {
+            table.assert_column("nameThatIsLongEnoughToExceedTheLimitToo", |col| {
+                col.assert_is_required()
+            })
+        })?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn changes_rewriting_a_non_empty_table_are_flagged_in_online_mode() -> TestResult {
+    let api = postgres12_online_test_api("changes_rewriting_a_non_empty_table_are_flagged_in_online_mode").await;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            age Int
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat").value("id", 1).value("age", 7).result_raw().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            age String
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send_inner().await?;
+
+    assert!(output.warnings.iter().any(|warning| warning.description
+        == "The migration will change the type of the column `age` on the `Cat` table (1 rows), which blocks writes to the table while it runs, even in online mode."));
+
+    Ok(())
+}
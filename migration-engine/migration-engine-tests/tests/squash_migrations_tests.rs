@@ -0,0 +1,156 @@
+use migration_connector::{DataMigration, DataMigrationPosition, MigrationStatus};
+use migration_engine_tests::*;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn squashing_migrations_must_keep_the_end_state(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("mig01"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("mig02"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm3 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm3)
+        .migration_id(Some("mig03"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let migrations_before = api.migration_persistence().load_all().await?;
+    let output = api.squash_migrations("mig01", "mig02").await?;
+
+    assert_eq!(output.migration_id, "mig02");
+    assert_eq!(output.squashed_migration_ids, &["mig01", "mig02"]);
+
+    let migrations = api.migration_persistence().load_all().await?;
+    let names: Vec<&str> = migrations.iter().map(|migration| migration.name.as_str()).collect();
+
+    assert_eq!(names, &["mig02", "mig03"]);
+    assert_eq!(migrations[0].status, MigrationStatus::MigrationSuccess);
+    assert_eq!(migrations[0].revision, migrations_before[0].revision);
+    assert_eq!(migrations[0].datamodel_string, migrations_before[1].datamodel_string);
+    assert_eq!(migrations[1], migrations_before[2]);
+
+    // Unapplying the squashed migration after the last one leads back to an empty schema.
+    api.unapply_migration().send().await?;
+    api.unapply_migration().send().await?;
+
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_migrations_in_the_wrong_order_must_fail(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("mig01"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("mig02"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let error = api.squash_migrations("mig02", "mig01").await.unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("The migration `mig02` was applied after the migration `mig01`."));
+    assert_eq!(api.migration_persistence().load_all().await?.len(), 2);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_migrations_with_data_migrations_must_fail(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("mig01"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String?
+        }
+    "#;
+
+    let steps = api.infer(dm2).send().await?.datamodel_steps;
+    let script = if api.sql_family().is_mysql() {
+        "UPDATE `Cat` SET `name` = 'Felix'"
+    } else {
+        r#"UPDATE "Cat" SET "name" = 'Felix'"#
+    };
+
+    api.apply()
+        .migration_id(Some("mig02"))
+        .steps(Some(steps.clone()))
+        .data_migrations(Some(vec![DataMigration {
+            script: script.to_owned(),
+            position: DataMigrationPosition::BeforeStep(steps.len()),
+        }]))
+        .send()
+        .await?;
+
+    let error = api.squash_migrations("mig01", "mig02").await.unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Migrations with data migrations can't be squashed, but the migration `mig02` has data migrations."));
+    assert_eq!(api.migration_persistence().load_all().await?.len(), 2);
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use migration_connector::IrreversibleStep;
 use migration_engine_tests::*;
 use quaint::ast as quaint_ast;
 
@@ -73,3 +74,85 @@ async fn destructive_change_checks_run_on_unapply_migration(api: &TestApi) -> Te
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn unapply_runs_the_down_migration_stored_with_the_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String @map("nickname")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("nickname", "Garfield")
+        .result_raw()
+        .await?;
+
+    let last_migration = api.migration_persistence().last().await?.unwrap();
+    assert!(last_migration.down_database_migration.is_some());
+
+    let output = api.unapply_migration().send().await?;
+
+    assert!(output.warnings.is_empty(), "{:?}", output.warnings);
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_has_column("name")?.assert_does_not_have_column("nickname")
+    })?;
+
+    let cat = api.select("Cat").column("id").column("name").send().await?;
+    let cat_row: Vec<quaint_ast::Value> = cat.into_single().unwrap().into_iter().collect();
+    assert_eq!(
+        cat_row,
+        &[quaint_ast::Value::text("garfield"), quaint_ast::Value::text("Garfield")]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn dropping_a_column_with_data_is_flagged_as_irreversible(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            field String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "the-id")
+        .value("field", "meow")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).force(Some(true)).send().await?.into_inner();
+
+    assert_eq!(
+        output.irreversible_steps,
+        &[IrreversibleStep {
+            description: "Dropping the column `field` on the `Test` table can't be reverted: unapplying the migration recreates the column, but not its 1 non-null values.".to_owned(),
+        }]
+    );
+
+    Ok(())
+}
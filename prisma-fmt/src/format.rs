@@ -1,4 +1,4 @@
-use datamodel::ast::reformat::Reformatter;
+use datamodel::{ast::reformat::Reformatter, SchemaFiles};
 use std::{
     fs::{self, File},
     io::{self, BufWriter, Read},
@@ -22,15 +22,24 @@ pub fn run(opts: FormatOpts) {
         }
     };
 
+    let schema_files = opts.schema.as_ref().map(|schema_path| {
+        SchemaFiles::load(schema_path).expect(&format!("Unable to read schema files at {}", schema_path.display()))
+    });
+
+    let reformatter = match &schema_files {
+        Some(schema_files) => Reformatter::new_in_schema(&datamodel_string, schema_files),
+        None => Reformatter::new(&datamodel_string),
+    };
+
     match opts.output {
         Some(file_name) => {
             let file = File::open(&file_name).expect(&format!("Unable to open file {}", file_name.display()));
             let mut stream = BufWriter::new(file);
 
-            Reformatter::new(&datamodel_string).reformat_to(&mut stream, opts.tabwidth);
+            reformatter.reformat_to(&mut stream, opts.tabwidth);
         }
         None => {
-            Reformatter::new(&datamodel_string).reformat_to(&mut io::stdout().lock(), opts.tabwidth);
+            reformatter.reformat_to(&mut io::stdout().lock(), opts.tabwidth);
         }
     }
 }
@@ -1,4 +1,11 @@
+mod config;
+mod rules;
+
+pub use config::Severity;
+pub use rules::Fix;
+
 use crate::{LintOpts, MiniError};
+use config::LintConfig;
 use datamodel::error::DatamodelError;
 use serde_json;
 use std::io::{self, Read};
@@ -16,22 +23,52 @@ pub fn run(opts: LintOpts) {
         datamodel::parse_datamodel(&datamodel_string)
     };
 
-    match datamodel_result {
-        Err(err) => {
-            let mini_errors: Vec<MiniError> = err
-                .errors
-                .iter()
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
-                })
-                .collect();
-
-            let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
-
-            print!("{}", json)
-        }
-        _ => print!("[]"),
-    }
+    let mini_errors: Vec<MiniError> = match datamodel_result {
+        Err(err) => err
+            .errors
+            .iter()
+            .map(|err: &DatamodelError| MiniError {
+                start: err.span().start,
+                end: err.span().end,
+                text: format!("{}", err),
+                rule: None,
+                severity: None,
+                fixes: Vec::new(),
+            })
+            .collect(),
+        Ok(_) if opts.rules || opts.config.is_some() => lint_rules(&opts, &datamodel_string),
+        Ok(_) => Vec::new(),
+    };
+
+    let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
+
+/// Run the lint rules on a valid schema.
+fn lint_rules(opts: &LintOpts, datamodel_string: &str) -> Vec<MiniError> {
+    let config = match &opts.config {
+        Some(path) => match LintConfig::load(path) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!("{}", err);
+                std::process::exit(1)
+            }
+        },
+        None => LintConfig::default(),
+    };
+
+    let ast = datamodel::parse_schema_ast(datamodel_string).expect("Failed to parse a valid schema");
+
+    rules::lint(datamodel_string, &ast, &config)
+        .into_iter()
+        .map(|result| MiniError {
+            start: result.problem.span.start,
+            end: result.problem.span.end,
+            text: result.problem.message,
+            rule: Some(result.rule),
+            severity: Some(result.severity),
+            fixes: result.problem.fixes,
+        })
+        .collect()
 }
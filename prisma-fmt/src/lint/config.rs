@@ -0,0 +1,124 @@
+use super::rules;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, path::Path};
+
+/// The configuration of the lint rules, read from a JSON file like:
+///
+/// ```json
+/// { "rules": { "unused-enum": "off", "relation-scalar-index": "error" } }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    #[serde(default)]
+    rules: HashMap<String, RuleSetting>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RuleSetting {
+    Off,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl LintConfig {
+    pub fn load(path: &Path) -> Result<LintConfig, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read the lint configuration {}: {}", path.display(), err))?;
+
+        Self::parse(&contents).map_err(|err| format!("Invalid lint configuration {}: {}", path.display(), err))
+    }
+
+    fn parse(contents: &str) -> Result<LintConfig, String> {
+        let config: LintConfig = serde_json::from_str(contents).map_err(|err| err.to_string())?;
+        let rules = rules::all_rules();
+
+        if let Some(unknown_rule) = config
+            .rules
+            .keys()
+            .find(|rule_id| !rules.iter().any(|rule| rule.id() == rule_id.as_str()))
+        {
+            return Err(format!("Unknown lint rule `{}`.", unknown_rule));
+        }
+
+        Ok(config)
+    }
+
+    /// The severity of the results of the rule, or `None` if it is disabled.
+    pub fn severity(&self, rule_id: &str, default_severity: Severity) -> Option<Severity> {
+        match self.rules.get(rule_id) {
+            None => Some(default_severity),
+            Some(RuleSetting::Off) => None,
+            Some(RuleSetting::Warning) => Some(Severity::Warning),
+            Some(RuleSetting::Error) => Some(Severity::Error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_keep_their_default_severity_when_not_configured() {
+        let config = LintConfig::parse("{}").unwrap();
+
+        assert_eq!(
+            config.severity("unused-enum", Severity::Warning),
+            Some(Severity::Warning)
+        );
+        assert_eq!(config.severity("unused-enum", Severity::Error), Some(Severity::Error));
+    }
+
+    #[test]
+    fn rules_can_be_disabled_and_their_severity_set() {
+        let config = LintConfig::parse(
+            r#"{ "rules": { "unused-enum": "off", "redundant-map": "error", "string-id-default": "warning" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.severity("unused-enum", Severity::Warning), None);
+        assert_eq!(
+            config.severity("redundant-map", Severity::Warning),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            config.severity("string-id-default", Severity::Error),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            config.severity("model-name-convention", Severity::Warning),
+            Some(Severity::Warning)
+        );
+    }
+
+    #[test]
+    fn unknown_rules_are_rejected() {
+        let err = LintConfig::parse(r#"{ "rules": { "no-such-rule": "off" } }"#).unwrap_err();
+
+        assert_eq!(err, "Unknown lint rule `no-such-rule`.");
+    }
+
+    #[test]
+    fn unknown_settings_are_rejected() {
+        assert!(LintConfig::parse(r#"{ "rules": { "unused-enum": "info" } }"#).is_err());
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(LintConfig::parse(r#"{ "rule": { "unused-enum": "off" } }"#).is_err());
+    }
+
+    #[test]
+    fn invalid_json_is_rejected() {
+        assert!(LintConfig::parse("{ rules").is_err());
+    }
+}
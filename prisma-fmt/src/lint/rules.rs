@@ -0,0 +1,118 @@
+//! The lint rules. A rule checks a valid schema for a problem that is not a validation error, and
+//! suggests fixes for it.
+
+mod model_name_convention;
+mod redundant_map;
+mod relation_scalar_index;
+mod string_id_default;
+mod unused_enum;
+
+use super::config::{LintConfig, Severity};
+use datamodel::ast::{reformat::TextEdit, SchemaAst, Span};
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+/// A lint rule.
+pub trait LintRule {
+    /// The id of the rule, used in the configuration and the results.
+    fn id(&self) -> &'static str;
+
+    /// The severity of the results of the rule, when the configuration does not set it.
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, schema: &LintedSchema<'_>, problems: &mut Vec<Problem>);
+}
+
+/// The schema being linted.
+pub struct LintedSchema<'a> {
+    pub text: &'a str,
+    pub ast: &'a SchemaAst,
+}
+
+/// A problem found by a rule.
+pub struct Problem {
+    pub span: Span,
+    pub message: String,
+    pub fixes: Vec<Fix>,
+}
+
+/// The result of a rule: a problem, with the rule that found it and its configured severity.
+pub struct LintResult {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub problem: Problem,
+}
+
+/// A fix for a problem, made of edits of the schema.
+#[derive(Debug, Clone, Serialize)]
+pub struct Fix {
+    pub message: String,
+    #[serde(serialize_with = "serialize_edits")]
+    pub edits: Vec<TextEdit>,
+}
+
+/// The edits are rendered as the byte offsets of the replaced text, and the new text.
+fn serialize_edits<S: Serializer>(edits: &[TextEdit], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(edits.iter().map(|edit| {
+        json!({
+            "start": edit.span.start,
+            "end": edit.span.end,
+            "text": edit.new_text,
+        })
+    }))
+}
+
+pub fn all_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(model_name_convention::ModelNameConvention),
+        Box::new(relation_scalar_index::RelationScalarIndex),
+        Box::new(string_id_default::StringIdDefault),
+        Box::new(redundant_map::RedundantMap),
+        Box::new(unused_enum::UnusedEnum),
+    ]
+}
+
+/// Run the rules enabled in the configuration on the schema.
+pub fn lint(text: &str, ast: &SchemaAst, config: &LintConfig) -> Vec<LintResult> {
+    let schema = LintedSchema { text, ast };
+    let mut results = Vec::new();
+
+    for rule in all_rules() {
+        let severity = match config.severity(rule.id(), rule.default_severity()) {
+            Some(severity) => severity,
+            None => continue,
+        };
+
+        let mut problems = Vec::new();
+        rule.check(&schema, &mut problems);
+
+        results.extend(problems.into_iter().map(|problem| LintResult {
+            rule: rule.id(),
+            severity,
+            problem,
+        }));
+    }
+
+    results.sort_by_key(|result| result.problem.span.start);
+
+    results
+}
+
+/// The problems the rule finds in the schema.
+#[cfg(test)]
+fn check(rule: &dyn LintRule, text: &str) -> Vec<Problem> {
+    let ast = datamodel::parse_schema_ast(text).unwrap();
+    let mut problems = Vec::new();
+
+    rule.check(&LintedSchema { text, ast: &ast }, &mut problems);
+
+    problems
+}
+
+/// The schema with the edits of the fix applied.
+#[cfg(test)]
+fn apply_fix(text: &str, fix: &Fix) -> String {
+    datamodel::ast::reformat::edits::apply_edits(text, &fix.edits)
+}
@@ -0,0 +1,183 @@
+use super::{Fix, LintRule, LintedSchema, Problem};
+use datamodel::ast::{
+    reformat::edits::{add_line_to_model, find_directive},
+    reformat::TextEdit,
+    Span,
+};
+
+/// Model names are singular and in PascalCase, like `BlogPost`. The fix renames the model and maps
+/// it to its former name, so the table keeps its name.
+pub struct ModelNameConvention;
+
+impl LintRule for ModelNameConvention {
+    fn id(&self) -> &'static str {
+        "model-name-convention"
+    }
+
+    fn check(&self, schema: &LintedSchema<'_>, problems: &mut Vec<Problem>) {
+        for model in schema.ast.models() {
+            let name = &model.name.name;
+            let pascal_case = to_pascal_case(name);
+            let suggestion = singularize(&pascal_case).unwrap_or_else(|| pascal_case.clone());
+
+            if &suggestion == name || schema.ast.find_model(&suggestion).is_some() {
+                continue;
+            }
+
+            let message = if &pascal_case != name {
+                format!("The model name `{}` is not in PascalCase.", name)
+            } else {
+                format!("The model name `{}` is plural. Model names should be singular.", name)
+            };
+
+            let mut edits = vec![TextEdit::replace(model.name.span, suggestion.clone())];
+
+            for field in schema.ast.models().iter().flat_map(|model| model.fields.iter()) {
+                if &field.field_type.name == name {
+                    // The span of the type includes the arity, like `Users[]`.
+                    let type_span = field.field_type.span;
+                    let start = type_span.start
+                        + schema.text[type_span.start..type_span.end]
+                            .find(name.as_str())
+                            .unwrap_or(0);
+
+                    edits.push(TextEdit::replace(
+                        Span::new(start, start + name.len()),
+                        suggestion.clone(),
+                    ));
+                }
+            }
+
+            if find_directive(&model.directives, "map").is_none() {
+                edits.push(add_line_to_model(schema.text, model, &format!("@@map(\"{}\")", name)));
+            }
+
+            problems.push(Problem {
+                span: model.name.span,
+                message,
+                fixes: vec![Fix {
+                    message: format!("Rename the model to `{}`.", suggestion),
+                    edits,
+                }],
+            });
+        }
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split(|c: char| c == '_' || c == '-')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase());
+
+            first.into_iter().chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+/// Words ending in `s` that are singular, or that have no singular. The inflector pluralizes their
+/// stems to them, like `New` to `News`.
+const SINGULAR_WORDS: &[&str] = &[
+    "Analytics",
+    "Diabetes",
+    "Economics",
+    "Ethics",
+    "Headquarters",
+    "Logistics",
+    "Mathematics",
+    "Means",
+    "News",
+    "Physics",
+    "Politics",
+    "Series",
+    "Species",
+    "Statistics",
+];
+
+/// The singular of a plural model name, if the name is plural.
+fn singularize(name: &str) -> Option<String> {
+    if !name.ends_with('s') || name.ends_with("ss") || name.ends_with("us") || name.ends_with("is") {
+        return None;
+    }
+
+    // The last word of the name, like `News` in `CompanyNews`.
+    let last_word_start = name.rfind(|c: char| c.is_ascii_uppercase()).unwrap_or(0);
+
+    if SINGULAR_WORDS
+        .iter()
+        .any(|word| word.eq_ignore_ascii_case(&name[last_word_start..]))
+    {
+        return None;
+    }
+
+    let inflector = prisma_inflector::default();
+    let mut candidates = vec![name[..name.len() - 1].to_owned()];
+
+    if name.ends_with("es") {
+        candidates.push(name[..name.len() - 2].to_owned());
+    }
+
+    if name.ends_with("ies") {
+        candidates.push(format!("{}y", &name[..name.len() - 3]));
+    }
+
+    candidates
+        .into_iter()
+        .find(|candidate| !candidate.is_empty() && inflector.pluralize(candidate) == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{apply_fix, check};
+    use super::*;
+
+    #[test]
+    fn model_names_not_in_pascal_case_are_renamed_and_mapped() {
+        let schema = "model blog_post {\n  id Int @id\n}\n\nmodel User {\n  id Int @id\n  posts blog_post[]\n}\n";
+        let problems = check(&ModelNameConvention, schema);
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].message, "The model name `blog_post` is not in PascalCase.");
+        assert_eq!(
+            apply_fix(schema, &problems[0].fixes[0]),
+            "model BlogPost {\n  id Int @id\n  @@map(\"blog_post\")\n}\n\nmodel User {\n  id Int @id\n  posts BlogPost[]\n}\n"
+        );
+    }
+
+    #[test]
+    fn plural_model_names_are_renamed_and_mapped() {
+        let schema = "model Users {\n  id Int @id\n}\n";
+        let problems = check(&ModelNameConvention, schema);
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(
+            problems[0].message,
+            "The model name `Users` is plural. Model names should be singular."
+        );
+        assert_eq!(
+            apply_fix(schema, &problems[0].fixes[0]),
+            "model User {\n  id Int @id\n  @@map(\"Users\")\n}\n"
+        );
+    }
+
+    #[test]
+    fn singular_model_names_in_pascal_case_are_not_reported() {
+        let schema = "model BlogPost {\n  id Int @id\n}\n\nmodel Address {\n  id Int @id\n}\n\nmodel Status {\n  id Int @id\n}\n";
+
+        assert!(check(&ModelNameConvention, schema).is_empty());
+    }
+
+    #[test]
+    fn singular_words_ending_in_s_are_not_plural() {
+        assert_eq!(singularize("News"), None);
+        assert_eq!(singularize("CompanyNews"), None);
+        assert_eq!(singularize("TvSeries"), None);
+        assert_eq!(singularize("Species"), None);
+        assert_eq!(singularize("Posts"), Some("Post".to_owned()));
+
+        let schema = "model News {\n  id Int @id\n}\n";
+
+        assert!(check(&ModelNameConvention, schema).is_empty());
+    }
+}
@@ -0,0 +1,85 @@
+use super::{Fix, LintRule, LintedSchema, Problem};
+use datamodel::ast::{
+    self,
+    reformat::edits::{find_directive, remove_attribute},
+};
+
+/// `@map` and `@@map` are not used to map a field, a model, an enum or an enum value to its own
+/// name.
+pub struct RedundantMap;
+
+impl LintRule for RedundantMap {
+    fn id(&self) -> &'static str {
+        "redundant-map"
+    }
+
+    fn check(&self, schema: &LintedSchema<'_>, problems: &mut Vec<Problem>) {
+        let mut check_map = |name: &ast::Identifier, directives: &[ast::Directive]| {
+            let map = match find_directive(directives, "map") {
+                Some(map) => map,
+                None => return,
+            };
+
+            match map.arguments.first().map(|argument| &argument.value) {
+                Some(ast::Expression::StringValue(mapped_name, _)) if mapped_name == &name.name => (),
+                _ => return,
+            }
+
+            problems.push(Problem {
+                span: map.span,
+                message: format!("`{}` is mapped to its own name.", name.name),
+                fixes: vec![Fix {
+                    message: "Remove the mapping.".to_owned(),
+                    edits: vec![remove_attribute(schema.text, map)],
+                }],
+            });
+        };
+
+        for model in schema.ast.models() {
+            check_map(&model.name, &model.directives);
+
+            for field in &model.fields {
+                check_map(&field.name, &field.directives);
+            }
+        }
+
+        for r#enum in schema.ast.enums() {
+            check_map(&r#enum.name, &r#enum.directives);
+
+            for value in &r#enum.values {
+                check_map(&value.name, &value.directives);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{apply_fix, check};
+    use super::*;
+
+    #[test]
+    fn maps_to_the_own_name_are_reported_and_removed() {
+        let schema = "model User {\n  id Int @id\n  email String @map(\"email\")\n\n  @@map(\"User\")\n}\n";
+        let problems = check(&RedundantMap, schema);
+
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0].message, "`User` is mapped to its own name.");
+        assert_eq!(problems[1].message, "`email` is mapped to its own name.");
+        assert_eq!(
+            apply_fix(schema, &problems[0].fixes[0]),
+            "model User {\n  id Int @id\n  email String @map(\"email\")\n\n}\n"
+        );
+        assert_eq!(
+            apply_fix(schema, &problems[1].fixes[0]),
+            "model User {\n  id Int @id\n  email String\n\n  @@map(\"User\")\n}\n"
+        );
+    }
+
+    #[test]
+    fn maps_to_other_names_are_not_reported() {
+        let schema = "model User {\n  id Int @id\n  email String @map(\"email_address\")\n\n  @@map(\"users\")\n}\n";
+
+        assert!(check(&RedundantMap, schema).is_empty());
+    }
+}
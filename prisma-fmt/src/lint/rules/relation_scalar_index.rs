@@ -0,0 +1,160 @@
+use super::{Fix, LintRule, LintedSchema, Problem};
+use datamodel::ast::{
+    self,
+    reformat::edits::{add_line_to_model, field_names, find_argument, find_directive},
+};
+
+/// The scalar fields of a relation are indexed, so the relation can be queried from both sides
+/// without scanning the table. MySQL creates these indexes with the foreign keys, so the rule does
+/// not apply to it.
+pub struct RelationScalarIndex;
+
+impl LintRule for RelationScalarIndex {
+    fn id(&self) -> &'static str {
+        "relation-scalar-index"
+    }
+
+    fn check(&self, schema: &LintedSchema<'_>, problems: &mut Vec<Problem>) {
+        if uses_provider(schema.ast, "mysql") {
+            return;
+        }
+
+        for model in schema.ast.models() {
+            for field in &model.fields {
+                let relation_fields = match find_directive(&field.directives, "relation")
+                    .and_then(|relation| find_argument(relation, "fields"))
+                {
+                    Some(argument) => field_names(&argument.value),
+                    None => continue,
+                };
+
+                if relation_fields.is_empty() || is_indexed(model, &relation_fields) {
+                    continue;
+                }
+
+                let index = format!("@@index([{}])", relation_fields.join(", "));
+
+                problems.push(Problem {
+                    span: field.name.span,
+                    message: format!(
+                        "The relation field `{}` has no index on its scalar fields: {}.",
+                        field.name.name,
+                        relation_fields.join(", ")
+                    ),
+                    fixes: vec![Fix {
+                        message: format!("Add `{}` to the model.", index),
+                        edits: vec![add_line_to_model(schema.text, model, &index)],
+                    }],
+                });
+            }
+        }
+    }
+}
+
+fn uses_provider(ast: &ast::SchemaAst, provider: &str) -> bool {
+    ast.sources().iter().any(|source| {
+        source
+            .properties
+            .iter()
+            .any(|property| property.name.name == "provider" && property_mentions(&property.value, provider))
+    })
+}
+
+fn property_mentions(value: &ast::Expression, provider: &str) -> bool {
+    match value {
+        ast::Expression::StringValue(value, _) => value == provider,
+        ast::Expression::Array(values, _) => values.iter().any(|value| property_mentions(value, provider)),
+        _ => false,
+    }
+}
+
+/// Whether an index, a unique constraint or the id of the model starts with the fields, in any
+/// order.
+fn is_indexed(model: &ast::Model, fields: &[&str]) -> bool {
+    if let [field] = fields {
+        let single_field_index = model
+            .fields
+            .iter()
+            .find(|model_field| model_field.name.name == *field)
+            .map(|model_field| {
+                find_directive(&model_field.directives, "id").is_some()
+                    || find_directive(&model_field.directives, "unique").is_some()
+            })
+            .unwrap_or(false);
+
+        if single_field_index {
+            return true;
+        }
+    }
+
+    model
+        .directives
+        .iter()
+        .filter(|directive| ["index", "unique", "id"].contains(&directive.name.name.as_str()))
+        .filter_map(|directive| {
+            directive
+                .arguments
+                .iter()
+                .find(|arg| arg.is_unnamed() || arg.name.name == "fields")
+        })
+        .any(|argument| {
+            let indexed_fields = field_names(&argument.value);
+
+            indexed_fields.len() >= fields.len()
+                && fields
+                    .iter()
+                    .all(|field| indexed_fields[..fields.len()].contains(field))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{apply_fix, check};
+    use super::*;
+
+    const MODELS: &str = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    #[test]
+    fn relation_scalar_fields_without_an_index_are_reported_and_indexed() {
+        let problems = check(&RelationScalarIndex, MODELS);
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(
+            problems[0].message,
+            "The relation field `author` has no index on its scalar fields: authorId."
+        );
+        assert_eq!(
+            apply_fix(MODELS, &problems[0].fixes[0]),
+            MODELS.replace("[id])\n}", "[id])\n  @@index([authorId])\n}")
+        );
+    }
+
+    #[test]
+    fn indexed_relation_scalar_fields_are_not_reported() {
+        let indexed = MODELS.replace("[id])\n}", "[id])\n\n  @@index([authorId, id])\n}");
+        let unique = MODELS.replace("authorId Int\n", "authorId Int @unique\n");
+
+        assert!(check(&RelationScalarIndex, &indexed).is_empty());
+        assert!(check(&RelationScalarIndex, &unique).is_empty());
+    }
+
+    #[test]
+    fn relations_on_mysql_are_not_reported() {
+        let schema = format!(
+            "datasource db {{\n  provider = \"mysql\"\n  url = \"mysql://localhost/db\"\n}}\n\n{}",
+            MODELS
+        );
+
+        assert!(check(&RelationScalarIndex, &schema).is_empty());
+    }
+}
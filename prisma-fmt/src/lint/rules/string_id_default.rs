@@ -0,0 +1,59 @@
+use super::{Fix, LintRule, LintedSchema, Problem};
+use datamodel::ast::reformat::edits::{add_field_attribute, find_directive};
+
+/// `String` ids have a default, so records can be created without generating an id in the
+/// application.
+pub struct StringIdDefault;
+
+impl LintRule for StringIdDefault {
+    fn id(&self) -> &'static str {
+        "string-id-default"
+    }
+
+    fn check(&self, schema: &LintedSchema<'_>, problems: &mut Vec<Problem>) {
+        for model in schema.ast.models() {
+            for field in model.fields.iter().filter(|field| field.field_type.name == "String") {
+                if find_directive(&field.directives, "id").is_none()
+                    || find_directive(&field.directives, "default").is_some()
+                {
+                    continue;
+                }
+
+                problems.push(Problem {
+                    span: field.name.span,
+                    message: format!("The `String` id field `{}` has no default value.", field.name.name),
+                    fixes: vec![Fix {
+                        message: "Add `@default(cuid())` to the field.".to_owned(),
+                        edits: vec![add_field_attribute(field, "@default(cuid())")],
+                    }],
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{apply_fix, check};
+    use super::*;
+
+    #[test]
+    fn string_ids_without_a_default_are_reported_and_get_a_cuid() {
+        let schema = "model User {\n  id String @id\n}\n";
+        let problems = check(&StringIdDefault, schema);
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].message, "The `String` id field `id` has no default value.");
+        assert_eq!(
+            apply_fix(schema, &problems[0].fixes[0]),
+            "model User {\n  id String @id @default(cuid())\n}\n"
+        );
+    }
+
+    #[test]
+    fn string_ids_with_a_default_and_int_ids_are_not_reported() {
+        let schema = "model User {\n  id String @id @default(uuid())\n}\n\nmodel Post {\n  id Int @id\n}\n";
+
+        assert!(check(&StringIdDefault, schema).is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+use super::{Fix, LintRule, LintedSchema, Problem};
+use datamodel::ast::{reformat::TextEdit, Span};
+
+/// Enums are used by at least one field.
+pub struct UnusedEnum;
+
+impl LintRule for UnusedEnum {
+    fn id(&self) -> &'static str {
+        "unused-enum"
+    }
+
+    fn check(&self, schema: &LintedSchema<'_>, problems: &mut Vec<Problem>) {
+        let field_types: Vec<&str> = schema
+            .ast
+            .models()
+            .iter()
+            .flat_map(|model| model.fields.iter())
+            .chain(
+                schema
+                    .ast
+                    .composite_types()
+                    .into_iter()
+                    .flat_map(|composite_type| composite_type.fields.iter()),
+            )
+            .chain(schema.ast.types())
+            .map(|field| field.field_type.name.as_str())
+            .collect();
+
+        for r#enum in schema.ast.enums() {
+            if field_types.contains(&r#enum.name.name.as_str()) {
+                continue;
+            }
+
+            let mut end = r#enum.span.end;
+
+            if schema.text[end..].starts_with("\r\n") {
+                end += 2;
+            } else if schema.text[end..].starts_with('\n') {
+                end += 1;
+            }
+
+            problems.push(Problem {
+                span: r#enum.name.span,
+                message: format!("The enum `{}` is not used by any field.", r#enum.name.name),
+                fixes: vec![Fix {
+                    message: "Remove the enum.".to_owned(),
+                    edits: vec![TextEdit::delete(Span::new(r#enum.span.start, end))],
+                }],
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{apply_fix, check};
+    use super::*;
+
+    #[test]
+    fn unused_enums_are_reported_and_removed() {
+        let schema = "model User {\n  id Int @id\n}\n\nenum Role {\n  USER\n}\n";
+        let problems = check(&UnusedEnum, schema);
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].message, "The enum `Role` is not used by any field.");
+        assert_eq!(
+            apply_fix(schema, &problems[0].fixes[0]),
+            "model User {\n  id Int @id\n}\n\n"
+        );
+    }
+
+    #[test]
+    fn enums_used_by_a_field_are_not_reported() {
+        let schema = "model User {\n  id Int @id\n  role Role?\n}\n\nenum Role {\n  USER\n}\n";
+
+        assert!(check(&UnusedEnum, schema).is_empty());
+    }
+}
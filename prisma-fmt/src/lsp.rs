@@ -0,0 +1,314 @@
+//! A language server for schema files, speaking the Language Server Protocol over STDIN and STDOUT.
+
+mod completion;
+mod diagnostics;
+mod docs;
+mod hover;
+mod navigation;
+mod protocol;
+mod transport;
+
+use datamodel::ast::SchemaAst;
+use protocol::*;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io::{self, BufReader},
+};
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+pub fn run() {
+    let stdin = io::stdin();
+    let mut reader = BufReader::new(stdin.lock());
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+    let mut server = Server::default();
+
+    loop {
+        let message = match transport::read_message(&mut reader) {
+            Ok(Some(message)) => message,
+            // A malformed message is skipped, the next one may be fine.
+            Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                let response = json!({
+                    "jsonrpc": "2.0",
+                    "id": Value::Null,
+                    "error": { "code": PARSE_ERROR, "message": err.to_string() },
+                });
+
+                transport::write_message(&mut writer, &response).expect("Unable to write to stdout.");
+                continue;
+            }
+            // The input ended, or the client went away.
+            Ok(None) | Err(_) => break,
+        };
+
+        let method = message["method"].as_str().unwrap_or("");
+
+        if method == "exit" {
+            break;
+        }
+
+        let params = message["params"].clone();
+
+        match message.get("id") {
+            Some(id) => {
+                let response = match server.handle_request(method, params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, message)) => {
+                        json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+                    }
+                };
+
+                transport::write_message(&mut writer, &response).expect("Unable to write to stdout.");
+            }
+            None => {
+                for notification in server.handle_notification(method, params) {
+                    transport::write_message(&mut writer, &notification).expect("Unable to write to stdout.");
+                }
+            }
+        }
+    }
+}
+
+type RequestResult = Result<Value, (i64, String)>;
+
+/// The open documents.
+#[derive(Default)]
+struct Server {
+    documents: HashMap<String, String>,
+}
+
+impl Server {
+    fn handle_request(&mut self, method: &str, params: Value) -> RequestResult {
+        match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "referencesProvider": true,
+                    "renameProvider": true,
+                    "codeActionProvider": true,
+                    "completionProvider": { "triggerCharacters": ["@", "(", " ", ","] },
+                },
+                "serverInfo": { "name": "prisma-fmt" },
+            })),
+            "shutdown" => Ok(Value::Null),
+            "textDocument/hover" => self.hover(parse_params(params)?),
+            "textDocument/definition" => self.definition(parse_params(params)?),
+            "textDocument/references" => self.references(parse_params(params)?),
+            "textDocument/rename" => self.rename(parse_params(params)?),
+            "textDocument/completion" => self.completion(parse_params(params)?),
+            "textDocument/codeAction" => self.code_actions(parse_params(params)?),
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method: {}", method))),
+        }
+    }
+
+    /// Handle a notification, returning the notifications to send back.
+    fn handle_notification(&mut self, method: &str, params: Value) -> Vec<Value> {
+        match method {
+            "textDocument/didOpen" => match serde_json::from_value::<DidOpenTextDocumentParams>(params) {
+                Ok(params) => self.update_document(params.text_document.uri, params.text_document.text),
+                Err(_) => Vec::new(),
+            },
+            "textDocument/didChange" => match serde_json::from_value::<DidChangeTextDocumentParams>(params) {
+                Ok(mut params) => match params.content_changes.pop() {
+                    Some(change) => self.update_document(params.text_document.uri, change.text),
+                    None => Vec::new(),
+                },
+                Err(_) => Vec::new(),
+            },
+            "textDocument/didClose" => match serde_json::from_value::<DidCloseTextDocumentParams>(params) {
+                Ok(params) => {
+                    let uri = params.text_document.uri;
+
+                    self.documents.remove(&uri);
+
+                    vec![publish_diagnostics(&uri, Vec::new())]
+                }
+                Err(_) => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    fn update_document(&mut self, uri: String, text: String) -> Vec<Value> {
+        let notification = publish_diagnostics(&uri, diagnostics::diagnostics(&text));
+
+        self.documents.insert(uri, text);
+
+        vec![notification]
+    }
+
+    fn hover(&self, params: TextDocumentPositionParams) -> RequestResult {
+        let (text, ast) = match self.parsed_document(&params.text_document.uri) {
+            Some(document) => document,
+            None => return Ok(Value::Null),
+        };
+
+        let offset = position_to_offset(text, params.position);
+
+        Ok(match hover::hover(&ast, offset) {
+            Some((markdown, span)) => json!({
+                "contents": { "kind": "markdown", "value": markdown },
+                "range": span_to_range(text, span),
+            }),
+            None => Value::Null,
+        })
+    }
+
+    fn definition(&self, params: TextDocumentPositionParams) -> RequestResult {
+        let uri = params.text_document.uri;
+        let (text, ast) = match self.parsed_document(&uri) {
+            Some(document) => document,
+            None => return Ok(Value::Null),
+        };
+
+        let occurrences = navigation::occurrences(&ast);
+        let offset = position_to_offset(text, params.position);
+
+        let declaration = navigation::occurrence_at(&occurrences, offset)
+            .and_then(|occurrence| navigation::declaration(&occurrences, &occurrence.symbol));
+
+        Ok(match declaration {
+            Some(declaration) => json!(Location {
+                uri,
+                range: span_to_range(text, declaration.span),
+            }),
+            None => Value::Null,
+        })
+    }
+
+    fn references(&self, params: ReferenceParams) -> RequestResult {
+        let uri = params.text_document.uri;
+        let (text, ast) = match self.parsed_document(&uri) {
+            Some(document) => document,
+            None => return Ok(Value::Null),
+        };
+
+        let occurrences = navigation::occurrences(&ast);
+        let offset = position_to_offset(text, params.position);
+
+        let symbol = match navigation::occurrence_at(&occurrences, offset) {
+            Some(occurrence) => occurrence.symbol.clone(),
+            None => return Ok(Value::Null),
+        };
+
+        let locations: Vec<Location> = navigation::references(&occurrences, &symbol)
+            .filter(|occurrence| params.context.include_declaration || !occurrence.is_declaration)
+            .map(|occurrence| Location {
+                uri: uri.clone(),
+                range: span_to_range(text, occurrence.span),
+            })
+            .collect();
+
+        Ok(json!(locations))
+    }
+
+    fn rename(&self, params: RenameParams) -> RequestResult {
+        let uri = params.text_document.uri;
+        let (text, ast) = match self.parsed_document(&uri) {
+            Some(document) => document,
+            None => return Ok(Value::Null),
+        };
+
+        if !is_valid_identifier(&params.new_name) {
+            return Err((INVALID_PARAMS, format!("`{}` is not a valid name.", params.new_name)));
+        }
+
+        let occurrences = navigation::occurrences(&ast);
+        let offset = position_to_offset(text, params.position);
+
+        let symbol = match navigation::occurrence_at(&occurrences, offset) {
+            Some(occurrence) => occurrence.symbol.clone(),
+            None => return Ok(Value::Null),
+        };
+
+        let edits: Vec<TextEdit> = navigation::references(&occurrences, &symbol)
+            .map(|occurrence| TextEdit {
+                range: span_to_range(text, occurrence.span),
+                new_text: params.new_name.clone(),
+            })
+            .collect();
+
+        Ok(json!({ "changes": { uri: edits } }))
+    }
+
+    fn completion(&self, params: TextDocumentPositionParams) -> RequestResult {
+        let (text, ast) = match self.parsed_document(&params.text_document.uri) {
+            Some(document) => document,
+            None => return Ok(Value::Null),
+        };
+
+        let offset = position_to_offset(text, params.position);
+
+        Ok(json!(completion::completions(text, offset, &ast)))
+    }
+
+    /// The quick fixes of the problems in the range.
+    fn code_actions(&self, params: CodeActionParams) -> RequestResult {
+        let uri = params.text_document.uri;
+        let text = match self.documents.get(&uri) {
+            Some(text) => text,
+            None => return Ok(Value::Null),
+        };
+
+        let start = position_to_offset(text, params.range.start);
+        let end = position_to_offset(text, params.range.end);
+
+        let actions: Vec<Value> = datamodel::code_actions(text)
+            .into_iter()
+            .filter(|action| action.span.start <= end && start <= action.span.end)
+            .map(|action| {
+                let edits: Vec<TextEdit> = action
+                    .edits
+                    .into_iter()
+                    .map(|edit| TextEdit {
+                        range: span_to_range(text, edit.span),
+                        new_text: edit.new_text,
+                    })
+                    .collect();
+
+                json!({
+                    "title": action.title,
+                    "kind": "quickfix",
+                    "edit": { "changes": { uri.clone(): edits } },
+                })
+            })
+            .collect();
+
+        Ok(json!(actions))
+    }
+
+    /// The text of the document, and the well-formed parts of its schema. Navigation needs the spans
+    /// of the current text.
+    fn parsed_document(&self, uri: &str) -> Option<(&str, SchemaAst)> {
+        let text = self.documents.get(uri)?;
+        let (ast, _) = datamodel::parse_schema_ast_with_recovery(text);
+
+        Some((text, ast))
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, (i64, String)> {
+    serde_json::from_value(params).map_err(|err| (INVALID_PARAMS, err.to_string()))
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Diagnostic>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars.next().map(|c| c.is_ascii_alphabetic()).unwrap_or(false)
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
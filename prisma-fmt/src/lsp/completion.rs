@@ -0,0 +1,246 @@
+use super::{
+    docs,
+    protocol::{CompletionItem, CompletionItemKind},
+};
+use datamodel::ast::SchemaAst;
+
+/// What is being typed at the cursor.
+#[derive(Debug, PartialEq)]
+enum CompletionContext<'a> {
+    /// The name of an attribute, after `@` or `@@`.
+    Attribute { is_block_attribute: bool },
+    /// An argument of an attribute, inside its parentheses.
+    AttributeArgument {
+        attribute: &'a str,
+        is_block_attribute: bool,
+    },
+    /// The type of a field.
+    FieldType,
+}
+
+/// The completions at the offset. The context is found from the text of the line, so completion
+/// keeps working while the schema doesn't parse. The well-formed parts of the schema provide the
+/// models, composite types and enums.
+pub fn completions(text: &str, offset: usize, ast: &SchemaAst) -> Vec<CompletionItem> {
+    let line_start = text[..offset].rfind('\n').map(|newline| newline + 1).unwrap_or(0);
+
+    match completion_context(&text[line_start..offset]) {
+        Some(CompletionContext::Attribute { is_block_attribute }) => docs::ATTRIBUTES
+            .iter()
+            .filter(|attribute| attribute.is_block_attribute == is_block_attribute)
+            .map(|attribute| CompletionItem {
+                label: attribute.name.to_owned(),
+                kind: CompletionItemKind::Property as u8,
+                detail: None,
+                documentation: Some(attribute.documentation.to_owned()),
+                insert_text: None,
+            })
+            .collect(),
+        Some(CompletionContext::AttributeArgument {
+            attribute,
+            is_block_attribute,
+        }) => docs::find_attribute(attribute, is_block_attribute)
+            .map(|attribute| attribute.arguments)
+            .unwrap_or(&[])
+            .iter()
+            .map(|argument| CompletionItem {
+                label: (*argument).to_owned(),
+                kind: CompletionItemKind::Field as u8,
+                detail: None,
+                documentation: None,
+                insert_text: Some(format!("{}: ", argument)),
+            })
+            .collect(),
+        Some(CompletionContext::FieldType) => {
+            let scalar_types = docs::SCALAR_TYPES.iter().map(|(name, documentation)| CompletionItem {
+                label: (*name).to_owned(),
+                kind: CompletionItemKind::TypeParameter as u8,
+                detail: None,
+                documentation: Some((*documentation).to_owned()),
+                insert_text: None,
+            });
+
+            let models = ast.models().into_iter().map(|model| CompletionItem {
+                label: model.name.name.clone(),
+                kind: CompletionItemKind::Class as u8,
+                detail: Some("model".to_owned()),
+                documentation: model.documentation.as_ref().map(|comment| comment.text.clone()),
+                insert_text: None,
+            });
+
+            let composite_types = ast.composite_types().into_iter().map(|composite_type| CompletionItem {
+                label: composite_type.name.name.clone(),
+                kind: CompletionItemKind::Struct as u8,
+                detail: Some("type".to_owned()),
+                documentation: composite_type
+                    .documentation
+                    .as_ref()
+                    .map(|comment| comment.text.clone()),
+                insert_text: None,
+            });
+
+            let enums = ast.enums().into_iter().map(|r#enum| CompletionItem {
+                label: r#enum.name.name.clone(),
+                kind: CompletionItemKind::Enum as u8,
+                detail: Some("enum".to_owned()),
+                documentation: r#enum.documentation.as_ref().map(|comment| comment.text.clone()),
+                insert_text: None,
+            });
+
+            scalar_types.chain(models).chain(composite_types).chain(enums).collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+fn completion_context(line_before_cursor: &str) -> Option<CompletionContext<'_>> {
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.';
+
+    // Inside the parentheses of an attribute.
+    if let Some(open_paren) = unclosed_paren(line_before_cursor) {
+        let before_paren = &line_before_cursor[..open_paren];
+        let attribute_start = before_paren.trim_end_matches(is_identifier_char).len();
+        let attribute = &before_paren[attribute_start..];
+        let before_attribute = &before_paren[..attribute_start];
+
+        if !attribute.is_empty() && before_attribute.ends_with('@') {
+            let arguments = &line_before_cursor[open_paren + 1..];
+
+            // Arguments are named after the opening parenthesis or a comma, outside of lists.
+            if arguments.matches('[').count() > arguments.matches(']').count() {
+                return None;
+            }
+
+            let current_argument = arguments.rsplit(',').next().unwrap_or("").trim_start();
+
+            if !current_argument.chars().all(is_identifier_char) {
+                return None;
+            }
+
+            return Some(CompletionContext::AttributeArgument {
+                attribute,
+                is_block_attribute: before_attribute.ends_with("@@"),
+            });
+        }
+
+        return None;
+    }
+
+    let word_start = line_before_cursor.trim_end_matches(is_identifier_char).len();
+    let before_word = &line_before_cursor[..word_start];
+
+    if before_word.ends_with('@') {
+        return Some(CompletionContext::Attribute {
+            is_block_attribute: before_word.ends_with("@@"),
+        });
+    }
+
+    // A field declaration is its name, then its type.
+    let mut words_before = before_word.split_whitespace();
+    let field_name = words_before.next()?;
+
+    if words_before.next().is_none()
+        && before_word.ends_with(char::is_whitespace)
+        && field_name.chars().all(is_identifier_char)
+        && !["model", "enum", "datasource", "generator", "type"].contains(&field_name)
+    {
+        return Some(CompletionContext::FieldType);
+    }
+
+    None
+}
+
+/// The offset of the outermost opening parenthesis that is not closed.
+fn unclosed_paren(text: &str) -> Option<usize> {
+    let mut open_parens = Vec::new();
+
+    for (offset, c) in text.char_indices() {
+        match c {
+            '(' => open_parens.push(offset),
+            ')' => {
+                open_parens.pop();
+            }
+            _ => (),
+        }
+    }
+
+    open_parens.first().copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_types_are_completed_after_the_field_name() {
+        assert_eq!(completion_context("  name "), Some(CompletionContext::FieldType));
+        assert_eq!(completion_context("  name Str"), Some(CompletionContext::FieldType));
+    }
+
+    #[test]
+    fn block_keywords_are_not_field_names() {
+        assert_eq!(completion_context("model "), None);
+        assert_eq!(completion_context("enum "), None);
+    }
+
+    #[test]
+    fn nothing_is_completed_after_the_field_type() {
+        assert_eq!(completion_context("  name String "), None);
+    }
+
+    #[test]
+    fn field_attributes_are_completed_after_an_at_sign() {
+        assert_eq!(
+            completion_context("  id Int @"),
+            Some(CompletionContext::Attribute {
+                is_block_attribute: false
+            })
+        );
+        assert_eq!(
+            completion_context("  id Int @def"),
+            Some(CompletionContext::Attribute {
+                is_block_attribute: false
+            })
+        );
+    }
+
+    #[test]
+    fn block_attributes_are_completed_after_two_at_signs() {
+        assert_eq!(
+            completion_context("  @@"),
+            Some(CompletionContext::Attribute {
+                is_block_attribute: true
+            })
+        );
+    }
+
+    #[test]
+    fn attribute_arguments_are_completed_after_the_parenthesis_and_commas() {
+        assert_eq!(
+            completion_context("  author User @relation("),
+            Some(CompletionContext::AttributeArgument {
+                attribute: "relation",
+                is_block_attribute: false
+            })
+        );
+        assert_eq!(
+            completion_context("  @@index(fields: [a, b], na"),
+            Some(CompletionContext::AttributeArgument {
+                attribute: "index",
+                is_block_attribute: true
+            })
+        );
+    }
+
+    #[test]
+    fn attribute_arguments_are_not_completed_inside_lists_or_values() {
+        assert_eq!(completion_context("  @@index(fields: [a, "), None);
+        assert_eq!(completion_context("  name String @default(\"a "), None);
+        assert_eq!(completion_context("  id Int @default(now("), None);
+    }
+
+    #[test]
+    fn parentheses_without_an_attribute_are_not_completed() {
+        assert_eq!(completion_context("  id Int ("), None);
+    }
+}
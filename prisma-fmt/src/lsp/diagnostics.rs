@@ -0,0 +1,44 @@
+use super::protocol::{span_to_range, Diagnostic, DiagnosticSeverity};
+use datamodel::error::DatamodelError;
+
+/// Validate the schema. Missing environment variables are reported as warnings, since schemas
+/// are usually edited without them, and the rest of the schema is still validated.
+pub fn diagnostics(text: &str) -> Vec<Diagnostic> {
+    let error_collection = match datamodel::parse_datamodel(text) {
+        Ok(_) => return Vec::new(),
+        Err(errors) => errors,
+    };
+
+    let (warnings, mut errors): (Vec<&DatamodelError>, Vec<&DatamodelError>) = error_collection
+        .to_iter()
+        .partition(|error| matches!(error, DatamodelError::EnvironmentFunctionalEvaluationError { .. }));
+
+    let ignoring_env_errors = if warnings.is_empty() {
+        None
+    } else {
+        datamodel::parse_datamodel_and_ignore_datasource_urls(text).err()
+    };
+
+    if let Some(ignoring_env_errors) = &ignoring_env_errors {
+        errors = ignoring_env_errors.to_iter().collect();
+    }
+
+    warnings
+        .into_iter()
+        .map(|warning| diagnostic(text, warning, DiagnosticSeverity::Warning))
+        .chain(
+            errors
+                .into_iter()
+                .map(|error| diagnostic(text, error, DiagnosticSeverity::Error)),
+        )
+        .collect()
+}
+
+fn diagnostic(text: &str, error: &DatamodelError, severity: DiagnosticSeverity) -> Diagnostic {
+    Diagnostic {
+        range: span_to_range(text, error.span()),
+        severity: severity as u8,
+        source: "prisma",
+        message: error.description(),
+    }
+}
@@ -0,0 +1,120 @@
+//! Documentation of the builtin types and attributes, for hovers and completions.
+
+/// A builtin attribute.
+pub struct AttributeDoc {
+    pub name: &'static str,
+    /// Whether it is a block attribute (`@@name`), or a field attribute (`@name`).
+    pub is_block_attribute: bool,
+    pub documentation: &'static str,
+    /// The names of its named arguments.
+    pub arguments: &'static [&'static str],
+}
+
+pub const ATTRIBUTES: &[AttributeDoc] = &[
+    AttributeDoc {
+        name: "id",
+        is_block_attribute: false,
+        documentation: "Defines a single-field ID on the model.",
+        arguments: &[],
+    },
+    AttributeDoc {
+        name: "unique",
+        is_block_attribute: false,
+        documentation: "Defines a unique constraint for this field.",
+        arguments: &[],
+    },
+    AttributeDoc {
+        name: "default",
+        is_block_attribute: false,
+        documentation: "Defines a default value for this field. It can be a constant, or one of the functions \
+                        `autoincrement()`, `sequence(\"name\")`, `cuid()`, `uuid()`, `uuid(7)`, `ulid()`, \
+                        `nanoid()`, `nanoid(length)`, `now()` and `dbgenerated()`.",
+        arguments: &[],
+    },
+    AttributeDoc {
+        name: "relation",
+        is_block_attribute: false,
+        documentation: "Defines meta information about the relation. `fields` are the scalar fields of this model \
+                        holding the foreign key, and `references` the fields of the related model they point to.",
+        arguments: &["name", "fields", "references", "onDelete"],
+    },
+    AttributeDoc {
+        name: "map",
+        is_block_attribute: false,
+        documentation: "Maps a field name or enum value from the schema to a column or value with a different name \
+                        in the database.",
+        arguments: &["name"],
+    },
+    AttributeDoc {
+        name: "updatedAt",
+        is_block_attribute: false,
+        documentation: "Automatically stores the time when a record was last updated.",
+        arguments: &[],
+    },
+    AttributeDoc {
+        name: "ignore",
+        is_block_attribute: false,
+        documentation: "Leaves this field out of the Prisma Client.",
+        arguments: &[],
+    },
+    AttributeDoc {
+        name: "id",
+        is_block_attribute: true,
+        documentation: "Defines a multi-field ID on the model.",
+        arguments: &["fields"],
+    },
+    AttributeDoc {
+        name: "unique",
+        is_block_attribute: true,
+        documentation: "Defines a compound unique constraint for the specified fields.",
+        arguments: &["fields", "name"],
+    },
+    AttributeDoc {
+        name: "index",
+        is_block_attribute: true,
+        documentation: "Defines an index on the specified fields.",
+        arguments: &["fields", "name"],
+    },
+    AttributeDoc {
+        name: "map",
+        is_block_attribute: true,
+        documentation: "Maps the model or enum name from the schema to a table or enum with a different name in \
+                        the database.",
+        arguments: &["name"],
+    },
+    AttributeDoc {
+        name: "ignore",
+        is_block_attribute: true,
+        documentation: "Leaves this model out of the Prisma Client, e.g. because its table has no unique identifier.",
+        arguments: &[],
+    },
+    AttributeDoc {
+        name: "embedded",
+        is_block_attribute: true,
+        documentation: "Defines the model as embedded in other models.",
+        arguments: &[],
+    },
+];
+
+/// The builtin scalar types and their documentation.
+pub const SCALAR_TYPES: &[(&str, &str)] = &[
+    ("String", "Variable length text."),
+    ("Boolean", "True or false value."),
+    ("Int", "Integer value."),
+    ("Float", "Floating point number."),
+    ("DateTime", "Timestamp."),
+    ("Json", "A JSON object."),
+];
+
+pub fn find_attribute(name: &str, is_block_attribute: bool) -> Option<&'static AttributeDoc> {
+    ATTRIBUTES
+        .iter()
+        .find(|attribute| attribute.name == name && attribute.is_block_attribute == is_block_attribute)
+}
+
+pub fn find_scalar_type(name: &str) -> Option<&'static str> {
+    SCALAR_TYPES
+        .iter()
+        .find(|(type_name, _)| *type_name == name)
+        .map(|(_, documentation)| *documentation)
+}
@@ -0,0 +1,121 @@
+use super::{
+    docs,
+    navigation::{self, Symbol},
+};
+use datamodel::ast::{self, SchemaAst, Span, WithDocumentation};
+
+/// The markdown documentation and span of the name at the offset: a model, composite type, enum or
+/// field with its doc comments, a builtin scalar type or an attribute.
+pub fn hover(ast: &SchemaAst, offset: usize) -> Option<(String, Span)> {
+    let occurrences = navigation::occurrences(ast);
+
+    if let Some(occurrence) = navigation::occurrence_at(&occurrences, offset) {
+        return symbol_documentation(ast, &occurrence.symbol).map(|documentation| (documentation, occurrence.span));
+    }
+
+    for model in ast.models() {
+        if !contains(model.span, offset) {
+            continue;
+        }
+
+        for directive in &model.directives {
+            if contains(directive.name.span, offset) {
+                return attribute_documentation(&directive.name.name, true).map(|doc| (doc, directive.name.span));
+            }
+        }
+
+        for field in &model.fields {
+            let type_name = &field.field_type.name;
+            let type_span = Span::new(
+                field.field_type.span.start,
+                field.field_type.span.start + type_name.len(),
+            );
+
+            if contains(type_span, offset) {
+                return docs::find_scalar_type(type_name)
+                    .map(|doc| (format!("```prisma\n{}\n```\n{}", type_name, doc), type_span));
+            }
+
+            for directive in &field.directives {
+                if contains(directive.name.span, offset) {
+                    return attribute_documentation(&directive.name.name, false).map(|doc| (doc, directive.name.span));
+                }
+            }
+        }
+    }
+
+    for r#enum in ast.enums() {
+        for directive in &r#enum.directives {
+            if contains(directive.name.span, offset) {
+                return attribute_documentation(&directive.name.name, true).map(|doc| (doc, directive.name.span));
+            }
+        }
+    }
+
+    None
+}
+
+fn symbol_documentation(ast: &SchemaAst, symbol: &Symbol) -> Option<String> {
+    let (signature, documentation) = match symbol {
+        Symbol::Model(name) => {
+            let model = ast.find_model(name)?;
+
+            (format!("model {}", name), model.documentation())
+        }
+        Symbol::CompositeType(name) => {
+            let composite_type = ast.find_composite_type(name)?;
+
+            (format!("type {}", name), composite_type.documentation())
+        }
+        Symbol::Enum(name) => {
+            let r#enum = ast.find_enum(name)?;
+            let values: Vec<&str> = r#enum.values.iter().map(|value| value.name.name.as_str()).collect();
+
+            (
+                format!("enum {} {{ {} }}", name, values.join(" ")),
+                r#enum.documentation(),
+            )
+        }
+        Symbol::Field { model, field } => {
+            let field = ast.find_field(model, field).or_else(|| {
+                ast.find_composite_type(model)?
+                    .fields
+                    .iter()
+                    .find(|composite_type_field| &composite_type_field.name.name == field)
+            })?;
+            let arity = match field.arity {
+                ast::FieldArity::Required => "",
+                ast::FieldArity::Optional => "?",
+                ast::FieldArity::List => "[]",
+            };
+
+            (
+                format!("{}.{}: {}{}", model, field.name.name, field.field_type.name, arity),
+                field.documentation(),
+            )
+        }
+    };
+
+    let mut markdown = format!("```prisma\n{}\n```", signature);
+
+    if let Some(documentation) = documentation {
+        markdown.push('\n');
+        markdown.push_str(&documentation.text);
+    }
+
+    Some(markdown)
+}
+
+fn attribute_documentation(name: &str, is_block_attribute: bool) -> Option<String> {
+    let attribute = docs::find_attribute(name, is_block_attribute)?;
+    let prefix = if is_block_attribute { "@@" } else { "@" };
+
+    Some(format!(
+        "```prisma\n{}{}\n```\n{}",
+        prefix, name, attribute.documentation
+    ))
+}
+
+fn contains(span: Span, offset: usize) -> bool {
+    span.start <= offset && offset <= span.end
+}
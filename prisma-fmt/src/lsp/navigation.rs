@@ -0,0 +1,312 @@
+//! Resolution of the names in a schema to the models, composite types, enums and fields they refer to, for
+//! go-to-definition, find-references and rename.
+
+use datamodel::ast::{self, Expression, SchemaAst, Span};
+
+/// A model, composite type, enum or field of the schema. The fields of composite types are
+/// fields with the name of the composite type as `model`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Symbol {
+    Model(String),
+    CompositeType(String),
+    Enum(String),
+    Field { model: String, field: String },
+}
+
+/// A name in the schema text, declaring or referring to a symbol.
+#[derive(Debug, Clone)]
+pub struct Occurrence {
+    pub symbol: Symbol,
+    /// The span of the name only.
+    pub span: Span,
+    pub is_declaration: bool,
+}
+
+/// All the names in the schema that refer to a model, composite type, enum or field, with the
+/// declarations.
+pub fn occurrences(ast: &SchemaAst) -> Vec<Occurrence> {
+    let mut occurrences = Vec::new();
+
+    for top in &ast.tops {
+        match top {
+            ast::Top::Model(model) => model_occurrences(ast, model, &mut occurrences),
+            ast::Top::CompositeType(composite_type) => {
+                composite_type_occurrences(ast, composite_type, &mut occurrences)
+            }
+            ast::Top::Enum(r#enum) => occurrences.push(Occurrence {
+                symbol: Symbol::Enum(r#enum.name.name.clone()),
+                span: r#enum.name.span,
+                is_declaration: true,
+            }),
+            ast::Top::Type(type_alias) => {
+                if let Some(occurrence) = field_type_occurrence(ast, type_alias) {
+                    occurrences.push(occurrence);
+                }
+            }
+            ast::Top::Source(_) | ast::Top::Generator(_) => (),
+        }
+    }
+
+    occurrences
+}
+
+/// The occurrence at the offset in the schema text.
+pub fn occurrence_at(occurrences: &[Occurrence], offset: usize) -> Option<&Occurrence> {
+    occurrences
+        .iter()
+        .find(|occurrence| occurrence.span.start <= offset && offset <= occurrence.span.end)
+}
+
+/// The declaration of the symbol.
+pub fn declaration<'a>(occurrences: &'a [Occurrence], symbol: &Symbol) -> Option<&'a Occurrence> {
+    occurrences
+        .iter()
+        .find(|occurrence| occurrence.is_declaration && &occurrence.symbol == symbol)
+}
+
+/// The declaration of the symbol and the names referring to it.
+pub fn references<'a>(occurrences: &'a [Occurrence], symbol: &'a Symbol) -> impl Iterator<Item = &'a Occurrence> + 'a {
+    occurrences
+        .iter()
+        .filter(move |occurrence| &occurrence.symbol == symbol)
+}
+
+fn model_occurrences(ast: &SchemaAst, model: &ast::Model, occurrences: &mut Vec<Occurrence>) {
+    let model_name = &model.name.name;
+
+    occurrences.push(Occurrence {
+        symbol: Symbol::Model(model_name.clone()),
+        span: model.name.span,
+        is_declaration: true,
+    });
+
+    for field in &model.fields {
+        occurrences.push(Occurrence {
+            symbol: Symbol::Field {
+                model: model_name.clone(),
+                field: field.name.name.clone(),
+            },
+            span: field.name.span,
+            is_declaration: true,
+        });
+
+        if let Some(occurrence) = field_type_occurrence(ast, field) {
+            occurrences.push(occurrence);
+        }
+
+        for directive in field
+            .directives
+            .iter()
+            .filter(|directive| directive.name.name == "relation")
+        {
+            for argument in &directive.arguments {
+                // `fields` refers to the fields of this model, `references` to the fields of the
+                // related model.
+                let referenced_model = match argument.name.name.as_str() {
+                    "fields" => model_name,
+                    "references" => &field.field_type.name,
+                    _ => continue,
+                };
+
+                field_list_occurrences(referenced_model, &argument.value, occurrences);
+            }
+        }
+    }
+
+    for directive in &model.directives {
+        if !["id", "unique", "index"].contains(&directive.name.name.as_str()) {
+            continue;
+        }
+
+        for argument in directive
+            .arguments
+            .iter()
+            .filter(|argument| argument.is_unnamed() || argument.name.name == "fields")
+        {
+            field_list_occurrences(model_name, &argument.value, occurrences);
+        }
+    }
+}
+
+fn composite_type_occurrences(ast: &SchemaAst, composite_type: &ast::CompositeType, occurrences: &mut Vec<Occurrence>) {
+    let type_name = &composite_type.name.name;
+
+    occurrences.push(Occurrence {
+        symbol: Symbol::CompositeType(type_name.clone()),
+        span: composite_type.name.span,
+        is_declaration: true,
+    });
+
+    for field in &composite_type.fields {
+        occurrences.push(Occurrence {
+            symbol: Symbol::Field {
+                model: type_name.clone(),
+                field: field.name.name.clone(),
+            },
+            span: field.name.span,
+            is_declaration: true,
+        });
+
+        if let Some(occurrence) = field_type_occurrence(ast, field) {
+            occurrences.push(occurrence);
+        }
+    }
+}
+
+/// The reference to a model, composite type or enum in the type of the field.
+fn field_type_occurrence(ast: &SchemaAst, field: &ast::Field) -> Option<Occurrence> {
+    let type_name = &field.field_type.name;
+
+    let symbol = if ast.find_model(type_name).is_some() {
+        Symbol::Model(type_name.clone())
+    } else if ast.find_composite_type(type_name).is_some() {
+        Symbol::CompositeType(type_name.clone())
+    } else if ast.find_enum(type_name).is_some() {
+        Symbol::Enum(type_name.clone())
+    } else {
+        return None;
+    };
+
+    // The span of the field type includes the `?` or `[]` of the arity.
+    let start = field.field_type.span.start;
+
+    Some(Occurrence {
+        symbol,
+        span: Span::new(start, start + type_name.len()),
+        is_declaration: false,
+    })
+}
+
+fn field_list_occurrences(model: &str, field_list: &Expression, occurrences: &mut Vec<Occurrence>) {
+    let field_names = match field_list {
+        Expression::Array(values, _) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+
+    for field_name in field_names {
+        if let Expression::ConstantValue(field, span) = field_name {
+            occurrences.push(Occurrence {
+                symbol: Symbol::Field {
+                    model: model.to_owned(),
+                    field: field.clone(),
+                },
+                span: *span,
+                is_declaration: false,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+model User {
+  id    Int    @id
+  email String
+  posts Post[]
+  role  Role
+
+  @@unique([email])
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+    fn occurrence_texts(symbol: &Symbol) -> Vec<(&'static str, bool)> {
+        let ast = datamodel::parse_schema_ast(SCHEMA).unwrap();
+        let occurrences = occurrences(&ast);
+
+        references(&occurrences, symbol)
+            .map(|occurrence| {
+                (
+                    &SCHEMA[occurrence.span.start..occurrence.span.end],
+                    occurrence.is_declaration,
+                )
+            })
+            .collect()
+    }
+
+    fn field(model: &str, field: &str) -> Symbol {
+        Symbol::Field {
+            model: model.to_owned(),
+            field: field.to_owned(),
+        }
+    }
+
+    #[test]
+    fn models_are_referred_to_by_field_types() {
+        assert_eq!(
+            occurrence_texts(&Symbol::Model("User".to_owned())),
+            &[("User", true), ("User", false)]
+        );
+    }
+
+    #[test]
+    fn the_spans_of_field_types_exclude_the_arity() {
+        assert_eq!(
+            occurrence_texts(&Symbol::Model("Post".to_owned())),
+            &[("Post", false), ("Post", true)]
+        );
+    }
+
+    #[test]
+    fn enums_are_referred_to_by_field_types() {
+        assert_eq!(
+            occurrence_texts(&Symbol::Enum("Role".to_owned())),
+            &[("Role", false), ("Role", true)]
+        );
+    }
+
+    #[test]
+    fn relation_fields_refer_to_the_fields_of_the_model() {
+        assert_eq!(
+            occurrence_texts(&field("Post", "authorId")),
+            &[("authorId", true), ("authorId", false)]
+        );
+    }
+
+    #[test]
+    fn relation_references_refer_to_the_fields_of_the_related_model() {
+        assert_eq!(occurrence_texts(&field("User", "id")), &[("id", true), ("id", false)]);
+        assert_eq!(occurrence_texts(&field("Post", "id")), &[("id", true)]);
+    }
+
+    #[test]
+    fn block_attributes_refer_to_the_fields_of_the_model() {
+        assert_eq!(
+            occurrence_texts(&field("User", "email")),
+            &[("email", true), ("email", false)]
+        );
+    }
+
+    #[test]
+    fn the_declaration_is_found_from_a_reference() {
+        let ast = datamodel::parse_schema_ast(SCHEMA).unwrap();
+        let occurrences = occurrences(&ast);
+        let reference_offset = SCHEMA.find("User @relation").unwrap() + 1;
+
+        let occurrence = occurrence_at(&occurrences, reference_offset).unwrap();
+        let declaration = declaration(&occurrences, &occurrence.symbol).unwrap();
+
+        assert_eq!(declaration.span.start, SCHEMA.find("User {").unwrap());
+    }
+
+    #[test]
+    fn names_that_are_not_symbols_have_no_occurrence() {
+        let ast = datamodel::parse_schema_ast(SCHEMA).unwrap();
+        let occurrences = occurrences(&ast);
+
+        assert!(occurrence_at(&occurrences, SCHEMA.find("Int").unwrap() + 1).is_none());
+    }
+}
@@ -0,0 +1,266 @@
+//! The subset of the Language Server Protocol types the server uses.
+
+use datamodel::ast::Span;
+use serde::{Deserialize, Serialize};
+
+/// A position in a document. Lines are zero-based, and characters are counted in UTF-16 code
+/// units, as the protocol specifies.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub character: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Location {
+    pub uri: String,
+    pub range: Range,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentIdentifier {
+    pub uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentItem {
+    pub uri: String,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidOpenTextDocumentParams {
+    pub text_document: TextDocumentItem,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentContentChangeEvent {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+    /// With full document synchronization, the last change holds the whole document.
+    pub content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCloseTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentPositionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceContext {
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+    pub context: ReferenceContext,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+    pub new_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeActionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub range: Range,
+}
+
+/// The severities of diagnostics.
+#[derive(Debug, Clone, Copy)]
+pub enum DiagnosticSeverity {
+    Error = 1,
+    Warning = 2,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: u8,
+    pub source: &'static str,
+    pub message: String,
+}
+
+/// The kinds of completion items.
+#[derive(Debug, Clone, Copy)]
+pub enum CompletionItemKind {
+    Field = 5,
+    Class = 7,
+    Property = 10,
+    Enum = 13,
+    Struct = 22,
+    TypeParameter = 25,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insert_text: Option<String>,
+}
+
+/// The offset of the position in the text, clamped to the end of its line.
+pub fn position_to_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(newline) => line_start += newline + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line_end = text[line_start..]
+        .find('\n')
+        .map(|newline| line_start + newline)
+        .unwrap_or_else(|| text.len());
+
+    let mut character = 0;
+
+    for (offset, c) in text[line_start..line_end].char_indices() {
+        if character >= position.character {
+            return line_start + offset;
+        }
+
+        character += c.len_utf16();
+    }
+
+    line_end
+}
+
+/// The position of the offset in the text.
+pub fn offset_to_position(text: &str, offset: usize) -> Position {
+    let offset = offset.min(text.len());
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map(|newline| newline + 1).unwrap_or(0);
+
+    Position {
+        line: before.matches('\n').count(),
+        character: before[line_start..].encode_utf16().count(),
+    }
+}
+
+pub fn span_to_range(text: &str, span: Span) -> Range {
+    Range {
+        start: offset_to_position(text, span.start),
+        end: offset_to_position(text, span.end),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "model A {\n  // 😀 id\n  id Int\n}";
+
+    #[test]
+    fn positions_are_converted_to_offsets() {
+        assert_eq!(position_to_offset(TEXT, Position { line: 0, character: 0 }), 0);
+        assert_eq!(position_to_offset(TEXT, Position { line: 2, character: 2 }), 25);
+        assert_eq!(&TEXT[25..27], "id");
+    }
+
+    #[test]
+    fn surrogate_pairs_count_as_two_characters() {
+        // The emoji is one character of four bytes, and two UTF-16 code units.
+        let offset = position_to_offset(TEXT, Position { line: 1, character: 7 });
+
+        assert_eq!(&TEXT[offset..], " id\n  id Int\n}");
+        assert_eq!(offset_to_position(TEXT, offset), Position { line: 1, character: 7 });
+    }
+
+    #[test]
+    fn positions_inside_a_surrogate_pair_go_to_the_next_character() {
+        let offset = position_to_offset(TEXT, Position { line: 1, character: 7 });
+
+        assert_eq!(position_to_offset(TEXT, Position { line: 1, character: 6 }), offset);
+    }
+
+    #[test]
+    fn positions_past_the_end_of_a_line_are_clamped_to_the_end_of_the_line() {
+        assert_eq!(
+            position_to_offset(
+                TEXT,
+                Position {
+                    line: 0,
+                    character: 100
+                }
+            ),
+            9
+        );
+        assert_eq!(&TEXT[9..10], "\n");
+    }
+
+    #[test]
+    fn positions_past_the_last_line_are_clamped_to_the_end_of_the_text() {
+        assert_eq!(
+            position_to_offset(TEXT, Position { line: 10, character: 0 }),
+            TEXT.len()
+        );
+        assert_eq!(
+            position_to_offset(TEXT, Position { line: 3, character: 10 }),
+            TEXT.len()
+        );
+    }
+
+    #[test]
+    fn offsets_past_the_end_are_clamped_to_the_end_of_the_text() {
+        assert_eq!(offset_to_position(TEXT, 1000), Position { line: 3, character: 1 });
+    }
+
+    #[test]
+    fn spans_are_converted_to_ranges() {
+        let range = span_to_range(TEXT, Span::new(25, 27));
+
+        assert_eq!(
+            range,
+            Range {
+                start: Position { line: 2, character: 2 },
+                end: Position { line: 2, character: 4 },
+            }
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use serde_json::Value;
+use std::io::{self, BufRead, Read, Write};
+
+const CONTENT_LENGTH_HEADER: &str = "Content-Length:";
+
+/// The largest message content read, in bytes. Schemas are far smaller, so larger messages are
+/// rejected instead of allocating whatever the peer announces.
+const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+/// Read a message framed with a `Content-Length` header. Returns `None` at the end of the input.
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length: Option<usize> = None;
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if header.starts_with(CONTENT_LENGTH_HEADER) {
+            let length = header[CONTENT_LENGTH_HEADER.len()..]
+                .trim()
+                .parse()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+            content_length = Some(length);
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing Content-Length header."))?;
+
+    if content_length > MAX_CONTENT_LENGTH {
+        // Skip the content, so the next message can still be read.
+        io::copy(&mut reader.by_ref().take(content_length as u64), &mut io::sink())?;
+
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "The message is {} bytes long, the limit is {} bytes.",
+                content_length, MAX_CONTENT_LENGTH
+            ),
+        ));
+    }
+
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Write a message framed with a `Content-Length` header.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let content = serde_json::to_string(message)?;
+
+    write!(writer, "Content-Length: {}\r\n\r\n{}", content.len(), content)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::Cursor;
+
+    fn read_all(input: &str) -> Vec<io::Result<Option<Value>>> {
+        let mut reader = Cursor::new(input.as_bytes());
+        let mut messages = Vec::new();
+
+        loop {
+            let message = read_message(&mut reader);
+            let is_end = matches!(message, Ok(None));
+
+            messages.push(message);
+
+            if is_end {
+                return messages;
+            }
+        }
+    }
+
+    #[test]
+    fn written_messages_can_be_read_back() {
+        let message = json!({ "jsonrpc": "2.0", "id": 1, "method": "hover", "params": { "text": "😀" } });
+        let mut buffer = Vec::new();
+
+        write_message(&mut buffer, &message).unwrap();
+        write_message(&mut buffer, &message).unwrap();
+
+        let mut reader = Cursor::new(buffer);
+
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message.clone()));
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn the_content_length_counts_bytes() {
+        let mut buffer = Vec::new();
+
+        write_message(&mut buffer, &json!("é")).unwrap();
+
+        assert_eq!(String::from_utf8(buffer).unwrap(), "Content-Length: 4\r\n\r\n\"é\"");
+    }
+
+    #[test]
+    fn other_headers_are_ignored() {
+        let messages =
+            read_all("Content-Type: application/vscode-jsonrpc; charset=utf-8\r\nContent-Length: 2\r\n\r\n{}");
+
+        assert_eq!(messages[0].as_ref().unwrap(), &Some(json!({})));
+    }
+
+    #[test]
+    fn an_empty_input_has_no_messages() {
+        assert!(read_message(&mut Cursor::new(b"")).unwrap().is_none());
+    }
+
+    #[test]
+    fn invalid_json_is_invalid_data_and_the_next_message_is_read() {
+        let messages = read_all("Content-Length: 6\r\n\r\n{oops}Content-Length: 2\r\n\r\n[]");
+
+        assert_eq!(messages[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(messages[1].as_ref().unwrap(), &Some(json!([])));
+        assert!(messages[2].as_ref().unwrap().is_none());
+    }
+
+    #[test]
+    fn a_missing_content_length_is_invalid_data() {
+        let messages = read_all("Content-Type: application/vscode-jsonrpc\r\n\r\n");
+
+        assert_eq!(messages[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn content_lengths_over_the_limit_are_invalid_data_and_the_next_message_is_read() {
+        let content = " ".repeat(MAX_CONTENT_LENGTH + 1);
+        let messages = read_all(&format!(
+            "Content-Length: {}\r\n\r\n{}Content-Length: 2\r\n\r\n[]",
+            content.len(),
+            content
+        ));
+
+        assert_eq!(messages[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(messages[1].as_ref().unwrap(), &Some(json!([])));
+
+        let messages = read_all(&format!("Content-Length: {}\r\n\r\n", usize::MAX));
+
+        assert_eq!(messages[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn an_invalid_content_length_is_invalid_data() {
+        let messages = read_all("Content-Length: many\r\n\r\n{}");
+
+        assert_eq!(messages[0].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -1,5 +1,6 @@
 mod format;
 mod lint;
+mod lsp;
 
 use std::path::PathBuf;
 
@@ -11,6 +12,14 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// If set, runs the lint rules on the schema when it is valid, and reports their warnings
+    /// along with the errors
+    #[structopt(long)]
+    rules: bool,
+    /// Specifies a JSON file enabling and disabling the lint rules and setting their severity.
+    /// Implies `--rules`
+    #[structopt(long)]
+    config: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -26,6 +35,10 @@ pub struct FormatOpts {
     /// Specifies wich tab width to use when formatting
     #[structopt(short = "s", long, default_value = "2")]
     tabwidth: usize,
+    /// Specifies the schema file or directory the input is a part of, when the schema is split
+    /// across multiple files. Back relation fields are then added from relations in the other files
+    #[structopt(long)]
+    schema: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -36,6 +49,8 @@ pub enum FmtOpts {
     Lint(LintOpts),
     /// Specifies format mode
     Format(FormatOpts),
+    /// Specifies language server mode, speaking the Language Server Protocol over STDIN and STDOUT
+    Lsp,
 }
 
 #[derive(serde::Serialize)]
@@ -43,11 +58,19 @@ pub struct MiniError {
     pub start: usize,
     pub end: usize,
     pub text: String,
+    /// The lint rule that reported the warning, for lint warnings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<lint::Severity>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<lint::Fix>,
 }
 
 fn main() {
     match FmtOpts::from_args() {
         FmtOpts::Lint(opts) => lint::run(opts),
         FmtOpts::Format(opts) => format::run(opts),
+        FmtOpts::Lsp => lsp::run(),
     }
 }
@@ -0,0 +1,51 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ScalarListFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    s"""model Post {
+      |   id   String   @id
+      |   tags String[]
+      |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query("""mutation { createPost(data: { id: "1", tags: { set: ["a", "b"] } }) { id } }""", project)
+    server.query("""mutation { createPost(data: { id: "2", tags: { set: ["b", "c"] } }) { id } }""", project)
+    server.query("""mutation { createPost(data: { id: "3", tags: { set: [] } }) { id } }""", project)
+  }
+
+  "The has filter" should "return lists containing the value" in {
+    posts("""{ tags_has: "b" }""") should equal("""{"data":{"posts":[{"id":"1"},{"id":"2"}]}}""")
+  }
+
+  "The has_every filter" should "return lists containing all of the values" in {
+    posts("""{ tags_has_every: ["a", "b"] }""") should equal("""{"data":{"posts":[{"id":"1"}]}}""")
+  }
+
+  "The has_some filter" should "return lists containing any of the values" in {
+    posts("""{ tags_has_some: ["a", "c"] }""") should equal("""{"data":{"posts":[{"id":"1"},{"id":"2"}]}}""")
+  }
+
+  "The is_empty filter" should "return empty or non-empty lists" in {
+    posts("""{ tags_is_empty: true }""") should equal("""{"data":{"posts":[{"id":"3"}]}}""")
+    posts("""{ tags_is_empty: false }""") should equal("""{"data":{"posts":[{"id":"1"},{"id":"2"}]}}""")
+  }
+
+  "Scalar list filters" should "combine with other filters" in {
+    posts("""{ OR: [{ tags_has: "a" }, { tags_is_empty: true }] }""") should equal("""{"data":{"posts":[{"id":"1"},{"id":"3"}]}}""")
+    posts("""{ NOT: { tags_has: "a" } }""") should equal("""{"data":{"posts":[{"id":"2"},{"id":"3"}]}}""")
+  }
+
+  def posts(filter: String): String = {
+    server.query(s"""{ posts(where: $filter, orderBy: id_ASC) { id } }""", project).toString
+  }
+}
@@ -0,0 +1,122 @@
+package writes.dataTypes.composites
+
+import org.scalatest.{FlatSpec, Matchers}
+import play.api.libs.json.Json
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class CompositeSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag)
+
+  val project = SchemaDsl.fromStringV11() {
+    s"""model User {
+      |   id      String   @id
+      |   address Address?
+      |}
+      |
+      |type Address {
+      |   street String
+      |   zip    String?
+      |   geo    Geo?
+      |}
+      |
+      |type Geo {
+      |   city String
+      |   lat  Float
+      |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  def address(id: String) = {
+    val result = server.query(s"""{ user(where: { id: "$id" }) { address } }""", project)
+    Json.parse(result.pathAsString("data.user.address"))
+  }
+
+  "A composite value" should "be created and read" in {
+    server.query(
+      """mutation {
+        |  createUser(data: { id: "1", address: { street: "Main St", geo: { city: "Berlin", lat: 52.5 } } }) { id }
+        |}""".stripMargin,
+      project
+    )
+
+    address("1") should equal(Json.obj("street" -> "Main St", "geo" -> Json.obj("city" -> "Berlin", "lat" -> 52.5)))
+  }
+
+  "Filters on the fields of a composite" should "find the matching records" in {
+    server.query("""mutation { createUser(data: { id: "1", address: { street: "Main St", geo: { city: "Berlin", lat: 52.5 } } }) { id } }""", project)
+    server.query("""mutation { createUser(data: { id: "2", address: { street: "Elm St", geo: { city: "Paris", lat: 48.9 } } }) { id } }""", project)
+    server.query("""mutation { createUser(data: { id: "3" }) { id } }""", project)
+
+    server.query("""{ users(where: { address: { geo: { city: "Paris" } } }) { id } }""", project).toString should equal(
+      """{"data":{"users":[{"id":"2"}]}}""")
+
+    server.query("""{ users(where: { address: { geo: { lat_gt: 50 } } }, orderBy: id_ASC) { id } }""", project).toString should equal(
+      """{"data":{"users":[{"id":"1"}]}}""")
+
+    server.query("""{ users(where: { address: { street_in: ["Main St", "Elm St"] } }, orderBy: id_ASC) { id } }""", project).toString should equal(
+      """{"data":{"users":[{"id":"1"},{"id":"2"}]}}""")
+
+    server.query("""{ users(where: { address: { street_in: [] } }) { id } }""", project).toString should equal(
+      """{"data":{"users":[]}}""")
+  }
+
+  "Filters on the fields of a composite" should "bind values with quotes and commas" in {
+    server.query("""mutation { createUser(data: { id: "1", address: { street: "O'Brien \"Lane\", 5" } }) { id } }""", project)
+    server.query("""mutation { createUser(data: { id: "2", address: { street: "Main St" } }) { id } }""", project)
+
+    server.query("""{ users(where: { address: { street: "O'Brien \"Lane\", 5" } }) { id } }""", project).toString should equal(
+      """{"data":{"users":[{"id":"1"}]}}""")
+
+    server.query("""{ users(where: { address: { street_contains: "'Brien \"La" } }) { id } }""", project).toString should equal(
+      """{"data":{"users":[{"id":"1"}]}}""")
+  }
+
+  "Updates of the fields of a composite" should "be merged into the stored value" in {
+    server.query(
+      """mutation {
+        |  createUser(data: { id: "1", address: { street: "Main St", zip: "10115", geo: { city: "Berlin", lat: 52.5 } } }) { id }
+        |}""".stripMargin,
+      project
+    )
+
+    server.query(
+      """mutation {
+        |  updateUser(where: { id: "1" }, data: { address: { update: { street: "Elm St, 'B' \"2\"", zip: null, geo: { update: { lat: 1.5 } } } } }) { id }
+        |}""".stripMargin,
+      project
+    )
+
+    address("1") should equal(Json.obj("street" -> "Elm St, 'B' \"2\"", "geo" -> Json.obj("city" -> "Berlin", "lat" -> 1.5)))
+  }
+
+  "Updates of the fields of a composite" should "create a missing value" in {
+    server.query("""mutation { createUser(data: { id: "1" }) { id } }""", project)
+
+    server.query(
+      """mutation { updateUser(where: { id: "1" }, data: { address: { update: { street: "Main St" } } }) { id } }""",
+      project
+    )
+
+    address("1") should equal(Json.obj("street" -> "Main St"))
+  }
+
+  "Updates of the fields of a composite" should "be merged into every matching record" in {
+    server.query("""mutation { createUser(data: { id: "1", address: { street: "Main St", zip: "10115" } }) { id } }""", project)
+    server.query("""mutation { createUser(data: { id: "2", address: { street: "Elm St", zip: "75001" } }) { id } }""", project)
+
+    server.query(
+      """mutation { updateManyUsers(where: { id_in: ["1", "2"] }, data: { address: { update: { zip: "00000" } } }) { count } }""",
+      project
+    )
+
+    address("1") should equal(Json.obj("street" -> "Main St", "zip" -> "00000"))
+    address("2") should equal(Json.obj("street" -> "Elm St", "zip" -> "00000"))
+  }
+}
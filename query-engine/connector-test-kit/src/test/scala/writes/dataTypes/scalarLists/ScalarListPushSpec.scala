@@ -0,0 +1,85 @@
+package writes.dataTypes.scalarLists
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ScalarListPushSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    s"""model Post {
+      |   id   String   @id
+      |   tags String[]
+      |   ints Int[]
+      |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "Pushing to a scalar list" should "append the values to the stored list" in {
+    server.query("""mutation { createPost(data: { id: "1", tags: { set: ["a"] } }) { id } }""", project)
+
+    val result = server.query(
+      """mutation { updatePost(where: { id: "1" }, data: { tags: { push: ["b", "c"] } }) { tags } }""",
+      project
+    )
+
+    result.toString should equal("""{"data":{"updatePost":{"tags":["a","b","c"]}}}""")
+  }
+
+  "Pushing a single value to a scalar list" should "work" in {
+    server.query("""mutation { createPost(data: { id: "1", ints: { set: [1] } }) { id } }""", project)
+
+    val result = server.query("""mutation { updatePost(where: { id: "1" }, data: { ints: { push: 2 } }) { ints } }""", project)
+
+    result.toString should equal("""{"data":{"updatePost":{"ints":[1,2]}}}""")
+  }
+
+  "Pushing to an empty scalar list" should "work" in {
+    server.query("""mutation { createPost(data: { id: "1" }) { id } }""", project)
+
+    val result = server.query("""mutation { updatePost(where: { id: "1" }, data: { tags: { push: "a" } }) { tags } }""", project)
+
+    result.toString should equal("""{"data":{"updatePost":{"tags":["a"]}}}""")
+  }
+
+  "Pushing with updateMany" should "append the values to every list" in {
+    server.query("""mutation { createPost(data: { id: "1", tags: { set: ["a"] } }) { id } }""", project)
+    server.query("""mutation { createPost(data: { id: "2", tags: { set: ["b"] } }) { id } }""", project)
+
+    server
+      .query("""mutation { updateManyPosts(data: { tags: { push: "z" } }) { count } }""", project)
+      .pathAsLong("data.updateManyPosts.count") should be(2)
+
+    server.query("""{ posts(orderBy: id_ASC) { tags } }""", project).toString should equal(
+      """{"data":{"posts":[{"tags":["a","z"]},{"tags":["b","z"]}]}}""")
+  }
+
+  "Pushing to a list and setting another one" should "apply both updates" in {
+    server.query("""mutation { createPost(data: { id: "1", tags: { set: ["a"] }, ints: { set: [1] } }) { id } }""", project)
+
+    val result = server.query(
+      """mutation { updatePost(where: { id: "1" }, data: { tags: { push: "b" }, ints: { set: [2, 3] } }) { tags ints } }""",
+      project
+    )
+
+    result.toString should equal("""{"data":{"updatePost":{"tags":["a","b"],"ints":[2,3]}}}""")
+  }
+
+  "Setting and pushing to the same list" should "fail" in {
+    server.query("""mutation { createPost(data: { id: "1" }) { id } }""", project)
+
+    server.queryThatMustFail(
+      """mutation { updatePost(where: { id: "1" }, data: { tags: { set: ["a"], push: "b" } }) { tags } }""",
+      project,
+      errorCode = 2019,
+      errorContains = "The list field `tags` can't be set and pushed to in the same operation."
+    )
+  }
+}
@@ -0,0 +1,93 @@
+package writes.dataTypes.unsupported
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.PostgresConnectorTag
+import util._
+
+class UnsupportedTypeSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag)
+
+  val project = SchemaDsl.fromStringV11() {
+    s"""model Post {
+      |   id     String                   @id
+      |   title  String
+      |   search Unsupported("tsvector")?
+      |}
+      |
+      |model Audit {
+      |   id     String                 @id
+      |   entry  Unsupported("tsvector")
+      |   userId String?
+      |   user   User?                  @relation(fields: [userId], references: [id])
+      |}
+      |
+      |model User {
+      |   id     String  @id
+      |   audits Audit[]
+      |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "Records with fields of unsupported types" should "be created and read without those fields" in {
+    server.query("""mutation { createPost(data: { id: "1", title: "Hello" }) { id } }""", project)
+
+    server.query("""{ posts { id title } }""", project).toString should equal(
+      """{"data":{"posts":[{"id":"1","title":"Hello"}]}}""")
+  }
+
+  "Fields of unsupported types" should "not be part of the client" in {
+    server.queryThatMustFail(
+      """{ posts { id search } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "Field does not exist on enclosing type."
+    )
+  }
+
+  "Models with required fields of unsupported types without defaults" should "not support creates" in {
+    server.queryThatMustFail(
+      """mutation { createAudit(data: { id: "1" }) { id } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "Field does not exist on enclosing type."
+    )
+  }
+
+  "Models with required fields of unsupported types without defaults" should "not support nested creates" in {
+    server.queryThatMustFail(
+      """mutation { createUser(data: { id: "1", audits: { create: { id: "1" } } }) { id } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "Field does not exist on enclosing type."
+    )
+
+    server.query("""mutation { createUser(data: { id: "1" }) { id } }""", project)
+
+    server.queryThatMustFail(
+      """mutation { updateUser(where: { id: "1" }, data: { audits: { create: { id: "1" } } }) { id } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "Field does not exist on enclosing type."
+    )
+
+    server.queryThatMustFail(
+      """mutation { updateUser(where: { id: "1" }, data: { audits: { upsert: { where: { id: "1" }, update: {}, create: { id: "1" } } } }) { id } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "Field does not exist on enclosing type."
+    )
+
+    server.queryThatMustFail(
+      """mutation { updateUser(where: { id: "1" }, data: { audits: { connectOrCreate: { where: { id: "1" }, create: { id: "1" } } } }) { id } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "Field does not exist on enclosing type."
+    )
+  }
+}
@@ -0,0 +1,41 @@
+package writes.ids
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GeneratedIdCreateSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  "Creating an item with an id field with a ulid() default" should "work" in {
+    val project = ProjectDsl.fromString {
+      s"""
+         |model Todo {
+         |  id    String @id @default(ulid())
+         |  title String
+         |}
+       """.stripMargin
+    }
+    database.setup(project)
+
+    val result = server.query("""mutation { createTodo(data: { title: "the title" }){ id title } }""", project)
+
+    result.pathAsString("data.createTodo.title") should equal("the title")
+    result.pathAsString("data.createTodo.id") should fullyMatch regex "[0-9A-HJKMNP-TV-Z]{26}"
+  }
+
+  "Creating an item with an id field with a nanoid() default" should "work" in {
+    val project = ProjectDsl.fromString {
+      s"""
+         |model Todo {
+         |  id    String @id @default(nanoid())
+         |  slug  String @default(nanoid(8))
+         |}
+       """.stripMargin
+    }
+    database.setup(project)
+
+    val result = server.query("""mutation { createTodo(data: {}){ id slug } }""", project)
+
+    result.pathAsString("data.createTodo.id") should fullyMatch regex "[A-Za-z0-9_-]{21}"
+    result.pathAsString("data.createTodo.slug") should fullyMatch regex "[A-Za-z0-9_-]{8}"
+  }
+}
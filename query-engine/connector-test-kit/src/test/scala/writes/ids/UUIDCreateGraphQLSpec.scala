@@ -38,6 +38,31 @@ class UUIDCreateGraphQLSpec extends FlatSpec with Matchers with ApiSpecBase {
     UUID.fromString(theUUID) // should just not blow up
   }
 
+  "Creating an item with an id field of model UUID version 7" should "work" in {
+    val project = ProjectDsl.fromString {
+      s"""
+         |model Todo {
+         |  id    String @id @default(uuid(7))
+         |  title String
+         |}
+       """.stripMargin
+    }
+    database.setup(project)
+
+    val result = server.query(
+      """
+        |mutation {
+        |  createTodo(data: { title: "the title" }){
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    UUID.fromString(result.pathAsString("data.createTodo.id")).version() should equal(7)
+  }
+
   "Fetching a UUID field that is null" should "work" in {
     val project = ProjectDsl.fromString {
       s"""
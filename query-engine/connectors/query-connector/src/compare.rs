@@ -87,13 +87,13 @@ pub trait ScalarListCompare {
     where
         T: Into<PrismaValue>;
 
-    fn contains_every_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_some_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_none(&self) -> Filter;
+    fn is_empty_list(&self, empty: bool) -> Filter;
 }
@@ -0,0 +1,30 @@
+use super::{Filter, ScalarCondition};
+use prisma_models::ScalarFieldRef;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Filtering on a field of a composite type. The composite is stored as a JSON
+/// document in the column of `field`:
+///
+/// ```graphql
+/// findManyUser(where: { address: { city: "Berlin" } })
+/// ```
+///
+/// This translates to the path `["city"]` into the `address` column, with a
+/// condition where the value at the path equals `"Berlin"`.
+pub struct CompositeFilter {
+    pub field: ScalarFieldRef,
+    pub path: Vec<String>,
+    pub condition: ScalarCondition,
+}
+
+impl CompositeFilter {
+    pub fn new(field: ScalarFieldRef, path: Vec<String>, condition: ScalarCondition) -> Self {
+        Self { field, path, condition }
+    }
+}
+
+impl From<CompositeFilter> for Filter {
+    fn from(filter: CompositeFilter) -> Self {
+        Filter::Composite(filter)
+    }
+}
@@ -20,11 +20,10 @@ pub enum ScalarListCondition {
     /// List contains some of the given values
     ContainsSome(Vec<PrismaValue>),
 
-    /// List is empty
-    ContainsNone,
+    /// List is empty (true) or has elements (false)
+    IsEmpty(bool),
 }
 
-#[allow(warnings)]
 impl ScalarListCompare for Arc<ScalarField> {
     fn contains_element<T>(&self, value: T) -> Filter
     where
@@ -38,30 +37,28 @@ impl ScalarListCompare for Arc<ScalarField> {
 
     fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>,
+        T: Into<PrismaValue>,
     {
-        // Filter::from(ScalarListFilter {
-        //     field: Arc::clone(self),
-        //     condition: ScalarListCondition::ContainsEvery(values.into()),
-        // })
-        unimplemented!()
+        Filter::from(ScalarListFilter {
+            field: Arc::clone(self),
+            condition: ScalarListCondition::ContainsEvery(values.into_iter().map(Into::into).collect()),
+        })
     }
 
     fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>,
+        T: Into<PrismaValue>,
     {
-        // Filter::from(ScalarListFilter {
-        //     field: Arc::clone(self),
-        //     condition: ScalarListCondition::ContainsSome(values.into()),
-        // })
-        unimplemented!()
+        Filter::from(ScalarListFilter {
+            field: Arc::clone(self),
+            condition: ScalarListCondition::ContainsSome(values.into_iter().map(Into::into).collect()),
+        })
     }
 
-    fn contains_none(&self) -> Filter {
+    fn is_empty_list(&self, empty: bool) -> Filter {
         Filter::from(ScalarListFilter {
             field: Arc::clone(self),
-            condition: ScalarListCondition::ContainsNone,
+            condition: ScalarListCondition::IsEmpty(empty),
         })
     }
 }
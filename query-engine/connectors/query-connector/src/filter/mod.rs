@@ -4,11 +4,13 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod composite;
 mod id_filter;
 mod list;
 mod relation;
 mod scalar;
 
+pub use composite::*;
 pub use id_filter::*;
 pub use list::*;
 pub use relation::*;
@@ -21,6 +23,7 @@ pub enum Filter {
     Not(Vec<Filter>),
     Scalar(ScalarFilter),
     ScalarList(ScalarListFilter),
+    Composite(CompositeFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
     NodeSubscription,
@@ -28,6 +28,13 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn supports_distinct(&self) -> bool {
+        match self {
+            Self::Connection(c) => c.supports_distinct(),
+            Self::Transaction(tx) => tx.supports_distinct(),
+        }
+    }
+
     async fn get_related_m2m_record_ids(
         &self,
         from_field: &RelationFieldRef,
@@ -117,4 +124,11 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
             Self::Transaction(tx) => tx.execute_raw(query, parameters).await,
         }
     }
+
+    async fn query_raw_typed(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value> {
+        match self {
+            Self::Connection(c) => c.query_raw_typed(query, parameters).await,
+            Self::Transaction(tx) => tx.query_raw_typed(query, parameters).await,
+        }
+    }
 }
@@ -167,6 +167,12 @@ pub trait ReadOperations {
         selected_fields: &ModelProjection,
     ) -> crate::Result<ManyRecords>;
 
+    /// Whether `distinct` in the `QueryArguments` of `get_many_records` is
+    /// applied by the data source. If not, the core sets `ignore_distinct`,
+    /// reads all records and deduplicates them in memory instead, applying
+    /// `skip` and `take` afterwards.
+    fn supports_distinct(&self) -> bool;
+
     /// Retrieves pairs of IDs that belong together from a intermediate join
     /// table.
     ///
@@ -238,4 +244,11 @@ pub trait WriteOperations {
     ///
     /// Returns resulting rows as JSON.
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value>;
+
+    /// Execute the raw query in the database as-is. The `parameters` are
+    /// parameterized values for databases that support prepared statements.
+    ///
+    /// Returns the column names, the column types and the resulting rows as
+    /// JSON, with values encoded according to their column type.
+    async fn query_raw_typed(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value>;
 }
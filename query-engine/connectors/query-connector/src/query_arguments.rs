@@ -15,6 +15,10 @@ pub struct QueryArguments {
     pub filter: Option<Filter>,
     pub order_by: Option<OrderBy>,
 
+    /// Fields to deduplicate the records on: of all records with the same values for them, only
+    /// the first one in the order of the query is returned.
+    pub distinct: Option<ModelProjection>,
+
     /// Temporary marker to indicate whether or not the
     /// `skip` argument should be ignored when building queries.
     pub ignore_skip: bool,
@@ -22,6 +26,10 @@ pub struct QueryArguments {
     /// Temporary marker to indicate whether or not the
     /// `take` argument should be ignored when building queries.
     pub ignore_take: bool,
+
+    /// Temporary marker to indicate whether or not the
+    /// `distinct` argument should be ignored when building queries.
+    pub ignore_distinct: bool,
 }
 
 impl QueryArguments {
@@ -31,7 +39,11 @@ impl QueryArguments {
     }
 
     fn needs_implicit_ordering(&self) -> bool {
-        self.skip.is_some() || self.cursor.is_some() || self.take.is_some() || self.order_by.is_some()
+        self.skip.is_some()
+            || self.cursor.is_some()
+            || self.take.is_some()
+            || self.order_by.is_some()
+            || self.distinct.is_some()
     }
 
     pub fn ordering_directions(&self) -> OrderDirections {
@@ -57,8 +69,10 @@ impl QueryArguments {
                 let take = self.take;
                 let skip = self.skip;
                 let order_by = self.order_by;
+                let distinct = self.distinct;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
+                let ignore_distinct = self.ignore_distinct;
 
                 filter
                     .batched()
@@ -69,8 +83,10 @@ impl QueryArguments {
                         skip: skip.clone(),
                         filter: Some(filter),
                         order_by: order_by.clone(),
+                        distinct: distinct.clone(),
                         ignore_skip,
                         ignore_take,
+                        ignore_distinct,
                     })
                     .collect()
             }
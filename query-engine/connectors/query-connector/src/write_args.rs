@@ -7,11 +7,24 @@ use std::collections::{hash_map::Keys, HashMap};
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct WriteArgs {
     pub args: HashMap<String, PrismaValue>,
+
+    /// Updates of single fields inside of composite fields, as JSON merge
+    /// patches (RFC 7396). The key is the data source field name of the
+    /// composite field. The patches are applied to the stored documents.
+    pub composite_updates: HashMap<String, serde_json::Value>,
+
+    /// Values appended to scalar list fields. The key is the data source
+    /// field name of the list field.
+    pub list_pushes: HashMap<String, Vec<PrismaValue>>,
 }
 
 impl From<HashMap<String, PrismaValue>> for WriteArgs {
     fn from(args: HashMap<String, PrismaValue>) -> Self {
-        Self { args }
+        Self {
+            args,
+            composite_updates: HashMap::new(),
+            list_pushes: HashMap::new(),
+        }
     }
 }
 
@@ -19,13 +32,19 @@ impl From<Vec<(String, PrismaValue)>> for WriteArgs {
     fn from(pairs: Vec<(String, PrismaValue)>) -> Self {
         Self {
             args: pairs.into_iter().collect(),
+            composite_updates: HashMap::new(),
+            list_pushes: HashMap::new(),
         }
     }
 }
 
 impl WriteArgs {
     pub fn new() -> Self {
-        Self { args: HashMap::new() }
+        Self {
+            args: HashMap::new(),
+            composite_updates: HashMap::new(),
+            list_pushes: HashMap::new(),
+        }
     }
 
     pub fn insert<T, V>(&mut self, key: T, arg: V)
@@ -52,8 +71,30 @@ impl WriteArgs {
         self.args.keys()
     }
 
+    pub fn insert_composite_update<T>(&mut self, key: T, patch: serde_json::Value)
+    where
+        T: Into<String>,
+    {
+        self.composite_updates.insert(key.into(), patch);
+    }
+
+    pub fn has_composite_updates(&self) -> bool {
+        !self.composite_updates.is_empty()
+    }
+
+    pub fn insert_list_push<T>(&mut self, key: T, values: Vec<PrismaValue>)
+    where
+        T: Into<String>,
+    {
+        self.list_pushes.insert(key.into(), values);
+    }
+
+    pub fn has_list_pushes(&self) -> bool {
+        !self.list_pushes.is_empty()
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.args.is_empty()
+        self.args.is_empty() && self.composite_updates.is_empty() && self.list_pushes.is_empty()
     }
 
     pub fn len(&self) -> usize {
@@ -79,7 +120,7 @@ impl WriteArgs {
     }
 
     pub fn update_datetimes(&mut self, model: ModelRef) {
-        if !self.args.is_empty() {
+        if !self.is_empty() {
             if let Some(field) = model.fields().updated_at() {
                 if let None = self.args.get(field.db_name()) {
                     self.insert(field.db_name().clone(), PrismaValue::DateTime(Utc::now()));
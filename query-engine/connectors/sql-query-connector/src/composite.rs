@@ -0,0 +1,357 @@
+//! Composite fields are stored as JSON documents in a single column.
+//!
+//! Quaint can't express paths into JSON documents, so the filters on the
+//! fields of a composite are rendered by hand, with the segments of the path
+//! bound as parameters. The filters are resolved to the ids of the matching
+//! records before the actual query runs, see `filter_resolution`.
+//!
+//! Updates of single fields in a composite are JSON merge patches (RFC 7396),
+//! merged into the stored documents by the database in a single `UPDATE`.
+
+use crate::{
+    query_builder::PARAMETER_LIMIT,
+    rendered_query::{quote_identifier, Flavour, RenderedQuery},
+    QueryContext, QueryExt, SqlError,
+};
+use connector_interface::filter::{CompositeFilter, ScalarCondition};
+use prisma_models::*;
+use quaint::ast::Value;
+use serde_json::Value as JsonValue;
+
+/// The ids of the records matching a composite filter.
+pub(crate) async fn matching_ids<Q: QueryExt + ?Sized>(
+    conn: &Q,
+    ctx: &QueryContext<'_>,
+    filter: CompositeFilter,
+) -> crate::Result<Vec<RecordProjection>> {
+    let model_id = filter.field.model().primary_identifier();
+    let idents: Vec<_> = model_id.type_identifiers_with_arities();
+    let flavour = Flavour::from_connection_info(ctx.connection_info());
+    let query = filter_query(flavour, filter)?;
+
+    let rows = conn
+        .filter_rendered(ctx, &query.sql, &query.params, idents.as_slice())
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let tuples: Vec<_> = model_id.scalar_fields().zip(row.values.into_iter()).collect();
+            RecordProjection::new(tuples)
+        })
+        .collect())
+}
+
+/// Generates a query of the form:
+/// ```sql
+/// SELECT "id" FROM "db"."User" WHERE ("address" #>> ARRAY[$1::text, $2::text]::text[]) = $3
+/// ```
+fn filter_query(flavour: Flavour, filter: CompositeFilter) -> crate::Result<RenderedQuery> {
+    let model = filter.field.model();
+    let type_identifier = path_type_identifier(&filter);
+    let mut query = RenderedQuery::new(flavour);
+
+    query.push_select_ids(&model);
+
+    let expression = match flavour {
+        Flavour::Postgres => postgres_path(&mut query, filter.field.db_name(), &filter.path, &type_identifier),
+        Flavour::Mysql => mysql_path(&mut query, filter.field.db_name(), &filter.path, &type_identifier),
+        Flavour::Sqlite => {
+            let message = "Filters on composite fields are only supported on PostgreSQL and MySQL.";
+            return Err(SqlError::QueryError(message.into()));
+        }
+    };
+
+    push_condition(&mut query, &expression, filter.condition);
+
+    Ok(query)
+}
+
+/// The type of the field at the end of the path.
+fn path_type_identifier(filter: &CompositeFilter) -> TypeIdentifier {
+    let internal_data_model = filter.field.internal_data_model();
+    let mut composite_type = filter.field.internal_composite_type();
+    let mut type_identifier = TypeIdentifier::Json;
+
+    for segment in filter.path.iter() {
+        let field = match composite_type.as_ref().and_then(|ct| ct.find_field(segment)) {
+            Some(field) => field.clone(),
+            None => return TypeIdentifier::Json,
+        };
+
+        composite_type = field
+            .composite_type
+            .as_ref()
+            .and_then(|name| internal_data_model.find_composite_type(name))
+            .cloned();
+
+        type_identifier = field.type_identifier;
+    }
+
+    type_identifier
+}
+
+fn postgres_path(query: &mut RenderedQuery, column: &str, path: &[String], type_identifier: &TypeIdentifier) -> String {
+    let segments: Vec<String> = path
+        .iter()
+        .map(|segment| format!("{}::text", query.bind(segment.clone())))
+        .collect();
+
+    let extracted = format!(
+        "({} #>> ARRAY[{}]::text[])",
+        quote_identifier(query.flavour, column),
+        segments.join(", ")
+    );
+
+    match type_identifier {
+        TypeIdentifier::Int | TypeIdentifier::Float => format!("{}::numeric", extracted),
+        TypeIdentifier::Boolean => format!("{}::boolean", extracted),
+        TypeIdentifier::DateTime => format!("{}::timestamptz", extracted),
+        TypeIdentifier::UUID => format!("{}::uuid", extracted),
+        _ => extracted,
+    }
+}
+
+fn mysql_path(query: &mut RenderedQuery, column: &str, path: &[String], type_identifier: &TypeIdentifier) -> String {
+    let extracted = format!(
+        "JSON_EXTRACT({}, {})",
+        quote_identifier(query.flavour, column),
+        query.bind(mysql_json_path(path))
+    );
+
+    match type_identifier {
+        TypeIdentifier::Int => format!("CAST({} AS SIGNED)", extracted),
+        TypeIdentifier::Float => format!("CAST({} AS DECIMAL(65, 30))", extracted),
+        TypeIdentifier::Boolean => format!("({} = CAST('true' AS JSON))", extracted),
+        TypeIdentifier::Json => extracted,
+        _ => format!("JSON_UNQUOTE({})", extracted),
+    }
+}
+
+/// The JSON path of the segments, every member name quoted as a JSON string:
+/// `$."geo"."city"`.
+fn mysql_json_path(path: &[String]) -> String {
+    path.iter().fold(String::from("$"), |mut json_path, segment| {
+        json_path.push('.');
+        json_path.push_str(&JsonValue::String(segment.clone()).to_string());
+        json_path
+    })
+}
+
+fn push_condition(query: &mut RenderedQuery, expression: &str, condition: ScalarCondition) {
+    let (operator, value) = match condition {
+        ScalarCondition::Equals(PrismaValue::Null(_)) => return query.push_str(&format!("{} IS NULL", expression)),
+        ScalarCondition::NotEquals(PrismaValue::Null(_)) => {
+            return query.push_str(&format!("{} IS NOT NULL", expression))
+        }
+        ScalarCondition::In(values) => return push_in(query, expression, "IN", values),
+        ScalarCondition::NotIn(values) => return push_in(query, expression, "NOT IN", values),
+        ScalarCondition::Equals(value) => ("=", value),
+        ScalarCondition::NotEquals(value) => ("<>", value),
+        ScalarCondition::Contains(value) => ("LIKE", PrismaValue::String(format!("%{}%", value))),
+        ScalarCondition::NotContains(value) => ("NOT LIKE", PrismaValue::String(format!("%{}%", value))),
+        ScalarCondition::StartsWith(value) => ("LIKE", PrismaValue::String(format!("{}%", value))),
+        ScalarCondition::NotStartsWith(value) => ("NOT LIKE", PrismaValue::String(format!("{}%", value))),
+        ScalarCondition::EndsWith(value) => ("LIKE", PrismaValue::String(format!("%{}", value))),
+        ScalarCondition::NotEndsWith(value) => ("NOT LIKE", PrismaValue::String(format!("%{}", value))),
+        ScalarCondition::LessThan(value) => ("<", value),
+        ScalarCondition::LessThanOrEquals(value) => ("<=", value),
+        ScalarCondition::GreaterThan(value) => (">", value),
+        ScalarCondition::GreaterThanOrEquals(value) => (">=", value),
+    };
+
+    query.push_str(&format!("{} {} ", expression, operator));
+    query.push_param(value);
+}
+
+fn push_in(query: &mut RenderedQuery, expression: &str, operator: &str, values: Vec<PrismaValue>) {
+    // Nothing is in an empty list.
+    if values.is_empty() {
+        let always = if operator == "IN" { "1 = 0" } else { "1 = 1" };
+        return query.push_str(always);
+    }
+
+    let placeholders: Vec<String> = values.into_iter().map(|value| query.bind(value)).collect();
+    query.push_str(&format!("{} {} ({})", expression, operator, placeholders.join(", ")));
+}
+
+/// Merges the composite updates into the stored documents of the records.
+/// Every chunk of records is updated by a single statement, so concurrent
+/// updates of other fields in the same documents are not lost.
+pub(crate) async fn merge_updates(
+    conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
+    model: &ModelRef,
+    ids: &[RecordProjection],
+    patches: Vec<(String, JsonValue)>,
+) -> crate::Result<()> {
+    if patches.is_empty() {
+        return Ok(());
+    }
+
+    let flavour = Flavour::from_connection_info(ctx.connection_info());
+    let chunk_size = ids_per_statement(flavour, model, &patches);
+
+    for chunk in ids.chunks(chunk_size) {
+        let query = merge_query(flavour, model, chunk, &patches);
+        conn.execute_rendered(ctx, &query.sql, &query.params).await?;
+    }
+
+    Ok(())
+}
+
+/// How many records fit in a merge statement. Every record binds a parameter
+/// for each of its id columns, next to the parameters of the patches.
+fn ids_per_statement(flavour: Flavour, model: &ModelRef, patches: &[(String, JsonValue)]) -> usize {
+    let id_columns = model.primary_identifier().scalar_fields().count().max(1);
+    let patch_params = merge_query(flavour, model, &[], patches).params.len();
+
+    (PARAMETER_LIMIT.saturating_sub(patch_params) / id_columns).max(1)
+}
+
+/// Generates a statement of the form:
+/// ```sql
+/// UPDATE `db`.`User` SET `address` = JSON_MERGE_PATCH(COALESCE(`address`, '{}'), ?) WHERE `id` IN (?)
+/// ```
+fn merge_query(
+    flavour: Flavour,
+    model: &ModelRef,
+    ids: &[RecordProjection],
+    patches: &[(String, JsonValue)],
+) -> RenderedQuery {
+    let mut query = RenderedQuery::new(flavour);
+
+    query.push_str("UPDATE ");
+    query.push_table(model);
+    query.push_str(" SET ");
+
+    for (i, (column, patch)) in patches.iter().enumerate() {
+        if i > 0 {
+            query.push_str(", ");
+        }
+
+        query.push_identifier(column);
+        query.push_str(" = ");
+
+        let column = quote_identifier(flavour, column);
+        let merged = merge_expression(&mut query, &column, patch);
+
+        query.push_str(&merged);
+    }
+
+    query.push_str(" WHERE ");
+    query.push_ids_condition(model, ids);
+
+    query
+}
+
+/// The expression merging `patch` into the document `target`.
+fn merge_expression(query: &mut RenderedQuery, target: &str, patch: &JsonValue) -> String {
+    match query.flavour {
+        Flavour::Postgres => postgres_merge_expression(query, target, patch),
+        Flavour::Mysql => format!(
+            "JSON_MERGE_PATCH(COALESCE({}, '{{}}'), {})",
+            target,
+            query.bind(patch.to_string())
+        ),
+        Flavour::Sqlite => format!(
+            "json_patch(COALESCE({}, '{{}}'), {})",
+            target,
+            query.bind(patch.to_string())
+        ),
+    }
+}
+
+/// PostgreSQL has no merge patch, it is built from `jsonb_set` for every
+/// member of the patch, removing the members set to null and merging the
+/// nested objects recursively.
+fn postgres_merge_expression(query: &mut RenderedQuery, target: &str, patch: &JsonValue) -> String {
+    let members = match patch {
+        JsonValue::Object(members) => members,
+        patch => return format!("{}::jsonb", query.bind(Value::Json(Some(patch.clone())))),
+    };
+
+    let base = format!(
+        "(CASE WHEN jsonb_typeof({target}) = 'object' THEN {target} ELSE '{{}}'::jsonb END)",
+        target = target
+    );
+
+    members.iter().fold(base.clone(), |merged, (key, value)| {
+        let key = format!("{}::text", query.bind(key.clone()));
+
+        match value {
+            JsonValue::Null => format!("({} - {})", merged, key),
+            JsonValue::Object(_) => {
+                let member = format!("({} -> {})", base, key);
+                let nested = postgres_merge_expression(query, &member, value);
+
+                format!("jsonb_set({}, ARRAY[{}], {})", merged, key, nested)
+            }
+            value => {
+                let value = query.bind(Value::Json(Some(value.clone())));
+                format!("jsonb_set({}, ARRAY[{}], {}::jsonb)", merged, key, value)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn mysql_paths_quote_every_member() {
+        let path = vec!["geo".to_owned(), "it's \"a\", b".to_owned()];
+
+        assert_eq!(mysql_json_path(&path), r#"$."geo"."it's \"a\", b""#);
+    }
+
+    #[test]
+    fn postgres_merges_bind_keys_and_values() {
+        let mut query = RenderedQuery::new(Flavour::Postgres);
+        let patch = json!({ "street": "Elm St", "zip": null });
+
+        let merged = postgres_merge_expression(&mut query, "\"address\"", &patch);
+        let base = "(CASE WHEN jsonb_typeof(\"address\") = 'object' THEN \"address\" ELSE '{}'::jsonb END)";
+
+        assert_eq!(
+            merged,
+            format!("(jsonb_set({}, ARRAY[$1::text], $2::jsonb) - $3::text)", base)
+        );
+        assert_eq!(query.params.len(), 3);
+    }
+
+    #[test]
+    fn postgres_paths_bind_every_segment() {
+        let mut query = RenderedQuery::new(Flavour::Postgres);
+        let path = vec!["geo".to_owned(), "it's \"a\", b".to_owned()];
+
+        let expression = postgres_path(&mut query, "address", &path, &TypeIdentifier::Float);
+        push_condition(&mut query, &expression, ScalarCondition::In(vec![]));
+
+        assert_eq!(
+            expression,
+            "(\"address\" #>> ARRAY[$1::text, $2::text]::text[])::numeric"
+        );
+        assert_eq!(query.sql, "1 = 0");
+        assert_eq!(query.params, vec![Value::from("geo"), Value::from("it's \"a\", b")]);
+    }
+
+    #[test]
+    fn postgres_merges_nested_objects_into_the_stored_members() {
+        let mut query = RenderedQuery::new(Flavour::Postgres);
+        let patch = json!({ "geo": { "lat": 3.0 } });
+
+        let merged = postgres_merge_expression(&mut query, "\"address\"", &patch);
+        let base = "(CASE WHEN jsonb_typeof(\"address\") = 'object' THEN \"address\" ELSE '{}'::jsonb END)";
+        let member = format!("({} -> $1::text)", base);
+        let nested = format!(
+            "jsonb_set((CASE WHEN jsonb_typeof({member}) = 'object' THEN {member} ELSE '{{}}'::jsonb END), \
+             ARRAY[$2::text], $3::jsonb)",
+            member = member
+        );
+
+        assert_eq!(merged, format!("jsonb_set({}, ARRAY[$1::text], {})", base, nested));
+    }
+}
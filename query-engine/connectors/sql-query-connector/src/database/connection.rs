@@ -1,5 +1,5 @@
 use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, QueryExt, SqlError};
+use crate::{database::operations::*, QueryContext, QueryExt, SlowQueryLog, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector, filter::Filter, AggregationResult, Aggregator, Connection, QueryArguments, ReadOperations,
@@ -12,15 +12,34 @@ use quaint::{connector::TransactionCapable, prelude::ConnectionInfo};
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    slow_query_log: SlowQueryLog,
 }
 
 impl<C> SqlConnection<C>
 where
     C: QueryExt + Send + Sync + 'static,
 {
-    pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
+    pub fn new(inner: C, connection_info: &ConnectionInfo, slow_query_log: &SlowQueryLog) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+        let slow_query_log = slow_query_log.clone();
+
+        Self {
+            inner,
+            connection_info,
+            slow_query_log,
+        }
+    }
+
+    /// The context of an operation on the model, like `findMany`.
+    fn context(&self, model: &ModelRef, action: &str) -> QueryContext<'_> {
+        let operation = format!("{}.{}", model.name, action);
+
+        QueryContext::new(&self.connection_info, &self.slow_query_log, operation)
+    }
+
+    /// The context of a raw operation, like `executeRaw`.
+    fn raw_context(&self, action: &str) -> QueryContext<'_> {
+        QueryContext::new(&self.connection_info, &self.slow_query_log, action)
     }
 
     async fn catch<O>(
@@ -42,9 +61,11 @@ where
     async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
         let fut_tx = self.inner.start_transaction();
         let connection_info = &self.connection_info;
+        let slow_query_log = &self.slow_query_log;
+
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info, slow_query_log)) as Box<dyn Transaction>)
         })
         .await
     }
@@ -61,8 +82,17 @@ where
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
+        self.catch(async move {
+            read::get_single_record(
+                &self.inner,
+                &self.context(model, "findOne"),
+                model,
+                filter,
+                selected_fields,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -71,8 +101,21 @@ where
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                &self.context(model, "findMany"),
+                model,
+                query_arguments,
+                selected_fields,
+            )
             .await
+        })
+        .await
+    }
+
+    fn supports_distinct(&self) -> bool {
+        read::supports_distinct(&self.connection_info)
     }
 
     async fn get_related_m2m_record_ids(
@@ -80,8 +123,16 @@ where
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
+        self.catch(async move {
+            read::get_related_m2m_record_ids(
+                &self.inner,
+                &self.context(&from_field.related_model(), "findMany"),
+                from_field,
+                from_record_ids,
+            )
             .await
+        })
+        .await
     }
 
     async fn aggregate_records(
@@ -90,8 +141,17 @@ where
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(async move {
+            read::aggregate(
+                &self.inner,
+                &self.context(model, "aggregate"),
+                model,
+                aggregators,
+                query_arguments,
+            )
             .await
+        })
+        .await
     }
 }
 
@@ -101,7 +161,7 @@ where
     C: QueryExt + Send + Sync + 'static,
 {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        self.catch(async move { write::create_record(&self.inner, &self.context(model, "create"), model, args).await })
             .await
     }
 
@@ -111,13 +171,17 @@ where
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            write::update_records(&self.inner, &self.context(model, "update"), model, record_filter, args).await
+        })
+        .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
-            .await
+        self.catch(async move {
+            write::delete_records(&self.inner, &self.context(model, "delete"), model, record_filter).await
+        })
+        .await
     }
 
     async fn connect(
@@ -126,8 +190,17 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move {
+            write::connect(
+                &self.inner,
+                &self.context(&field.model(), "connect"),
+                field,
+                parent_id,
+                child_ids,
+            )
             .await
+        })
+        .await
     }
 
     async fn disconnect(
@@ -136,17 +209,39 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move {
+            write::disconnect(
+                &self.inner,
+                &self.context(&field.model(), "disconnect"),
+                field,
+                parent_id,
+                child_ids,
+            )
             .await
+        })
+        .await
     }
 
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
-            .await
+        self.catch(
+            async move { write::execute_raw(&self.inner, &self.raw_context("executeRaw"), query, parameters).await },
+        )
+        .await
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(&self.inner, &self.raw_context("queryRaw"), query, parameters).await })
             .await
     }
+
+    async fn query_raw_typed(
+        &self,
+        query: String,
+        parameters: Vec<PrismaValue>,
+    ) -> connector::Result<serde_json::Value> {
+        self.catch(async move {
+            write::query_raw_typed(&self.inner, &self.raw_context("queryRawTyped"), query, parameters).await
+        })
+        .await
+    }
 }
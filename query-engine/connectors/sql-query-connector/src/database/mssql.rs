@@ -1,5 +1,5 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{FromSource, SlowQueryLog, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
@@ -13,6 +13,7 @@ use std::time::Duration;
 pub struct Mssql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    slow_query_log: SlowQueryLog,
 }
 
 #[async_trait]
@@ -32,7 +33,15 @@ impl FromSource for Mssql {
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
-        Ok(Self { pool, connection_info })
+        // SQL Server connection strings don't use URL query parameters, so
+        // the slow query log stays disabled.
+        let slow_query_log = SlowQueryLog::default();
+
+        Ok(Self {
+            pool,
+            connection_info,
+            slow_query_log,
+        })
     }
 }
 
@@ -41,7 +50,7 @@ impl Connector for Mssql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = SqlConnection::new(conn, &self.connection_info, &self.slow_query_log);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
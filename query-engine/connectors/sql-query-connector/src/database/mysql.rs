@@ -1,5 +1,5 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{FromSource, SlowQueryLog, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
@@ -13,15 +13,17 @@ use std::time::Duration;
 pub struct Mysql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    slow_query_log: SlowQueryLog,
 }
 
-#[async_trait]
-impl FromSource for Mysql {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let connection_info = ConnectionInfo::from_url(&source.url().value)
+impl Mysql {
+    /// Creates a connector for the database behind `url`, e.g. a read replica
+    /// of a datasource.
+    pub async fn from_url(url: &str) -> connector_interface::Result<Self> {
+        let connection_info = ConnectionInfo::from_url(url)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let mut builder = Quaint::builder(url)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -31,8 +33,20 @@ impl FromSource for Mysql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
+        let slow_query_log = SlowQueryLog::from_url(url);
+
+        Ok(Mysql {
+            pool,
+            connection_info,
+            slow_query_log,
+        })
+    }
+}
 
-        Ok(Mysql { pool, connection_info })
+#[async_trait]
+impl FromSource for Mysql {
+    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
+        Self::from_url(&source.url().value).await
     }
 }
 
@@ -41,7 +55,7 @@ impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = SqlConnection::new(conn, &self.connection_info, &self.slow_query_log);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -1,23 +1,26 @@
 use crate::{
+    filter_resolution,
     query_builder::{self, read},
-    QueryExt, SqlError,
+    QueryContext, QueryExt, SqlError, SqlRow,
 };
 use connector_interface::*;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::ConnectionInfo};
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.as_columns(), filter);
+    let filter = filter_resolution::resolve_filters(conn, ctx, filter.clone()).await?;
+    let query = read::get_records(&model, selected_fields.as_columns(), filter)?;
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
 
-    let record = (match conn.find(query, idents.as_slice()).await {
+    let record = (match conn.find(ctx, query, idents.as_slice()).await {
         Ok(result) => Ok(Some(result)),
         Err(_e @ SqlError::RecordNotFoundForWhere(_)) => Ok(None),
         Err(_e @ SqlError::RecordDoesNotExist) => Ok(None),
@@ -29,17 +32,39 @@ pub async fn get_single_record(
     Ok(record)
 }
 
+/// Distinct reads number the rows with a window function, which not every
+/// supported MySQL and SQLite version has. On other databases than Postgres,
+/// the core deduplicates records in memory.
+pub fn supports_distinct(connection_info: &ConnectionInfo) -> bool {
+    match connection_info {
+        ConnectionInfo::Postgres(..) => true,
+        _ => false,
+    }
+}
+
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
 ) -> crate::Result<ManyRecords> {
+    if let Some(filter) = query_arguments.filter.take() {
+        query_arguments.filter = Some(filter_resolution::resolve_filters(conn, ctx, filter).await?);
+    }
+
     let reversed = query_arguments.needs_reversed_order();
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
     let mut records = ManyRecords::new(field_names);
 
+    let distinct = match query_arguments.distinct {
+        Some(ref distinct) if !query_arguments.ignore_distinct && supports_distinct(ctx.connection_info()) => {
+            Some(distinct.clone())
+        }
+        _ => None,
+    };
+
     if query_arguments.can_batch() {
         // We don't need to order in the database due to us ordering in this function.
         let order = query_arguments.order_by.take();
@@ -48,8 +73,14 @@ pub async fn get_many_records(
         let mut futures = FuturesUnordered::new();
 
         for args in batches.into_iter() {
-            let query = read::get_records(model, selected_fields.as_columns(), args);
-            futures.push(conn.filter(query.into(), idents.as_slice()));
+            futures.push(filter_records(
+                conn,
+                ctx,
+                model,
+                args,
+                selected_fields,
+                distinct.as_ref(),
+            ));
         }
 
         while let Some(result) = futures.next().await {
@@ -61,10 +92,15 @@ pub async fn get_many_records(
         if let Some(ref order_by) = order {
             records.order_by(order_by)
         }
+
+        // Every batch is distinct in itself, but records can repeat across batches.
+        if let Some(ref distinct) = distinct {
+            records.distinct(distinct);
+        }
     } else {
-        let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let rows = filter_records(conn, ctx, model, query_arguments, selected_fields, distinct.as_ref()).await?;
 
-        for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
+        for item in rows.into_iter() {
             records.push(Record::from(item))
         }
     }
@@ -76,8 +112,27 @@ pub async fn get_many_records(
     Ok(records)
 }
 
+async fn filter_records(
+    conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    distinct: Option<&ModelProjection>,
+) -> crate::Result<Vec<SqlRow>> {
+    let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
+
+    let query = match distinct {
+        Some(distinct) => read::get_distinct_records(model, selected_fields, query_arguments, distinct)?,
+        None => read::get_records(model, selected_fields.as_columns(), query_arguments)?,
+    };
+
+    conn.filter(ctx, query.into(), idents.as_slice()).await
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     from_field: &RelationFieldRef,
     from_record_ids: &[RecordProjection],
 ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>> {
@@ -113,7 +168,7 @@ pub async fn get_related_m2m_record_ids(
 
     // first parent id, then child id
     Ok(conn
-        .filter(select.into(), idents.as_slice())
+        .filter(ctx, select.into(), idents.as_slice())
         .await?
         .into_iter()
         .map(|row| {
@@ -143,17 +198,22 @@ pub async fn get_related_m2m_record_ids(
 
 pub async fn aggregate(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     model: &ModelRef,
     aggregators: Vec<Aggregator>,
-    query_arguments: QueryArguments,
+    mut query_arguments: QueryArguments,
 ) -> crate::Result<Vec<AggregationResult>> {
-    let query = read::aggregate(model, &aggregators, query_arguments);
+    if let Some(filter) = query_arguments.filter.take() {
+        query_arguments.filter = Some(filter_resolution::resolve_filters(conn, ctx, filter).await?);
+    }
+
+    let query = read::aggregate(model, &aggregators, query_arguments)?;
     let idents: Vec<_> = aggregators
         .iter()
         .flat_map(|aggregator| aggregator.identifiers())
         .collect();
 
-    let mut rows = conn.filter(query.into(), idents.as_slice()).await?;
+    let mut rows = conn.filter(ctx, query.into(), idents.as_slice()).await?;
     let row = rows
         .pop()
         .expect("Expected exactly one return row for aggregation query.");
@@ -1,4 +1,4 @@
-use crate::{error::SqlError, query_builder::write, QueryExt};
+use crate::{composite, error::SqlError, query_builder::write, scalar_list, QueryContext, QueryExt};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
@@ -8,10 +8,15 @@ use user_facing_errors::query_engine::DatabaseConstraint;
 
 /// Create a single record to the database defined in `conn`, resulting into a
 /// `RecordProjection` as an identifier pointing to the just-created record.
-pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
+pub async fn create_record(
+    conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
+    model: &ModelRef,
+    args: WriteArgs,
+) -> crate::Result<RecordProjection> {
     let (insert, returned_id) = write::create_record(model, args);
 
-    let result_set = match conn.insert(insert).await {
+    let result_set = match ctx.query(conn, insert.into()).await {
         Ok(id) => id,
         Err(e) => match e.kind() {
             ErrorKind::UniqueConstraintViolation { constraint } => match constraint {
@@ -68,24 +73,31 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
 /// operation.
 pub async fn update_records(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     model: &ModelRef,
     record_filter: RecordFilter,
-    args: WriteArgs,
+    mut args: WriteArgs,
 ) -> crate::Result<Vec<RecordProjection>> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(ctx, model, record_filter).await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
     if ids.len() == 0 {
         return Ok(vec![]);
     }
 
-    let updates = {
-        let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
-        write::update_many(model, ids.as_slice(), args)?
-    };
+    // The composite updates and list pushes are applied before the other
+    // updates, which might change the ids of the records.
+    let patches: Vec<_> = args.composite_updates.drain().collect();
+    composite::merge_updates(conn, ctx, model, ids.as_slice(), patches).await?;
+
+    let pushes: Vec<_> = args.list_pushes.drain().collect();
+    scalar_list::append_pushes(conn, ctx, model, ids.as_slice(), pushes).await?;
+
+    let id_refs: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
+    let updates = write::update_many(model, id_refs.as_slice(), args)?;
 
     for update in updates {
-        conn.query(update).await?;
+        ctx.query(conn, update).await?;
     }
 
     Ok(merge_write_args(ids, id_args))
@@ -94,10 +106,11 @@ pub async fn update_records(
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
 pub async fn delete_records(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     model: &ModelRef,
     record_filter: RecordFilter,
 ) -> crate::Result<usize> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(ctx, model, record_filter).await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -106,7 +119,7 @@ pub async fn delete_records(
     }
 
     for delete in write::delete_many(model, ids.as_slice()) {
-        conn.query(delete).await?;
+        ctx.query(conn, delete).await?;
     }
 
     Ok(count)
@@ -116,12 +129,13 @@ pub async fn delete_records(
 /// The relation information is in the `RelationFieldRef`.
 pub async fn connect(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
 ) -> crate::Result<()> {
     let query = write::create_relation_table_records(field, parent_id, child_ids);
-    conn.query(query).await?;
+    ctx.query(conn, query).await?;
 
     Ok(())
 }
@@ -130,20 +144,26 @@ pub async fn connect(
 /// The relation information is in the `RelationFieldRef`.
 pub async fn disconnect(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
 ) -> crate::Result<()> {
     let query = write::delete_relation_table_records(field, parent_id, child_ids);
-    conn.delete(query).await?;
+    ctx.query(conn, query.into()).await?;
 
     Ok(())
 }
 
 /// Execute a plain SQL query with the given parameters, returning the number of
 /// affected rows.
-pub async fn execute_raw(conn: &dyn QueryExt, query: String, parameters: Vec<PrismaValue>) -> crate::Result<usize> {
-    let value = conn.raw_count(query, parameters).await?;
+pub async fn execute_raw(
+    conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> crate::Result<usize> {
+    let value = conn.raw_count(ctx, query, parameters).await?;
     Ok(value)
 }
 
@@ -151,10 +171,23 @@ pub async fn execute_raw(conn: &dyn QueryExt, query: String, parameters: Vec<Pri
 /// a JSON `Value`.
 pub async fn query_raw(
     conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> crate::Result<serde_json::Value> {
+    let value = conn.raw_json(ctx, query, parameters).await?;
+    Ok(value)
+}
+
+/// Execute a plain SQL query with the given parameters, returning the column
+/// names, column types and typed rows of the answer as a JSON `Value`.
+pub async fn query_raw_typed(
+    conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
     query: String,
     parameters: Vec<PrismaValue>,
 ) -> crate::Result<serde_json::Value> {
-    let value = conn.raw_json(query, parameters).await?;
+    let value = conn.raw_typed_json(ctx, query, parameters).await?;
     Ok(value)
 }
 
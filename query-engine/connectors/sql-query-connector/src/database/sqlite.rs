@@ -1,5 +1,5 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{FromSource, SlowQueryLog, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
@@ -13,6 +13,7 @@ use std::{convert::TryFrom, time::Duration};
 pub struct Sqlite {
     pool: Quaint,
     file_path: String,
+    slow_query_log: SlowQueryLog,
 }
 
 impl Sqlite {
@@ -68,8 +69,13 @@ impl FromSource for Sqlite {
         builder.test_on_check_out(true);
 
         let pool = builder.build();
+        let slow_query_log = SlowQueryLog::from_url(&source.url().value);
 
-        Ok(Sqlite { pool, file_path })
+        Ok(Sqlite {
+            pool,
+            file_path,
+            slow_query_log,
+        })
     }
 }
 
@@ -86,7 +92,7 @@ impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+            let conn = SqlConnection::new(conn, self.connection_info(), &self.slow_query_log);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
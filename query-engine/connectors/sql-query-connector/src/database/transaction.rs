@@ -1,5 +1,5 @@
 use crate::database::operations::*;
-use crate::SqlError;
+use crate::{QueryContext, SlowQueryLog, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector, filter::Filter, AggregationResult, Aggregator, QueryArguments, ReadOperations, RecordFilter,
@@ -12,17 +12,37 @@ use quaint::prelude::ConnectionInfo;
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    slow_query_log: SlowQueryLog,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
-    pub fn new<'b: 'tx>(tx: quaint::connector::Transaction<'tx>, connection_info: &ConnectionInfo) -> Self {
+    pub fn new<'b: 'tx>(
+        tx: quaint::connector::Transaction<'tx>,
+        connection_info: &ConnectionInfo,
+        slow_query_log: &SlowQueryLog,
+    ) -> Self {
         let connection_info = connection_info.clone();
+        let slow_query_log = slow_query_log.clone();
+
         Self {
             inner: tx,
             connection_info,
+            slow_query_log,
         }
     }
 
+    /// The context of an operation on the model, like `findMany`.
+    fn context(&self, model: &ModelRef, action: &str) -> QueryContext<'_> {
+        let operation = format!("{}.{}", model.name, action);
+
+        QueryContext::new(&self.connection_info, &self.slow_query_log, operation).within_transaction()
+    }
+
+    /// The context of a raw operation, like `executeRaw`.
+    fn raw_context(&self, action: &str) -> QueryContext<'_> {
+        QueryContext::new(&self.connection_info, &self.slow_query_log, action).within_transaction()
+    }
+
     async fn catch<O>(
         &self,
         fut: impl std::future::Future<Output = Result<O, SqlError>>,
@@ -55,8 +75,17 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
+        self.catch(async move {
+            read::get_single_record(
+                &self.inner,
+                &self.context(model, "findOne"),
+                model,
+                filter,
+                selected_fields,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -65,8 +94,21 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                &self.context(model, "findMany"),
+                model,
+                query_arguments,
+                selected_fields,
+            )
             .await
+        })
+        .await
+    }
+
+    fn supports_distinct(&self) -> bool {
+        read::supports_distinct(&self.connection_info)
     }
 
     async fn get_related_m2m_record_ids(
@@ -74,8 +116,16 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
+        self.catch(async move {
+            read::get_related_m2m_record_ids(
+                &self.inner,
+                &self.context(&from_field.related_model(), "findMany"),
+                from_field,
+                from_record_ids,
+            )
             .await
+        })
+        .await
     }
 
     async fn aggregate_records(
@@ -84,15 +134,24 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(async move {
+            read::aggregate(
+                &self.inner,
+                &self.context(model, "aggregate"),
+                model,
+                aggregators,
+                query_arguments,
+            )
             .await
+        })
+        .await
     }
 }
 
 #[async_trait]
 impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        self.catch(async move { write::create_record(&self.inner, &self.context(model, "create"), model, args).await })
             .await
     }
 
@@ -102,13 +161,17 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            write::update_records(&self.inner, &self.context(model, "update"), model, record_filter, args).await
+        })
+        .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
-            .await
+        self.catch(async move {
+            write::delete_records(&self.inner, &self.context(model, "delete"), model, record_filter).await
+        })
+        .await
     }
 
     async fn connect(
@@ -117,8 +180,17 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move {
+            write::connect(
+                &self.inner,
+                &self.context(&field.model(), "connect"),
+                field,
+                parent_id,
+                child_ids,
+            )
             .await
+        })
+        .await
     }
 
     async fn disconnect(
@@ -127,17 +199,39 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move {
+            write::disconnect(
+                &self.inner,
+                &self.context(&field.model(), "disconnect"),
+                field,
+                parent_id,
+                child_ids,
+            )
             .await
+        })
+        .await
     }
 
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
-            .await
+        self.catch(
+            async move { write::execute_raw(&self.inner, &self.raw_context("executeRaw"), query, parameters).await },
+        )
+        .await
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(&self.inner, &self.raw_context("queryRaw"), query, parameters).await })
             .await
     }
+
+    async fn query_raw_typed(
+        &self,
+        query: String,
+        parameters: Vec<PrismaValue>,
+    ) -> connector::Result<serde_json::Value> {
+        self.catch(async move {
+            write::query_raw_typed(&self.inner, &self.raw_context("queryRawTyped"), query, parameters).await
+        })
+        .await
+    }
 }
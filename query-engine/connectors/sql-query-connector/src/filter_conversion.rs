@@ -1,3 +1,4 @@
+use crate::SqlError;
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
 use quaint::ast::*;
@@ -60,7 +61,7 @@ pub trait AliasedCondition {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static>;
+    fn aliased_cond(self, alias: Option<Alias>) -> crate::Result<ConditionTree<'static>>;
 }
 
 trait AliasedSelect {
@@ -69,55 +70,52 @@ trait AliasedSelect {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static>;
+    fn aliased_sel(self, alias: Option<Alias>) -> crate::Result<Select<'static>>;
 }
 
 impl AliasedCondition for Filter {
     /// Conversion from a `Filter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
-        match self {
+    fn aliased_cond(self, alias: Option<Alias>) -> crate::Result<ConditionTree<'static>> {
+        let tree = match self {
             Filter::And(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias)?,
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
-                        .map(Expression::from)
-                        .collect();
+                        .map(|f| f.aliased_cond(alias).map(Expression::from))
+                        .collect::<crate::Result<Vec<_>>>()?;
 
                     ConditionTree::And(exprs)
                 }
             },
             Filter::Or(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NegativeCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias)?,
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
-                        .map(Expression::from)
-                        .collect();
+                        .map(|f| f.aliased_cond(alias).map(Expression::from))
+                        .collect::<crate::Result<Vec<_>>>()?;
 
                     ConditionTree::Or(exprs)
                 }
             },
             Filter::Not(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias).not(),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias)?.not(),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias).not())
-                        .map(Expression::from)
-                        .collect();
+                        .map(|f| f.aliased_cond(alias).map(|cond| Expression::from(cond.not())))
+                        .collect::<crate::Result<Vec<_>>>()?;
 
                     ConditionTree::And(exprs)
                 }
             },
-            Filter::Scalar(filter) => filter.aliased_cond(alias),
-            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
-            Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Scalar(filter) => filter.aliased_cond(alias)?,
+            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias)?,
+            Filter::Relation(filter) => filter.aliased_cond(alias)?,
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -126,62 +124,30 @@ impl AliasedCondition for Filter {
                 }
             }
             Filter::Empty => ConditionTree::NoCondition,
+            Filter::Composite(_) => return Err(unresolved_filter("composite")),
+            Filter::ScalarList(_) => return Err(unresolved_filter("scalar list")),
             _ => unimplemented!(),
-        }
+        };
+
+        Ok(tree)
     }
 }
 
-impl AliasedCondition for ScalarFilter {
-    /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
-        fn compare(comparable: impl Comparable<'static>, cond: ScalarCondition) -> ConditionTree<'static> {
-            let condition = match cond {
-                ScalarCondition::Equals(PrismaValue::Null(_)) => comparable.is_null(),
-                ScalarCondition::NotEquals(PrismaValue::Null(_)) => comparable.is_not_null(),
-                ScalarCondition::Equals(value) => comparable.equals(value),
-                ScalarCondition::NotEquals(value) => comparable.not_equals(value),
-                ScalarCondition::Contains(value) => comparable.like(format!("{}", value)),
-                ScalarCondition::NotContains(value) => comparable.not_like(format!("{}", value)),
-                ScalarCondition::StartsWith(value) => comparable.begins_with(format!("{}", value)),
-                ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(format!("{}", value)),
-                ScalarCondition::EndsWith(value) => comparable.ends_into(format!("{}", value)),
-                ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(format!("{}", value)),
-                ScalarCondition::LessThan(value) => comparable.less_than(value),
-                ScalarCondition::LessThanOrEquals(value) => comparable.less_than_or_equals(value),
-                ScalarCondition::GreaterThan(value) => comparable.greater_than(value),
-                ScalarCondition::GreaterThanOrEquals(value) => comparable.greater_than_or_equals(value),
-                ScalarCondition::In(values) => match values.split_first() {
-                    Some((PrismaValue::List(_), _)) => {
-                        let mut sql_values = Values::with_capacity(values.len());
-
-                        for pv in values {
-                            let list_value = pv.into_list().unwrap();
-                            sql_values.push(list_value);
-                        }
-
-                        comparable.in_selection(sql_values)
-                    }
-                    _ => comparable.in_selection(values),
-                },
-                ScalarCondition::NotIn(values) => match values.split_first() {
-                    Some((PrismaValue::List(_), _)) => {
-                        let mut sql_values = Values::with_capacity(values.len());
-
-                        for pv in values {
-                            let list_value = pv.into_list().unwrap();
-                            sql_values.push(list_value);
-                        }
-
-                        comparable.not_in_selection(sql_values)
-                    }
-                    _ => comparable.not_in_selection(values),
-                },
-            };
+/// Filters that can't be expressed with Quaint are resolved to the ids of the
+/// matching records before the conversion, see `filter_resolution`.
+fn unresolved_filter(kind: &str) -> SqlError {
+    let message = format!(
+        "Filters on {} fields must be resolved to record ids before they are converted to SQL.",
+        kind
+    );
 
-            ConditionTree::single(condition)
-        }
+    SqlError::QueryError(message.into())
+}
 
-        match (alias, self.projection) {
+impl AliasedCondition for ScalarFilter {
+    /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> crate::Result<ConditionTree<'static>> {
+        let tree = match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
                 compare(field.as_column().table(alias.to_string(None)), self.condition)
             }
@@ -199,13 +165,63 @@ impl AliasedCondition for ScalarFilter {
 
                 compare(Row::from(columns), self.condition)
             }
-        }
+        };
+
+        Ok(tree)
     }
 }
 
+/// The condition tree comparing `comparable` with the condition.
+pub(crate) fn compare(comparable: impl Comparable<'static>, cond: ScalarCondition) -> ConditionTree<'static> {
+    let condition = match cond {
+        ScalarCondition::Equals(PrismaValue::Null(_)) => comparable.is_null(),
+        ScalarCondition::NotEquals(PrismaValue::Null(_)) => comparable.is_not_null(),
+        ScalarCondition::Equals(value) => comparable.equals(value),
+        ScalarCondition::NotEquals(value) => comparable.not_equals(value),
+        ScalarCondition::Contains(value) => comparable.like(format!("{}", value)),
+        ScalarCondition::NotContains(value) => comparable.not_like(format!("{}", value)),
+        ScalarCondition::StartsWith(value) => comparable.begins_with(format!("{}", value)),
+        ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(format!("{}", value)),
+        ScalarCondition::EndsWith(value) => comparable.ends_into(format!("{}", value)),
+        ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(format!("{}", value)),
+        ScalarCondition::LessThan(value) => comparable.less_than(value),
+        ScalarCondition::LessThanOrEquals(value) => comparable.less_than_or_equals(value),
+        ScalarCondition::GreaterThan(value) => comparable.greater_than(value),
+        ScalarCondition::GreaterThanOrEquals(value) => comparable.greater_than_or_equals(value),
+        ScalarCondition::In(values) => match values.split_first() {
+            Some((PrismaValue::List(_), _)) => {
+                let mut sql_values = Values::with_capacity(values.len());
+
+                for pv in values {
+                    let list_value = pv.into_list().unwrap();
+                    sql_values.push(list_value);
+                }
+
+                comparable.in_selection(sql_values)
+            }
+            _ => comparable.in_selection(values),
+        },
+        ScalarCondition::NotIn(values) => match values.split_first() {
+            Some((PrismaValue::List(_), _)) => {
+                let mut sql_values = Values::with_capacity(values.len());
+
+                for pv in values {
+                    let list_value = pv.into_list().unwrap();
+                    sql_values.push(list_value);
+                }
+
+                comparable.not_in_selection(sql_values)
+            }
+            _ => comparable.not_in_selection(values),
+        },
+    };
+
+    ConditionTree::single(condition)
+}
+
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>) -> crate::Result<ConditionTree<'static>> {
         let identifier = self.field.model().primary_identifier();
         let ids = identifier.as_columns();
 
@@ -215,7 +231,7 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition.clone();
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)))?;
 
         let comparison = match condition {
             RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
@@ -224,13 +240,13 @@ impl AliasedCondition for RelationFilter {
             RelationCondition::ToOneRelatedRecord => Row::from(columns).in_selection(sub_select),
         };
 
-        comparison.into()
+        Ok(comparison.into())
     }
 }
 
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static> {
+    fn aliased_sel(self, alias: Option<Alias>) -> crate::Result<Select<'static>> {
         let alias = alias.unwrap_or(Alias::default());
         let condition = self.condition.clone();
         let relation = self.field.relation();
@@ -261,12 +277,12 @@ impl AliasedSelect for RelationFilter {
             // Don't do the useless join
             let conditions = self
                 .nested_filter
-                .aliased_cond(Some(alias))
+                .aliased_cond(Some(alias))?
                 .invert_if(condition.invert_of_subselect());
 
             let select_base = Select::from_table(relation.as_table().alias(alias.to_string(None))).so_that(conditions);
 
-            these_columns.fold(select_base, |acc, column| acc.column(column))
+            Ok(these_columns.fold(select_base, |acc, column| acc.column(column)))
         } else {
             let other_columns: Vec<_> = self
                 .field
@@ -284,7 +300,7 @@ impl AliasedSelect for RelationFilter {
 
             let conditions = self
                 .nested_filter
-                .aliased_cond(Some(alias.flip(AliasMode::Join)))
+                .aliased_cond(Some(alias.flip(AliasMode::Join)))?
                 .invert_if(condition.invert_of_subselect());
 
             let join = related_table
@@ -294,14 +310,14 @@ impl AliasedSelect for RelationFilter {
 
             let select_base = Select::from_table(table).inner_join(join).so_that(conditions);
 
-            these_columns.fold(select_base, |acc, column| acc.column(column))
+            Ok(these_columns.fold(select_base, |acc, column| acc.column(column)))
         }
     }
 }
 
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>) -> crate::Result<ConditionTree<'static>> {
         let alias = alias.map(|a| a.to_string(None));
 
         let condition = if self.field.relation_is_inlined_in_parent() {
@@ -341,6 +357,6 @@ impl AliasedCondition for OneRelationIsNullFilter {
             Row::from(id_columns).not_in_selection(select).into()
         };
 
-        ConditionTree::single(condition)
+        Ok(ConditionTree::single(condition))
     }
 }
@@ -0,0 +1,92 @@
+//! Some filters can't be expressed with Quaint, and are rendered to raw SQL
+//! instead. These are resolved to filters on the ids of the matching records
+//! before the actual query is built.
+//!
+//! The ids are an `IN` filter, which is split into batches of queries like
+//! any other long `IN` filter, keeping the statements under the parameter
+//! limits of the databases.
+
+use crate::{composite, scalar_list, QueryContext, QueryExt};
+use connector_interface::{filter::Filter, ScalarCompare};
+use futures::future::{BoxFuture, FutureExt};
+use prisma_models::*;
+
+/// Replaces the composite and scalar list filters in `filter` with filters on
+/// the ids of the records matching them.
+pub(crate) async fn resolve_filters<Q: QueryExt + ?Sized>(
+    conn: &Q,
+    ctx: &QueryContext<'_>,
+    filter: Filter,
+) -> crate::Result<Filter> {
+    if needs_resolution(&filter) {
+        resolve(conn, ctx, filter).await
+    } else {
+        Ok(filter)
+    }
+}
+
+fn needs_resolution(filter: &Filter) -> bool {
+    match filter {
+        Filter::And(filters) | Filter::Or(filters) | Filter::Not(filters) => filters.iter().any(needs_resolution),
+        Filter::Relation(filter) => needs_resolution(&filter.nested_filter),
+        Filter::Composite(_) | Filter::ScalarList(_) => true,
+        _ => false,
+    }
+}
+
+fn resolve<'a, Q: QueryExt + ?Sized>(
+    conn: &'a Q,
+    ctx: &'a QueryContext<'_>,
+    filter: Filter,
+) -> BoxFuture<'a, crate::Result<Filter>> {
+    async move {
+        let filter = match filter {
+            Filter::And(filters) => Filter::And(resolve_all(conn, ctx, filters).await?),
+            Filter::Or(filters) => Filter::Or(resolve_all(conn, ctx, filters).await?),
+            Filter::Not(filters) => Filter::Not(resolve_all(conn, ctx, filters).await?),
+            Filter::Relation(mut filter) => {
+                let nested_filter = resolve(conn, ctx, *filter.nested_filter).await?;
+                filter.nested_filter = Box::new(nested_filter);
+
+                Filter::Relation(filter)
+            }
+            Filter::Composite(filter) => {
+                let model_id = filter.field.model().primary_identifier();
+                ids_filter(&model_id, composite::matching_ids(conn, ctx, filter).await?)
+            }
+            Filter::ScalarList(filter) => {
+                let model_id = filter.field.model().primary_identifier();
+                ids_filter(&model_id, scalar_list::matching_ids(conn, ctx, filter).await?)
+            }
+            filter => filter,
+        };
+
+        Ok(filter)
+    }
+    .boxed()
+}
+
+async fn resolve_all<Q: QueryExt + ?Sized>(
+    conn: &Q,
+    ctx: &QueryContext<'_>,
+    filters: Vec<Filter>,
+) -> crate::Result<Vec<Filter>> {
+    let mut resolved = Vec::with_capacity(filters.len());
+
+    for filter in filters {
+        resolved.push(resolve(conn, ctx, filter).await?);
+    }
+
+    Ok(resolved)
+}
+
+/// The filter matching the records with the ids.
+fn ids_filter(model_id: &ModelProjection, ids: Vec<RecordProjection>) -> Filter {
+    if ids.is_empty() {
+        return Filter::BoolFilter(false);
+    }
+
+    let values: Vec<Vec<PrismaValue>> = ids.into_iter().map(|id| id.values().collect()).collect();
+
+    model_id.is_in(values)
+}
@@ -1,17 +1,29 @@
+#[macro_use]
+extern crate tracing;
+
+mod composite;
 mod cursor_condition;
 mod database;
 mod error;
 mod filter_conversion;
+mod filter_resolution;
 mod ordering;
 mod query_builder;
 mod query_ext;
+mod query_log;
+mod raw_result;
+mod rendered_query;
 mod row;
+mod scalar_list;
 
 use filter_conversion::*;
 use query_ext::QueryExt;
+use query_log::QueryContext;
 use row::*;
 
 pub use database::*;
 pub use error::SqlError;
+pub use query_log::SlowQueryLog;
+pub use raw_result::{RawColumnType, RawResult};
 
 type Result<T> = std::result::Result<T, error::SqlError>;
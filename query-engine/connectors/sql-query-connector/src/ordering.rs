@@ -19,6 +19,22 @@ impl Ordering {
         )
     }
 
+    /// Orderings for a query reading from a subquery of the model that is aliased as `alias`.
+    pub fn for_alias(model: &ModelRef, alias: &str, order_directive: OrderDirections) -> OrderVec<'static> {
+        Self::by_fields(
+            order_directive
+                .primary_order_by
+                .as_ref()
+                .map(|oby| oby.field.as_column().table(alias.to_owned())),
+            model
+                .primary_identifier()
+                .as_columns()
+                .map(|c| c.table(alias.to_owned()))
+                .collect(),
+            order_directive,
+        )
+    }
+
     fn by_fields(
         first_column: Option<Column<'static>>,
         identifier: Vec<Column<'static>>,
@@ -7,7 +7,7 @@ pub use write::*;
 use prisma_models::RecordProjection;
 use quaint::ast::{Column, Comparable, ConditionTree, Query, Row, Values};
 
-const PARAMETER_LIMIT: usize = 10000;
+pub(crate) const PARAMETER_LIMIT: usize = 10000;
 
 pub(super) fn chunked_conditions<F, Q>(
     columns: &[Column<'static>],
@@ -6,47 +6,36 @@ use quaint::ast::*;
 use std::sync::Arc;
 
 pub trait SelectDefinition {
-    fn into_select(self, _: &ModelRef) -> Select<'static>;
+    fn into_select(self, _: &ModelRef) -> crate::Result<Select<'static>>;
 }
 
 impl SelectDefinition for Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef) -> crate::Result<Select<'static>> {
         let args = QueryArguments::from(self);
         args.into_select(model)
     }
 }
 
 impl SelectDefinition for &Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef) -> crate::Result<Select<'static>> {
         self.clone().into_select(model)
     }
 }
 
 impl SelectDefinition for Select<'static> {
-    fn into_select(self, _: &ModelRef) -> Select<'static> {
-        self
+    fn into_select(self, _: &ModelRef) -> crate::Result<Select<'static>> {
+        Ok(self)
     }
 }
 
 impl SelectDefinition for QueryArguments {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        let cursor: ConditionTree = cursor_condition::build(&self, Arc::clone(&model));
+    fn into_select(self, model: &ModelRef) -> crate::Result<Select<'static>> {
         let ordering_directions = self.ordering_directions();
         let ordering = Ordering::for_model(&model, ordering_directions);
 
         let limit = if self.ignore_take { None } else { self.take_abs() };
         let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
-
-        let filter: ConditionTree = self
-            .filter
-            .map(|f| f.aliased_cond(None))
-            .unwrap_or(ConditionTree::NoCondition);
-
-        let conditions = match (filter, cursor) {
-            (ConditionTree::NoCondition, cursor) => cursor,
-            (filter, ConditionTree::NoCondition) => filter,
-            (filter, cursor) => ConditionTree::and(filter, cursor),
-        };
+        let conditions = conditions(&self, model)?;
 
         let select_ast = Select::from_table(model.as_table())
             .so_that(conditions)
@@ -54,20 +43,109 @@ impl SelectDefinition for QueryArguments {
 
         let select_ast = ordering.into_iter().fold(select_ast, |acc, ord| acc.order_by(ord));
 
-        match limit {
+        let select_ast = match limit {
             Some(limit) => select_ast.limit(limit as usize),
             None => select_ast,
-        }
+        };
+
+        Ok(select_ast)
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+/// The filter and cursor conditions of the query arguments.
+fn conditions(args: &QueryArguments, model: &ModelRef) -> crate::Result<ConditionTree<'static>> {
+    let cursor: ConditionTree = cursor_condition::build(args, Arc::clone(&model));
+
+    let filter: ConditionTree = match args.filter.clone() {
+        Some(filter) => filter.aliased_cond(None)?,
+        None => ConditionTree::NoCondition,
+    };
+
+    let conditions = match (filter, cursor) {
+        (ConditionTree::NoCondition, cursor) => cursor,
+        (filter, ConditionTree::NoCondition) => filter,
+        (filter, cursor) => ConditionTree::and(filter, cursor),
+    };
+
+    Ok(conditions)
+}
+
+pub fn get_records<T>(
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    query: T,
+) -> crate::Result<Select<'static>>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    Ok(columns.fold(query.into_select(model)?, |acc, col| acc.column(col)))
+}
+
+/// Generates a query of the form:
+/// ```sql
+/// SELECT "distinct"."id", "distinct"."country" FROM (
+///     SELECT
+///         "User"."id",
+///         "User"."country",
+///         ROW_NUMBER() OVER (PARTITION BY "User"."country" ORDER BY "User"."id") AS "distinct_row"
+///     FROM
+///         "User"
+///     WHERE
+///         1 = 1
+/// ) AS "distinct"
+/// WHERE
+///     "distinct"."distinct_row" = 1
+/// ORDER BY
+///     "distinct"."id"
+/// LIMIT 10 OFFSET 0;
+/// ```
+///
+/// Numbering the rows of every group of distinct values in the order of the query arguments keeps
+/// the first record of each group, like `DISTINCT ON` does. Ordering, `skip` and `take` of the
+/// query arguments then apply to the distinct records.
+pub fn get_distinct_records(
+    model: &ModelRef,
+    selected_fields: &ModelProjection,
+    args: QueryArguments,
+    distinct: &ModelProjection,
+) -> crate::Result<Select<'static>> {
+    let row_number = Ordering::for_model(&model, args.ordering_directions())
+        .into_iter()
+        .fold(row_number(), |acc, ord| acc.order_by(ord));
+
+    let row_number = distinct
+        .as_columns()
+        .fold(row_number, |acc, column| acc.partition_by(column));
+
+    let inner = selected_fields.as_columns().fold(
+        Select::from_table(model.as_table()).so_that(conditions(&args, model)?),
+        |acc, column| acc.column(column),
+    );
+
+    let inner = inner.value(Function::from(row_number).alias(DISTINCT_ROW));
+    let ordering = Ordering::for_alias(&model, DISTINCT_ALIAS, args.ordering_directions());
+    let skip = if args.ignore_skip { 0 } else { args.skip.unwrap_or(0) };
+
+    let outer = selected_fields.as_columns().fold(
+        Select::from_table(Table::from(inner).alias(DISTINCT_ALIAS))
+            .so_that(Column::from((DISTINCT_ALIAS, DISTINCT_ROW)).equals(1))
+            .offset(skip as usize),
+        |acc, column| acc.column(column.table(DISTINCT_ALIAS)),
+    );
+
+    let outer = ordering.into_iter().fold(outer, |acc, ord| acc.order_by(ord));
+
+    let outer = match args.take_abs() {
+        Some(limit) if !args.ignore_take => outer.limit(limit as usize),
+        _ => outer,
+    };
+
+    Ok(outer)
 }
 
+const DISTINCT_ALIAS: &str = "distinct";
+const DISTINCT_ROW: &str = "distinct_row";
+
 /// Generates a query of the form:
 /// ```sql
 /// SELECT
@@ -92,12 +170,12 @@ where
 ///             1 = 1
 ///     ) AS `sub`;
 /// ```
-pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArguments) -> Select<'static> {
+pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArguments) -> crate::Result<Select<'static>> {
     let columns = extract_columns(model, &aggregators);
-    let sub_query = get_records(model, columns.into_iter(), args);
+    let sub_query = get_records(model, columns.into_iter(), args)?;
     let sub_table = Table::from(sub_query).alias("sub");
 
-    aggregators
+    let select = aggregators
         .into_iter()
         .fold(Select::from_table(sub_table), |select, next_op| match next_op {
             Aggregator::Count => select.value(count(asterisk())),
@@ -117,7 +195,9 @@ pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArgume
             Aggregator::Max(fields) => fields
                 .into_iter()
                 .fold(select, |select, next_field| select.value(max(next_field.name.clone()))),
-        })
+        });
+
+    Ok(select)
 }
 
 fn extract_columns(model: &ModelRef, aggregators: &[Aggregator]) -> Vec<Column<'static>> {
@@ -1,4 +1,4 @@
-use crate::{error::*, AliasedCondition, SqlRow, ToSqlRow};
+use crate::{error::*, raw_result::RawResult, AliasedCondition, QueryContext, SqlRow, ToSqlRow};
 use async_trait::async_trait;
 use connector_interface::{filter::Filter, RecordFilter};
 use datamodel::FieldArity;
@@ -11,7 +11,7 @@ use quaint::{
 };
 
 use serde_json::{Map, Value};
-use std::{convert::TryFrom, panic::AssertUnwindSafe};
+use std::{collections::HashSet, convert::TryFrom, panic::AssertUnwindSafe, time::Instant};
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
 impl QueryExt for PooledConnection {}
@@ -21,8 +21,13 @@ impl QueryExt for PooledConnection {}
 #[async_trait]
 pub trait QueryExt: Queryable + Send + Sync {
     /// Filter and map the resulting types with the given identifiers.
-    async fn filter(&self, q: Query<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<Vec<SqlRow>> {
-        let result_set = self.query(q).await?;
+    async fn filter(
+        &self,
+        ctx: &QueryContext<'_>,
+        q: Query<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+    ) -> crate::Result<Vec<SqlRow>> {
+        let result_set = ctx.query(self, q).await?;
         let mut sql_rows = Vec::new();
 
         for row in result_set {
@@ -32,15 +37,55 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
+    /// Filter with a query that was already rendered to SQL, mapping the
+    /// resulting types with the given identifiers.
+    async fn filter_rendered(
+        &self,
+        ctx: &QueryContext<'_>,
+        sql: &str,
+        params: &[quaint::ast::Value<'_>],
+        idents: &[(TypeIdentifier, FieldArity)],
+    ) -> crate::Result<Vec<SqlRow>> {
+        let start = Instant::now();
+        let result_set = self.query_raw(sql, params).await?;
+        ctx.log_rendered(self, sql, params, start.elapsed()).await;
+
+        let mut sql_rows = Vec::new();
+
+        for row in result_set {
+            sql_rows.push(row.to_sql_row(idents)?);
+        }
+
+        Ok(sql_rows)
+    }
+
+    /// Execute a statement that was already rendered to SQL, returning the
+    /// number of affected rows.
+    async fn execute_rendered(
+        &self,
+        ctx: &QueryContext<'_>,
+        sql: &str,
+        params: &[quaint::ast::Value<'_>],
+    ) -> crate::Result<u64> {
+        let start = Instant::now();
+        let changes = self.execute_raw(sql, params).await?;
+        ctx.log_rendered(self, sql, params, start.elapsed()).await;
+
+        Ok(changes)
+    }
+
     /// Execute a singular SQL query in the database, returning an arbitrary
     /// JSON `Value` as a result.
     async fn raw_json<'a>(
         &'a self,
+        ctx: &QueryContext<'_>,
         q: String,
         params: Vec<PrismaValue>,
     ) -> std::result::Result<Value, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(quaint::ast::Value::from).collect();
+        let start = Instant::now();
         let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
+        ctx.log_raw(self, &q, &params, start.elapsed()).await;
 
         let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
         let mut result = Vec::new();
@@ -59,22 +104,48 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(Value::Array(result))
     }
 
+    /// Execute a singular SQL query in the database, returning the column
+    /// names, column types and typed rows of the result as a JSON `Value`.
+    async fn raw_typed_json<'a>(
+        &'a self,
+        ctx: &QueryContext<'_>,
+        q: String,
+        params: Vec<PrismaValue>,
+    ) -> std::result::Result<Value, crate::error::RawError> {
+        let params: Vec<_> = params.into_iter().map(quaint::ast::Value::from).collect();
+        let start = Instant::now();
+        let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
+        ctx.log_raw(self, &q, &params, start.elapsed()).await;
+
+        let result = RawResult::from(result_set);
+
+        Ok(serde_json::to_value(&result).expect("Failed to render raw result."))
+    }
+
     /// Execute a singular SQL query in the database, returning the number of
     /// affected rows.
     async fn raw_count<'a>(
         &'a self,
+        ctx: &QueryContext<'_>,
         q: String,
         params: Vec<PrismaValue>,
     ) -> std::result::Result<usize, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(quaint::ast::Value::from).collect();
+        let start = Instant::now();
         let changes = AssertUnwindSafe(self.execute_raw(&q, &params)).catch_unwind().await??;
+        ctx.log_raw(self, &q, &params, start.elapsed()).await;
 
         Ok(changes as usize)
     }
 
     /// Select one row from the database.
-    async fn find(&self, q: Select<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<SqlRow> {
-        self.filter(q.limit(1).into(), idents)
+    async fn find(
+        &self,
+        ctx: &QueryContext<'_>,
+        q: Select<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+    ) -> crate::Result<SqlRow> {
+        self.filter(ctx, q.limit(1).into(), idents)
             .await?
             .into_iter()
             .next()
@@ -82,10 +153,10 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the first column from the first row as an integer.
-    async fn find_int(&self, q: Select<'_>) -> crate::Result<i64> {
+    async fn find_int(&self, ctx: &QueryContext<'_>, q: Select<'_>) -> crate::Result<i64> {
         // UNWRAP: A dataset will always have at least one column, even if it contains no data.
         let id = self
-            .find(q, &[(TypeIdentifier::Int, FieldArity::Required)])
+            .find(ctx, q, &[(TypeIdentifier::Int, FieldArity::Required)])
             .await?
             .values
             .into_iter()
@@ -102,29 +173,59 @@ pub trait QueryExt: Queryable + Send + Sync {
     /// or fetch IDs from the database.
     async fn filter_selectors(
         &self,
+        ctx: &QueryContext<'_>,
         model: &ModelRef,
         record_filter: RecordFilter,
     ) -> crate::Result<Vec<RecordProjection>> {
         if let Some(selectors) = record_filter.selectors {
             Ok(selectors)
         } else {
-            self.filter_ids(model, record_filter.filter).await
+            self.filter_ids(ctx, model, record_filter.filter).await
         }
     }
 
     /// Read the all columns as a (primary) identifier.
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<RecordProjection>> {
+    async fn filter_ids(
+        &self,
+        ctx: &QueryContext<'_>,
+        model: &ModelRef,
+        filter: Filter,
+    ) -> crate::Result<Vec<RecordProjection>> {
+        let filter = crate::filter_resolution::resolve_filters(self, ctx, filter).await?;
         let model_id = model.primary_identifier();
         let id_cols: Vec<Column<'static>> = model_id.as_columns().collect();
 
-        let select = Select::from_table(model.as_table())
-            .columns(id_cols)
-            .so_that(filter.aliased_cond(None));
+        let filters = if filter.can_batch() {
+            filter.batched()
+        } else {
+            vec![filter]
+        };
+
+        let mut ids = Vec::new();
+        let mut seen = HashSet::new();
+
+        // Records can match more than one batch.
+        for filter in filters {
+            let select = Select::from_table(model.as_table())
+                .columns(id_cols.clone())
+                .so_that(filter.aliased_cond(None)?);
+
+            for id in self.select_ids(ctx, select, model_id.clone()).await? {
+                if seen.insert(id.clone()) {
+                    ids.push(id);
+                }
+            }
+        }
 
-        self.select_ids(select, model_id).await
+        Ok(ids)
     }
 
-    async fn select_ids(&self, select: Select<'_>, model_id: ModelProjection) -> crate::Result<Vec<RecordProjection>> {
+    async fn select_ids(
+        &self,
+        ctx: &QueryContext<'_>,
+        select: Select<'_>,
+        model_id: ModelProjection,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let idents: Vec<_> = model_id
             .fields()
             .into_iter()
@@ -134,7 +235,7 @@ pub trait QueryExt: Queryable + Send + Sync {
             })
             .collect();
 
-        let mut rows = self.filter(select.into(), &idents).await?;
+        let mut rows = self.filter(ctx, select.into(), &idents).await?;
         let mut result = Vec::new();
 
         for row in rows.drain(0..) {
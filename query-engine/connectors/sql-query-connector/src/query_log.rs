@@ -0,0 +1,397 @@
+use quaint::{
+    ast::*,
+    connector::{Queryable, ResultSet},
+    prelude::ConnectionInfo,
+    visitor::{self, Visitor},
+};
+use serde_json::{Map, Value as JsonValue};
+use std::time::{Duration, Instant};
+
+/// Connection string parameter holding the slow query threshold in milliseconds.
+const THRESHOLD_PARAM: &str = "slow_query_threshold";
+
+/// Connection string parameter enabling `EXPLAIN` capture for slow queries.
+const EXPLAIN_PARAM: &str = "explain_slow_queries";
+
+/// Settings of the slow query log. Statements running longer than the
+/// threshold are logged on the `slow_query` target. In explain mode, the query
+/// plan the database reports for the statement is attached to the log entry.
+#[derive(Debug, Clone, Default)]
+pub struct SlowQueryLog {
+    threshold: Option<Duration>,
+    explain: bool,
+}
+
+impl SlowQueryLog {
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold: Some(threshold),
+            explain: false,
+        }
+    }
+
+    /// Run `EXPLAIN` (`EXPLAIN QUERY PLAN` on SQLite) for every slow statement.
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    /// Reads the settings from the `slow_query_threshold` and
+    /// `explain_slow_queries` connection string parameters. Without a
+    /// threshold, the log is disabled.
+    pub fn from_url(url: &str) -> Self {
+        Self {
+            threshold: datamodel::url_parameter(url, THRESHOLD_PARAM)
+                .and_then(|millis| millis.parse().ok())
+                .map(Duration::from_millis),
+            explain: datamodel::url_parameter(url, EXPLAIN_PARAM)
+                .and_then(|flag| flag.parse().ok())
+                .unwrap_or(false),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.threshold.is_some()
+    }
+
+    fn is_slow(&self, elapsed: Duration) -> bool {
+        self.threshold.map(|threshold| elapsed >= threshold).unwrap_or(false)
+    }
+}
+
+/// A statement that exceeded the slow query threshold.
+#[derive(Debug)]
+struct SlowQuery {
+    query: String,
+    params: usize,
+    duration: Duration,
+    /// The query plan as a JSON array of rows, in explain mode.
+    plan: Option<String>,
+}
+
+/// Everything needed to attribute a statement to the Prisma operation that
+/// issued it, like `User.findMany`.
+pub struct QueryContext<'a> {
+    connection_info: &'a ConnectionInfo,
+    slow_query_log: &'a SlowQueryLog,
+    operation: String,
+    in_transaction: bool,
+}
+
+impl<'a> QueryContext<'a> {
+    pub fn new(
+        connection_info: &'a ConnectionInfo,
+        slow_query_log: &'a SlowQueryLog,
+        operation: impl Into<String>,
+    ) -> Self {
+        Self {
+            connection_info,
+            slow_query_log,
+            operation: operation.into(),
+            in_transaction: false,
+        }
+    }
+
+    /// The statements run in an open transaction, so slow statements are not
+    /// explained: a failing `EXPLAIN` would abort the transaction on
+    /// PostgreSQL.
+    pub fn within_transaction(mut self) -> Self {
+        self.in_transaction = true;
+        self
+    }
+
+    pub fn connection_info(&self) -> &ConnectionInfo {
+        self.connection_info
+    }
+
+    /// Execute the query, logging it if it exceeds the slow query threshold.
+    pub(crate) async fn query<Q>(&self, conn: &Q, q: Query<'_>) -> quaint::Result<ResultSet>
+    where
+        Q: Queryable + Sync + ?Sized,
+    {
+        if !self.slow_query_log.is_enabled() {
+            return conn.query(q).await;
+        }
+
+        let logged = q.clone();
+        let start = Instant::now();
+        let result = conn.query(q).await;
+        let elapsed = start.elapsed();
+
+        if self.slow_query_log.is_slow(elapsed) {
+            match self.render(logged) {
+                Some((sql, params)) => self.log_statement(conn, &sql, &params, elapsed, true).await,
+                None => self.log_statement(conn, "<not rendered>", &[], elapsed, false).await,
+            }
+        }
+
+        result
+    }
+
+    /// Log a statement Prisma rendered to SQL by hand, if it exceeded the slow
+    /// query threshold.
+    pub(crate) async fn log_rendered<Q>(&self, conn: &Q, sql: &str, params: &[Value<'_>], elapsed: Duration)
+    where
+        Q: Queryable + Sync + ?Sized,
+    {
+        self.log_statement(conn, sql, params, elapsed, true).await
+    }
+
+    /// Log a raw SQL statement of the user, if it exceeded the slow query
+    /// threshold. Only single `SELECT` statements are explained, prefixing
+    /// arbitrary SQL with `EXPLAIN` could fail, or run more than the plan.
+    pub(crate) async fn log_raw<Q>(&self, conn: &Q, sql: &str, params: &[Value<'_>], elapsed: Duration)
+    where
+        Q: Queryable + Sync + ?Sized,
+    {
+        self.log_statement(conn, sql, params, elapsed, is_single_select(sql))
+            .await
+    }
+
+    async fn log_statement<Q>(&self, conn: &Q, sql: &str, params: &[Value<'_>], elapsed: Duration, explainable: bool)
+    where
+        Q: Queryable + Sync + ?Sized,
+    {
+        if let Some(slow_query) = self.slow_query(conn, sql, params, elapsed, explainable).await {
+            warn!(
+                target: "slow_query",
+                operation = self.operation.as_str(),
+                query = slow_query.query.as_str(),
+                params = slow_query.params,
+                duration_ms = slow_query.duration.as_millis() as u64,
+                plan = slow_query.plan.as_deref().unwrap_or(""),
+                "Slow query",
+            );
+        }
+    }
+
+    /// The log entry for the statement, or `None` if it stayed below the
+    /// threshold.
+    async fn slow_query<Q>(
+        &self,
+        conn: &Q,
+        sql: &str,
+        params: &[Value<'_>],
+        elapsed: Duration,
+        explainable: bool,
+    ) -> Option<SlowQuery>
+    where
+        Q: Queryable + Sync + ?Sized,
+    {
+        if !self.slow_query_log.is_slow(elapsed) {
+            return None;
+        }
+
+        let plan = if self.slow_query_log.explain && explainable && !self.in_transaction {
+            self.explain(conn, sql, params).await
+        } else {
+            None
+        };
+
+        Some(SlowQuery {
+            query: sql.to_owned(),
+            params: params.len(),
+            duration: elapsed,
+            plan,
+        })
+    }
+
+    async fn explain<Q>(&self, conn: &Q, sql: &str, params: &[Value<'_>]) -> Option<String>
+    where
+        Q: Queryable + Sync + ?Sized,
+    {
+        let prefix = match self.connection_info {
+            ConnectionInfo::Postgres(..) | ConnectionInfo::Mysql(..) => "EXPLAIN",
+            ConnectionInfo::Sqlite { .. } => "EXPLAIN QUERY PLAN",
+            _ => return None,
+        };
+
+        let result_set = match conn.query_raw(&format!("{} {}", prefix, sql), params).await {
+            Ok(result_set) => result_set,
+            Err(err) => {
+                warn!(
+                    target: "slow_query",
+                    operation = self.operation.as_str(),
+                    "Could not explain slow query: {}",
+                    err
+                );
+                return None;
+            }
+        };
+
+        let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
+        let mut plan = Vec::new();
+
+        for row in result_set.into_iter() {
+            let mut object = Map::new();
+
+            for (idx, value) in row.into_iter().enumerate() {
+                object.insert(columns[idx].clone(), JsonValue::from(value));
+            }
+
+            plan.push(JsonValue::Object(object));
+        }
+
+        Some(JsonValue::Array(plan).to_string())
+    }
+
+    fn render<'q>(&self, q: Query<'q>) -> Option<(String, Vec<Value<'q>>)> {
+        let rendered = match self.connection_info {
+            ConnectionInfo::Postgres(..) => visitor::Postgres::build(q),
+            ConnectionInfo::Mysql(..) => visitor::Mysql::build(q),
+            ConnectionInfo::Sqlite { .. } => visitor::Sqlite::build(q),
+            _ => return None,
+        };
+
+        rendered.ok()
+    }
+}
+
+/// Whether the SQL is a single `SELECT` statement, that `EXPLAIN` can't turn
+/// into anything but a read.
+fn is_single_select(sql: &str) -> bool {
+    let sql = sql.trim().trim_end_matches(';');
+    let keyword: String = sql.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+
+    keyword.eq_ignore_ascii_case("select") && !sql.contains(';')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::single::Quaint;
+
+    async fn test_database(name: &str) -> Quaint {
+        let path = std::env::temp_dir().join(format!("slow_query_log_{}.db", name));
+
+        if path.exists() {
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        let conn = Quaint::new(&format!("file://{}", path.display())).await.unwrap();
+
+        conn.query_raw(r#"CREATE TABLE "Cat" (id INTEGER PRIMARY KEY, name TEXT)"#, &[])
+            .await
+            .unwrap();
+
+        conn
+    }
+
+    const SQL: &str = r#"SELECT * FROM "Cat" WHERE name = ?"#;
+
+    #[test]
+    fn the_settings_are_read_from_the_connection_string() {
+        let log =
+            SlowQueryLog::from_url("postgresql://localhost:5432/db?slow_query_threshold=250&explain_slow_queries=true");
+
+        assert_eq!(log.threshold, Some(Duration::from_millis(250)));
+        assert!(log.explain);
+
+        let log = SlowQueryLog::from_url("postgresql://localhost:5432/db?schema=public");
+
+        assert!(!log.is_enabled());
+        assert!(!log.explain);
+    }
+
+    #[tokio::test]
+    async fn statements_below_the_threshold_are_not_logged() {
+        let conn = test_database("below_threshold").await;
+        let log = SlowQueryLog::new(Duration::from_secs(60)).explain(true);
+        let ctx = QueryContext::new(conn.connection_info(), &log, "Cat.findMany");
+
+        let slow_query = ctx
+            .slow_query(&conn, SQL, &[Value::from("Felix")], Duration::from_millis(59_999), true)
+            .await;
+
+        assert!(slow_query.is_none());
+    }
+
+    #[tokio::test]
+    async fn statements_above_the_threshold_are_logged() {
+        let conn = test_database("above_threshold").await;
+        let log = SlowQueryLog::new(Duration::from_millis(100));
+        let ctx = QueryContext::new(conn.connection_info(), &log, "Cat.findMany");
+
+        let slow_query = ctx
+            .slow_query(&conn, SQL, &[Value::from("Felix")], Duration::from_millis(100), true)
+            .await
+            .unwrap();
+
+        assert_eq!(slow_query.query, SQL);
+        assert_eq!(slow_query.params, 1);
+        assert_eq!(slow_query.duration, Duration::from_millis(100));
+        assert_eq!(slow_query.plan, None);
+    }
+
+    #[tokio::test]
+    async fn slow_statements_are_explained_in_explain_mode() {
+        let conn = test_database("explain").await;
+        let log = SlowQueryLog::new(Duration::from_millis(100)).explain(true);
+        let ctx = QueryContext::new(conn.connection_info(), &log, "Cat.findMany");
+
+        let slow_query = ctx
+            .slow_query(&conn, SQL, &[Value::from("Felix")], Duration::from_secs(1), true)
+            .await
+            .unwrap();
+
+        let plan: JsonValue = serde_json::from_str(&slow_query.plan.unwrap()).unwrap();
+        let rows = plan.as_array().unwrap();
+
+        assert!(!rows.is_empty());
+        assert!(rows
+            .iter()
+            .any(|row| row["detail"].as_str().unwrap_or("").contains("Cat")));
+    }
+
+    #[tokio::test]
+    async fn slow_statements_in_transactions_are_not_explained() {
+        let conn = test_database("explain_in_transaction").await;
+        let log = SlowQueryLog::new(Duration::from_millis(100)).explain(true);
+        let ctx = QueryContext::new(conn.connection_info(), &log, "Cat.findMany").within_transaction();
+
+        let slow_query = ctx
+            .slow_query(&conn, SQL, &[Value::from("Felix")], Duration::from_secs(1), true)
+            .await
+            .unwrap();
+
+        assert_eq!(slow_query.plan, None);
+    }
+
+    #[tokio::test]
+    async fn statements_that_are_not_explainable_are_not_explained() {
+        let conn = test_database("not_explainable").await;
+        let log = SlowQueryLog::new(Duration::from_millis(100)).explain(true);
+        let ctx = QueryContext::new(conn.connection_info(), &log, "executeRaw");
+        let sql = r#"DELETE FROM "Cat""#;
+
+        let slow_query = ctx
+            .slow_query(&conn, sql, &[], Duration::from_secs(1), is_single_select(sql))
+            .await
+            .unwrap();
+
+        assert_eq!(slow_query.query, sql);
+        assert_eq!(slow_query.plan, None);
+    }
+
+    #[test]
+    fn only_single_select_statements_are_explainable_raw_sql() {
+        assert!(is_single_select(SQL));
+        assert!(is_single_select("  select 1;"));
+        assert!(!is_single_select("SELECT 1; DROP TABLE \"Cat\""));
+        assert!(!is_single_select(r#"UPDATE "Cat" SET name = 'Felix'"#));
+        assert!(!is_single_select("SELECTION"));
+        assert!(!is_single_select("CREATE TABLE \"Dog\" (id INTEGER)"));
+    }
+
+    #[tokio::test]
+    async fn logged_queries_still_return_their_result() {
+        let conn = test_database("query").await;
+        let log = SlowQueryLog::new(Duration::from_millis(0)).explain(true);
+        let ctx = QueryContext::new(conn.connection_info(), &log, "Cat.findMany");
+
+        let query = Select::from_table("Cat").so_that("name".equals("Felix"));
+        let result = ctx.query(&conn, query.into()).await.unwrap();
+
+        assert_eq!(result.into_iter().count(), 0);
+    }
+}
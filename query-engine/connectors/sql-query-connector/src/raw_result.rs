@@ -0,0 +1,186 @@
+use chrono::SecondsFormat;
+use quaint::{ast::Value, connector::ResultSet};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+/// Integers outside of this range can't be represented by a JavaScript number
+/// and are encoded as strings.
+const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+
+/// The type of a column in a raw query result.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RawColumnType {
+    /// Integers, encoded as numbers.
+    Int,
+    /// Integers exceeding the safe JavaScript range, encoded as strings.
+    BigInt,
+    /// Exact numerics and floats, encoded as strings.
+    Decimal,
+    String,
+    Enum,
+    Bool,
+    Char,
+    /// JSON values, embedded as they are.
+    Json,
+    Uuid,
+    /// `YYYY-MM-DD` strings.
+    Date,
+    /// `HH:MM:SS[.fff]` strings.
+    Time,
+    /// RFC 3339 strings.
+    DateTime,
+    /// Base64 encoded strings.
+    Bytes,
+    /// Arrays with each element encoded according to its own type.
+    Array,
+    /// Columns of empty results.
+    Unknown,
+}
+
+/// The result of a raw query: column names and types, and the rows with values
+/// encoded according to the column types.
+#[derive(Debug, Serialize)]
+pub struct RawResult {
+    pub columns: Vec<String>,
+    pub types: Vec<RawColumnType>,
+    pub rows: Vec<Vec<JsonValue>>,
+}
+
+impl From<ResultSet> for RawResult {
+    fn from(result_set: ResultSet) -> Self {
+        let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
+        let values: Vec<Vec<Value>> = result_set.into_iter().map(|row| row.into_iter().collect()).collect();
+        let types = column_types(columns.len(), &values);
+
+        let rows = values
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .zip(types.iter())
+                    .map(|(value, column_type)| encode_value(value, *column_type))
+                    .collect()
+            })
+            .collect();
+
+        Self { columns, types, rows }
+    }
+}
+
+/// The result sets of Quaint carry no column types, they come from the values.
+/// The first value that isn't null decides the type of a column, as nulls of
+/// expressions without a declared type can be read as a different type than
+/// the values of the column. Columns with only nulls fall back to the type of
+/// the null, columns of empty results are `Unknown`. Integer columns holding
+/// values outside of the safe range are returned as `BigInt` to encode all of
+/// their values the same way.
+fn column_types(column_count: usize, rows: &[Vec<Value>]) -> Vec<RawColumnType> {
+    (0..column_count)
+        .map(|idx| {
+            let value = rows
+                .iter()
+                .map(|row| &row[idx])
+                .find(|value| !value.is_null())
+                .or_else(|| rows.first().map(|row| &row[idx]));
+
+            let column_type = value.map(value_type).unwrap_or(RawColumnType::Unknown);
+
+            match column_type {
+                RawColumnType::Int if rows.iter().any(|row| exceeds_safe_range(&row[idx])) => RawColumnType::BigInt,
+                column_type => column_type,
+            }
+        })
+        .collect()
+}
+
+fn exceeds_safe_range(value: &Value) -> bool {
+    match value {
+        Value::Integer(Some(i)) => !is_safe_integer(*i),
+        _ => false,
+    }
+}
+
+fn value_type(value: &Value) -> RawColumnType {
+    match value {
+        Value::Integer(_) => RawColumnType::Int,
+        Value::Real(_) => RawColumnType::Decimal,
+        Value::Text(_) => RawColumnType::String,
+        Value::Enum(_) => RawColumnType::Enum,
+        Value::Boolean(_) => RawColumnType::Bool,
+        Value::Char(_) => RawColumnType::Char,
+        Value::Json(_) => RawColumnType::Json,
+        Value::Uuid(_) => RawColumnType::Uuid,
+        Value::Date(_) => RawColumnType::Date,
+        Value::Time(_) => RawColumnType::Time,
+        Value::DateTime(_) => RawColumnType::DateTime,
+        Value::Bytes(_) => RawColumnType::Bytes,
+        Value::Array(_) => RawColumnType::Array,
+    }
+}
+
+fn encode_value(value: Value, column_type: RawColumnType) -> JsonValue {
+    match value {
+        Value::Integer(Some(i)) if column_type == RawColumnType::BigInt || !is_safe_integer(i) => {
+            JsonValue::String(i.to_string())
+        }
+        Value::Integer(Some(i)) => JsonValue::from(i),
+        Value::Real(Some(d)) => JsonValue::String(d.to_string()),
+        Value::Text(Some(s)) | Value::Enum(Some(s)) => JsonValue::String(s.into_owned()),
+        Value::Boolean(Some(b)) => JsonValue::Bool(b),
+        Value::Char(Some(c)) => JsonValue::String(c.to_string()),
+        Value::Json(Some(json)) => json,
+        Value::Uuid(Some(uuid)) => JsonValue::String(uuid.to_hyphenated().to_string()),
+        Value::Date(Some(date)) => JsonValue::String(date.format("%Y-%m-%d").to_string()),
+        Value::Time(Some(time)) => JsonValue::String(time.format("%H:%M:%S%.f").to_string()),
+        Value::DateTime(Some(dt)) => JsonValue::String(dt.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+        Value::Bytes(Some(bytes)) => JsonValue::String(base64::encode(&bytes)),
+        Value::Array(Some(values)) => JsonValue::Array(
+            values
+                .into_iter()
+                .map(|value| encode_value(value, RawColumnType::Unknown))
+                .collect(),
+        ),
+        _ => JsonValue::Null,
+    }
+}
+
+fn is_safe_integer(i: i64) -> bool {
+    i >= -MAX_SAFE_INTEGER && i <= MAX_SAFE_INTEGER
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_types_come_from_the_first_value_that_is_not_null() {
+        let rows = vec![
+            vec![Value::Integer(None), Value::Text(None)],
+            vec![Value::Text(Some("Felix".into())), Value::Text(None)],
+        ];
+
+        assert_eq!(
+            column_types(2, &rows),
+            vec![RawColumnType::String, RawColumnType::String]
+        );
+    }
+
+    #[test]
+    fn integer_columns_with_unsafe_values_are_big_ints() {
+        let rows = vec![
+            vec![Value::Integer(Some(1))],
+            vec![Value::Integer(Some(MAX_SAFE_INTEGER + 1))],
+        ];
+
+        assert_eq!(column_types(1, &rows), vec![RawColumnType::BigInt]);
+        assert_eq!(
+            encode_value(Value::Integer(Some(1)), RawColumnType::BigInt),
+            JsonValue::String("1".to_owned())
+        );
+    }
+
+    #[test]
+    fn empty_results_have_unknown_column_types() {
+        assert_eq!(column_types(2, &[]), vec![RawColumnType::Unknown; 2]);
+    }
+}
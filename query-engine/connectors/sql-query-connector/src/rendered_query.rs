@@ -0,0 +1,165 @@
+//! Queries Quaint can't express, like paths into JSON documents or array
+//! operators, are rendered to SQL by hand. Identifiers are quoted for the
+//! database, and every value, including the segments of JSON paths, is a bound
+//! parameter.
+
+use prisma_models::*;
+use quaint::{ast::Value, prelude::ConnectionInfo};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Flavour {
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+
+impl Flavour {
+    pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Flavour {
+        match connection_info {
+            ConnectionInfo::Postgres(..) => Flavour::Postgres,
+            ConnectionInfo::Mysql(..) => Flavour::Mysql,
+            _ => Flavour::Sqlite,
+        }
+    }
+}
+
+/// The SQL and the parameters of a query rendered by hand.
+#[derive(Debug)]
+pub(crate) struct RenderedQuery {
+    pub(crate) flavour: Flavour,
+    pub(crate) sql: String,
+    pub(crate) params: Vec<Value<'static>>,
+}
+
+impl RenderedQuery {
+    pub(crate) fn new(flavour: Flavour) -> RenderedQuery {
+        RenderedQuery {
+            flavour,
+            sql: String::new(),
+            params: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push_str(&mut self, sql: &str) {
+        self.sql.push_str(sql);
+    }
+
+    pub(crate) fn push_identifier(&mut self, name: &str) {
+        let sql = quote_identifier(self.flavour, name);
+        self.sql.push_str(&sql);
+    }
+
+    /// The table of the model, with the schema or database it is in.
+    pub(crate) fn push_table(&mut self, model: &ModelRef) {
+        self.push_identifier(&model.internal_data_model().db_name);
+        self.push_str(".");
+        self.push_identifier(model.db_name());
+    }
+
+    /// Binds the value, and pushes its placeholder.
+    pub(crate) fn push_param(&mut self, value: impl Into<Value<'static>>) {
+        let placeholder = self.bind(value);
+        self.sql.push_str(&placeholder);
+    }
+
+    /// Binds the value, returning its placeholder. The placeholders of
+    /// PostgreSQL can be used more than once.
+    pub(crate) fn bind(&mut self, value: impl Into<Value<'static>>) -> String {
+        self.params.push(value.into());
+
+        match self.flavour {
+            Flavour::Postgres => format!("${}", self.params.len()),
+            _ => "?".to_owned(),
+        }
+    }
+
+    /// `SELECT <id columns> FROM <table> WHERE `, the condition is pushed
+    /// by the caller.
+    pub(crate) fn push_select_ids(&mut self, model: &ModelRef) {
+        let columns: Vec<String> = model
+            .primary_identifier()
+            .scalar_fields()
+            .map(|field| quote_identifier(self.flavour, field.db_name()))
+            .collect();
+
+        self.push_str("SELECT ");
+        self.push_str(&columns.join(", "));
+        self.push_str(" FROM ");
+        self.push_table(model);
+        self.push_str(" WHERE ");
+    }
+
+    /// The condition of the form `<id column> IN (...)` for the records.
+    pub(crate) fn push_ids_condition(&mut self, model: &ModelRef, ids: &[RecordProjection]) {
+        let columns: Vec<String> = model
+            .primary_identifier()
+            .scalar_fields()
+            .map(|field| quote_identifier(self.flavour, field.db_name()))
+            .collect();
+
+        if ids.is_empty() {
+            self.push_str("1 = 0");
+            return;
+        }
+
+        if columns.len() == 1 {
+            self.push_str(&columns[0]);
+        } else {
+            self.push_str(&format!("({})", columns.join(", ")));
+        }
+
+        self.push_str(" IN (");
+
+        for (i, id) in ids.iter().enumerate() {
+            if i > 0 {
+                self.push_str(", ");
+            }
+
+            let placeholders: Vec<String> = id.values().map(|value| self.bind(value)).collect();
+
+            if placeholders.len() == 1 {
+                self.push_str(&placeholders[0]);
+            } else {
+                self.push_str(&format!("({})", placeholders.join(", ")));
+            }
+        }
+
+        self.push_str(")");
+    }
+}
+
+pub(crate) fn quote_identifier(flavour: Flavour, name: &str) -> String {
+    match flavour {
+        Flavour::Mysql | Flavour::Sqlite => format!("`{}`", name.replace('`', "``")),
+        Flavour::Postgres => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers_are_quoted_for_the_database() {
+        assert_eq!(quote_identifier(Flavour::Postgres, "we\"ird"), "\"we\"\"ird\"");
+        assert_eq!(quote_identifier(Flavour::Mysql, "we`ird"), "`we``ird`");
+    }
+
+    #[test]
+    fn placeholders_are_numbered_on_postgres() {
+        let mut query = RenderedQuery::new(Flavour::Postgres);
+        query.push_param("a");
+        query.push_str(" = ");
+        query.push_param("b");
+
+        assert_eq!(query.sql, "$1 = $2");
+        assert_eq!(query.params.len(), 2);
+
+        let mut query = RenderedQuery::new(Flavour::Mysql);
+        query.push_param("a");
+        query.push_str(" = ");
+        query.push_param("b");
+
+        assert_eq!(query.sql, "? = ?");
+    }
+}
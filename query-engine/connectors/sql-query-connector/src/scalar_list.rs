@@ -0,0 +1,147 @@
+//! Scalar lists are stored as native arrays, which are only available on
+//! PostgreSQL.
+//!
+//! Quaint has no array operators, so the filters on scalar lists are rendered
+//! by hand, and resolved to the ids of the matching records before the actual
+//! query runs, see `filter_resolution`.
+//!
+//! Pushes to scalar lists are appended to the stored arrays by the database,
+//! in a single `UPDATE`.
+
+use crate::{
+    query_builder::PARAMETER_LIMIT,
+    rendered_query::{quote_identifier, Flavour, RenderedQuery},
+    QueryContext, QueryExt, SqlError,
+};
+use connector_interface::filter::{ScalarListCondition, ScalarListFilter};
+use prisma_models::*;
+
+/// The ids of the records matching a scalar list filter.
+pub(crate) async fn matching_ids<Q: QueryExt + ?Sized>(
+    conn: &Q,
+    ctx: &QueryContext<'_>,
+    filter: ScalarListFilter,
+) -> crate::Result<Vec<RecordProjection>> {
+    let model_id = filter.field.model().primary_identifier();
+    let idents: Vec<_> = model_id.type_identifiers_with_arities();
+    let flavour = Flavour::from_connection_info(ctx.connection_info());
+    let query = filter_query(flavour, filter)?;
+
+    let rows = conn
+        .filter_rendered(ctx, &query.sql, &query.params, idents.as_slice())
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let tuples: Vec<_> = model_id.scalar_fields().zip(row.values.into_iter()).collect();
+            RecordProjection::new(tuples)
+        })
+        .collect())
+}
+
+/// Generates a query of the form:
+/// ```sql
+/// SELECT "id" FROM "db"."Post" WHERE "tags" && $1
+/// ```
+fn filter_query(flavour: Flavour, filter: ScalarListFilter) -> crate::Result<RenderedQuery> {
+    if flavour != Flavour::Postgres {
+        let message = "Filters on scalar lists are only supported on PostgreSQL.";
+        return Err(SqlError::QueryError(message.into()));
+    }
+
+    let model = filter.field.model();
+    let column = quote_identifier(flavour, filter.field.db_name());
+    let mut query = RenderedQuery::new(flavour);
+
+    query.push_select_ids(&model);
+
+    match filter.condition {
+        ScalarListCondition::Contains(value) => {
+            query.push_param(value);
+            query.push_str(&format!(" = ANY({})", column));
+        }
+        ScalarListCondition::ContainsEvery(values) => {
+            query.push_str(&format!("{} @> ", column));
+            query.push_param(PrismaValue::List(values));
+        }
+        ScalarListCondition::ContainsSome(values) => {
+            query.push_str(&format!("{} && ", column));
+            query.push_param(PrismaValue::List(values));
+        }
+        ScalarListCondition::IsEmpty(true) => query.push_str(&format!(
+            "({column} IS NULL OR cardinality({column}) = 0)",
+            column = column
+        )),
+        ScalarListCondition::IsEmpty(false) => query.push_str(&format!("cardinality({}) > 0", column)),
+    }
+
+    Ok(query)
+}
+
+/// Appends the values pushed to the list fields to the stored arrays of the
+/// records. Every chunk of records is updated by a single statement, so
+/// concurrent pushes to the same arrays are not lost.
+pub(crate) async fn append_pushes(
+    conn: &dyn QueryExt,
+    ctx: &QueryContext<'_>,
+    model: &ModelRef,
+    ids: &[RecordProjection],
+    pushes: Vec<(String, Vec<PrismaValue>)>,
+) -> crate::Result<()> {
+    if pushes.is_empty() {
+        return Ok(());
+    }
+
+    let flavour = Flavour::from_connection_info(ctx.connection_info());
+
+    if flavour != Flavour::Postgres {
+        let message = "Pushes to scalar lists are only supported on PostgreSQL.";
+        return Err(SqlError::QueryError(message.into()));
+    }
+
+    // Every record binds a parameter for each of its id columns, next to one
+    // parameter for the values of every pushed list.
+    let id_columns = model.primary_identifier().scalar_fields().count().max(1);
+    let chunk_size = (PARAMETER_LIMIT.saturating_sub(pushes.len()) / id_columns).max(1);
+
+    for chunk in ids.chunks(chunk_size) {
+        let query = push_query(flavour, model, chunk, &pushes);
+        conn.execute_rendered(ctx, &query.sql, &query.params).await?;
+    }
+
+    Ok(())
+}
+
+/// Generates a statement of the form:
+/// ```sql
+/// UPDATE "db"."Post" SET "tags" = COALESCE("tags", '{}') || $1 WHERE "id" IN ($2)
+/// ```
+fn push_query(
+    flavour: Flavour,
+    model: &ModelRef,
+    ids: &[RecordProjection],
+    pushes: &[(String, Vec<PrismaValue>)],
+) -> RenderedQuery {
+    let mut query = RenderedQuery::new(flavour);
+
+    query.push_str("UPDATE ");
+    query.push_table(model);
+    query.push_str(" SET ");
+
+    for (i, (column, values)) in pushes.iter().enumerate() {
+        if i > 0 {
+            query.push_str(", ");
+        }
+
+        let column = quote_identifier(flavour, column);
+
+        query.push_str(&format!("{column} = COALESCE({column}, '{{}}') || ", column = column));
+        query.push_param(PrismaValue::List(values.clone()));
+    }
+
+    query.push_str(" WHERE ");
+    query.push_ids_condition(model, ids);
+
+    query
+}
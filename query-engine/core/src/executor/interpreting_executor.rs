@@ -1,14 +1,54 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{pipeline::QueryPipeline, InFlightGuard, QueryExecutor, ReadReplicas, ReplicaStrategy};
+use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData, StreamedDocument};
 use async_trait::async_trait;
 use connector::{Connection, ConnectionLike, Connector};
-use futures::future;
+use futures::{channel::mpsc, future, SinkExt};
+
+/// The database an operation runs on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Route {
+    Primary,
+    Replica,
+}
+
+impl Route {
+    /// Reads go to a read replica, writes to the primary.
+    fn of(operation: &Operation) -> Self {
+        if operation.is_read() {
+            Self::Replica
+        } else {
+            Self::Primary
+        }
+    }
+
+    /// Routes of the operations of a non-transactional batch. Every operation from the first write
+    /// on stays on the primary, so reads see the writes of the batch.
+    fn of_batch(operations: &[Operation]) -> Vec<Self> {
+        let mut after_write = false;
+
+        operations
+            .iter()
+            .map(|operation| {
+                after_write = after_write || !operation.is_read();
+
+                if after_write {
+                    Self::Primary
+                } else {
+                    Self::of(operation)
+                }
+            })
+            .collect()
+    }
+}
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
     connector: C,
     primary_connector: &'static str,
 
+    /// Read replicas that read-only operations are routed to, if any.
+    replicas: ReadReplicas<C>,
+
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
@@ -22,10 +62,32 @@ where
         InterpretingExecutor {
             connector,
             primary_connector,
+            replicas: ReadReplicas::new(Vec::new(), ReplicaStrategy::default()),
             force_transactions,
         }
     }
 
+    pub fn with_read_replicas(mut self, replicas: ReadReplicas<C>) -> Self {
+        self.replicas = replicas;
+        self
+    }
+
+    /// Checks out a connection to run an operation on. If no replica connection can be
+    /// acquired, the primary is used instead.
+    async fn checkout(&self, route: Route) -> crate::Result<(Box<dyn Connection>, Option<InFlightGuard>)> {
+        if route == Route::Replica && !self.replicas.is_empty() {
+            match self.replicas.get_connection().await {
+                Ok((conn, in_flight)) => return Ok((conn, Some(in_flight))),
+                Err(err) => warn!(
+                    "Could not connect to a read replica, falling back to the primary: {}",
+                    err
+                ),
+            }
+        }
+
+        Ok((self.connector.get_connection().await?, None))
+    }
+
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
     async fn execute_single_operation(
         operation: Operation,
@@ -72,6 +134,9 @@ where
     /// A failing operation does not fail the batch, instead, an error is returned alongside other responses.
     /// Note that individual operations executed in non-transactional mode can still be transactions in themselves
     /// if the query (e.g. a write op) requires it.
+    ///
+    /// Transactional batches always run on the primary. In non-transactional batches, reads are routed to the
+    /// read replicas until the first write of the batch, all later operations stay on the primary.
     async fn execute_batch(
         &self,
         operations: Vec<Operation>,
@@ -91,7 +156,7 @@ where
                 .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
                 .collect::<std::result::Result<Vec<_>, _>>()?;
 
-            let conn = self.connector.get_connection().await?;
+            let (conn, _) = self.checkout(Route::Primary).await?;
             let tx = conn.start_transaction().await?;
             let mut results = Vec::with_capacity(queries.len());
 
@@ -110,15 +175,17 @@ where
             Ok(results)
         } else {
             let mut futures = Vec::with_capacity(operations.len());
+            let routes = Route::of_batch(&operations);
+
+            for (operation, route) in operations.into_iter().zip(routes) {
+                let (conn, in_flight) = self.checkout(route).await?;
+                let fut =
+                    Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone());
 
-            for operation in operations {
-                let conn = self.connector.get_connection().await?;
-                futures.push(tokio::spawn(Self::execute_single_operation(
-                    operation,
-                    conn,
-                    self.force_transactions,
-                    query_schema.clone(),
-                )));
+                futures.push(tokio::spawn(async move {
+                    let _in_flight = in_flight;
+                    fut.await
+                }));
             }
 
             let responses: Vec<_> = future::join_all(futures)
@@ -133,11 +200,132 @@ where
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
     async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
-        let conn = self.connector.get_connection().await?;
+        let (conn, _in_flight) = self.checkout(Route::of(&operation)).await?;
         Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
     }
 
+    /// Executes a streamed `findMany`. Every batch checks out its own connection, so no connection is held
+    /// while the previous batch is being consumed.
+    async fn execute_streamed(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        batch_size: usize,
+        mut sink: mpsc::Sender<crate::Result<ResponseData>>,
+    ) {
+        let mut document = match StreamedDocument::new(operation, &query_schema, batch_size) {
+            Ok(document) => document,
+            Err(err) => {
+                let _ = sink.send(Err(err)).await;
+                return;
+            }
+        };
+
+        while let Some(operation) = document.next_operation() {
+            let result = match self.checkout(Route::of(&operation)).await {
+                Ok((conn, _in_flight)) => {
+                    Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone())
+                        .await
+                        .and_then(|response| document.process(response))
+                }
+                Err(err) => Err(err),
+            };
+
+            let failed = result.is_err();
+
+            if sink.send(result).await.is_err() || failed {
+                break;
+            }
+        }
+    }
+
     fn primary_connector(&self) -> &'static str {
         self.primary_connector
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{executor::test_connector::TestConnector, Selection};
+
+    fn read() -> Operation {
+        Operation::Read(Selection::builder("findManyCat").build())
+    }
+
+    fn write() -> Operation {
+        Operation::Write(Selection::builder("createOneCat").build())
+    }
+
+    fn executor(primary: &TestConnector, replicas: &[TestConnector]) -> InterpretingExecutor<TestConnector> {
+        InterpretingExecutor::new(primary.clone(), "test", false)
+            .with_read_replicas(ReadReplicas::new(replicas.to_vec(), ReplicaStrategy::RoundRobin))
+    }
+
+    #[test]
+    fn reads_are_routed_to_replicas_and_writes_to_the_primary() {
+        assert_eq!(Route::of(&read()), Route::Replica);
+        assert_eq!(Route::of(&write()), Route::Primary);
+    }
+
+    #[test]
+    fn operations_after_the_first_write_of_a_batch_are_pinned_to_the_primary() {
+        let routes = Route::of_batch(&[read(), read(), write(), read()]);
+
+        assert_eq!(
+            routes,
+            vec![Route::Replica, Route::Replica, Route::Primary, Route::Primary]
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_check_out_replica_connections() {
+        let primary = TestConnector::default();
+        let replica = TestConnector::default();
+        let executor = executor(&primary, &[replica.clone()]);
+
+        let (_, in_flight) = executor.checkout(Route::of(&read())).await.unwrap();
+
+        assert!(in_flight.is_some());
+        assert_eq!(replica.checkouts(), 1);
+        assert_eq!(primary.checkouts(), 0);
+    }
+
+    #[tokio::test]
+    async fn writes_and_transactions_check_out_primary_connections() {
+        let primary = TestConnector::default();
+        let replica = TestConnector::default();
+        let executor = executor(&primary, &[replica.clone()]);
+
+        let (_, in_flight) = executor.checkout(Route::of(&write())).await.unwrap();
+        assert!(in_flight.is_none());
+
+        // Transactional batches always run on the primary.
+        executor.checkout(Route::Primary).await.unwrap();
+
+        assert_eq!(primary.checkouts(), 2);
+        assert_eq!(replica.checkouts(), 0);
+    }
+
+    #[tokio::test]
+    async fn reads_fall_back_to_the_primary_without_replicas() {
+        let primary = TestConnector::default();
+        let executor = executor(&primary, &[]);
+
+        executor.checkout(Route::Replica).await.unwrap();
+
+        assert_eq!(primary.checkouts(), 1);
+    }
+
+    #[tokio::test]
+    async fn reads_fall_back_to_the_primary_when_the_replica_fails() {
+        let primary = TestConnector::default();
+        let replica = TestConnector::unreachable();
+        let executor = executor(&primary, &[replica]);
+
+        let (_, in_flight) = executor.checkout(Route::Replica).await.unwrap();
+
+        assert!(in_flight.is_none());
+        assert_eq!(primary.checkouts(), 1);
+    }
+}
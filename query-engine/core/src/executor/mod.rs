@@ -7,11 +7,17 @@
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
 mod interpreting_executor;
 mod pipeline;
+mod read_replicas;
+
+#[cfg(test)]
+mod test_connector;
 
 pub use interpreting_executor::*;
+pub use read_replicas::*;
 
 use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
 use async_trait::async_trait;
+use futures::channel::mpsc;
 
 #[async_trait]
 pub trait QueryExecutor {
@@ -26,5 +32,16 @@ pub trait QueryExecutor {
         query_schema: QuerySchemaRef,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
+    /// Executes a top-level `findMany` in batches of at most `batch_size` records. Every batch is sent to `sink`
+    /// as soon as it has been read, an error ends the stream as its last item. Execution stops early if the
+    /// receiving end is dropped.
+    async fn execute_streamed(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        batch_size: usize,
+        sink: mpsc::Sender<crate::Result<ResponseData>>,
+    );
+
     fn primary_connector(&self) -> &'static str;
 }
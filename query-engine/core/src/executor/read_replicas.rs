@@ -0,0 +1,172 @@
+use connector::{Connection, Connector};
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// Decides which read replica serves the next read operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplicaStrategy {
+    /// Cycle through the replicas in order.
+    RoundRobin,
+
+    /// Pick the replica with the fewest operations in flight.
+    LeastConnections,
+}
+
+impl Default for ReplicaStrategy {
+    fn default() -> Self {
+        Self::RoundRobin
+    }
+}
+
+impl FromStr for ReplicaStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "round-robin" => Ok(Self::RoundRobin),
+            "least-connections" => Ok(Self::LeastConnections),
+            _ => Err(format!(
+                "Unknown replica strategy `{}`, expected `round-robin` or `least-connections`.",
+                s
+            )),
+        }
+    }
+}
+
+/// The read replicas of the primary database. Read-only operations are
+/// spread over them according to the configured strategy.
+pub struct ReadReplicas<C> {
+    replicas: Vec<Replica<C>>,
+    strategy: ReplicaStrategy,
+    next: AtomicUsize,
+}
+
+struct Replica<C> {
+    connector: C,
+    in_flight: Arc<AtomicUsize>,
+}
+
+/// Counts an operation as in flight on a replica until dropped.
+pub struct InFlightGuard(Arc<AtomicUsize>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<C> ReadReplicas<C>
+where
+    C: Connector + Send + Sync,
+{
+    pub fn new(connectors: Vec<C>, strategy: ReplicaStrategy) -> Self {
+        let replicas = connectors
+            .into_iter()
+            .map(|connector| Replica {
+                connector,
+                in_flight: Arc::new(AtomicUsize::new(0)),
+            })
+            .collect();
+
+        Self {
+            replicas,
+            strategy,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.replicas.is_empty()
+    }
+
+    /// Checks out a connection from the replica chosen by the strategy. The
+    /// returned guard must be kept alive for as long as the operation runs.
+    pub async fn get_connection(&self) -> connector::Result<(Box<dyn Connection>, InFlightGuard)> {
+        let replica = self.pick();
+
+        replica.in_flight.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlightGuard(replica.in_flight.clone());
+        let conn = replica.connector.get_connection().await?;
+
+        Ok((conn, guard))
+    }
+
+    fn pick(&self) -> &Replica<C> {
+        match self.strategy {
+            ReplicaStrategy::RoundRobin => {
+                let idx = self.next.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+                &self.replicas[idx]
+            }
+            ReplicaStrategy::LeastConnections => self
+                .replicas
+                .iter()
+                .min_by_key(|replica| replica.in_flight.load(Ordering::SeqCst))
+                .expect("Expected at least one read replica."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::test_connector::TestConnector;
+
+    fn picked_index(replicas: &ReadReplicas<TestConnector>) -> usize {
+        let picked = replicas.pick();
+
+        replicas
+            .replicas
+            .iter()
+            .position(|replica| std::ptr::eq(replica, picked))
+            .unwrap()
+    }
+
+    #[test]
+    fn strategies_are_parsed() {
+        assert_eq!(
+            "round-robin".parse::<ReplicaStrategy>(),
+            Ok(ReplicaStrategy::RoundRobin)
+        );
+        assert_eq!(
+            "least-connections".parse::<ReplicaStrategy>(),
+            Ok(ReplicaStrategy::LeastConnections)
+        );
+        assert!("random".parse::<ReplicaStrategy>().is_err());
+    }
+
+    #[test]
+    fn round_robin_cycles_through_the_replicas() {
+        let replicas = ReadReplicas::new(vec![TestConnector::default(); 3], ReplicaStrategy::RoundRobin);
+        let picked: Vec<usize> = (0..5).map(|_| picked_index(&replicas)).collect();
+
+        assert_eq!(picked, vec![0, 1, 2, 0, 1]);
+    }
+
+    #[tokio::test]
+    async fn least_connections_picks_the_replica_with_the_fewest_operations_in_flight() {
+        let replicas = ReadReplicas::new(vec![TestConnector::default(); 2], ReplicaStrategy::LeastConnections);
+
+        let (_, first) = replicas.get_connection().await.unwrap();
+        assert_eq!(picked_index(&replicas), 1);
+
+        let (_, second) = replicas.get_connection().await.unwrap();
+        drop(first);
+        assert_eq!(picked_index(&replicas), 0);
+
+        drop(second);
+        assert_eq!(picked_index(&replicas), 0);
+    }
+
+    #[tokio::test]
+    async fn failed_checkouts_are_not_counted_as_in_flight() {
+        let replicas = ReadReplicas::new(vec![TestConnector::unreachable()], ReplicaStrategy::LeastConnections);
+
+        assert!(replicas.get_connection().await.is_err());
+        assert_eq!(replicas.replicas[0].in_flight.load(Ordering::SeqCst), 0);
+    }
+}
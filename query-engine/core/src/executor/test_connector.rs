@@ -0,0 +1,154 @@
+//! A connector counting the connections checked out from it, for testing how operations are routed.
+
+use async_trait::async_trait;
+use connector::{
+    error::{ConnectorError, ErrorKind},
+    AggregationResult, Aggregator, Connection, Connector, Filter, QueryArguments, ReadOperations, RecordFilter,
+    Transaction, WriteArgs, WriteOperations,
+};
+use prisma_models::prelude::*;
+use prisma_value::PrismaValue;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[derive(Clone, Default)]
+pub struct TestConnector {
+    checkouts: Arc<AtomicUsize>,
+    unreachable: bool,
+}
+
+impl TestConnector {
+    /// A connector that fails every checkout.
+    pub fn unreachable() -> Self {
+        Self {
+            unreachable: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn checkouts(&self) -> usize {
+        self.checkouts.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Connector for TestConnector {
+    async fn get_connection(&self) -> connector::Result<Box<dyn Connection>> {
+        if self.unreachable {
+            return Err(ConnectorError::from_kind(ErrorKind::ConnectionError(failure::err_msg(
+                "The database is unreachable.",
+            ))));
+        }
+
+        self.checkouts.fetch_add(1, Ordering::SeqCst);
+
+        Ok(Box::new(TestConnection))
+    }
+}
+
+pub struct TestConnection;
+
+#[async_trait]
+impl Connection for TestConnection {
+    async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl ReadOperations for TestConnection {
+    async fn get_single_record(
+        &self,
+        _model: &ModelRef,
+        _filter: &Filter,
+        _selected_fields: &ModelProjection,
+    ) -> connector::Result<Option<SingleRecord>> {
+        unimplemented!()
+    }
+
+    async fn get_many_records(
+        &self,
+        _model: &ModelRef,
+        _query_arguments: QueryArguments,
+        _selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        unimplemented!()
+    }
+
+    fn supports_distinct(&self) -> bool {
+        false
+    }
+
+    async fn get_related_m2m_record_ids(
+        &self,
+        _from_field: &RelationFieldRef,
+        _from_record_ids: &[RecordProjection],
+    ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
+        unimplemented!()
+    }
+
+    async fn aggregate_records(
+        &self,
+        _model: &ModelRef,
+        _aggregators: Vec<Aggregator>,
+        _query_arguments: QueryArguments,
+    ) -> connector::Result<Vec<AggregationResult>> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl WriteOperations for TestConnection {
+    async fn create_record(&self, _model: &ModelRef, _args: WriteArgs) -> connector::Result<RecordProjection> {
+        unimplemented!()
+    }
+
+    async fn update_records(
+        &self,
+        _model: &ModelRef,
+        _record_filter: RecordFilter,
+        _args: WriteArgs,
+    ) -> connector::Result<Vec<RecordProjection>> {
+        unimplemented!()
+    }
+
+    async fn delete_records(&self, _model: &ModelRef, _record_filter: RecordFilter) -> connector::Result<usize> {
+        unimplemented!()
+    }
+
+    async fn connect(
+        &self,
+        _field: &RelationFieldRef,
+        _parent_id: &RecordProjection,
+        _child_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        unimplemented!()
+    }
+
+    async fn disconnect(
+        &self,
+        _field: &RelationFieldRef,
+        _parent_id: &RecordProjection,
+        _child_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        unimplemented!()
+    }
+
+    async fn execute_raw(&self, _query: String, _parameters: Vec<PrismaValue>) -> connector::Result<usize> {
+        unimplemented!()
+    }
+
+    async fn query_raw(&self, _query: String, _parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
+        unimplemented!()
+    }
+
+    async fn query_raw_typed(
+        &self,
+        _query: String,
+        _parameters: Vec<PrismaValue>,
+    ) -> connector::Result<serde_json::Value> {
+        unimplemented!()
+    }
+}
@@ -5,6 +5,7 @@ pub struct NestedPagination {
     skip: Option<i64>,
     take: Option<i64>,
     cursor: Option<RecordProjection>,
+    distinct: Option<ModelProjection>,
     needs_reversing: bool,
 }
 
@@ -14,6 +15,7 @@ impl NestedPagination {
             skip: args.skip.clone(),
             take: args.take_abs(),
             cursor: args.cursor.clone(),
+            distinct: args.distinct.clone(),
             needs_reversing: args.needs_reversed_order(),
         }
     }
@@ -68,6 +70,11 @@ impl NestedPagination {
             });
         }
 
+        // Distinct is applied per parent, before skip and take.
+        if let Some(ref distinct) = self.distinct {
+            many_records.distinct(distinct);
+        }
+
         // The records are sorted by their parent id. Hence we just need to remember the count for the last parent id to apply pagination.
         let mut current_count: i64 = 0;
         let mut last_parent_id: Option<RecordProjection> = None;
@@ -100,6 +107,6 @@ impl NestedPagination {
     }
 
     fn must_apply_pagination(&self) -> bool {
-        self.take.or(self.skip).is_some() || self.cursor.is_some()
+        self.take.or(self.skip).is_some() || self.cursor.is_some() || self.distinct.is_some()
     }
 }
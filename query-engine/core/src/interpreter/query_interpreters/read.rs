@@ -1,7 +1,7 @@
 use super::*;
 use crate::interpreter::query_interpreters::nested_pagination::NestedPagination;
 use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, ConnectionLike, ReadOperations};
+use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
 use futures::future::{BoxFuture, FutureExt};
 use prisma_models::ManyRecords;
 
@@ -66,9 +66,20 @@ fn read_many<'a, 'b>(
     query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let scalars = tx
-            .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
-            .await?;
+        let mut args = query.args.clone();
+        let in_memory_distinct = args.distinct.is_some() && !tx.supports_distinct();
+
+        if in_memory_distinct {
+            args.ignore_distinct = true;
+            args.ignore_skip = true;
+            args.ignore_take = true;
+        }
+
+        let mut scalars = tx.get_many_records(&query.model, args, &query.selected_fields).await?;
+
+        if in_memory_distinct {
+            scalars = apply_distinct(scalars, &query.args);
+        }
 
         let model_id = query.model.primary_identifier();
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
@@ -97,8 +108,10 @@ fn read_related<'a, 'b>(
         let is_m2m = relation.is_many_to_many();
         let paginator = NestedPagination::new_from_query_args(&query.args);
 
+        // Distinct applies per parent, which only the in-memory pagination can do.
         query.args.ignore_take = true;
         query.args.ignore_skip = true;
+        query.args.ignore_distinct = true;
 
         let scalars = if is_m2m {
             nested_read::m2m(tx, &query, parent_result, paginator).await?
@@ -132,6 +145,32 @@ fn read_related<'a, 'b>(
     fut.boxed()
 }
 
+/// Deduplicates records for connectors that can't apply `distinct` themselves. `skip` and `take`
+/// count distinct records, so they are applied afterwards. Records read backwards (negative `take`)
+/// are deduplicated in that order, as the connector would.
+fn apply_distinct(mut records: ManyRecords, args: &QueryArguments) -> ManyRecords {
+    let reversed = args.needs_reversed_order();
+
+    if reversed {
+        records.reverse();
+    }
+
+    if let Some(ref distinct) = args.distinct {
+        records.distinct(distinct);
+    }
+
+    let skip = args.skip.unwrap_or(0) as usize;
+    let take = args.take_abs().map(|take| take as usize).unwrap_or(usize::MAX);
+
+    records.records = records.records.into_iter().skip(skip).take(take).collect();
+
+    if reversed {
+        records.reverse();
+    }
+
+    records
+}
+
 async fn aggregate<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: AggregateRecordsQuery,
@@ -25,6 +25,7 @@ pub async fn execute<'a, 'b>(
         } => match raw_type {
             RawQueryType::Execute => execute_raw(tx, query, parameters).await,
             RawQueryType::Query => query_raw(tx, query, parameters).await,
+            RawQueryType::TypedQuery => query_raw_typed(tx, query, parameters).await,
         },
     }
 }
@@ -38,6 +39,15 @@ async fn query_raw<'a, 'b>(
     Ok(QueryResult::Json(res))
 }
 
+async fn query_raw_typed<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.query_raw_typed(query, parameters).await?;
+    Ok(QueryResult::Json(res))
+}
+
 async fn execute_raw<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: String,
@@ -20,6 +20,7 @@ mod parse_ast;
 mod parser;
 mod query_value;
 mod selection;
+mod streamed;
 mod transformers;
 
 pub use error::*;
@@ -28,6 +29,7 @@ pub use parse_ast::*;
 pub use parser::*;
 pub use query_value::*;
 pub use selection::*;
+pub use streamed::*;
 pub use transformers::*;
 
 pub type QueryParserResult<T> = std::result::Result<T, QueryParserError>;
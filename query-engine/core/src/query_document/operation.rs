@@ -7,6 +7,13 @@ pub enum Operation {
 }
 
 impl Operation {
+    pub fn is_read(&self) -> bool {
+        match self {
+            Self::Read(_) => true,
+            Self::Write(_) => false,
+        }
+    }
+
     pub fn is_find_one(&self) -> bool {
         match self {
             Self::Read(selection) => selection.is_find_one(),
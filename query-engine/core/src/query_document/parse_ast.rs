@@ -2,7 +2,7 @@
 //! Structures represent parsed and validated parts of the query document, used by the query builders.
 use super::{QueryParserError, QueryParserResult};
 use crate::FieldRef;
-use prisma_models::{OrderBy, PrismaValue};
+use prisma_models::{OrderBy, PrismaValue, ScalarFieldRef};
 use std::collections::BTreeMap;
 
 pub type ParsedInputMap = BTreeMap<String, ParsedInputValue>;
@@ -31,6 +31,9 @@ pub enum RawQueryType {
     Execute,
     /// Execute the query, returning rows from the database.
     Query,
+    /// Execute the query, returning the column names, the column types and
+    /// the rows from the database.
+    TypedQuery,
 }
 
 impl ParsedField {
@@ -39,6 +42,7 @@ impl ParsedField {
         match self.name.as_str() {
             "executeRaw" => Some(RawQueryType::Execute),
             "queryRaw" => Some(RawQueryType::Query),
+            "queryRawTyped" => Some(RawQueryType::TypedQuery),
             _ => None,
         }
     }
@@ -54,6 +58,7 @@ pub struct ParsedArgument {
 pub enum ParsedInputValue {
     Single(PrismaValue),
     OrderBy(OrderBy),
+    ScalarField(ScalarFieldRef),
     List(Vec<ParsedInputValue>),
     Map(ParsedInputMap),
 }
@@ -105,6 +110,7 @@ impl InputAssertions for ParsedInputValue {
             Self::Map(m) => m.assert_non_null()?,
             Self::Single(v) => v.assert_non_null()?,
             Self::OrderBy(_) => (),
+            Self::ScalarField(_) => (),
         };
 
         Ok(())
@@ -268,6 +268,13 @@ impl QueryDocumentParser {
                     raw, ord.name
                 ))),
             },
+            EnumType::FieldRef(f) => match f.value_for(raw.as_str()) {
+                Some(field) => Ok(ParsedInputValue::ScalarField(field.clone())),
+                None => Err(QueryParserError::ValueParseError(format!(
+                    "Enum value '{}' is invalid for enum type {}",
+                    raw, f.name
+                ))),
+            },
         }
     }
 
@@ -0,0 +1,246 @@
+use super::{Operation, QueryValue, Selection};
+use crate::{
+    response_ir::{Item, List, Map},
+    schema::{QuerySchemaRef, QueryTag, SchemaQueryBuilder},
+    schema_builder, CoreError, ResponseData,
+};
+use std::collections::BTreeMap;
+
+/// A top-level `findMany` that is read in batches instead of all at once.
+///
+/// Batches are paginated with a cursor on the primary identifier of the model (keyset pagination):
+/// every batch after the first one starts at the last record of the previous batch and skips it.
+/// The connector resolves the cursor into a condition on the order and identifier columns, so
+/// reading a batch costs the same regardless of how many records were read before.
+///
+/// Batches are separate reads, they don't see a consistent snapshot of the data. Records written
+/// concurrently may or may not be included, depending on where they end up in the sort order.
+#[derive(Debug, Clone)]
+pub struct StreamedDocument {
+    selection: Selection,
+
+    /// Names of the primary identifier fields of the model.
+    id_fields: Vec<String>,
+
+    /// Name of the compound field the identifier values are nested under in the cursor, if the
+    /// primary identifier spans several fields.
+    compound_name: Option<String>,
+
+    /// Identifier fields that were added to the nested selections for reading the cursor and are
+    /// removed from the records again before they are returned.
+    injected: Vec<String>,
+
+    batch_size: usize,
+
+    /// Records left to read if the query has a `take` argument.
+    remaining: Option<usize>,
+
+    /// Cursor argument pointing at the last record read.
+    cursor: Option<QueryValue>,
+
+    exhausted: bool,
+}
+
+impl StreamedDocument {
+    pub fn new(operation: Operation, query_schema: &QuerySchemaRef, batch_size: usize) -> crate::Result<Self> {
+        let selection = match operation {
+            Operation::Read(selection) => selection,
+            Operation::Write(_) => return Err(Self::unsupported()),
+        };
+
+        let model = match query_schema
+            .find_query_field(selection.name())
+            .as_ref()
+            .and_then(|field| field.query_builder())
+        {
+            Some(SchemaQueryBuilder::ModelQueryBuilder(builder)) if builder.tag == QueryTag::FindMany => {
+                builder.model.clone()
+            }
+            _ => return Err(Self::unsupported()),
+        };
+
+        let remaining = match Self::argument(&selection, "take") {
+            Some(QueryValue::Int(take)) if *take < 0 => {
+                return Err(CoreError::UnsupportedFeatureError(
+                    "Streamed reads with a negative `take` argument.".to_owned(),
+                ))
+            }
+            Some(QueryValue::Int(take)) => Some(*take as usize),
+            _ => None,
+        };
+
+        // Batches are distinct in themselves only, records could repeat across batches.
+        if Self::argument(&selection, "distinct").is_some() {
+            return Err(CoreError::UnsupportedFeatureError(
+                "Streamed reads with a `distinct` argument.".to_owned(),
+            ));
+        }
+
+        let id_fields: Vec<String> = model
+            .primary_identifier()
+            .scalar_fields()
+            .map(|field| field.name.clone())
+            .collect();
+
+        let compound_name = if id_fields.len() > 1 {
+            match model.fields().id() {
+                Some(_) => Some(schema_builder::compound_id_field_name(&id_fields)),
+                None => model
+                    .unique_indexes()
+                    .into_iter()
+                    .find(|index| index.fields().iter().map(|f| &f.name).eq(id_fields.iter()))
+                    .map(|index| schema_builder::compound_index_field_name(&index)),
+            }
+        } else {
+            None
+        };
+
+        let mut builder = Selection::builder(selection.name());
+        builder.nested_selections(selection.nested_selections().to_vec());
+
+        // The identifier values of the last record are needed for the cursor of the next batch.
+        let injected: Vec<String> = id_fields
+            .iter()
+            .filter(|field| !builder.contains_nested_selection(field))
+            .cloned()
+            .collect();
+
+        for field in injected.iter() {
+            builder.push_nested_selection(Selection::builder(field).build());
+        }
+
+        builder.set_arguments(selection.arguments().to_vec());
+
+        if let Some(alias) = selection.alias() {
+            builder.alias(alias);
+        }
+
+        Ok(Self {
+            selection: builder.build(),
+            id_fields,
+            compound_name,
+            injected,
+            batch_size: batch_size.max(1),
+            remaining,
+            cursor: None,
+            exhausted: remaining == Some(0),
+        })
+    }
+
+    /// The operation reading the next batch, or `None` if all records have been read.
+    pub fn next_operation(&self) -> Option<Operation> {
+        if self.exhausted {
+            return None;
+        }
+
+        let take = self.next_take();
+        let mut builder = Selection::builder(self.selection.name());
+        builder.nested_selections(self.selection.nested_selections().to_vec());
+
+        if let Some(alias) = self.selection.alias() {
+            builder.alias(alias);
+        }
+
+        for (name, value) in self.selection.arguments() {
+            match name.as_str() {
+                "take" => continue,
+                "cursor" | "skip" if self.cursor.is_some() => continue,
+                _ => builder.push_argument(name.clone(), value.clone()),
+            };
+        }
+
+        if let Some(ref cursor) = self.cursor {
+            builder.push_argument("cursor", cursor.clone());
+            builder.push_argument("skip", QueryValue::Int(1));
+        }
+
+        builder.push_argument("take", QueryValue::Int(take as i64));
+
+        Some(Operation::Read(builder.build()))
+    }
+
+    /// Advances the cursor past the records of a batch and removes the injected identifier fields
+    /// from them.
+    pub fn process(&mut self, response: ResponseData) -> crate::Result<ResponseData> {
+        let take = self.next_take();
+        let records = response.data.into_list().ok_or_else(|| {
+            CoreError::SerializationError("Expected a list of records for a streamed read.".to_owned())
+        })?;
+
+        let mut maps: Vec<Map> = records
+            .into_iter()
+            .map(|item| {
+                item.into_map().ok_or_else(|| {
+                    CoreError::SerializationError("Expected records to be objects in a streamed read.".to_owned())
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        if let Some(last) = maps.last() {
+            self.cursor = Some(self.cursor_for(last)?);
+        }
+
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= maps.len().min(*remaining);
+        }
+
+        self.exhausted = maps.len() < take || self.remaining == Some(0);
+
+        for map in maps.iter_mut() {
+            for field in self.injected.iter() {
+                map.shift_remove(field);
+            }
+        }
+
+        let items = maps.into_iter().map(Item::Map).collect();
+
+        Ok(ResponseData::new(response.key, Item::List(List::from(items))))
+    }
+
+    fn next_take(&self) -> usize {
+        match self.remaining {
+            Some(remaining) => remaining.min(self.batch_size),
+            None => self.batch_size,
+        }
+    }
+
+    /// Builds the `cursor` argument from the identifier values of a record. Compound identifiers
+    /// are nested under their compound field name, as in `where` unique inputs.
+    fn cursor_for(&self, record: &Map) -> crate::Result<QueryValue> {
+        let mut values = BTreeMap::new();
+
+        for field in self.id_fields.iter() {
+            let value = record
+                .get(field)
+                .cloned()
+                .and_then(|item| item.into_value())
+                .ok_or_else(|| {
+                    CoreError::SerializationError(format!("Missing identifier field `{}` in streamed read.", field))
+                })?;
+
+            values.insert(field.clone(), QueryValue::from(value));
+        }
+
+        match self.compound_name {
+            Some(ref name) => {
+                let mut compound = BTreeMap::new();
+                compound.insert(name.clone(), QueryValue::Object(values));
+
+                Ok(QueryValue::Object(compound))
+            }
+            None => Ok(QueryValue::Object(values)),
+        }
+    }
+
+    fn argument<'a>(selection: &'a Selection, name: &str) -> Option<&'a QueryValue> {
+        selection
+            .arguments()
+            .iter()
+            .find(|(arg_name, _)| arg_name == name)
+            .map(|(_, value)| value)
+    }
+
+    fn unsupported() -> CoreError {
+        CoreError::UnsupportedFeatureError("Streamed reads of anything but a top-level `findMany`.".to_owned())
+    }
+}
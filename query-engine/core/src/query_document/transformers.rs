@@ -6,7 +6,7 @@
 //! assume the data has to be because of the structural guarantees of the query schema validation.
 use super::*;
 use chrono::prelude::*;
-use prisma_models::{OrderBy, PrismaValue};
+use prisma_models::{OrderBy, PrismaValue, ScalarFieldRef};
 use rust_decimal::prelude::ToPrimitive;
 use std::convert::TryInto;
 
@@ -105,6 +105,20 @@ impl TryInto<OrderBy> for ParsedInputValue {
     }
 }
 
+impl TryInto<ScalarFieldRef> for ParsedInputValue {
+    type Error = QueryParserError;
+
+    fn try_into(self) -> QueryParserResult<ScalarFieldRef> {
+        match self {
+            Self::ScalarField(field) => Ok(field),
+            v => Err(QueryParserError::AssertionError(format!(
+                "Attempted conversion of non-field-reference enum ({:?}) into scalar field failed.",
+                v
+            ))),
+        }
+    }
+}
+
 impl TryInto<Option<f64>> for ParsedInputValue {
     type Error = QueryParserError;
 
@@ -3,9 +3,14 @@ use crate::{
     query_document::{ParsedInputMap, ParsedInputValue},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
-use std::{collections::BTreeMap, convert::TryInto};
+use connector::{
+    filter::{CompositeFilter, Filter, ScalarCondition},
+    RelationCompare, ScalarCompare, ScalarListCompare,
+};
+use prisma_models::{
+    Field, InternalCompositeType, InternalDataModelRef, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef,
+};
+use std::{collections::BTreeMap, convert::TryInto, sync::Arc};
 
 static FILTER_OPERATIONS: &'static [FilterOp] = &[
     FilterOp::NotIn,
@@ -21,6 +26,10 @@ static FILTER_OPERATIONS: &'static [FilterOp] = &[
     FilterOp::Contains,
     FilterOp::StartsWith,
     FilterOp::EndsWith,
+    FilterOp::HasEvery, // Needs to be before `Every`
+    FilterOp::HasSome,  // Needs to be before `Some`
+    FilterOp::Has,
+    FilterOp::IsEmpty,
     FilterOp::Some,
     FilterOp::None,
     FilterOp::Every,
@@ -46,6 +55,10 @@ enum FilterOp {
     NotStartsWith,
     EndsWith,
     NotEndsWith,
+    Has,
+    HasEvery,
+    HasSome,
+    IsEmpty,
     Some,
     None,
     Every,
@@ -83,6 +96,10 @@ impl FilterOp {
             FilterOp::NotStartsWith => "_not_starts_with",
             FilterOp::EndsWith => "_ends_with",
             FilterOp::NotEndsWith => "_not_ends_with",
+            FilterOp::Has => "_has",
+            FilterOp::HasEvery => "_has_every",
+            FilterOp::HasSome => "_has_some",
+            FilterOp::IsEmpty => "_is_empty",
             FilterOp::Some => "_some",
             FilterOp::None => "_none",
             FilterOp::Every => "_every",
@@ -157,6 +174,8 @@ pub fn extract_filter(
                     // Always try to resolve regular fields first. If that fails, try to resolve compound fields.
                     match model.fields().find_from_all(&field_name) {
                         Ok(field) => match field {
+                            Field::Scalar(field) if field.is_composite() => handle_composite_field(field, value),
+                            Field::Scalar(field) if field.is_list => handle_scalar_list_field(field, value, &op),
                             Field::Scalar(field) => handle_scalar_field(field, value, &op),
                             Field::Relation(field) => handle_relation_field(field, value, &op),
                         },
@@ -203,6 +222,98 @@ fn handle_scalar_field(
     })
 }
 
+fn handle_scalar_list_field(
+    field: &ScalarFieldRef,
+    value: ParsedInputValue,
+    op: &FilterOp,
+) -> QueryGraphBuilderResult<Filter> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match (op, value) {
+        (FilterOp::Has, val) => field.contains_element(val),
+        (FilterOp::HasEvery, PrismaValue::List(values)) => field.contains_every_element(values),
+        (FilterOp::HasSome, PrismaValue::List(values)) => field.contains_some_element(values),
+        (FilterOp::IsEmpty, PrismaValue::Boolean(empty)) => field.is_empty_list(empty),
+        (_, _) => unreachable!(),
+    })
+}
+
+fn handle_composite_field(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    match value {
+        ParsedInputValue::Map(map) => {
+            let composite_type = field
+                .internal_composite_type()
+                .expect("A composite field must always have an associated composite type.");
+
+            extract_composite_filter(field, &field.internal_data_model(), &composite_type, vec![], map)
+        }
+        value => {
+            let value: PrismaValue = value.try_into()?;
+            Ok(field.equals(value))
+        }
+    }
+}
+
+/// Extracts the filters on the fields of a composite value, as conditions on
+/// the paths into the document stored in `field`.
+fn extract_composite_filter(
+    field: &ScalarFieldRef,
+    internal_data_model: &InternalDataModelRef,
+    composite_type: &InternalCompositeType,
+    path: Vec<String>,
+    value_map: ParsedInputMap,
+) -> QueryGraphBuilderResult<Filter> {
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value): (String, ParsedInputValue)| {
+            let op = FilterOp::find_op(key.as_str());
+            let field_name = key.trim_end_matches(op.suffix());
+
+            let mut path = path.clone();
+            path.push(field_name.to_owned());
+
+            let nested = composite_type
+                .find_field(field_name)
+                .and_then(|field| field.composite_type.as_ref())
+                .and_then(|name| internal_data_model.find_composite_type(name));
+
+            match (nested, value) {
+                (Some(nested), ParsedInputValue::Map(map)) => {
+                    extract_composite_filter(field, internal_data_model, nested, path, map)
+                }
+                (_, value) => {
+                    let condition = composite_condition(&op, value.try_into()?);
+                    Ok(CompositeFilter::new(Arc::clone(field), path, condition).into())
+                }
+            }
+        })
+        .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
+
+    Ok(Filter::and(filters))
+}
+
+fn composite_condition(op: &FilterOp, value: PrismaValue) -> ScalarCondition {
+    match (op, value) {
+        (FilterOp::In, PrismaValue::Null(hint)) => ScalarCondition::Equals(PrismaValue::Null(hint)),
+        (FilterOp::In, PrismaValue::List(values)) => ScalarCondition::In(values),
+        (FilterOp::NotIn, PrismaValue::Null(hint)) => ScalarCondition::NotEquals(PrismaValue::Null(hint)),
+        (FilterOp::NotIn, PrismaValue::List(values)) => ScalarCondition::NotIn(values),
+        (FilterOp::Not, val) => ScalarCondition::NotEquals(val),
+        (FilterOp::Lt, val) => ScalarCondition::LessThan(val),
+        (FilterOp::Lte, val) => ScalarCondition::LessThanOrEquals(val),
+        (FilterOp::Gt, val) => ScalarCondition::GreaterThan(val),
+        (FilterOp::Gte, val) => ScalarCondition::GreaterThanOrEquals(val),
+        (FilterOp::Contains, val) => ScalarCondition::Contains(val),
+        (FilterOp::NotContains, val) => ScalarCondition::NotContains(val),
+        (FilterOp::StartsWith, val) => ScalarCondition::StartsWith(val),
+        (FilterOp::NotStartsWith, val) => ScalarCondition::NotStartsWith(val),
+        (FilterOp::EndsWith, val) => ScalarCondition::EndsWith(val),
+        (FilterOp::NotEndsWith, val) => ScalarCondition::NotEndsWith(val),
+        (FilterOp::Field, val) => ScalarCondition::Equals(val),
+        (_, _) => unreachable!(),
+    }
+}
+
 fn handle_relation_field(
     field: &RelationFieldRef,
     value: ParsedInputValue,
@@ -4,7 +4,7 @@ use crate::{
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
 use connector::QueryArguments;
-use prisma_models::{ModelRef, PrismaValue, RecordProjection, ScalarFieldRef};
+use prisma_models::{Field, ModelProjection, ModelRef, PrismaValue, RecordProjection, ScalarFieldRef};
 use std::convert::TryInto;
 
 /// Expects the caller to know that it is structurally guaranteed that query arguments can be extracted,
@@ -36,6 +36,11 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
 
+                    "distinct" => Ok(QueryArguments {
+                        distinct: extract_distinct(arg.value)?,
+                        ..res
+                    }),
+
                     "where" => {
                         let val: Option<ParsedInputMap> = arg.value.try_into()?;
                         match val {
@@ -68,6 +73,27 @@ fn extract_skip(value: ParsedInputValue) -> QueryGraphBuilderResult<Option<i64>>
     }
 }
 
+fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<Option<ModelProjection>> {
+    if let Err(_) = value.assert_non_null() {
+        return Ok(None);
+    }
+
+    let values: Vec<ParsedInputValue> = value.try_into()?;
+    let fields = values
+        .into_iter()
+        .map(|value| {
+            let field: ScalarFieldRef = value.try_into()?;
+            Ok(Field::from(field))
+        })
+        .collect::<QueryGraphBuilderResult<Vec<Field>>>()?;
+
+    if fields.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(ModelProjection::new(fields)))
+    }
+}
+
 fn extract_cursor(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<Option<RecordProjection>> {
     if let Err(_) = value.assert_non_null() {
         return Ok(None);
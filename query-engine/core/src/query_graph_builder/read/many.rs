@@ -24,6 +24,7 @@ impl Builder<ReadQuery> for ReadManyRecordsBuilder {
         let nested = collect_nested_queries(nested_fields, &self.model)?;
         let model = self.model;
         let selected_fields = merge_relation_selections(selected_fields, None, &nested);
+        let selected_fields = merge_distinct_selections(selected_fields, &args);
 
         Ok(ReadQuery::ManyRecordsQuery(ManyRecordsQuery {
             name,
@@ -10,6 +10,7 @@ pub use related::*;
 
 use super::*;
 use crate::{query_document::ParsedField, ReadQuery};
+use connector::QueryArguments;
 use prisma_models::{Field, ModelProjection, ModelRef, RelationFieldRef};
 use std::sync::Arc;
 
@@ -115,3 +116,11 @@ pub fn merge_relation_selections(
 
     selected_fields.merge(ModelProjection::union(nested))
 }
+
+/// Merges the fields of the `distinct` argument, if any, as records are deduplicated on their values.
+pub fn merge_distinct_selections(selected_fields: ModelProjection, args: &QueryArguments) -> ModelProjection {
+    match args.distinct {
+        Some(ref distinct) => selected_fields.merge(distinct.clone()),
+        None => selected_fields,
+    }
+}
@@ -102,7 +102,7 @@ pub fn nested_update_many(
         let child_model_identifier = parent_relation_field.related_model().primary_identifier();
 
         let filter = extract_filter(where_map, child_model)?;
-        let update_args = WriteArgsParser::from(&child_model, data_map)?;
+        let update_args = WriteArgsParser::from_update(&child_model, data_map)?;
 
         let find_child_records_node =
             utils::insert_find_children_by_parent_node(graph, parent, parent_relation_field, filter)?;
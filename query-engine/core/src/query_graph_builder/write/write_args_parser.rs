@@ -1,7 +1,11 @@
 use super::*;
 use crate::query_document::{ParsedInputMap, ParsedInputValue};
 use connector::WriteArgs;
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
+use prisma_models::{
+    Field, InternalCompositeType, InternalDataModelRef, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef,
+    TypeHint,
+};
+use serde_json::{Map, Value as JsonValue};
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
@@ -14,30 +18,33 @@ impl WriteArgsParser {
     /// Creates a new set of WriteArgsParser. Expects the parsed input map from the respective data key, not the enclosing map.
     /// E.g.: { data: { THIS MAP } } from the `data` argument of a write query.
     pub fn from(model: &ModelRef, data_map: ParsedInputMap) -> QueryGraphBuilderResult<Self> {
+        Self::parse(model, data_map, false)
+    }
+
+    /// Same as `from`, for the data of update operations. Composite fields are
+    /// wrapped in `set` / `update` inputs there.
+    pub fn from_update(model: &ModelRef, data_map: ParsedInputMap) -> QueryGraphBuilderResult<Self> {
+        Self::parse(model, data_map, true)
+    }
+
+    fn parse(model: &ModelRef, data_map: ParsedInputMap, is_update: bool) -> QueryGraphBuilderResult<Self> {
         data_map.into_iter().try_fold(
             WriteArgsParser::default(),
             |mut args, (k, v): (String, ParsedInputValue)| {
                 let field = model.fields().find_from_all(&k).unwrap();
 
                 match field {
-                    Field::Scalar(sf) if sf.is_list => {
-                        let vals: ParsedInputMap = v.try_into()?;
-                        let set_value = vals.into_iter().find(|(k, _)| k == "set");
-
-                        let set_value: PrismaValue = match set_value {
-                            Some(value) => value.1.try_into()?,
-                            None => {
-                                return Err(QueryGraphBuilderError::MissingRequiredArgument {
-                                    argument_name: "set".to_owned(),
-                                    field_name: sf.name.to_owned(),
-                                    object_name: model.name.to_owned(),
-                                })
-                            }
-                        };
-
-                        args.args.insert(sf.db_name().clone(), set_value)
+                    Field::Scalar(sf) if sf.is_composite() && is_update => {
+                        parse_composite_update(&mut args.args, sf, v)?
                     }
 
+                    Field::Scalar(sf) if sf.is_composite() => {
+                        let value = composite_value(composite_document(v)?);
+                        args.args.insert(sf.db_name().clone(), value)
+                    }
+
+                    Field::Scalar(sf) if sf.is_list => parse_scalar_list(model, &mut args.args, sf, v)?,
+
                     Field::Scalar(sf) => {
                         let value: PrismaValue = v.try_into()?;
                         args.args.insert(sf.db_name().clone(), value)
@@ -54,3 +61,191 @@ impl WriteArgsParser {
         )
     }
 }
+
+/// Scalar lists are either replaced (`set`), or appended to (`push`).
+fn parse_scalar_list(
+    model: &ModelRef,
+    args: &mut WriteArgs,
+    field: &ScalarFieldRef,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<()> {
+    let mut envelope: ParsedInputMap = value.try_into()?;
+    let set = envelope.remove("set");
+    let push = envelope.remove("push");
+
+    match (set, push) {
+        (Some(_), Some(_)) => Err(QueryGraphBuilderError::InputError(format!(
+            "The list field `{}` can't be set and pushed to in the same operation.",
+            field.name
+        ))),
+        (Some(set), None) => {
+            let value: PrismaValue = set.try_into()?;
+            args.insert(field.db_name().clone(), value);
+
+            Ok(())
+        }
+        (None, Some(push)) => {
+            let value: PrismaValue = push.try_into()?;
+            let values = match value {
+                PrismaValue::List(values) => values,
+                value => vec![value],
+            };
+
+            args.insert_list_push(field.db_name().clone(), values);
+            Ok(())
+        }
+        (None, None) => Err(QueryGraphBuilderError::MissingRequiredArgument {
+            argument_name: "set".to_owned(),
+            field_name: field.name.to_owned(),
+            object_name: model.name.to_owned(),
+        }),
+    }
+}
+
+/// Composite updates either replace the whole value (`set`), or update single
+/// fields of it (`update`), which becomes a merge patch on the stored document.
+fn parse_composite_update(
+    args: &mut WriteArgs,
+    field: &ScalarFieldRef,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<()> {
+    let mut envelope: ParsedInputMap = value.try_into()?;
+    let set = envelope.remove("set");
+    let update = envelope.remove("update");
+
+    match (set, update) {
+        (Some(_), Some(_)) => Err(QueryGraphBuilderError::InputError(format!(
+            "The composite field `{}` can't be set and updated in the same operation.",
+            field.name
+        ))),
+        (Some(set), None) => {
+            args.insert(field.db_name().clone(), composite_value(composite_document(set)?));
+            Ok(())
+        }
+        (None, Some(update)) => {
+            let composite_type = field
+                .internal_composite_type()
+                .expect("A composite field must always have an associated composite type.");
+
+            let patch = update_patch(&field.internal_data_model(), &composite_type, update.try_into()?)?;
+            args.insert_composite_update(field.db_name().clone(), patch);
+
+            Ok(())
+        }
+        (None, None) => Ok(()),
+    }
+}
+
+/// Builds the merge patch for the fields of a `<x>CompositeUpdateDataInput`.
+fn update_patch(
+    internal_data_model: &InternalDataModelRef,
+    composite_type: &InternalCompositeType,
+    data: ParsedInputMap,
+) -> QueryGraphBuilderResult<JsonValue> {
+    let mut patch = Map::new();
+
+    for (name, value) in data {
+        let nested = composite_type
+            .find_field(&name)
+            .and_then(|field| field.composite_type.as_ref().filter(|_| !field.is_list()))
+            .and_then(|name| internal_data_model.find_composite_type(name));
+
+        let member = match nested {
+            Some(nested) => {
+                let mut envelope: ParsedInputMap = value.try_into()?;
+
+                match (envelope.remove("set"), envelope.remove("update")) {
+                    (Some(_), Some(_)) => {
+                        return Err(QueryGraphBuilderError::InputError(format!(
+                            "The composite field `{}` can't be set and updated in the same operation.",
+                            name
+                        )))
+                    }
+                    (Some(set), None) => set_patch(internal_data_model, nested, composite_document(set)?),
+                    (None, Some(update)) => update_patch(internal_data_model, nested, update.try_into()?)?,
+                    (None, None) => continue,
+                }
+            }
+            None => composite_document(value)?,
+        };
+
+        patch.insert(name, member);
+    }
+
+    Ok(JsonValue::Object(patch))
+}
+
+/// Merge patches merge objects, so replacing a nested composite value has to
+/// remove the fields missing in the new value explicitly.
+fn set_patch(
+    internal_data_model: &InternalDataModelRef,
+    composite_type: &InternalCompositeType,
+    value: JsonValue,
+) -> JsonValue {
+    let mut members = match value {
+        JsonValue::Object(members) => members,
+        value => return value,
+    };
+
+    let patch = composite_type
+        .fields
+        .iter()
+        .map(|field| {
+            let member = members.remove(&field.name).unwrap_or(JsonValue::Null);
+            let nested = field
+                .composite_type
+                .as_ref()
+                .filter(|_| !field.is_list())
+                .and_then(|name| internal_data_model.find_composite_type(name));
+
+            let member = match nested {
+                Some(nested) => set_patch(internal_data_model, nested, member),
+                None => member,
+            };
+
+            (field.name.clone(), member)
+        })
+        .collect();
+
+    JsonValue::Object(patch)
+}
+
+fn composite_value(document: JsonValue) -> PrismaValue {
+    match document {
+        JsonValue::Null => PrismaValue::Null(TypeHint::Json),
+        document => PrismaValue::Json(document.to_string()),
+    }
+}
+
+/// Converts the input of a composite value into the JSON document stored in the database.
+fn composite_document(value: ParsedInputValue) -> QueryGraphBuilderResult<JsonValue> {
+    match value {
+        ParsedInputValue::Map(map) => {
+            let members = map
+                .into_iter()
+                .map(|(name, value)| Ok((name, composite_document(value)?)))
+                .collect::<QueryGraphBuilderResult<Map<String, JsonValue>>>()?;
+
+            Ok(JsonValue::Object(members))
+        }
+        ParsedInputValue::List(values) => {
+            let values = values
+                .into_iter()
+                .map(composite_document)
+                .collect::<QueryGraphBuilderResult<Vec<JsonValue>>>()?;
+
+            Ok(JsonValue::Array(values))
+        }
+        ParsedInputValue::Single(PrismaValue::Json(json)) => serde_json::from_str(&json)
+            .map_err(|err| QueryGraphBuilderError::InputError(format!("Invalid JSON in composite value: {}", err))),
+        ParsedInputValue::Single(PrismaValue::List(values)) => composite_document(ParsedInputValue::List(
+            values.into_iter().map(ParsedInputValue::Single).collect(),
+        )),
+        ParsedInputValue::Single(value) => serde_json::to_value(value)
+            .map_err(|err| QueryGraphBuilderError::InputError(format!("Invalid composite value: {}", err))),
+        value => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid composite value: {:?}",
+            value
+        ))),
+    }
+}
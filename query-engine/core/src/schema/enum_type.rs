@@ -1,9 +1,10 @@
-use prisma_models::{InternalEnum, OrderBy};
+use prisma_models::{InternalEnum, OrderBy, ScalarFieldRef};
 
 #[derive(Debug)]
 pub enum EnumType {
     Internal(InternalEnum),
     OrderBy(OrderByEnumType),
+    FieldRef(FieldRefEnumType),
 }
 
 impl EnumType {
@@ -11,6 +12,7 @@ impl EnumType {
         match self {
             Self::Internal(i) => &i.name,
             Self::OrderBy(ord) => &ord.name,
+            Self::FieldRef(f) => &f.name,
         }
     }
 }
@@ -36,6 +38,28 @@ impl OrderByEnumType {
     }
 }
 
+/// An enum whose values refer to the scalar fields of a model, e.g. for `distinct`.
+#[derive(Debug, Clone)]
+pub struct FieldRefEnumType {
+    pub name: String,
+
+    /// E.g. email -> the email field of the model
+    pub values: Vec<(String, ScalarFieldRef)>,
+}
+
+impl FieldRefEnumType {
+    /// Attempts to find an enum value for the given value key.
+    pub fn value_for(&self, name: &str) -> Option<&ScalarFieldRef> {
+        self.values
+            .iter()
+            .find_map(|val| if &val.0 == name { Some(&val.1) } else { None })
+    }
+
+    pub fn values(&self) -> Vec<String> {
+        self.values.iter().map(|(name, _)| name.to_owned()).collect()
+    }
+}
+
 impl From<InternalEnum> for EnumType {
     fn from(internal_enum: InternalEnum) -> EnumType {
         EnumType::Internal(internal_enum)
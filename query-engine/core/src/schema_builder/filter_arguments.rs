@@ -12,6 +12,7 @@ struct StaticFilterArguments {
     pub inclusion: &'static [FilterArgument],
     pub alphanumeric: &'static [FilterArgument],
     pub string: &'static [FilterArgument],
+    pub scalar_list: &'static [FilterArgument],
     pub multi_relation: &'static [FilterArgument],
     pub one_relation: &'static [FilterArgument],
 }
@@ -85,6 +86,25 @@ static FILTER_ARGUMENTS: StaticFilterArguments = StaticFilterArguments {
         }, // All values not ending with the given string.
     ],
 
+    scalar_list: &[
+        FilterArgument {
+            suffix: "_has",
+            is_list: false,
+        }, // All lists containing the given value.
+        FilterArgument {
+            suffix: "_has_every",
+            is_list: true,
+        }, // All lists containing every one of the given values.
+        FilterArgument {
+            suffix: "_has_some",
+            is_list: true,
+        }, // All lists containing at least one of the given values.
+        FilterArgument {
+            suffix: "_is_empty",
+            is_list: false,
+        }, // All lists that are empty (true) or not empty (false).
+    ],
+
     multi_relation: &[
         FilterArgument {
             suffix: "_every",
@@ -111,20 +131,43 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
 
     let filters = match field {
         ModelField::Relation(_) if field.is_list() => vec![&args.multi_relation],
-        ModelField::Scalar(_) if field.is_list() => vec![],
         ModelField::Relation(_) => vec![&args.one_relation],
-        ModelField::Scalar(sf) => match sf.type_identifier {
-            TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
-            TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
-            TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Boolean => vec![&args.base],
-            TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
-            TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Json => vec![&args.base],
-        },
+        // Composite fields are filtered on the fields of the composite type.
+        ModelField::Scalar(sf) if sf.is_composite() => vec![],
+        ModelField::Scalar(_) if field.is_list() => vec![&args.scalar_list],
+        ModelField::Scalar(sf) => type_filters(&sf.type_identifier),
     };
 
+    flatten(filters)
+}
+
+/// Filters on a field of a composite type. Lists and nested composites have none.
+pub fn get_composite_field_filters<'a>(field: &InternalCompositeTypeField) -> Vec<&'a FilterArgument> {
+    let filters = if field.is_list() || field.composite_type.is_some() {
+        vec![]
+    } else {
+        type_filters(&field.type_identifier)
+    };
+
+    flatten(filters)
+}
+
+fn type_filters(type_identifier: &TypeIdentifier) -> Vec<&'static &'static [FilterArgument]> {
+    let args = &FILTER_ARGUMENTS;
+
+    match type_identifier {
+        TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+        TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+        TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Boolean => vec![&args.base],
+        TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
+        TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Json => vec![&args.base],
+    }
+}
+
+fn flatten<'a>(filters: Vec<&'static &'static [FilterArgument]>) -> Vec<&'a FilterArgument> {
     filters
         .into_iter()
         .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
@@ -124,6 +124,14 @@ impl<'a> FilterObjectTypeBuilder<'a> {
     }
 
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
+        if field.is_composite() {
+            return self.map_composite_filter_input_field(field);
+        }
+
+        if field.is_list {
+            return self.map_scalar_list_filter_input_fields(field);
+        }
+
         get_field_filters(&ModelField::Scalar(Arc::clone(&field)))
             .into_iter()
             .map(|arg| {
@@ -145,6 +153,95 @@ impl<'a> FilterObjectTypeBuilder<'a> {
             .collect()
     }
 
+    /// Maps scalar lists to filters on their elements. Filter values are never nullable.
+    fn map_scalar_list_filter_input_fields(&self, field: ScalarFieldRef) -> Vec<InputField> {
+        get_field_filters(&ModelField::Scalar(Arc::clone(&field)))
+            .into_iter()
+            .map(|arg| {
+                let field_name = format!("{}{}", field.name, arg.suffix);
+                let typ = match arg.suffix {
+                    "_is_empty" => InputType::boolean(),
+                    _ if arg.is_list => InputType::list(self.map_scalar_input_type(&field)),
+                    _ => self.map_scalar_input_type(&field),
+                };
+
+                input_field(field_name, InputType::opt(typ), None)
+            })
+            .collect()
+    }
+
+    /// Maps composite fields to a single filter input field. Lists of composites can't be filtered.
+    fn map_composite_filter_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
+        if field.is_composite_list() {
+            return vec![];
+        }
+
+        let composite_type = field
+            .internal_composite_type()
+            .expect("A composite field must always have an associated composite type.");
+
+        let filter_object = self.composite_filter_object_type(&field.internal_data_model(), &composite_type);
+        let typ = InputType::opt(InputType::null(InputType::object(filter_object)));
+
+        vec![input_field(field.name.clone(), typ, None)]
+    }
+
+    /// Builds "<x>CompositeFilter" input object types, filtering on the fields of a composite type.
+    fn composite_filter_object_type(
+        &self,
+        internal_data_model: &InternalDataModelRef,
+        composite_type: &InternalCompositeType,
+    ) -> InputObjectTypeRef {
+        let name = format!("{}CompositeFilter", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .flat_map(|field| {
+                let nested = field
+                    .composite_type
+                    .as_ref()
+                    .and_then(|name| internal_data_model.find_composite_type(name));
+
+                match nested {
+                    Some(_) if field.is_list() => vec![],
+                    Some(nested) => {
+                        let filter_object = self.composite_filter_object_type(internal_data_model, nested);
+                        let typ = InputType::opt(InputType::null(InputType::object(filter_object)));
+
+                        vec![input_field(field.name.clone(), typ, None)]
+                    }
+                    None => self.map_composite_field_filters(field),
+                }
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    fn map_composite_field_filters(&self, field: &InternalCompositeTypeField) -> Vec<InputField> {
+        get_composite_field_filters(field)
+            .into_iter()
+            .map(|arg| {
+                let field_name = format!("{}{}", field.name, arg.suffix);
+                let mapped = self.map_composite_field_input_type(field);
+
+                if arg.is_list {
+                    input_field(field_name, InputType::opt(InputType::list(mapped)), None)
+                } else if field.is_required() {
+                    input_field(field_name, InputType::opt(mapped), None)
+                } else {
+                    input_field(field_name, InputType::opt(InputType::null(mapped)), None)
+                }
+            })
+            .collect()
+    }
+
     /// Maps relations to (filter) input fields.
     fn map_relation_filter_input_field(&self, field: RelationFieldRef) -> Vec<InputField> {
         let related_model = field.related_model();
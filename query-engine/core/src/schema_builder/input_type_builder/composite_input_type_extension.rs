@@ -0,0 +1,177 @@
+use super::*;
+
+pub trait CompositeInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
+    /// Maps a composite field of a model for create input types.
+    fn map_composite_create_input_type(&self, field: &ScalarFieldRef) -> InputType {
+        let composite_type = Self::expect_composite_type(field);
+        let input_object = self.composite_input_type(&field.internal_data_model(), &composite_type);
+        let typ = InputType::object(input_object);
+
+        if field.is_composite_list() {
+            InputType::opt(InputType::list(typ))
+        } else if field.is_required {
+            typ
+        } else {
+            InputType::opt(InputType::null(typ))
+        }
+    }
+
+    /// Maps a composite field of a model for update input types.
+    fn map_composite_update_input_type(&self, field: &ScalarFieldRef) -> InputType {
+        let internal_data_model = field.internal_data_model();
+        let composite_type = Self::expect_composite_type(field);
+
+        let input_object = if field.is_composite_list() {
+            self.composite_list_update_input_type(&internal_data_model, &composite_type)
+        } else {
+            self.composite_update_input_type(&internal_data_model, &composite_type)
+        };
+
+        InputType::opt(InputType::object(input_object))
+    }
+
+    /// Builds "<x>CompositeInput" input object types, holding a complete composite value.
+    fn composite_input_type(
+        &self,
+        internal_data_model: &InternalDataModelRef,
+        composite_type: &InternalCompositeType,
+    ) -> InputObjectTypeRef {
+        let name = format!("{}CompositeInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|field| {
+                let typ = match Self::nested_composite_type(internal_data_model, field) {
+                    Some(nested) => InputType::object(self.composite_input_type(internal_data_model, &nested)),
+                    None => self.map_composite_field_input_type(field),
+                };
+
+                let typ = if field.is_list() {
+                    InputType::opt(InputType::list(typ))
+                } else if field.is_required() {
+                    typ
+                } else {
+                    InputType::opt(InputType::null(typ))
+                };
+
+                input_field(field.name.clone(), typ, None)
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds "<x>CompositeUpdateInput" input object types, either replacing
+    /// the composite value (`set`) or updating single fields of it (`update`).
+    fn composite_update_input_type(
+        &self,
+        internal_data_model: &InternalDataModelRef,
+        composite_type: &InternalCompositeType,
+    ) -> InputObjectTypeRef {
+        let name = format!("{}CompositeUpdateInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let set_object = self.composite_input_type(internal_data_model, composite_type);
+        let update_object = self.composite_update_data_input_type(internal_data_model, composite_type);
+
+        let fields = vec![
+            input_field(
+                "set",
+                InputType::opt(InputType::null(InputType::object(set_object))),
+                None,
+            ),
+            input_field("update", InputType::opt(InputType::object(update_object)), None),
+        ];
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds "<x>CompositeUpdateDataInput" input object types. All fields are optional.
+    fn composite_update_data_input_type(
+        &self,
+        internal_data_model: &InternalDataModelRef,
+        composite_type: &InternalCompositeType,
+    ) -> InputObjectTypeRef {
+        let name = format!("{}CompositeUpdateDataInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|field| {
+                let nested = Self::nested_composite_type(internal_data_model, field);
+
+                let typ = match nested {
+                    Some(ref nested) if field.is_list() => {
+                        let nested_object = self.composite_input_type(internal_data_model, nested);
+                        InputType::list(InputType::object(nested_object))
+                    }
+                    Some(ref nested) => {
+                        let nested_object = self.composite_update_input_type(internal_data_model, nested);
+                        InputType::object(nested_object)
+                    }
+                    None if field.is_list() => InputType::list(self.map_composite_field_input_type(field)),
+                    None if field.is_required() => self.map_composite_field_input_type(field),
+                    None => InputType::null(self.map_composite_field_input_type(field)),
+                };
+
+                input_field(field.name.clone(), InputType::opt(typ), None)
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds "<x>CompositeListUpdateInput" input object types, replacing the whole list.
+    fn composite_list_update_input_type(
+        &self,
+        internal_data_model: &InternalDataModelRef,
+        composite_type: &InternalCompositeType,
+    ) -> InputObjectTypeRef {
+        let name = format!("{}CompositeListUpdateInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let set_object = self.composite_input_type(internal_data_model, composite_type);
+        let fields = vec![input_field(
+            "set",
+            InputType::opt(InputType::list(InputType::object(set_object))),
+            None,
+        )];
+
+        let input_object = Arc::new(input_object_type(name.clone(), fields));
+        self.cache(name, Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
+    }
+
+    fn nested_composite_type(
+        internal_data_model: &InternalDataModelRef,
+        field: &InternalCompositeTypeField,
+    ) -> Option<InternalCompositeType> {
+        field
+            .composite_type
+            .as_ref()
+            .and_then(|name| internal_data_model.find_composite_type(name))
+            .cloned()
+    }
+
+    fn expect_composite_type(field: &ScalarFieldRef) -> InternalCompositeType {
+        field
+            .internal_composite_type()
+            .expect("A composite field must always have an associated composite type.")
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
+pub trait CreateInputTypeBuilderExtension<'a>: CompositeInputTypeBuilderExtension<'a> {
     fn nested_connect_or_create_field(&self, field: RelationFieldRef) -> Option<InputField> {
         self.nested_connect_or_create_input_object(Arc::clone(&field))
             .map(|input_object| {
@@ -13,6 +13,10 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
     fn nested_connect_or_create_input_object(&self, parent_field: RelationFieldRef) -> Option<InputObjectTypeRef> {
         let related_model = parent_field.related_model();
 
+        if !related_model.supports_create_operation() {
+            return None;
+        }
+
         let where_object = self.where_unique_object_type(&related_model);
         let create_object = self.create_input_type(Arc::clone(&related_model), Some(Arc::clone(&parent_field)));
 
@@ -70,7 +74,9 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             "Create",
             scalar_fields,
             |f: ScalarFieldRef| {
-                if f.is_required && f.default_value.is_none() && (f.is_created_at() || f.is_updated_at()) {
+                if f.is_composite() {
+                    self.map_composite_create_input_type(&f)
+                } else if f.is_required && f.default_value.is_none() && (f.is_created_at() || f.is_updated_at()) {
                     //todo shouldnt these also be Default Value expressions at some point?
                     self.map_optional_input_type(&f)
                 } else if f.is_required && f.default_value.is_none() {
@@ -129,7 +135,9 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
                             let input_object = Arc::new(init_input_object_type(input_name.clone()));
                             self.cache(input_name, Arc::clone(&input_object));
 
-                            let mut fields = vec![self.nested_create_input_field(Arc::clone(&rf))];
+                            let mut fields = vec![];
+                            append_opt(&mut fields, self.nested_create_input_field(Arc::clone(&rf)));
+
                             let nested_connect = self.nested_connect_input_field(Arc::clone(&rf));
                             append_opt(&mut fields, nested_connect);
 
@@ -156,11 +164,16 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             .collect()
     }
 
-    fn nested_create_input_field(&self, field: RelationFieldRef) -> InputField {
+    /// Records of models with required fields the client can't set can't be created, nested or not.
+    fn nested_create_input_field(&self, field: RelationFieldRef) -> Option<InputField> {
+        if !field.related_model().supports_create_operation() {
+            return None;
+        }
+
         let input_object = self.create_input_type(field.related_model(), Some(Arc::clone(&field)));
         let input_object = Self::wrap_list_input_object_type(input_object, field.is_list);
 
-        input_field("create", input_object, None)
+        Some(input_field("create", input_object, None))
     }
 
     fn field_should_be_kept_for_create_input_type(field: &ScalarFieldRef) -> bool {
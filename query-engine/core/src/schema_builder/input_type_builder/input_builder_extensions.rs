@@ -7,7 +7,17 @@ pub trait InputBuilderExtensions {
     }
 
     fn map_required_input_type(&self, field: &ScalarFieldRef) -> InputType {
-        let typ = match field.type_identifier {
+        let typ = self.map_scalar_input_type(field);
+        let typ = if field.is_list { InputType::list(typ) } else { typ };
+        let typ = if !field.is_required { InputType::null(typ) } else { typ };
+
+        typ
+    }
+
+    /// Maps the type of a scalar field, or the element type of a scalar list.
+    /// Lists and nullability are left to the caller.
+    fn map_scalar_input_type(&self, field: &ScalarFieldRef) -> InputType {
+        match field.type_identifier {
             TypeIdentifier::String => InputType::string(),
             TypeIdentifier::Int => InputType::int(),
             TypeIdentifier::Float => InputType::float(),
@@ -16,12 +26,30 @@ pub trait InputBuilderExtensions {
             TypeIdentifier::DateTime => InputType::date_time(),
             TypeIdentifier::Json => InputType::json(),
             TypeIdentifier::Enum(_) => self.map_enum_input_type(&field),
-        };
+        }
+    }
 
-        let typ = if field.is_list { InputType::list(typ) } else { typ };
-        let typ = if !field.is_required { InputType::null(typ) } else { typ };
+    /// Maps a field of a composite type that is not itself of a composite type.
+    /// Lists and nullability are left to the caller.
+    fn map_composite_field_input_type(&self, field: &InternalCompositeTypeField) -> InputType {
+        match field.type_identifier {
+            TypeIdentifier::String => InputType::string(),
+            TypeIdentifier::Int => InputType::int(),
+            TypeIdentifier::Float => InputType::float(),
+            TypeIdentifier::Boolean => InputType::boolean(),
+            TypeIdentifier::UUID => InputType::uuid(),
+            TypeIdentifier::DateTime => InputType::date_time(),
+            TypeIdentifier::Json => InputType::json(),
+            TypeIdentifier::Enum(_) => {
+                let internal_enum = field
+                    .internal_enum
+                    .as_ref()
+                    .expect("A field with TypeIdentifier Enum must always have an associated internal enum.");
 
-        typ
+                let et: EnumType = internal_enum.clone().into();
+                et.into()
+            }
+        }
     }
 
     fn map_enum_input_type(&self, field: &ScalarFieldRef) -> InputType {
@@ -1,9 +1,11 @@
 use super::*;
 
+mod composite_input_type_extension;
 mod create_input_type_extension;
 mod input_builder_extensions;
 mod update_input_type_extension;
 
+pub use composite_input_type_extension::*;
 pub use create_input_type_extension::*;
 pub use input_builder_extensions::*;
 pub use update_input_type_extension::*;
@@ -42,7 +44,14 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
                 let input_object = match self.get_cache().get(&set_name) {
                     Some(t) => t,
                     None => {
-                        let set_fields = vec![input_field("set", self.map_optional_input_type(&f), None)];
+                        let mut set_fields = vec![input_field("set", self.map_optional_input_type(&f), None)];
+
+                        // Only existing lists can be pushed to.
+                        if input_object_name == "Update" {
+                            let push_type = InputType::opt(InputType::list(self.map_scalar_input_type(&f)));
+                            set_fields.push(input_field("push", push_type, None));
+                        }
+
                         let input_object = Arc::new(input_object_type(set_name.clone(), set_fields));
 
                         self.cache(set_name, Arc::clone(&input_object));
@@ -215,6 +224,7 @@ impl<'a> InputTypeBuilderBase<'a> for InputTypeBuilder<'a> {
 }
 
 impl<'a> InputBuilderExtensions for InputTypeBuilder<'a> {}
+impl<'a> CompositeInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 impl<'a> CreateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 impl<'a> UpdateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 
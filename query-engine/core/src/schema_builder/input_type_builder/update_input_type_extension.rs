@@ -37,7 +37,13 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
             model.name.clone(),
             "Update",
             model.fields().scalar_writable(),
-            |f: ScalarFieldRef| self.map_optional_input_type(&f),
+            |f: ScalarFieldRef| {
+                if f.is_composite() {
+                    self.map_composite_update_input_type(&f)
+                } else {
+                    self.map_optional_input_type(&f)
+                }
+            },
             false,
         )
     }
@@ -88,8 +94,9 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
                             let input_object = Arc::new(init_input_object_type(input_name.clone()));
                             self.cache(input_name, Arc::clone(&input_object));
 
-                            let mut fields = vec![self.nested_create_input_field(Arc::clone(&rf))];
+                            let mut fields = vec![];
 
+                            append_opt(&mut fields, self.nested_create_input_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_connect_input_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_set_input_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_disconnect_input_field(Arc::clone(&rf)));
@@ -127,6 +134,10 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
     }
 
     fn nested_upsert_input_object(&self, parent_field: RelationFieldRef) -> Option<InputObjectTypeRef> {
+        if !parent_field.related_model().supports_create_operation() {
+            return None;
+        }
+
         let nested_update_data_object = self.nested_update_data(Arc::clone(&parent_field));
 
         if parent_field.is_list {
@@ -18,8 +18,8 @@ use object_type_builder::*;
 /// Common module imports shared accross submodules.
 use crate::schema::*;
 use prisma_models::{
-    Field as ModelField, Index, InternalDataModelRef, ModelRef, RelationFieldRef, ScalarField, ScalarFieldRef,
-    SortOrder, TypeIdentifier,
+    Field as ModelField, Index, InternalCompositeType, InternalCompositeTypeField, InternalDataModelRef, ModelRef,
+    RelationFieldRef, ScalarField, ScalarFieldRef, SortOrder, TypeIdentifier,
 };
 use std::sync::{Arc, Weak};
 
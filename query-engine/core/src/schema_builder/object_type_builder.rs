@@ -130,7 +130,9 @@ impl<'a> ObjectTypeBuilder<'a> {
         match field {
             ModelField::Scalar(_) => vec![],
             ModelField::Relation(rf) if rf.is_list && !rf.related_model().is_embedded => {
-                self.many_records_arguments(&rf.related_model())
+                let mut args = self.many_records_arguments(&rf.related_model());
+                args.push(self.distinct_argument(&rf.related_model()));
+                args
             }
             ModelField::Relation(rf) if rf.is_list && rf.related_model().is_embedded => vec![],
             ModelField::Relation(rf) if !rf.is_list => vec![],
@@ -197,6 +199,22 @@ impl<'a> ObjectTypeBuilder<'a> {
         argument("orderBy", InputType::opt(enum_type.into()), None)
     }
 
+    // Builds "distinct" argument.
+    pub fn distinct_argument(&self, model: &ModelRef) -> Argument {
+        let enum_values: Vec<_> = model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|field| !field.is_list)
+            .map(|field| (field.name.clone(), field))
+            .collect();
+
+        let enum_name = format!("{}DistinctFieldEnum", model.name);
+        let enum_type = field_ref_enum_type(enum_name, enum_values);
+
+        argument("distinct", InputType::opt(InputType::list(enum_type.into())), None)
+    }
+
     pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
         match scalar_field.type_identifier {
             TypeIdentifier::Enum(_) => {
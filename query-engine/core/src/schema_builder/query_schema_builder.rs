@@ -160,8 +160,9 @@ impl<'a> QuerySchemaBuilder<'a> {
         let mut fields: Vec<Field> = non_embedded_models
             .into_iter()
             .map(|model| {
-                let mut vec = vec![self.create_item_field(Arc::clone(&model))];
+                let mut vec = vec![];
 
+                append_opt(&mut vec, self.create_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.delete_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.update_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.upsert_item_field(Arc::clone(&model)));
@@ -177,6 +178,7 @@ impl<'a> QuerySchemaBuilder<'a> {
         if self.enable_raw_queries {
             fields.push(self.create_execute_raw_field());
             fields.push(self.create_query_raw_field());
+            fields.push(self.create_query_raw_typed_field());
         }
 
         let strong_ref = Arc::new(object_type("Mutation", fields, None));
@@ -227,7 +229,9 @@ impl<'a> QuerySchemaBuilder<'a> {
 
     /// Builds a "multiple" query arity items field (e.g. "users", "posts", ...) for given model.
     fn all_items_field(&self, model: ModelRef) -> Field {
-        let args = self.object_type_builder.many_records_arguments(&model);
+        let mut args = self.object_type_builder.many_records_arguments(&model);
+        args.push(self.object_type_builder.distinct_argument(&model));
+
         let field_name = self.pluralize_internal(
             camel_case(pluralize(model.name.clone())),
             format!("findMany{}", model.name.clone()),
@@ -311,8 +315,31 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Like `queryRaw`, returning `{ columns, types, rows }` with the values
+    /// of every row encoded according to the type of their column, instead of
+    /// an object for every row.
+    fn create_query_raw_typed_field(&self) -> Field {
+        field(
+            "queryRawTyped",
+            vec![
+                argument("query", InputType::string(), None),
+                argument(
+                    "parameters",
+                    InputType::opt(InputType::json_list()),
+                    Some(dml::DefaultValue::Single(PrismaValue::String("[]".into()))),
+                ),
+            ],
+            OutputType::json(),
+            None,
+        )
+    }
+
     /// Builds a create mutation field (e.g. createUser) for given model.
-    fn create_item_field(&self, model: ModelRef) -> Field {
+    fn create_item_field(&self, model: ModelRef) -> Option<Field> {
+        if !model.supports_create_operation() {
+            return None;
+        }
+
         let args = self
             .argument_builder
             .create_arguments(Arc::clone(&model))
@@ -323,7 +350,7 @@ impl<'a> QuerySchemaBuilder<'a> {
             format!("createOne{}", model.name.clone()),
         );
 
-        field(
+        Some(field(
             field_name,
             args,
             OutputType::object(self.object_type_builder.map_model_object_type(&model)),
@@ -337,7 +364,7 @@ impl<'a> QuerySchemaBuilder<'a> {
                     Ok(graph)
                 }),
             ))),
-        )
+        ))
     }
 
     /// Builds a delete mutation field (e.g. deleteUser) for given model.
@@ -446,6 +473,10 @@ impl<'a> QuerySchemaBuilder<'a> {
 
     /// Builds an upsert mutation field (e.g. upsertUser) for given model.
     fn upsert_item_field(&self, model: ModelRef) -> Option<Field> {
+        if !model.supports_create_operation() {
+            return None;
+        }
+
         self.argument_builder.upsert_arguments(Arc::clone(&model)).map(|args| {
             let field_name =
                 self.pluralize_internal(format!("upsert{}", model.name), format!("upsertOne{}", model.name));
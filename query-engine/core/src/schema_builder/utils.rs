@@ -50,6 +50,17 @@ where
     })
 }
 
+/// Enum type convenience wrapper function.
+pub fn field_ref_enum_type<T>(name: T, values: Vec<(String, ScalarFieldRef)>) -> EnumType
+where
+    T: Into<String>,
+{
+    EnumType::FieldRef(FieldRefEnumType {
+        name: name.into(),
+        values,
+    })
+}
+
 /// Argument convenience wrapper function.
 pub fn argument<T>(name: T, arg_type: InputType, default_value: Option<dml::DefaultValue>) -> Argument
 where
@@ -1,7 +1,7 @@
 use crate::{exec_loader, PrismaError, PrismaResult};
 use query_core::{
     schema::{QuerySchemaRef, SupportedCapabilities},
-    BuildMode, QueryExecutor, QuerySchemaBuilder,
+    BuildMode, QueryExecutor, QuerySchemaBuilder, ReplicaStrategy,
 };
 // use prisma_models::InternalDataModelRef;
 use datamodel::{Configuration, Datamodel};
@@ -22,6 +22,7 @@ pub struct PrismaContext {
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    replica_strategy: ReplicaStrategy,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -37,14 +38,32 @@ impl ContextBuilder {
         self
     }
 
+    pub fn replica_strategy(mut self, val: ReplicaStrategy) -> Self {
+        self.replica_strategy = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.replica_strategy,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        replica_strategy: ReplicaStrategy,
+    ) -> PrismaResult<Self> {
         let template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
@@ -53,8 +72,8 @@ impl PrismaContext {
             .first()
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
-        // Load executor
-        let (db_name, executor) = exec_loader::load(&data_source).await?;
+        // Load executor, routing reads to the replicas of the data source if it has any.
+        let (db_name, executor) = exec_loader::load(&data_source, replica_strategy).await?;
 
         // Build internal data model
         let internal_data_model = template.build(db_name);
@@ -80,6 +99,7 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            replica_strategy: ReplicaStrategy::default(),
             datamodel,
             config,
         }
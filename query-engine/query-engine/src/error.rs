@@ -1,5 +1,5 @@
 use connector::error::ConnectorError;
-use datamodel::error::ErrorCollection;
+use datamodel::{error::ErrorCollection, SchemaFiles};
 use failure::{Error, Fail};
 use feature_flags::FeatureFlagError;
 use graphql_parser::query::ParseError as GqlParseError;
@@ -24,7 +24,7 @@ pub enum PrismaError {
     ConnectorError(ConnectorError),
 
     #[fail(display = "{}", _0)]
-    ConversionError(ErrorCollection, String),
+    ConversionError(ErrorCollection, SchemaFiles),
 
     #[fail(display = "{}", _0)]
     IOError(Error),
@@ -56,8 +56,8 @@ impl PrismaError {
                 user_facing_error: Some(err),
                 ..
             }) => err.into(),
-            PrismaError::ConversionError(errors, dml_string) => {
-                let mut full_error = errors.to_pretty_string("schema.prisma", &dml_string);
+            PrismaError::ConversionError(errors, schema_files) => {
+                let mut full_error = errors.to_pretty_string_in_files(&schema_files);
                 write!(full_error, "\nValidation Error Count: {}", errors.to_iter().len())?;
 
                 user_facing_errors::Error::from(
@@ -5,7 +5,7 @@ use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Datasource,
 };
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
+use query_core::executor::{InterpretingExecutor, QueryExecutor, ReadReplicas, ReplicaStrategy};
 use std::{collections::HashMap, path::PathBuf};
 use url::Url;
 
@@ -15,16 +15,19 @@ use sql_connector::*;
 #[cfg(all(feature = "sql", feature = "mssql"))]
 use datamodel::configuration::MSSQL_SOURCE_NAME;
 
-pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+pub async fn load(
+    source: &Datasource,
+    replica_strategy: ReplicaStrategy,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     match source.active_provider.as_str() {
         #[cfg(feature = "sql")]
         SQLITE_SOURCE_NAME => sqlite(source).await,
 
         #[cfg(feature = "sql")]
-        MYSQL_SOURCE_NAME => mysql(source).await,
+        MYSQL_SOURCE_NAME => mysql(source, replica_strategy).await,
 
         #[cfg(feature = "sql")]
-        POSTGRES_SOURCE_NAME => postgres(source).await,
+        POSTGRES_SOURCE_NAME => postgres(source, replica_strategy).await,
 
         #[cfg(all(feature = "sql", feature = "mssql"))]
         MSSQL_SOURCE_NAME => mssql(source).await,
@@ -45,11 +48,17 @@ async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecu
     let db_name = path.file_stem().unwrap().to_str().unwrap().to_owned(); // Safe due to previous validations.
 
     trace!("Loaded SQLite connector.");
-    Ok((db_name, sql_executor("sqlite", sqlite, false)))
+    Ok((
+        db_name,
+        sql_executor("sqlite", sqlite, Vec::new(), ReplicaStrategy::default(), false),
+    ))
 }
 
 #[cfg(feature = "sql")]
-async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn postgres(
+    source: &Datasource,
+    replica_strategy: ReplicaStrategy,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading Postgres connector...");
 
     let url = Url::parse(&source.url().value)?;
@@ -61,6 +70,11 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .unwrap_or_else(|| String::from("public"));
 
     let psql = PostgreSql::from_source(source).await?;
+    let mut replicas = Vec::with_capacity(source.replicas.len());
+
+    for replica in source.replicas.iter() {
+        replicas.push(PostgreSql::from_url(&replica.value).await?);
+    }
 
     let force_transactions = params
         .get("pgbouncer")
@@ -68,14 +82,26 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .unwrap_or(false);
 
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor("postgres", psql, force_transactions)))
+    Ok((
+        db_name,
+        sql_executor("postgres", psql, replicas, replica_strategy, force_transactions),
+    ))
 }
 
 #[cfg(feature = "sql")]
-async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mysql(
+    source: &Datasource,
+    replica_strategy: ReplicaStrategy,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading MySQL connector...");
 
     let mysql = Mysql::from_source(source).await?;
+    let mut replicas = Vec::with_capacity(source.replicas.len());
+
+    for replica in source.replicas.iter() {
+        replicas.push(Mysql::from_url(&replica.value).await?);
+    }
+
     let url = Url::parse(&source.url().value)?;
     let err_str = "No database found in connection string";
 
@@ -86,7 +112,7 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = db_name.next().expect(err_str).to_owned();
 
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor("mysql", mysql, false)))
+    Ok((db_name, sql_executor("mysql", mysql, replicas, replica_strategy, false)))
 }
 
 #[cfg(all(feature = "sql", feature = "mssql"))]
@@ -111,21 +137,24 @@ async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = params.remove("database").unwrap_or_else(|| String::from("master"));
 
     trace!("Loaded SQL Server connector.");
-    Ok((db_name, sql_executor("mssql", mssql, false)))
+    Ok((
+        db_name,
+        sql_executor("mssql", mssql, Vec::new(), ReplicaStrategy::default(), false),
+    ))
 }
 
 #[cfg(feature = "sql")]
 fn sql_executor<T>(
     primary_connector: &'static str,
     connector: T,
+    replicas: Vec<T>,
+    replica_strategy: ReplicaStrategy,
     force_transactions: bool,
 ) -> Box<dyn QueryExecutor + Send + Sync + 'static>
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(
-        connector,
-        primary_connector,
-        force_transactions,
-    ))
+    let replicas = ReadReplicas::new(replicas, replica_strategy);
+
+    Box::new(InterpretingExecutor::new(connector, primary_connector, force_transactions).with_read_replicas(replicas))
 }
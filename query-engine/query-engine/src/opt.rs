@@ -1,7 +1,8 @@
 use crate::{error::PrismaError, PrismaResult};
-use datamodel::{Configuration, Datamodel};
+use datamodel::{Configuration, Datamodel, SchemaFiles};
+use query_core::ReplicaStrategy;
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{ffi::OsStr, path::Path};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -53,9 +54,10 @@ pub struct PrismaOpt {
     #[structopt(long, short, env, default_value = "4466")]
     pub port: u16,
 
-    /// Path to the Prisma datamodel file
-    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_file))]
-    datamodel_path: Option<String>,
+    /// Path to the Prisma datamodel file, or to a directory with the `.prisma` files of a datamodel
+    /// split across multiple files
+    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_files))]
+    datamodel_path: Option<SchemaFiles>,
 
     /// Base64 encoded Prisma datamodel
     #[structopt(long, env = "PRISMA_DML", parse(try_from_str = parse_base64_string))]
@@ -69,10 +71,18 @@ pub struct PrismaOpt {
     #[structopt(long, short)]
     pub legacy: bool,
 
-    /// Enables raw SQL queries with executeRaw/queryRaw mutation
+    /// Enables raw SQL queries with executeRaw/queryRaw/queryRawTyped mutation
     #[structopt(long, short = "r")]
     pub enable_raw_queries: bool,
 
+    /// How reads are spread over the read replicas of the datasource: `round-robin` or `least-connections`.
+    #[structopt(long, env = "PRISMA_REPLICA_STRATEGY", default_value = "round-robin")]
+    pub replica_strategy: ReplicaStrategy,
+
+    /// Number of records read per batch by streamed `findMany` queries on the `/stream` endpoint.
+    #[structopt(long, env = "PRISMA_STREAM_BATCH_SIZE", default_value = "1000")]
+    pub stream_batch_size: usize,
+
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
@@ -95,12 +105,12 @@ struct SourceOverride {
 }
 
 impl PrismaOpt {
-    fn datamodel_str(&self) -> PrismaResult<&str> {
+    fn schema_files(&self) -> PrismaResult<SchemaFiles> {
         let res = self
             .datamodel
             .as_ref()
-            .map(|dm| dm.as_str())
-            .or(self.datamodel_path.as_ref().map(|dm| dm.as_str()))
+            .map(|dm| SchemaFiles::single("schema.prisma", dm))
+            .or_else(|| self.datamodel_path.clone())
             .ok_or_else(|| {
                 PrismaError::ConfigurationError(
                     "Datamodel should be provided either as path or base64-encoded string.".into(),
@@ -111,7 +121,8 @@ impl PrismaOpt {
     }
 
     pub fn datamodel(&self, ignore_env_errors: bool) -> PrismaResult<Datamodel> {
-        let datamodel_str = self.datamodel_str()?;
+        let schema_files = self.schema_files()?;
+        let datamodel_str = schema_files.combined();
 
         let datamodel = if ignore_env_errors {
             datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_str)
@@ -120,13 +131,14 @@ impl PrismaOpt {
         };
 
         match datamodel {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, schema_files)),
             _ => Ok(datamodel?),
         }
     }
 
     pub fn configuration(&self, ignore_env_errors: bool) -> PrismaResult<Configuration> {
-        let datamodel_str = self.datamodel_str()?;
+        let schema_files = self.schema_files()?;
+        let datamodel_str = schema_files.combined();
 
         let datasource_url_overrides: Vec<(String, String)> = if let Some(ref json) = self.overwrite_datasources {
             let datasource_url_overrides: Vec<SourceOverride> = serde_json::from_str(&json)?;
@@ -141,7 +153,7 @@ impl PrismaOpt {
             datamodel::parse_configuration_with_url_overrides(datamodel_str, datasource_url_overrides)
         };
 
-        config_result.map_err(|errors| PrismaError::ConversionError(errors, datamodel_str.to_string()))
+        config_result.map_err(|errors| PrismaError::ConversionError(errors, schema_files))
     }
 }
 
@@ -159,12 +171,6 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
-fn load_datamodel_file(path: &OsStr) -> String {
-    let mut f = File::open(path).expect(&format!("Could not open datamodel file {:?}", path));
-    let mut datamodel = String::new();
-
-    f.read_to_string(&mut datamodel)
-        .expect(&format!("Could not read datamodel file: {:?}", path));
-
-    datamodel
+fn load_datamodel_files(path: &OsStr) -> SchemaFiles {
+    SchemaFiles::load(Path::new(path)).expect(&format!("Could not read datamodel files at {:?}", path))
 }
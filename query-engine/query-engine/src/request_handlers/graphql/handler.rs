@@ -1,9 +1,16 @@
 use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
-use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
-use futures::FutureExt;
+use crate::{context::PrismaContext, PrismaError, PrismaResponse, PrismaResult};
+use futures::{
+    channel::mpsc,
+    future::{self, Either},
+    stream::{self, Stream},
+    FutureExt, StreamExt,
+};
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    BatchDocument, CompactedDocument, CoreError, Item, Operation, QueryDocument, QueryValue, ResponseData,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
@@ -88,6 +95,39 @@ pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaR
     }
 }
 
+/// Handle a GraphQL request for a top-level `findMany` in streaming mode. Every batch of records read is
+/// yielded as a separate response. Only single queries can be streamed.
+pub(crate) fn handle_streamed(
+    body: GraphQlBody,
+    cx: Arc<PrismaContext>,
+    batch_size: usize,
+) -> impl Stream<Item = GQLResponse> + Send + Sync + Unpin {
+    debug!("Incoming streamed GraphQL query: {:?}", body);
+
+    let query = match body.into_doc() {
+        Ok(QueryDocument::Single(query)) => query,
+        Ok(QueryDocument::Multi(_)) => {
+            let err = CoreError::UnsupportedFeatureError("Streamed batch queries.".to_owned());
+            return Either::Right(stream::once(future::ready(GQLResponse::from(PrismaError::from(err)))));
+        }
+        Err(err) => return Either::Right(stream::once(future::ready(GQLResponse::from(err)))),
+    };
+
+    // A single batch in flight keeps memory bounded while the client consumes the previous one.
+    let (sender, receiver) = mpsc::channel(1);
+
+    async_std::task::spawn(async move {
+        cx.executor
+            .execute_streamed(query, Arc::clone(cx.query_schema()), batch_size, sender)
+            .await
+    });
+
+    Either::Left(receiver.map(|result| match result {
+        Ok(data) => GQLResponse::from(data),
+        Err(err) => GQLResponse::from(PrismaError::from(err)),
+    }))
+}
+
 async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
     use user_facing_errors::Error;
 
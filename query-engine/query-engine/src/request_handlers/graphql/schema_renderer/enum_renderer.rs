@@ -27,6 +27,7 @@ impl<'a> GqlEnumRenderer<'a> {
         match self.enum_type {
             EnumType::Internal(i) => i.external_values(),
             EnumType::OrderBy(ord) => ord.values(),
+            EnumType::FieldRef(f) => f.values(),
         }
     }
 }
@@ -7,11 +7,13 @@ use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody}
 use crate::PrismaResult;
 use elapsed_middleware::ElapsedMiddleware;
 
+use futures::{StreamExt, TryStreamExt};
 use query_core::schema::QuerySchemaRenderer;
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{Body, Request, Response};
 
+use std::io;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
@@ -22,15 +24,17 @@ pub(crate) struct State {
     cx: Arc<PrismaContext>,
     enable_playground: bool,
     enable_debug_mode: bool,
+    stream_batch_size: usize,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool, stream_batch_size: usize) -> Self {
         Self {
             cx: Arc::new(cx),
             enable_playground,
             enable_debug_mode,
+            stream_batch_size,
         }
     }
 }
@@ -41,6 +45,7 @@ impl Clone for State {
             cx: self.cx.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
+            stream_batch_size: self.stream_batch_size,
         }
     }
 }
@@ -54,14 +59,21 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .replica_strategy(opts.replica_strategy)
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let mut app = tide::with_state(State::new(
+        cx,
+        opts.enable_playground,
+        opts.enable_debug_mode,
+        opts.stream_batch_size,
+    ));
     app.middleware(ElapsedMiddleware::new());
 
     app.at("/").post(graphql_handler);
     app.at("/").get(playground_handler);
+    app.at("/stream").post(stream_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
@@ -90,6 +102,27 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
     Ok(res)
 }
 
+/// Handler for streamed `findMany` queries. The records are read in batches and sent as they arrive, using
+/// chunked transfer encoding. Every batch is a separate GraphQL response on its own line (NDJSON), an error
+/// ends the stream as the last line.
+async fn stream_handler(mut req: Request<State>) -> tide::Result {
+    let body: GraphQlBody = req.body_json().await?;
+    let cx = req.state().cx.clone();
+    let batch_size = req.state().stream_batch_size;
+
+    let lines = graphql::handle_streamed(body, cx, batch_size).map(|response| -> io::Result<Vec<u8>> {
+        let mut line = serde_json::to_vec(&response).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        line.push(b'\n');
+
+        Ok(line)
+    });
+
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(Body::from_reader(lines.into_async_read(), None));
+    res.set_content_type("application/x-ndjson");
+    Ok(res)
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -1,4 +1,6 @@
+mod distinct_reads;
 mod dmmf;
 mod execute_raw;
+mod streamed_reads;
 mod test_api;
 mod type_mappings;
@@ -0,0 +1,104 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static USER_POSTS: &str = indoc! {"
+    model User {
+        id      Int    @id
+        country String
+        posts   Post[]
+    }
+
+    model Post {
+        id     Int    @id
+        title  String
+        userId Int
+        user   User   @relation(fields: [userId], references: [id])
+    }
+"};
+
+async fn create_users(engine: &QueryEngine) {
+    let users = vec![(1, "DE"), (2, "FR"), (3, "DE"), (4, "US"), (5, "FR")];
+
+    for (id, country) in users {
+        let mutation = format!(
+            r#"mutation {{ createOneUser(data: {{ id: {}, country: "{}" }}) {{ id }} }}"#,
+            id, country
+        );
+
+        engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn distinct_keeps_the_first_record_of_every_group(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER_POSTS).await?;
+    create_users(&query_engine).await;
+
+    let response = query_engine
+        .request("query { findManyUser(distinct: [country]) { id country } }")
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [
+            { "id": 1, "country": "DE" },
+            { "id": 2, "country": "FR" },
+            { "id": 4, "country": "US" },
+        ] } }),
+        response
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn distinct_works_with_order_skip_and_take(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER_POSTS).await?;
+    create_users(&query_engine).await;
+
+    let response = query_engine
+        .request("query { findManyUser(distinct: [country], orderBy: id_DESC) { id } }")
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 5 }, { "id": 4 }, { "id": 3 }] } }),
+        response
+    );
+
+    let response = query_engine
+        .request("query { findManyUser(distinct: [country], orderBy: id_DESC, skip: 1, take: 1) { id } }")
+        .await;
+
+    assert_eq!(json!({ "data": { "findManyUser": [{ "id": 4 }] } }), response);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn distinct_on_nested_to_many_reads(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER_POSTS).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: 1,
+                country: "DE",
+                posts: { create: [{ id: 1, title: "x" }, { id: 2, title: "x" }, { id: 3, title: "y" }] }
+            }) { id }
+        }
+    "#};
+
+    query_engine.request(mutation).await;
+
+    let response = query_engine
+        .request("query { findOneUser(where: { id: 1 }) { posts(distinct: [title]) { id title } } }")
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findOneUser": { "posts": [{ "id": 1, "title": "x" }, { "id": 3, "title": "y" }] } } }),
+        response
+    );
+
+    Ok(())
+}
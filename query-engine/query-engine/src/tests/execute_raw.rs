@@ -46,6 +46,14 @@ fn query_raw(query: &str, params: Vec<Value>) -> String {
     )
 }
 
+fn query_raw_typed(query: &str, params: serde_json::Value) -> String {
+    format!(
+        r#"mutation {{ queryRawTyped(query: "{}", parameters: "{}") }}"#,
+        query.replace("\"", "\\\""),
+        params.to_string().replace("\"", "\\\"")
+    )
+}
+
 #[test_each_connector]
 async fn select_1(api: &TestApi) -> anyhow::Result<()> {
     let query_engine = api.create_engine(&TODO).await?;
@@ -276,6 +284,117 @@ async fn arrays(api: &TestApi) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_each_connector]
+async fn typed_queries_return_columns_types_and_rows(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let dt = DateTime::parse_from_rfc3339("1996-12-19T16:39:57+00:00")?;
+    let dt: DateTime<Utc> = dt.into();
+
+    let insert = Insert::multi_into("Todo", &["id", "title", "dt"])
+        .values(("id1", "title1", Value::DateTime(None)))
+        .values(("id2", "title2", dt));
+
+    let (query, params) = api.to_sql_string(insert)?;
+    query_engine.request(execute_raw(&query, params)).await;
+
+    let select = Select::from_table("Todo")
+        .value(asterisk())
+        .order_by(Column::from("id").ascend());
+    let (query, _) = api.to_sql_string(select)?;
+    let result = query_engine.request(query_raw_typed(&query, json!([]))).await;
+
+    let (dt_type, dt_value) = match api.connection_info().sql_family() {
+        SqlFamily::Sqlite => (json!("int"), json!(851013597000u64)),
+        _ => (json!("datetime"), json!("1996-12-19T16:39:57Z")),
+    };
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRawTyped": {
+                    "columns": ["id", "title", "dt"],
+                    "types": ["string", "string", dt_type],
+                    "rows": [
+                        ["id1", "title1", serde_json::Value::Null],
+                        ["id2", "title2", dt_value]
+                    ]
+                }
+            }
+        }),
+        result
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn typed_queries_without_rows_have_unknown_column_types(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let (query, _) = api.to_sql_string(Select::from_table("Todo").value(asterisk()))?;
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRawTyped": {
+                    "columns": ["id", "title", "dt"],
+                    "types": ["unknown", "unknown", "unknown"],
+                    "rows": []
+                }
+            }
+        }),
+        query_engine.request(query_raw_typed(&query, json!([]))).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn typed_arrays(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = "SELECT ARRAY_AGG(columnInfos.attname) AS postgres_array FROM pg_attribute columnInfos";
+    let result = query_engine.request(query_raw_typed(query, json!([]))).await;
+
+    assert_eq!(json!(["postgres_array"]), result["data"]["queryRawTyped"]["columns"]);
+    assert_eq!(json!(["array"]), result["data"]["queryRawTyped"]["types"]);
+
+    let array = result["data"]["queryRawTyped"]["rows"][0][0].as_array().unwrap();
+
+    for val in array.into_iter() {
+        assert!(val.is_string());
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn typed_values_round_trip(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = "SELECT $1::numeric AS decimal, $2::int8 AS bigint, decode('0102', 'hex') AS bytes";
+    let params = json!([
+        { "prisma__type": "decimal", "prisma__value": "1.23456789012345678901" },
+        { "prisma__type": "bigint", "prisma__value": "9007199254740993" },
+    ]);
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRawTyped": {
+                    "columns": ["decimal", "bigint", "bytes"],
+                    "types": ["decimal", "bigint", "bytes"],
+                    "rows": [["1.23456789012345678901", "9007199254740993", "AQI="]]
+                }
+            }
+        }),
+        query_engine.request(query_raw_typed(query, params)).await
+    );
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn syntactic_errors_bubbling_through_to_the_user(api: &TestApi) -> anyhow::Result<()> {
     let query_engine = api.create_engine(&TODO).await?;
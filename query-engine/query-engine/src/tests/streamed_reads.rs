@@ -0,0 +1,92 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static ITEM: &str = indoc! {"
+    model Item {
+        id    Int    @id
+        title String
+    }
+"};
+
+async fn create_items(engine: &QueryEngine, count: i64) {
+    for id in 1..=count {
+        let mutation = format!(
+            r#"mutation {{ createOneItem(data: {{ id: {}, title: "item {}" }}) {{ id }} }}"#,
+            id, id
+        );
+
+        engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn streams_all_records_in_batches(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(&query_engine, 5).await;
+
+    let batches = query_engine
+        .request_streamed("query { findManyItem { title } }", 2)
+        .await;
+
+    assert_eq!(
+        vec![
+            json!({ "data": { "findManyItem": [{ "title": "item 1" }, { "title": "item 2" }] } }),
+            json!({ "data": { "findManyItem": [{ "title": "item 3" }, { "title": "item 4" }] } }),
+            json!({ "data": { "findManyItem": [{ "title": "item 5" }] } }),
+        ],
+        batches
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn respects_skip_take_and_order(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(&query_engine, 6).await;
+
+    let batches = query_engine
+        .request_streamed("query { findManyItem(orderBy: id_DESC, skip: 1, take: 3) { id } }", 2)
+        .await;
+
+    assert_eq!(
+        vec![
+            json!({ "data": { "findManyItem": [{ "id": 5 }, { "id": 4 }] } }),
+            json!({ "data": { "findManyItem": [{ "id": 3 }] } }),
+        ],
+        batches
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rejects_anything_but_find_many(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+
+    let batches = query_engine
+        .request_streamed("query { findOneItem(where: { id: 1 }) { id } }", 2)
+        .await;
+
+    assert_eq!(1, batches.len());
+    assert!(batches[0]["errors"].is_array());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rejects_distinct_reads(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(&query_engine, 3).await;
+
+    let batches = query_engine
+        .request_streamed("query { findManyItem(distinct: [title]) { id } }", 2)
+        .await;
+
+    assert_eq!(1, batches.len());
+    assert!(batches[0]["errors"].is_array());
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use crate::{
     request_handlers::{graphql, GraphQlBody, SingleQuery},
     PrismaResponse,
 };
+use futures::StreamExt;
 use migration_connector::*;
 use migration_core::{
     api::{GenericApi, MigrationApi},
@@ -34,6 +35,16 @@ impl QueryEngine {
             _ => unreachable!(),
         }
     }
+
+    pub async fn request_streamed(&self, body: impl Into<SingleQuery>, batch_size: usize) -> Vec<serde_json::Value> {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+
+        graphql::handle_streamed(body, cx, batch_size)
+            .map(|response| serde_json::to_value(response).unwrap())
+            .collect()
+            .await
+    }
 }
 
 pub struct TestApi {
@@ -52,6 +63,8 @@ impl TestApi {
             assume_to_be_applied: Some(Vec::new()),
             datamodel: datamodel_string.clone(),
             migration_id: migration_id.clone(),
+            renames: None,
+            render_script: None,
         };
 
         self.migration_api.reset(&serde_json::Value::Null).await?;
@@ -61,6 +74,8 @@ impl TestApi {
             force: Some(true),
             migration_id,
             steps: result.datamodel_steps,
+            renames: None,
+            data_migrations: None,
         };
 
         self.migration_api.apply_migration(&apply_input).await?;